@@ -4,6 +4,9 @@ mod client_core;
 mod platform;
 mod runtime;
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn run_native() {
     platform::native::run();
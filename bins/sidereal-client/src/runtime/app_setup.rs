@@ -104,6 +104,10 @@ fn init_tactical_resources(app: &mut App) {
     app.insert_resource(TacticalFogCache::default());
     app.insert_resource(TacticalContactsCache::default());
     app.insert_resource(TacticalResnapshotRequestState::default());
+    app.insert_resource(PendingWeaponGroupAssignments::default());
+    app.insert_resource(PendingMarketTrades::default());
+    app.insert_resource(PendingCraftingJobs::default());
+    app.insert_resource(MarketSnapshotCache::default());
     app.insert_resource(TacticalMapUiState::default());
     app.insert_resource(TacticalSensorRingUiState::default());
     app.insert_resource(ActiveScannerProfileCache::default());
@@ -159,6 +163,7 @@ pub(crate) fn configure_client_runtime(
     app.add_message::<EntityDestroyedEvent>();
     app.add_message::<combat_messages::RemoteWeaponFiredRuntimeMessage>();
     app.add_message::<combat_messages::RemoteEntityDestructionRuntimeMessage>();
+    app.add_message::<combat_messages::RemoteBeamWeaponRuntimeMessage>();
 
     app.insert_resource(Time::<Fixed>::from_hz(f64::from(SIM_TICK_HZ)));
     init_transport_resources(
@@ -199,6 +204,12 @@ pub(crate) fn configure_client_runtime(
             .before(visuals::receive_remote_destruction_effect_messages_system)
             .run_if(in_state(ClientAppState::InWorld)),
     );
+    app.add_systems(
+        Update,
+        combat_messages::fanout_remote_beam_weapon_messages_system
+            .before(audio::receive_remote_beam_weapon_audio_system)
+            .run_if(in_state(ClientAppState::InWorld)),
+    );
     app.add_systems(
         Update,
         (
@@ -206,6 +217,7 @@ pub(crate) fn configure_client_runtime(
             audio::receive_local_destruction_audio_system,
             audio::receive_remote_weapon_fire_audio_system,
             audio::receive_remote_destruction_audio_system,
+            audio::receive_remote_beam_weapon_audio_system,
             audio::debug_audio_probe_system,
         )
             .run_if(in_state(ClientAppState::InWorld)),
@@ -795,7 +795,10 @@ fn debug_force_listener_position(profile_id: &str) -> bool {
 fn debug_force_nonspatial_track(profile_id: &str) -> bool {
     matches!(
         profile_id,
-        "weapon.ballistic_gatling" | "destruction.asteroid.default" | "explosion_burst"
+        "weapon.ballistic_gatling"
+            | "weapon.beam_laser"
+            | "destruction.asteroid.default"
+            | "explosion_burst"
     )
 }
 
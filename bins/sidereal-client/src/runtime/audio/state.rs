@@ -1,8 +1,22 @@
 use bevy::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Resource, Default)]
 pub(crate) struct AudioAssetDemandState {
     pub desired_asset_ids: HashSet<String>,
     pub critical_asset_ids: HashSet<String>,
 }
+
+/// Beams the server reported as firing, keyed by weapon guid. Their looping
+/// `fire` cue is re-triggered every frame until the stop message arrives.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct ActiveBeamAudioState {
+    pub by_weapon_guid: HashMap<String, ActiveBeamAudio>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) struct ActiveBeamAudio {
+    pub profile_id: String,
+    pub position: Vec2,
+}
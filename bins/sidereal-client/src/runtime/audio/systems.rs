@@ -3,17 +3,20 @@ use super::catalog::AudioCatalogState;
 use super::settings::{AudioBusSettings, AudioSettings};
 use super::state::AudioAssetDemandState;
 #[cfg(not(target_arch = "wasm32"))]
+use super::state::{ActiveBeamAudio, ActiveBeamAudioState};
+#[cfg(not(target_arch = "wasm32"))]
 use crate::runtime::assets::LocalAssetManager;
 use crate::runtime::assets::{AssetCatalogHotReloadState, RuntimeAssetDependencyState};
 use crate::runtime::combat_messages::{
-    RemoteEntityDestructionRuntimeMessage, RemoteWeaponFiredRuntimeMessage,
+    RemoteBeamWeaponRuntimeMessage, RemoteEntityDestructionRuntimeMessage,
+    RemoteWeaponFiredRuntimeMessage,
 };
 use crate::runtime::components::GameplayCamera;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::runtime::resources::{AssetCacheAdapter, AssetRootPath};
 use bevy::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
-use sidereal_game::{BallisticWeapon, EntityGuid};
+use sidereal_game::{BallisticWeapon, BeamWeapon, EntityGuid};
 use sidereal_game::{EntityDestroyedEvent, ShotFiredEvent};
 use std::collections::HashSet;
 
@@ -353,6 +356,83 @@ pub(crate) fn receive_remote_weapon_fire_audio_system(
     for _ in events.read() {}
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+const BEAM_AUDIO_RELEASE_TIMEOUT_S: f64 = 0.2;
+
+#[allow(clippy::too_many_arguments)]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn receive_remote_beam_weapon_audio_system(
+    mut backend: NonSendMut<'_, AudioBackendResource>,
+    catalog: Res<'_, AudioCatalogState>,
+    settings: Res<'_, AudioSettings>,
+    asset_root: Res<'_, AssetRootPath>,
+    asset_manager: Res<'_, LocalAssetManager>,
+    cache_adapter: Res<'_, AssetCacheAdapter>,
+    time: Res<'_, Time>,
+    mut demand: ResMut<'_, AudioAssetDemandState>,
+    mut active_beams: ResMut<'_, ActiveBeamAudioState>,
+    beams: Query<'_, '_, (&'_ EntityGuid, &'_ GlobalTransform), With<BeamWeapon>>,
+    mut events: MessageReader<'_, '_, RemoteBeamWeaponRuntimeMessage>,
+) {
+    for event in events.read() {
+        let message = &event.message;
+        if !message.active {
+            active_beams.by_weapon_guid.remove(&message.weapon_guid);
+            continue;
+        }
+        let Some(profile_id) = message.audio_profile_id.clone() else {
+            continue;
+        };
+        active_beams.by_weapon_guid.insert(
+            message.weapon_guid.clone(),
+            ActiveBeamAudio {
+                profile_id,
+                position: Vec2::new(message.origin_xy[0] as f32, message.origin_xy[1] as f32),
+            },
+        );
+    }
+    if active_beams.by_weapon_guid.is_empty() {
+        return;
+    }
+    for (weapon_guid, transform) in &beams {
+        if let Some(active) = active_beams
+            .by_weapon_guid
+            .get_mut(&weapon_guid.0.to_string())
+        {
+            active.position = transform.translation().truncate();
+        }
+    }
+
+    let resolver = AudioAssetResolver {
+        asset_root: &asset_root.0,
+        asset_manager: &asset_manager,
+        cache_adapter: *cache_adapter,
+    };
+    for (weapon_guid, active) in &active_beams.by_weapon_guid {
+        backend.trigger_loop_emitter(
+            LoopEmitterRequest {
+                key: weapon_guid.clone(),
+                profile_id: active.profile_id.as_str(),
+                cue_id: "fire",
+                position: active.position,
+                release_timeout_s: BEAM_AUDIO_RELEASE_TIMEOUT_S,
+                now_s: time.elapsed_secs_f64(),
+            },
+            &resolver,
+            &catalog,
+            &settings,
+            &mut demand.desired_asset_ids,
+        );
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn receive_remote_beam_weapon_audio_system(
+    mut events: MessageReader<'_, '_, RemoteBeamWeaponRuntimeMessage>,
+) {
+    for _ in events.read() {}
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn resolve_weapon_fire_profile_id(weapon: &BallisticWeapon) -> Option<&str> {
     if let Some(profile_id) = weapon.fire_audio_profile_id.as_deref() {
@@ -1,12 +1,19 @@
 use bevy::prelude::*;
 use lightyear::prelude::MessageReceiver;
-use sidereal_net::{ServerEntityDestructionMessage, ServerWeaponFiredMessage};
+use sidereal_net::{
+    ServerBeamWeaponMessage, ServerEntityDestructionMessage, ServerWeaponFiredMessage,
+};
 
 #[derive(Debug, Clone, Message)]
 pub(crate) struct RemoteWeaponFiredRuntimeMessage {
     pub message: ServerWeaponFiredMessage,
 }
 
+#[derive(Debug, Clone, Message)]
+pub(crate) struct RemoteBeamWeaponRuntimeMessage {
+    pub message: ServerBeamWeaponMessage,
+}
+
 #[derive(Debug, Clone, Message)]
 pub(crate) struct RemoteEntityDestructionRuntimeMessage {
     pub message: ServerEntityDestructionMessage,
@@ -53,3 +60,24 @@ pub(crate) fn fanout_remote_destruction_messages_system(
         }
     }
 }
+
+pub(crate) fn fanout_remote_beam_weapon_messages_system(
+    mut beam_writer: MessageWriter<'_, RemoteBeamWeaponRuntimeMessage>,
+    mut receivers: Query<
+        '_,
+        '_,
+        &'_ mut MessageReceiver<ServerBeamWeaponMessage>,
+        (
+            With<lightyear::prelude::client::Client>,
+            With<lightyear::prelude::client::Connected>,
+        ),
+    >,
+) {
+    for mut receiver in &mut receivers {
+        for message in receiver.receive() {
+            beam_writer.write(RemoteBeamWeaponRuntimeMessage {
+                message: message.clone(),
+            });
+        }
+    }
+}
@@ -15,12 +15,27 @@ pub(crate) struct HudSpeedValueText;
 #[derive(Component)]
 pub(crate) struct HudPositionValueText;
 
+#[derive(Component)]
+pub(crate) struct HudAmmoValueText;
+
+#[derive(Component)]
+pub(crate) struct HudCreditsValueText;
+
+#[derive(Component)]
+pub(crate) struct HudMissionValueText;
+
 #[derive(Component)]
 pub(crate) struct HudHealthBarFill;
 
 #[derive(Component)]
 pub(crate) struct HudFuelBarFill;
 
+#[derive(Component)]
+pub(crate) struct HudPowerBarFill;
+
+#[derive(Component)]
+pub(crate) struct HudHeatBarFill;
+
 #[derive(Component, Clone, Copy)]
 pub(crate) struct SegmentedBarStyle {
     pub segments: u8,
@@ -270,6 +285,15 @@ pub(crate) struct StreamedVisualChild;
 #[derive(Component)]
 pub(crate) struct BallisticProjectileVisualAttached;
 
+#[derive(Component)]
+pub(crate) struct GuidedMissileVisualAttached;
+
+/// Sustained beam sprite spawned as a child of a firing `BeamWeapon` module.
+#[derive(Component)]
+pub(crate) struct BeamWeaponVisual {
+    pub beam_entity: Entity,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum RuntimeWorldVisualFamily {
     Planet,
@@ -5,8 +5,9 @@ use bevy::prelude::*;
 use lightyear::prelude::client::{Client, Connected};
 use lightyear::prelude::{MessageReceiver, MessageSender};
 use sidereal_net::{
-    ClientControlRequestMessage, ClientLocalViewMode, ClientLocalViewModeMessage, ControlChannel,
-    PlayerEntityId, ServerControlAckMessage, ServerControlRejectMessage,
+    ClientControlRequestMessage, ClientCraftingJobMessage, ClientLocalViewMode,
+    ClientLocalViewModeMessage, ClientWeaponGroupAssignmentMessage, ControlChannel, PlayerEntityId,
+    ServerControlAckMessage, ServerControlRejectMessage,
 };
 use sidereal_runtime_sync::parse_guid_from_entity_id;
 
@@ -18,7 +19,7 @@ use super::platform::safe_viewport_size;
 use super::resources::ClientViewModeState;
 use super::resources::{
     ClientControlRequestState, ClientInputAckTracker, ClientInputSendState, HeadlessTransportMode,
-    NativePredictionRecoveryState,
+    NativePredictionRecoveryState, PendingCraftingJobs, PendingWeaponGroupAssignments,
 };
 
 fn ids_refer_to_same_guid(left: &str, right: &str) -> bool {
@@ -231,6 +232,97 @@ pub fn send_local_view_mode_updates(
     state.last_sent_at_s = now_s;
 }
 
+/// Sends queued weapon-group edits for the currently controlled ship. Edits are
+/// dropped when nothing is controlled; the server re-validates ownership.
+pub fn send_weapon_group_assignments(
+    session: Res<'_, ClientSession>,
+    player_view_state: Res<'_, LocalPlayerViewState>,
+    mut pending: ResMut<'_, PendingWeaponGroupAssignments>,
+    mut senders: Query<
+        '_,
+        '_,
+        &mut MessageSender<ClientWeaponGroupAssignmentMessage>,
+        (With<Client>, With<Connected>),
+    >,
+) {
+    if pending.requests.is_empty() || senders.is_empty() {
+        return;
+    }
+    let requests = std::mem::take(&mut pending.requests);
+    let Some(player_entity_id) = session
+        .player_entity_id
+        .as_deref()
+        .and_then(PlayerEntityId::parse)
+        .map(PlayerEntityId::canonical_wire_id)
+    else {
+        return;
+    };
+    let Some(ship_entity_id) = player_view_state
+        .controlled_entity_id
+        .as_deref()
+        .and_then(parse_guid_from_entity_id)
+    else {
+        warn!("client dropped weapon group assignments; no controlled ship");
+        return;
+    };
+    for (hardpoint_id, group) in requests {
+        let message = ClientWeaponGroupAssignmentMessage {
+            player_entity_id: player_entity_id.clone(),
+            ship_entity_id: ship_entity_id.to_string(),
+            hardpoint_id,
+            group,
+        };
+        for mut sender in &mut senders {
+            sender.send::<ControlChannel>(message.clone());
+        }
+    }
+}
+
+/// Sends queued crafting jobs for the facility on the controlled ship. The
+/// server re-validates ownership and the recipe.
+pub fn send_crafting_job_requests(
+    session: Res<'_, ClientSession>,
+    player_view_state: Res<'_, LocalPlayerViewState>,
+    mut pending: ResMut<'_, PendingCraftingJobs>,
+    mut senders: Query<
+        '_,
+        '_,
+        &mut MessageSender<ClientCraftingJobMessage>,
+        (With<Client>, With<Connected>),
+    >,
+) {
+    if pending.recipe_ids.is_empty() || senders.is_empty() {
+        return;
+    }
+    let recipe_ids = std::mem::take(&mut pending.recipe_ids);
+    let Some(player_entity_id) = session
+        .player_entity_id
+        .as_deref()
+        .and_then(PlayerEntityId::parse)
+        .map(PlayerEntityId::canonical_wire_id)
+    else {
+        return;
+    };
+    let Some(facility_entity_id) = player_view_state
+        .controlled_entity_id
+        .as_deref()
+        .and_then(parse_guid_from_entity_id)
+    else {
+        warn!("client dropped crafting jobs; no controlled ship");
+        return;
+    };
+    for recipe_id in recipe_ids {
+        let message = ClientCraftingJobMessage {
+            player_entity_id: player_entity_id.clone(),
+            facility_entity_id: facility_entity_id.to_string(),
+            recipe_id,
+        };
+        for mut sender in &mut senders {
+            sender.send::<ControlChannel>(message.clone());
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn receive_lightyear_control_results(
     session: Res<'_, ClientSession>,
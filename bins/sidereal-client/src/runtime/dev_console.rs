@@ -27,6 +27,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(not(target_arch = "wasm32"))]
 use sidereal_core::logging::prepare_timestamped_log_file_in_dir;
+use sidereal_game::{MAX_WEAPON_GROUPS, MarketTradeSide, WeaponGroup};
 use sidereal_net::{
     NotificationImageRef, NotificationPayload, NotificationPlacement, NotificationSeverity,
     ServerNotificationMessage,
@@ -36,7 +37,10 @@ use uuid::Uuid;
 use super::app_state::ClientSession;
 use super::ecs_util::queue_despawn_if_exists;
 use super::notification_ui::NotificationQueue;
-use super::resources::SharedClientTransportErrorBuffer;
+use super::resources::{
+    PendingCraftingJobs, PendingMarketTrades, PendingWeaponGroupAssignments,
+    SharedClientTransportErrorBuffer,
+};
 
 const DEV_CONSOLE_MAX_BUFFER_LINES: usize = 10_000;
 const DEV_CONSOLE_VISIBLE_LINES_MAX: usize = 200;
@@ -607,12 +611,16 @@ fn animate_dev_console_system(time: Res<'_, Time>, mut state: ResMut<'_, DevCons
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_dev_console_input_system(
     mut key_events: MessageReader<'_, '_, KeyboardInput>,
     mut mouse_wheel_events: MessageReader<'_, '_, MouseWheel>,
     keys: Res<'_, ButtonInput<KeyCode>>,
     mut state: ResMut<'_, DevConsoleState>,
     mut notifications: ResMut<'_, NotificationQueue>,
+    mut weapon_groups: ResMut<'_, PendingWeaponGroupAssignments>,
+    mut market_trades: ResMut<'_, PendingMarketTrades>,
+    mut crafting_jobs: ResMut<'_, PendingCraftingJobs>,
     session: Res<'_, ClientSession>,
     time: Res<'_, Time>,
 ) {
@@ -748,6 +756,9 @@ fn handle_dev_console_input_system(
             &command,
             &mut state,
             &mut notifications,
+            &mut weapon_groups,
+            &mut market_trades,
+            &mut crafting_jobs,
             &session,
             time.elapsed_secs_f64(),
         );
@@ -758,10 +769,14 @@ fn handle_dev_console_input_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_dev_console_command(
     command: &str,
     state: &mut DevConsoleState,
     notifications: &mut NotificationQueue,
+    weapon_groups: &mut PendingWeaponGroupAssignments,
+    market_trades: &mut PendingMarketTrades,
+    crafting_jobs: &mut PendingCraftingJobs,
     session: &ClientSession,
     now_s: f64,
 ) {
@@ -794,12 +809,57 @@ fn execute_dev_console_command(
                 push_local_console_line(state, Level::WARN, "sidereal_client::dev_console", err);
             }
         },
+        "weapon_group" => match parse_weapon_group_assignment(args) {
+            Ok((hardpoint_id, group)) => {
+                push_local_console_line(
+                    state,
+                    Level::INFO,
+                    "sidereal_client::dev_console",
+                    format!("requested hardpoint={hardpoint_id} group={group}"),
+                );
+                weapon_groups.requests.push((hardpoint_id, group));
+            }
+            Err(err) => {
+                push_local_console_line(state, Level::WARN, "sidereal_client::dev_console", err);
+            }
+        },
+        "trade" => match parse_market_trade(args) {
+            Ok((item_id, side, quantity)) => {
+                push_local_console_line(
+                    state,
+                    Level::INFO,
+                    "sidereal_client::dev_console",
+                    format!(
+                        "requested {} item={item_id} quantity={quantity}",
+                        side.as_str()
+                    ),
+                );
+                market_trades.requests.push((item_id, side, quantity));
+            }
+            Err(err) => {
+                push_local_console_line(state, Level::WARN, "sidereal_client::dev_console", err);
+            }
+        },
+        "craft" => match parse_crafting_job(args) {
+            Ok(recipe_id) => {
+                push_local_console_line(
+                    state,
+                    Level::INFO,
+                    "sidereal_client::dev_console",
+                    format!("requested craft recipe={recipe_id}"),
+                );
+                crafting_jobs.recipe_ids.push(recipe_id);
+            }
+            Err(err) => {
+                push_local_console_line(state, Level::WARN, "sidereal_client::dev_console", err);
+            }
+        },
         "help" => {
             push_local_console_line(
                 state,
                 Level::INFO,
                 "sidereal_client::dev_console",
-                "commands: notify [info|success|warning|error] [top_left|top_center|top_right|bottom_left|bottom_center|bottom_right] [duration=seconds|duration=none] [title=Text] [image=asset_id] message".to_string(),
+                "commands: notify [info|success|warning|error] [top_left|top_center|top_right|bottom_left|bottom_center|bottom_right] [duration=seconds|duration=none] [title=Text] [image=asset_id] message | weapon_group <hardpoint_id> <group> | trade <buy|sell> <item_id> <quantity> | craft <recipe_id>".to_string(),
             );
         }
         _ => {
@@ -813,6 +873,55 @@ fn execute_dev_console_command(
     }
 }
 
+fn parse_weapon_group_assignment(args: &str) -> Result<(String, u8), String> {
+    let mut tokens = args.split_whitespace();
+    let (Some(hardpoint_id), Some(raw_group), None) = (tokens.next(), tokens.next(), tokens.next())
+    else {
+        return Err("usage: weapon_group <hardpoint_id> <group>".to_string());
+    };
+    let group = raw_group
+        .parse::<u8>()
+        .ok()
+        .filter(|group| WeaponGroup::is_valid_group(*group))
+        .ok_or_else(|| {
+            format!(
+                "weapon group must be 1..={}, got `{raw_group}`",
+                MAX_WEAPON_GROUPS
+            )
+        })?;
+    Ok((hardpoint_id.to_string(), group))
+}
+
+fn parse_market_trade(args: &str) -> Result<(String, MarketTradeSide, u32), String> {
+    let mut tokens = args.split_whitespace();
+    let (Some(raw_side), Some(item_id), Some(raw_quantity), None) =
+        (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+    else {
+        return Err("usage: trade <buy|sell> <item_id> <quantity>".to_string());
+    };
+    let side = match raw_side {
+        "buy" => MarketTradeSide::Buy,
+        "sell" => MarketTradeSide::Sell,
+        _ => return Err(format!("trade side must be buy or sell, got `{raw_side}`")),
+    };
+    let quantity = raw_quantity
+        .parse::<u32>()
+        .ok()
+        .filter(|quantity| *quantity > 0)
+        .ok_or_else(|| {
+            format!("trade quantity must be a positive integer, got `{raw_quantity}`")
+        })?;
+    Ok((item_id.to_string(), side, quantity))
+}
+
+fn parse_crafting_job(args: &str) -> Result<String, String> {
+    let mut tokens = args.split_whitespace();
+    let (Some(recipe_id), None) = (tokens.next(), tokens.next()) else {
+        return Err("usage: craft <recipe_id>".to_string());
+    };
+    Ok(recipe_id.to_string())
+}
+
 fn build_dev_notification(
     args: &str,
     session: &ClientSession,
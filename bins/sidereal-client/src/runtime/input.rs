@@ -40,6 +40,60 @@ pub(crate) struct PredictionGuardParams<'w, 's> {
     confirmed_ticks: Query<'w, 's, &'static ConfirmedTick>,
 }
 
+/// Held keys that fire the non-primary weapon groups; `Space` fires the
+/// primary group through `InputAxes::fire_primary`.
+const WEAPON_GROUP_FIRE_BINDINGS: [(KeyCode, EntityAction); 3] = [
+    (KeyCode::KeyF, EntityAction::FireSecondary),
+    (KeyCode::Digit3, EntityAction::FireWeaponGroup3),
+    (KeyCode::Digit4, EntityAction::FireWeaponGroup4),
+];
+
+/// Held key that reloads every mounted weapon from cargo ammo.
+const RELOAD_WEAPONS_KEY: KeyCode = KeyCode::KeyR;
+
+/// Keys that raise and lower mounted shield generators; shields keep the
+/// last requested state once the key is released.
+const SHIELD_BINDINGS: [(KeyCode, EntityAction); 2] = [
+    (KeyCode::KeyQ, EntityAction::ActivateShield),
+    (KeyCode::KeyZ, EntityAction::DeactivateShield),
+];
+
+/// Keys held to run a hull module. Every snapshot carries either the start or
+/// the stop action, so releasing the key (or losing focus) switches it off.
+const HELD_MODULE_BINDINGS: [(KeyCode, EntityAction, EntityAction); 2] = [
+    (
+        KeyCode::KeyE,
+        EntityAction::ActivateTractor,
+        EntityAction::DeactivateTractor,
+    ),
+    (
+        KeyCode::KeyB,
+        EntityAction::ActivateMiningLaser,
+        EntityAction::DeactivateMiningLaser,
+    ),
+];
+
+/// Held key that engages autopilot toward the selected target; any manual
+/// flight input disengages it server-side.
+const ENGAGE_AUTOPILOT_KEY: KeyCode = KeyCode::KeyN;
+
+/// Key that docks with (or undocks from) a station port in range; the server
+/// acts once per press however long the key is held.
+const INITIATE_DOCKING_KEY: KeyCode = KeyCode::KeyL;
+
+/// Held key that field-repairs damaged modules with cargo repair kits.
+const REPAIR_MODULES_KEY: KeyCode = KeyCode::KeyG;
+
+/// Key that jettisons the selected cargo stack (or the whole hold); the server
+/// drops one container per press however long the key is held.
+const DEPLOY_CARGO_KEY: KeyCode = KeyCode::KeyJ;
+
+/// Held keys that buy hull repairs and fuel from a nearby or docked station.
+const STATION_SERVICE_BINDINGS: [(KeyCode, EntityAction); 2] = [
+    (KeyCode::KeyH, EntityAction::PurchaseHullRepair),
+    (KeyCode::KeyT, EntityAction::PurchaseRefuel),
+];
+
 pub(crate) fn player_input_from_keyboard(
     input: Option<&ButtonInput<KeyCode>>,
 ) -> (PlayerInput, InputAxes) {
@@ -72,10 +126,49 @@ pub(crate) fn player_input_from_keyboard(
         afterburner,
         fire_primary,
     };
-    (
-        PlayerInput::from_axis_inputs(thrust, turn, brake, afterburner, fire_primary),
-        axes,
-    )
+    let mut player_input =
+        PlayerInput::from_axis_inputs(thrust, turn, brake, afterburner, fire_primary);
+    if let Some(keys) = input {
+        player_input.actions.extend(
+            WEAPON_GROUP_FIRE_BINDINGS
+                .iter()
+                .filter(|(key, _)| keys.pressed(*key))
+                .map(|(_, action)| *action),
+        );
+        if keys.pressed(RELOAD_WEAPONS_KEY) {
+            player_input.actions.push(EntityAction::ReloadWeapons);
+        }
+        player_input.actions.extend(
+            SHIELD_BINDINGS
+                .iter()
+                .filter(|(key, _)| keys.pressed(*key))
+                .map(|(_, action)| *action),
+        );
+        player_input
+            .actions
+            .extend(HELD_MODULE_BINDINGS.iter().map(|(key, start, stop)| {
+                if keys.pressed(*key) { *start } else { *stop }
+            }));
+        if keys.pressed(ENGAGE_AUTOPILOT_KEY) {
+            player_input.actions.push(EntityAction::EngageAutopilot);
+        }
+        if keys.pressed(INITIATE_DOCKING_KEY) {
+            player_input.actions.push(EntityAction::InitiateDocking);
+        }
+        if keys.pressed(REPAIR_MODULES_KEY) {
+            player_input.actions.push(EntityAction::RepairModules);
+        }
+        if keys.pressed(DEPLOY_CARGO_KEY) {
+            player_input.actions.push(EntityAction::DeployCargo);
+        }
+        player_input.actions.extend(
+            STATION_SERVICE_BINDINGS
+                .iter()
+                .filter(|(key, _)| keys.pressed(*key))
+                .map(|(_, action)| *action),
+        );
+    }
+    (player_input, axes)
 }
 
 pub(crate) fn neutral_player_input() -> (PlayerInput, InputAxes) {
@@ -86,21 +179,29 @@ pub(crate) fn neutral_player_input() -> (PlayerInput, InputAxes) {
         afterburner: false,
         fire_primary: false,
     };
-    (
-        PlayerInput::from_axis_inputs(0.0, 0.0, false, false, false),
-        axes,
-    )
+    let mut player_input = PlayerInput::from_axis_inputs(0.0, 0.0, false, false, false);
+    player_input
+        .actions
+        .extend(HELD_MODULE_BINDINGS.iter().map(|(_, _, stop)| *stop));
+    (player_input, axes)
+}
+
+fn is_neutral_action(action: EntityAction) -> bool {
+    matches!(
+        action,
+        EntityAction::LongitudinalNeutral
+            | EntityAction::LateralNeutral
+            | EntityAction::AfterburnerOff
+    ) || HELD_MODULE_BINDINGS
+        .iter()
+        .any(|(_, _, stop)| *stop == action)
 }
 
 fn player_input_has_active_intent(input: &PlayerInput) -> bool {
-    input.actions.iter().any(|action| {
-        !matches!(
-            action,
-            EntityAction::LongitudinalNeutral
-                | EntityAction::LateralNeutral
-                | EntityAction::AfterburnerOff
-        )
-    })
+    input
+        .actions
+        .iter()
+        .any(|action| !is_neutral_action(*action))
 }
 
 #[derive(SystemParam)]
@@ -483,14 +584,13 @@ mod tests {
         assert!(!axes.brake);
         assert!(!axes.afterburner);
         assert!(!axes.fire_primary);
-        assert!(input.actions.iter().all(|action| {
-            matches!(
-                action,
-                sidereal_game::EntityAction::LongitudinalNeutral
-                    | sidereal_game::EntityAction::LateralNeutral
-                    | sidereal_game::EntityAction::AfterburnerOff
-            )
-        }));
+        assert!(
+            input
+                .actions
+                .iter()
+                .all(|action| is_neutral_action(*action))
+        );
+        assert!(input.actions.contains(&EntityAction::DeactivateTractor));
     }
 
     #[test]
@@ -510,6 +610,118 @@ mod tests {
         assert!(player_input_has_active_intent(&fire));
     }
 
+    #[test]
+    fn weapon_group_keys_emit_group_fire_actions() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyF);
+        keys.press(KeyCode::Digit4);
+
+        let (input, axes) = player_input_from_keyboard(Some(&keys));
+
+        assert!(!axes.fire_primary);
+        assert!(input.actions.contains(&EntityAction::FireSecondary));
+        assert!(input.actions.contains(&EntityAction::FireWeaponGroup4));
+        assert!(!input.actions.contains(&EntityAction::FireWeaponGroup3));
+        assert!(player_input_has_active_intent(&input));
+    }
+
+    #[test]
+    fn reload_key_emits_reload_action() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyR);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::ReloadWeapons));
+        assert!(player_input_has_active_intent(&input));
+    }
+
+    #[test]
+    fn shield_keys_emit_shield_toggle_actions() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyQ);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::ActivateShield));
+        assert!(!input.actions.contains(&EntityAction::DeactivateShield));
+        assert!(player_input_has_active_intent(&input));
+    }
+
+    #[test]
+    fn held_module_keys_start_while_held_and_stop_on_release() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyE);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+        assert!(input.actions.contains(&EntityAction::ActivateTractor));
+        assert!(input.actions.contains(&EntityAction::DeactivateMiningLaser));
+        assert!(player_input_has_active_intent(&input));
+
+        keys.press(KeyCode::KeyB);
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+        assert!(input.actions.contains(&EntityAction::ActivateMiningLaser));
+
+        keys.release(KeyCode::KeyE);
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+        assert!(input.actions.contains(&EntityAction::DeactivateTractor));
+        assert!(!player_input_has_active_intent(&input));
+    }
+
+    #[test]
+    fn autopilot_key_emits_engage_action() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyN);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::EngageAutopilot));
+        assert!(input.actions.contains(&EntityAction::LongitudinalNeutral));
+    }
+
+    #[test]
+    fn docking_key_emits_docking_action() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyL);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::InitiateDocking));
+    }
+
+    #[test]
+    fn repair_key_emits_repair_action() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyG);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::RepairModules));
+        assert!(!input.actions.contains(&EntityAction::ReloadWeapons));
+    }
+
+    #[test]
+    fn deploy_cargo_key_emits_jettison_action() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyJ);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::DeployCargo));
+    }
+
+    #[test]
+    fn station_service_keys_emit_purchase_actions() {
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyH);
+        keys.press(KeyCode::KeyT);
+
+        let (input, _) = player_input_from_keyboard(Some(&keys));
+
+        assert!(input.actions.contains(&EntityAction::PurchaseHullRepair));
+        assert!(input.actions.contains(&EntityAction::PurchaseRefuel));
+    }
+
     #[test]
     fn headless_input_script_parses_forward_duration() {
         let script = parse_headless_input_script("forward:2.5").unwrap();
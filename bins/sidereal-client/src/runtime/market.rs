@@ -0,0 +1,152 @@
+//! Market snapshots from the server and trade requests for the controlled ship.
+
+use bevy::log::warn;
+use bevy::prelude::*;
+use lightyear::prelude::client::{Client, Connected};
+use lightyear::prelude::{MessageReceiver, MessageSender};
+use sidereal_game::MarketTradeSide;
+use sidereal_net::{
+    ClientMarketTradeMessage, ControlChannel, PlayerEntityId, ServerMarketSnapshotMessage,
+};
+use sidereal_runtime_sync::parse_guid_from_entity_id;
+
+use super::app_state::{ClientSession, LocalPlayerViewState};
+use super::resources::{CachedMarketSnapshot, MarketSnapshotCache, PendingMarketTrades};
+
+/// The server refreshes in-range markets every second; anything older has
+/// fallen out of trading range.
+const MARKET_SNAPSHOT_STALE_S: f64 = 3.0;
+
+fn local_player_canonical_id(session: &ClientSession) -> Option<String> {
+    session
+        .player_entity_id
+        .as_deref()
+        .and_then(PlayerEntityId::parse)
+        .map(PlayerEntityId::canonical_wire_id)
+}
+
+pub(crate) fn apply_market_snapshot(
+    cache: &mut MarketSnapshotCache,
+    local_player_id: &str,
+    message: ServerMarketSnapshotMessage,
+    now_s: f64,
+) {
+    if message.player_entity_id != local_player_id {
+        return;
+    }
+    cache.markets_by_entity_id.insert(
+        message.market_entity_id.clone(),
+        CachedMarketSnapshot {
+            snapshot: message,
+            received_at_s: now_s,
+        },
+    );
+}
+
+pub(crate) fn prune_stale_market_snapshots(cache: &mut MarketSnapshotCache, now_s: f64) {
+    cache
+        .markets_by_entity_id
+        .retain(|_, cached| now_s - cached.received_at_s <= MARKET_SNAPSHOT_STALE_S);
+}
+
+/// Freshest cached market that quotes `item_id` in the requested direction.
+pub(crate) fn market_for_trade<'a>(
+    cache: &'a MarketSnapshotCache,
+    item_id: &str,
+    side: MarketTradeSide,
+) -> Option<&'a str> {
+    cache
+        .markets_by_entity_id
+        .iter()
+        .filter(|(_, cached)| {
+            cached.snapshot.quotes.iter().any(|quote| {
+                quote.item_id == item_id
+                    && match side {
+                        MarketTradeSide::Buy => quote.buy_price.is_some(),
+                        MarketTradeSide::Sell => quote.sell_price.is_some(),
+                    }
+            })
+        })
+        .max_by(|(_, left), (_, right)| left.received_at_s.total_cmp(&right.received_at_s))
+        .map(|(market_entity_id, _)| market_entity_id.as_str())
+}
+
+pub fn receive_market_snapshot_messages(
+    session: Res<'_, ClientSession>,
+    time: Res<'_, Time<Real>>,
+    mut cache: ResMut<'_, MarketSnapshotCache>,
+    mut receivers: Query<
+        '_,
+        '_,
+        &mut MessageReceiver<ServerMarketSnapshotMessage>,
+        (With<Client>, With<Connected>),
+    >,
+) {
+    let now_s = time.elapsed_secs_f64();
+    let Some(local_player_id) = local_player_canonical_id(&session) else {
+        return;
+    };
+    if cache.player_entity_id.as_deref() != Some(local_player_id.as_str()) {
+        *cache = MarketSnapshotCache {
+            player_entity_id: Some(local_player_id.clone()),
+            ..default()
+        };
+    }
+    for mut receiver in &mut receivers {
+        for message in receiver.receive() {
+            apply_market_snapshot(&mut cache, &local_player_id, message, now_s);
+        }
+    }
+    prune_stale_market_snapshots(&mut cache, now_s);
+}
+
+/// Sends queued trades for the currently controlled ship. The server
+/// re-validates range, stock, cargo and credits.
+pub fn send_market_trade_requests(
+    session: Res<'_, ClientSession>,
+    player_view_state: Res<'_, LocalPlayerViewState>,
+    cache: Res<'_, MarketSnapshotCache>,
+    mut pending: ResMut<'_, PendingMarketTrades>,
+    mut senders: Query<
+        '_,
+        '_,
+        &mut MessageSender<ClientMarketTradeMessage>,
+        (With<Client>, With<Connected>),
+    >,
+) {
+    if pending.requests.is_empty() || senders.is_empty() {
+        return;
+    }
+    let requests = std::mem::take(&mut pending.requests);
+    let Some(player_entity_id) = local_player_canonical_id(&session) else {
+        return;
+    };
+    let Some(ship_entity_id) = player_view_state
+        .controlled_entity_id
+        .as_deref()
+        .and_then(parse_guid_from_entity_id)
+    else {
+        warn!("client dropped market trades; no controlled ship");
+        return;
+    };
+    for (item_id, side, quantity) in requests {
+        let Some(market_entity_id) = market_for_trade(&cache, &item_id, side) else {
+            warn!(
+                "client dropped market trade item={item_id} side={}; no market in range trades it",
+                side.as_str()
+            );
+            continue;
+        };
+        let message = ClientMarketTradeMessage {
+            player_entity_id: player_entity_id.clone(),
+            ship_entity_id: ship_entity_id.to_string(),
+            market_entity_id: market_entity_id.to_string(),
+            item_id,
+            side,
+            quantity,
+        };
+        for mut sender in &mut senders {
+            sender.send::<ControlChannel>(message.clone());
+        }
+    }
+}
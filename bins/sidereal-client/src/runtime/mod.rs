@@ -20,6 +20,7 @@ mod debug_overlay;
 mod input;
 mod lighting;
 mod logout;
+pub(crate) mod market;
 mod motion;
 mod notification_ui;
 mod owner_manifest;
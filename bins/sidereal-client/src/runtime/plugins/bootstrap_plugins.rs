@@ -90,6 +90,12 @@ fn add_headless_transport_systems(app: &mut App) {
             transport::ensure_client_transport_channels,
             transport::ensure_client_notification_message_components
                 .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_weapon_group_message_components
+                .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_market_message_components
+                .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_crafting_message_components
+                .after(transport::ensure_client_transport_channels),
             transport::adapt_client_timeline_tuning_for_window_focus,
             transport::handle_lightyear_udp_receive_error_system,
             transport::handle_unexpected_server_disconnect_system,
@@ -113,6 +119,12 @@ fn add_windowed_transport_systems(app: &mut App) {
             transport::ensure_client_transport_channels,
             transport::ensure_client_notification_message_components
                 .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_weapon_group_message_components
+                .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_market_message_components
+                .after(transport::ensure_client_transport_channels),
+            transport::ensure_client_crafting_message_components
+                .after(transport::ensure_client_transport_channels),
             transport::adapt_client_timeline_tuning_for_window_focus,
             transport::handle_lightyear_udp_receive_error_system,
             transport::handle_unexpected_server_disconnect_system,
@@ -52,6 +52,9 @@ impl Plugin for ClientVisualsPlugin {
             visuals::attach_ballistic_projectile_visuals_system
                 .after(visuals::sync_unadopted_ballistic_projectile_visual_roots_system)
                 .after(visuals::suppress_duplicate_predicted_interpolated_visuals_system),
+            visuals::attach_guided_missile_visuals_system
+                .after(visuals::suppress_duplicate_predicted_interpolated_visuals_system),
+            visuals::sync_beam_weapon_visuals_system,
             visuals::attach_thruster_plume_visuals_system
                 .after(visuals::suppress_duplicate_predicted_interpolated_visuals_system),
         );
@@ -7,7 +7,7 @@ use crate::runtime::motion::{
     seed_controlled_predicted_motion_from_confirmed,
 };
 use crate::runtime::{
-    assets, backdrop, control, input, owner_manifest, replication, tactical, transforms,
+    assets, backdrop, control, input, market, owner_manifest, replication, tactical, transforms,
 };
 
 pub(crate) struct ClientReplicationPlugin {
@@ -78,6 +78,12 @@ fn add_shared_replication_control_systems(app: &mut App) {
             control::send_lightyear_control_requests.after(control::send_local_view_mode_updates),
             control::receive_lightyear_control_results
                 .after(control::send_lightyear_control_requests),
+            control::send_weapon_group_assignments
+                .after(control::receive_lightyear_control_results),
+            control::send_crafting_job_requests.after(control::receive_lightyear_control_results),
+            market::receive_market_snapshot_messages
+                .after(control::receive_lightyear_control_results),
+            market::send_market_trade_requests.after(market::receive_market_snapshot_messages),
             replication::sync_controlled_entity_tags_system
                 .after(transforms::sync_confirmed_world_entity_transforms_from_world_space)
                 .after(control::receive_lightyear_control_results),
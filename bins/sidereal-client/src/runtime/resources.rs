@@ -10,7 +10,7 @@ use sidereal_core::gateway_dtos::{
     EnterWorldResponse, LoginRequest, MeResponse, PasswordLoginResponse,
     StartupAssetManifestResponse, TotpLoginChallengeRequest,
 };
-use sidereal_game::{EntityAction, ScannerContactDetailTier};
+use sidereal_game::{EntityAction, MarketTradeSide, ScannerContactDetailTier};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
@@ -148,6 +148,42 @@ pub(crate) struct TacticalResnapshotRequestState {
     pub last_contacts_snapshot_received_at_s: f64,
 }
 
+/// Weapon-group edits queued by the dev console until the control channel is
+/// available; entries are `(hardpoint_id, group)` for the controlled ship.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct PendingWeaponGroupAssignments {
+    pub requests: Vec<(String, u8)>,
+}
+
+/// Crafting jobs queued by the dev console until the control channel is
+/// available; entries are recipe ids to run at the controlled ship's facility.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct PendingCraftingJobs {
+    pub recipe_ids: Vec<String>,
+}
+
+/// Market trades queued by the dev console until the control channel is
+/// available; entries are `(item_id, side, quantity)` for the controlled ship
+/// and are routed to a cached market that quotes the item.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct PendingMarketTrades {
+    pub requests: Vec<(String, MarketTradeSide, u32)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedMarketSnapshot {
+    pub snapshot: sidereal_net::ServerMarketSnapshotMessage,
+    pub received_at_s: f64,
+}
+
+/// Latest snapshot of every market the controlled ship can trade with, keyed
+/// by market entity id. Entries age out once the server stops refreshing them.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct MarketSnapshotCache {
+    pub player_entity_id: Option<String>,
+    pub markets_by_entity_id: HashMap<String, CachedMarketSnapshot>,
+}
+
 #[derive(Debug, Resource)]
 pub(crate) struct TacticalMapUiState {
     pub enabled: bool,
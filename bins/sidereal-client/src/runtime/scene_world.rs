@@ -2,6 +2,7 @@
 
 use bevy::asset::RenderAssetUsages;
 use bevy::camera::visibility::RenderLayers;
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
 use bevy::sprite_render::{ColorMaterial, MeshMaterial2d};
@@ -21,11 +22,13 @@ use super::components::{
     DebugOverlayPanelTertiaryValueText, DebugOverlayPanelText, DebugOverlayPanelValueShadowText,
     DebugOverlayPanelValueText, DebugVelocityArrowHeadLower, DebugVelocityArrowHeadUpper,
     DebugVelocityArrowShaft, FullscreenForegroundCamera, GameplayCamera, GameplayHud,
-    HudFuelBarFill, HudHealthBarFill, HudPositionValueText, HudSpeedValueText, LoadingOverlayRoot,
-    LoadingOverlayText, LoadingProgressBarFill, PlanetBodyCamera, PostProcessCamera,
-    RuntimeScreenOverlayPass, RuntimeScreenOverlayPassKind, RuntimeStreamingIconText,
-    SegmentedBarSegment, SegmentedBarStyle, SegmentedBarValue, SpaceBackdropFallback,
-    TacticalMapCursorText, TacticalMapOverlayRoot, TacticalMapTitle, TopDownCamera, UiOverlayLayer,
+    HudAmmoValueText, HudCreditsValueText, HudFuelBarFill, HudHealthBarFill, HudHeatBarFill,
+    HudMissionValueText, HudPositionValueText, HudPowerBarFill, HudSpeedValueText,
+    LoadingOverlayRoot, LoadingOverlayText, LoadingProgressBarFill, PlanetBodyCamera,
+    PostProcessCamera, RuntimeScreenOverlayPass, RuntimeScreenOverlayPassKind,
+    RuntimeStreamingIconText, SegmentedBarSegment, SegmentedBarStyle, SegmentedBarValue,
+    SpaceBackdropFallback, TacticalMapCursorText, TacticalMapOverlayRoot, TacticalMapTitle,
+    TopDownCamera, UiOverlayLayer,
 };
 use super::platform::{
     BACKDROP_RENDER_LAYER, DEBUG_OVERLAY_RENDER_LAYER, FULLSCREEN_FOREGROUND_RENDER_LAYER,
@@ -39,6 +42,77 @@ use super::shaders;
 const TACTICAL_FOG_MASK_RESOLUTION: u32 = 384;
 const HUD_TELEMETRY_LABEL_WIDTH_PX: f32 = 84.0;
 
+/// Spawns a labelled 20-segment telemetry bar row; `marker` tags the bar so
+/// the HUD update system can drive its `SegmentedBarValue`.
+fn spawn_hud_bar_row(
+    panel: &mut ChildSpawnerCommands,
+    images: &mut Assets<Image>,
+    label: &str,
+    label_font: TextFont,
+    label_color: Color,
+    style: SegmentedBarStyle,
+    marker: impl Bundle,
+) {
+    panel
+        .spawn((Node {
+            width: percent(100.0),
+            flex_direction: FlexDirection::Row,
+            column_gap: px(8.0),
+            align_items: AlignItems::Center,
+            ..default()
+        },))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: px(HUD_TELEMETRY_LABEL_WIDTH_PX),
+                    ..default()
+                },
+                Text::new(label),
+                label_font,
+                TextColor(label_color),
+            ));
+            row.spawn((
+                Node {
+                    // Same 220px geometry as the health and fuel bars.
+                    width: px(220),
+                    height: px(14),
+                    column_gap: px(style.segment_gap_px),
+                    align_items: AlignItems::Stretch,
+                    border: UiRect::all(px(1.0)),
+                    padding: UiRect::all(px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(style.shell_color),
+                BorderColor::all(style.border_color),
+                style,
+                SegmentedBarValue { ratio: 0.0 },
+                marker,
+            ))
+            .with_children(|bar| {
+                spawn_scanline_overlay(
+                    bar,
+                    images,
+                    style.scanline_primary_color,
+                    style.scanline_secondary_color,
+                    1.0,
+                    3.0,
+                    2,
+                );
+                for index in 0..style.segments {
+                    bar.spawn((
+                        Node {
+                            width: px(style.segment_width_px),
+                            height: percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(style.inactive_color),
+                        SegmentedBarSegment { index },
+                    ));
+                }
+            });
+        });
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn spawn_world_scene(
     mut commands: Commands<'_, '_>,
@@ -522,6 +596,105 @@ pub(super) fn spawn_world_scene(
                         HudPositionValueText,
                     ));
                 });
+            panel
+                .spawn((Node {
+                    width: percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: px(8),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("AMMO"),
+                        Node {
+                            width: px(HUD_TELEMETRY_LABEL_WIDTH_PX),
+                            ..default()
+                        },
+                        TextFont {
+                            font: fonts.mono_bold.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.muted_foreground_color()),
+                    ));
+                    row.spawn((
+                        Text::new("--"),
+                        TextFont {
+                            font: fonts.display.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.primary_color()),
+                        HudAmmoValueText,
+                    ));
+                });
+            panel
+                .spawn((Node {
+                    width: percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: px(8),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("CREDITS"),
+                        Node {
+                            width: px(HUD_TELEMETRY_LABEL_WIDTH_PX),
+                            ..default()
+                        },
+                        TextFont {
+                            font: fonts.mono_bold.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.muted_foreground_color()),
+                    ));
+                    row.spawn((
+                        Text::new("--"),
+                        TextFont {
+                            font: fonts.display.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.primary_color()),
+                        HudCreditsValueText,
+                    ));
+                });
+            panel
+                .spawn((Node {
+                    width: percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: px(8),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },))
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("MISSION"),
+                        Node {
+                            width: px(HUD_TELEMETRY_LABEL_WIDTH_PX),
+                            ..default()
+                        },
+                        TextFont {
+                            font: fonts.mono_bold.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.muted_foreground_color()),
+                    ));
+                    row.spawn((
+                        Text::new("--"),
+                        TextFont {
+                            font: fonts.mono_bold.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.primary_color()),
+                        HudMissionValueText,
+                    ));
+                });
             panel
                 .spawn((Node {
                     width: percent(100.0),
@@ -672,6 +845,52 @@ pub(super) fn spawn_world_scene(
                         }
                     });
                 });
+            let label_font = TextFont {
+                font: fonts.mono_bold.clone(),
+                font_size: 16.0,
+                ..default()
+            };
+            let label_color = theme.colors.muted_foreground_color();
+            spawn_hud_bar_row(
+                panel,
+                &mut images,
+                "POWER",
+                label_font.clone(),
+                label_color,
+                SegmentedBarStyle {
+                    segments: 20,
+                    active_color: Color::srgb(0.98, 0.82, 0.3),
+                    inactive_color: Color::srgba(0.15, 0.2, 0.28, 0.85),
+                    shell_color: Color::srgba(0.14, 0.12, 0.07, 0.88),
+                    border_color: Color::srgba(0.78, 0.62, 0.2, 0.88),
+                    corner_color: Color::srgba(1.0, 0.86, 0.4, 0.78),
+                    scanline_primary_color: Color::srgba(0.95, 0.78, 0.25, 0.018),
+                    scanline_secondary_color: Color::srgba(0.7, 0.56, 0.18, 0.009),
+                    segment_width_px: 9.0,
+                    segment_gap_px: 2.0,
+                },
+                HudPowerBarFill,
+            );
+            spawn_hud_bar_row(
+                panel,
+                &mut images,
+                "HEAT",
+                label_font,
+                label_color,
+                SegmentedBarStyle {
+                    segments: 20,
+                    active_color: Color::srgb(1.0, 0.42, 0.24),
+                    inactive_color: Color::srgba(0.15, 0.2, 0.28, 0.85),
+                    shell_color: Color::srgba(0.15, 0.08, 0.07, 0.88),
+                    border_color: Color::srgba(0.82, 0.3, 0.2, 0.88),
+                    corner_color: Color::srgba(1.0, 0.5, 0.32, 0.78),
+                    scanline_primary_color: Color::srgba(1.0, 0.4, 0.2, 0.018),
+                    scanline_secondary_color: Color::srgba(0.72, 0.28, 0.16, 0.009),
+                    segment_width_px: 9.0,
+                    segment_gap_px: 2.0,
+                },
+                HudHeatBarFill,
+            );
         });
     commands
         .spawn((
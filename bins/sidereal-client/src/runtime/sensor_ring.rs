@@ -23,8 +23,9 @@ use super::resources::{
     TacticalContactsCache, TacticalMapUiState, TacticalSensorRingUiState,
 };
 use super::ui::{
-    TacticalMapIconSvgCache, TacticalMarkerColorRole, resolve_tactical_marker_svg_with_color,
-    tactical_icon_centered_translation, tactical_marker_color, tactical_marker_scale_multiplier,
+    TacticalMapIconSvgCache, resolve_tactical_marker_svg_with_color, tactical_contact_color_role,
+    tactical_icon_centered_translation, tactical_marker_color, tactical_marker_role_suffix,
+    tactical_marker_scale_multiplier,
 };
 
 const SENSOR_RING_TICK_COUNT: usize = 96;
@@ -697,9 +698,12 @@ fn draw_contact_markers(
             continue;
         }
         let alpha_bucket = (marker_alpha.clamp(0.0, 1.0) * 20.0).round() as u8;
-        let marker_color =
-            tactical_marker_color(TacticalMarkerColorRole::HostileContact).with_alpha(marker_alpha);
-        let variant_suffix = format!("sensor-contact-alpha-{alpha_bucket}");
+        let color_role = tactical_contact_color_role(contact);
+        let marker_color = tactical_marker_color(color_role).with_alpha(marker_alpha);
+        let variant_suffix = format!(
+            "sensor-{}-alpha-{alpha_bucket}",
+            tactical_marker_role_suffix(color_role)
+        );
         let Some(svg_handle) = resolve_tactical_marker_svg_with_color(
             asset_io,
             (&mut *svg_assets, &mut *meshes),
@@ -1043,6 +1047,7 @@ mod tests {
             kind: "star".to_string(),
             map_icon_asset_id: None,
             faction_id: None,
+            standing: None,
             position_xy: [0.0, 0.0],
             size_m: None,
             mass_kg: None,
@@ -307,6 +307,7 @@ mod tests {
             kind: "ship".to_string(),
             map_icon_asset_id: Some("map_icon_ship_svg".to_string()),
             faction_id: None,
+            standing: None,
             position_xy: pos,
             size_m: None,
             mass_kg: None,
@@ -26,7 +26,8 @@ use lightyear::prelude::{
     Transport,
 };
 use sidereal_net::{
-    ClientNotificationDismissedMessage, ControlChannel, InputChannel, ManifestChannel,
+    ClientCraftingJobMessage, ClientMarketTradeMessage, ClientNotificationDismissedMessage,
+    ClientWeaponGroupAssignmentMessage, ControlChannel, InputChannel, ManifestChannel,
     NotificationChannel, ServerNotificationMessage, TacticalDeltaChannel, TacticalSnapshotChannel,
 };
 use std::net::SocketAddr;
@@ -437,6 +438,75 @@ pub fn ensure_client_notification_message_components(
     }
 }
 
+pub fn ensure_client_weapon_group_message_components(
+    mut commands: Commands<'_, '_>,
+    clients: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<Client>,
+            Without<MessageSender<ClientWeaponGroupAssignmentMessage>>,
+        ),
+    >,
+) {
+    for client in &clients {
+        commands
+            .entity(client)
+            .insert(MessageSender::<ClientWeaponGroupAssignmentMessage>::default());
+        info!(
+            "client patched missing weapon group message sender for client entity={:?}",
+            client
+        );
+    }
+}
+
+pub fn ensure_client_market_message_components(
+    mut commands: Commands<'_, '_>,
+    clients: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<Client>,
+            Without<MessageSender<ClientMarketTradeMessage>>,
+        ),
+    >,
+) {
+    for client in &clients {
+        commands
+            .entity(client)
+            .insert(MessageSender::<ClientMarketTradeMessage>::default());
+        info!(
+            "client patched missing market trade message sender for client entity={:?}",
+            client
+        );
+    }
+}
+
+pub fn ensure_client_crafting_message_components(
+    mut commands: Commands<'_, '_>,
+    clients: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<Client>,
+            Without<MessageSender<ClientCraftingJobMessage>>,
+        ),
+    >,
+) {
+    for client in &clients {
+        commands
+            .entity(client)
+            .insert(MessageSender::<ClientCraftingJobMessage>::default());
+        info!(
+            "client patched missing crafting job message sender for client entity={:?}",
+            client
+        );
+    }
+}
+
 pub fn configure_client_input_timeline_on_add(
     trigger: On<Add, Client>,
     tuning: Res<'_, ClientInputTimelineTuning>,
@@ -164,15 +164,22 @@ fn describe_entity_action(action: &EntityAction) -> &'static str {
         EntityAction::AfterburnerOff => "Afterburner Off",
         EntityAction::FirePrimary => "Fire Primary",
         EntityAction::FireSecondary => "Fire Secondary",
+        EntityAction::FireWeaponGroup3 => "Fire Group 3",
+        EntityAction::FireWeaponGroup4 => "Fire Group 4",
+        EntityAction::ReloadWeapons => "Reload",
+        EntityAction::RepairModules => "Repair Modules",
         EntityAction::ActivateShield => "Shield On",
         EntityAction::DeactivateShield => "Shield Off",
         EntityAction::ActivateTractor => "Tractor On",
         EntityAction::DeactivateTractor => "Tractor Off",
+        EntityAction::ActivateMiningLaser => "Mining Laser On",
+        EntityAction::DeactivateMiningLaser => "Mining Laser Off",
         EntityAction::ActivateScanner => "Scanner On",
         EntityAction::DeployCargo => "Deploy Cargo",
         EntityAction::EngageAutopilot => "Autopilot On",
         EntityAction::DisengageAutopilot => "Autopilot Off",
         EntityAction::InitiateDocking => "Dock",
+        EntityAction::PurchaseHullRepair => "Buy Repair",
+        EntityAction::PurchaseRefuel => "Buy Fuel",
     }
 }
-
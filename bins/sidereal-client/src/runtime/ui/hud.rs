@@ -10,10 +10,16 @@ pub(super) fn update_hud_system(
             Option<&Rotation>,
             Option<&LinearVelocity>,
             Option<&HealthPool>,
+            Option<&Inventory>,
+            Option<&ShipPowerState>,
         ),
         (With<ControlledEntity>, Without<GameplayCamera>),
     >,
     fuel_tank_query: Query<'_, '_, (&MountedOn, &FuelTank)>,
+    weapon_ammo_query: Query<'_, '_, (&MountedOn, &AmmoCount, Option<&AmmoFeed>)>,
+    // Wallets replicate owner-only, so the local player's is the only one.
+    wallet_query: Query<'_, '_, &CreditWallet>,
+    mission_log_query: Query<'_, '_, &MissionLog>,
     camera_query: Query<'_, '_, &Transform, With<GameplayCamera>>,
     mut text_queries: ParamSet<
         '_,
@@ -21,6 +27,9 @@ pub(super) fn update_hud_system(
         (
             Query<'_, '_, &mut Text, With<HudSpeedValueText>>,
             Query<'_, '_, &mut Text, With<HudPositionValueText>>,
+            Query<'_, '_, &mut Text, With<HudAmmoValueText>>,
+            Query<'_, '_, &mut Text, With<HudCreditsValueText>>,
+            Query<'_, '_, &mut Text, With<HudMissionValueText>>,
         ),
     >,
     mut bar_value_queries: ParamSet<
@@ -29,57 +38,75 @@ pub(super) fn update_hud_system(
         (
             Query<'_, '_, &mut SegmentedBarValue, With<HudHealthBarFill>>,
             Query<'_, '_, &mut SegmentedBarValue, With<HudFuelBarFill>>,
+            Query<'_, '_, &mut SegmentedBarValue, With<HudPowerBarFill>>,
+            Query<'_, '_, &mut SegmentedBarValue, With<HudHeatBarFill>>,
         ),
     >,
 ) {
-    let (pos, _heading_rad, vel, health_ratio, fuel_ratio) =
-        if let Ok((guid, transform, maybe_rotation, maybe_velocity, maybe_health)) =
-            controlled_query.single()
-        {
-            let vel = maybe_velocity.map_or(Vec2::ZERO, |velocity| velocity.0.as_vec2());
-            let heading_rad = maybe_rotation
-                .map(|rotation| rotation.as_radians() as f32)
-                .unwrap_or_else(|| vel.to_angle());
-            let health_ratio = if let Some(health) = maybe_health {
-                if health.maximum > 0.0 {
-                    (health.current / health.maximum).clamp(0.0, 1.0)
-                } else {
-                    0.0
-                }
+    let (pos, _heading_rad, vel, health_ratio, fuel_ratio, ammo_readout, power_state) = if let Ok(
+        (guid, transform, maybe_rotation, maybe_velocity, maybe_health, inventory, power_state),
+    ) =
+        controlled_query.single()
+    {
+        let vel = maybe_velocity.map_or(Vec2::ZERO, |velocity| velocity.0.as_vec2());
+        let heading_rad = maybe_rotation
+            .map(|rotation| rotation.as_radians() as f32)
+            .unwrap_or_else(|| vel.to_angle());
+        let health_ratio = if let Some(health) = maybe_health {
+            if health.maximum > 0.0 {
+                (health.current / health.maximum).clamp(0.0, 1.0)
             } else {
                 0.0
-            };
-
-            let mut fuel_current = 0.0_f32;
-            for (mounted_on, fuel_tank) in &fuel_tank_query {
-                if mounted_on.parent_entity_id == guid.0 {
-                    fuel_current += fuel_tank.fuel_kg.max(0.0);
-                }
             }
-            let baseline_entry = fuel_baseline_by_parent
-                .entry(guid.0)
-                .or_insert(fuel_current);
-            *baseline_entry = baseline_entry.max(fuel_current);
-            let fuel_capacity = (*baseline_entry).max(1.0);
-            let fuel_ratio = if fuel_current > 0.0 || fuel_capacity > 1.0 {
-                (fuel_current / fuel_capacity).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
+        } else {
+            0.0
+        };
 
-            (
-                transform.translation,
-                heading_rad,
-                vel,
-                health_ratio,
-                fuel_ratio,
-            )
+        let mut fuel_current = 0.0_f32;
+        for (mounted_on, fuel_tank) in &fuel_tank_query {
+            if mounted_on.parent_entity_id == guid.0 {
+                fuel_current += fuel_tank.fuel_kg.max(0.0);
+            }
+        }
+        let baseline_entry = fuel_baseline_by_parent
+            .entry(guid.0)
+            .or_insert(fuel_current);
+        *baseline_entry = baseline_entry.max(fuel_current);
+        let fuel_capacity = (*baseline_entry).max(1.0);
+        let fuel_ratio = if fuel_current > 0.0 || fuel_capacity > 1.0 {
+            (fuel_current / fuel_capacity).clamp(0.0, 1.0)
         } else {
-            let Ok(camera_transform) = camera_query.single() else {
-                return;
-            };
-            (camera_transform.translation, 0.0, Vec2::ZERO, 0.0, 0.0)
+            0.0
+        };
+        let magazines = weapon_ammo_query
+            .iter()
+            .filter(|(mounted_on, _, _)| mounted_on.parent_entity_id == guid.0)
+            .map(|(_, ammo, feed)| (ammo, feed))
+            .collect::<Vec<_>>();
+
+        (
+            transform.translation,
+            heading_rad,
+            vel,
+            health_ratio,
+            fuel_ratio,
+            hud_ammo_readout(&magazines, inventory),
+            power_state,
+        )
+    } else {
+        let Ok(camera_transform) = camera_query.single() else {
+            return;
         };
+        (
+            camera_transform.translation,
+            0.0,
+            Vec2::ZERO,
+            0.0,
+            0.0,
+            "--".to_string(),
+            None,
+        )
+    };
     let speed = vel.length();
 
     if let Ok(mut text) = text_queries.p0().single_mut() {
@@ -88,11 +115,94 @@ pub(super) fn update_hud_system(
     if let Ok(mut text) = text_queries.p1().single_mut() {
         text.0 = format!("SECTOR {}", format_sector_code(pos.x, pos.y));
     }
+    if let Ok(mut text) = text_queries.p2().single_mut()
+        && text.0 != ammo_readout
+    {
+        text.0 = ammo_readout;
+    }
+    let credits_readout = wallet_query.iter().next().map_or_else(
+        || "--".to_string(),
+        |wallet| format!("{} CR", wallet.balance),
+    );
+    if let Ok(mut text) = text_queries.p3().single_mut()
+        && text.0 != credits_readout
+    {
+        text.0 = credits_readout;
+    }
+    // Same owner-only replication as the wallet; show the oldest active mission.
+    let mission_readout = mission_log_query
+        .iter()
+        .next()
+        .and_then(|log| log.active.first())
+        .map_or_else(
+            || "--".to_string(),
+            |mission| {
+                let done = mission
+                    .objectives
+                    .iter()
+                    .filter(|objective| objective.is_complete())
+                    .count();
+                format!(
+                    "{} {done}/{}",
+                    mission.display_name,
+                    mission.objectives.len()
+                )
+            },
+        );
+    if let Ok(mut text) = text_queries.p4().single_mut()
+        && text.0 != mission_readout
+    {
+        text.0 = mission_readout;
+    }
     if let Ok(mut fill) = bar_value_queries.p0().single_mut() {
         fill.ratio = health_ratio;
     }
     if let Ok(mut fill) = bar_value_queries.p1().single_mut() {
         fill.ratio = fuel_ratio;
     }
+    // Read from the predicted hull so local draws show up without waiting for
+    // the server's correction.
+    if let Ok(mut fill) = bar_value_queries.p2().single_mut() {
+        fill.ratio = power_state.map_or(0.0, ShipPowerState::power_ratio);
+    }
+    if let Ok(mut fill) = bar_value_queries.p3().single_mut() {
+        fill.ratio = power_state.map_or(0.0, ShipPowerState::heat_ratio);
+    }
 }
 
+/// Loaded rounds across the controlled hull's magazines plus the rounds its
+/// cargo can still feed them, or reload progress while any weapon reloads.
+fn hud_ammo_readout(
+    magazines: &[(&AmmoCount, Option<&AmmoFeed>)],
+    inventory: Option<&Inventory>,
+) -> String {
+    if magazines.is_empty() {
+        return "--".to_string();
+    }
+    if let Some(progress) = magazines
+        .iter()
+        .filter_map(|(_, feed)| feed.filter(|feed| feed.is_reloading()))
+        .map(AmmoFeed::reload_progress)
+        .reduce(f32::min)
+    {
+        return format!("RELOADING {:.0}%", progress * 100.0);
+    }
+    let loaded = magazines.iter().map(|(ammo, _)| ammo.current).sum::<u32>();
+    let capacity = magazines.iter().map(|(ammo, _)| ammo.capacity).sum::<u32>();
+    let mut counted_item_ids = HashSet::<&str>::new();
+    let mut reserve = 0_u32;
+    if let Some(inventory) = inventory {
+        for feed in magazines.iter().filter_map(|(_, feed)| *feed) {
+            for item_id in &feed.ammo_item_ids {
+                if counted_item_ids.insert(item_id.as_str()) {
+                    reserve = reserve.saturating_add(
+                        inventory
+                            .quantity_of(item_id)
+                            .saturating_mul(feed.rounds_per_item.max(1)),
+                    );
+                }
+            }
+        }
+    }
+    format!("{loaded}/{capacity} +{reserve}")
+}
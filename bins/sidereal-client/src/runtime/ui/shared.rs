@@ -14,8 +14,9 @@ use bevy::state::state_scoped::DespawnOnExit;
 use bevy::window::PrimaryWindow;
 use bevy_svg::prelude::{Svg, Svg2d};
 use sidereal_game::{
-    EntityAction, EntityGuid, FuelTank, HealthPool, MapIcon, MountedOn, PlanetBodyShaderSettings,
-    SizeM, TacticalMapUiSettings, TacticalPresentationDefaults,
+    AmmoCount, AmmoFeed, CreditWallet, EntityAction, EntityGuid, FactionStanding, FuelTank,
+    HealthPool, Inventory, MapIcon, MissionLog, MountedOn, PlanetBodyShaderSettings,
+    ShipPowerState, SizeM, TacticalMapUiSettings, TacticalPresentationDefaults,
 };
 use sidereal_runtime_sync::parse_guid_from_entity_id;
 use sidereal_ui::layout;
@@ -41,8 +42,9 @@ use super::components::{
     DebugOverlayPanelTertiaryLabelShadowText, DebugOverlayPanelTertiaryLabelText,
     DebugOverlayPanelTertiaryValueShadowText, DebugOverlayPanelTertiaryValueText,
     DebugOverlayPanelText, DebugOverlayPanelValueShadowText, DebugOverlayPanelValueText,
-    EntityNameplateHealthFill, EntityNameplateRoot, GameplayCamera, GameplayHud, HudFuelBarFill,
-    HudHealthBarFill, HudPositionValueText, HudSpeedValueText, LoadingOverlayRoot,
+    EntityNameplateHealthFill, EntityNameplateRoot, GameplayCamera, GameplayHud, HudAmmoValueText,
+    HudCreditsValueText, HudFuelBarFill, HudHealthBarFill, HudHeatBarFill, HudMissionValueText,
+    HudPositionValueText, HudPowerBarFill, HudSpeedValueText, LoadingOverlayRoot,
     LoadingOverlayText, LoadingProgressBarFill, OwnedEntitiesPanelAction, OwnedEntitiesPanelButton,
     OwnedEntitiesPanelRoot, ResolvedRuntimeRenderLayer, RuntimeScreenOverlayPass,
     RuntimeScreenOverlayPassKind, SegmentedBarSegment, SegmentedBarStyle, SegmentedBarValue,
@@ -340,4 +342,3 @@ fn apply_ui_overlay_to_subtree(
         }
     }
 }
-
@@ -466,7 +466,7 @@ pub(super) fn update_tactical_map_overlay_system(
         let Some(base_asset_id) = base_asset_id else {
             continue;
         };
-        let color_role = TacticalMarkerColorRole::HostileContact;
+        let color_role = tactical_contact_color_role(contact);
         let Some(svg_handle) = resolve_tactical_marker_svg(
             (&asset_manager, &asset_root.0, *cache_adapter),
             (&mut svg_assets, &mut meshes),
@@ -694,20 +694,39 @@ pub(super) struct TacticalMapIconSvgCache {
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) enum TacticalMarkerColorRole {
     FriendlySelf,
+    AlliedContact,
+    /// Also used for contacts without a server-resolved IFF standing.
+    NeutralContact,
     HostileContact,
 }
 
+/// IFF role for a contact from the standing the server resolved for this
+/// player.
+pub(super) fn tactical_contact_color_role(
+    contact: &sidereal_net::TacticalContact,
+) -> TacticalMarkerColorRole {
+    match contact.standing.as_deref().and_then(FactionStanding::parse) {
+        Some(FactionStanding::Allied) => TacticalMarkerColorRole::AlliedContact,
+        Some(FactionStanding::Hostile) => TacticalMarkerColorRole::HostileContact,
+        Some(FactionStanding::Neutral) | None => TacticalMarkerColorRole::NeutralContact,
+    }
+}
+
 pub(super) fn tactical_marker_color(role: TacticalMarkerColorRole) -> Color {
     match role {
         TacticalMarkerColorRole::FriendlySelf => Color::srgb(0.22, 0.62, 1.0),
-        TacticalMarkerColorRole::HostileContact => Color::srgb(1.0, 0.9, 0.34),
+        TacticalMarkerColorRole::AlliedContact => Color::srgb(0.36, 0.9, 0.46),
+        TacticalMarkerColorRole::NeutralContact => Color::srgb(1.0, 0.9, 0.34),
+        TacticalMarkerColorRole::HostileContact => Color::srgb(1.0, 0.32, 0.28),
     }
 }
 
-fn tactical_marker_role_suffix(role: TacticalMarkerColorRole) -> &'static str {
+pub(super) fn tactical_marker_role_suffix(role: TacticalMarkerColorRole) -> &'static str {
     match role {
         TacticalMarkerColorRole::FriendlySelf => "self",
-        TacticalMarkerColorRole::HostileContact => "contact",
+        TacticalMarkerColorRole::AlliedContact => "contact-allied",
+        TacticalMarkerColorRole::NeutralContact => "contact",
+        TacticalMarkerColorRole::HostileContact => "contact-hostile",
     }
 }
 
@@ -844,7 +863,7 @@ fn prewarm_tactical_map_marker_svgs(
             cache,
             &mut prewarmed_roles,
             &asset_id,
-            TacticalMarkerColorRole::HostileContact,
+            tactical_contact_color_role(contact),
         );
     }
 }
@@ -261,6 +261,7 @@ pub(super) fn emit_weapon_tracer_visuals_system(
             &'_ MountedOn,
             &'_ BallisticWeapon,
             Option<&'_ AmmoCount>,
+            Option<&'_ WeaponGroup>,
         ),
         With<WorldEntity>,
     >,
@@ -307,17 +308,28 @@ pub(super) fn emit_weapon_tracer_visuals_system(
         action_state,
     ) in &controlled_roots
     {
-        let firing =
-            action_state.is_some_and(|state| state.0.actions.contains(&EntityAction::FirePrimary));
-        if !firing {
+        let fired_groups = action_state
+            .map(|state| {
+                state
+                    .0
+                    .actions
+                    .iter()
+                    .filter_map(|action| action.fired_weapon_group())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if fired_groups.is_empty() {
             continue;
         }
         let ship_quat = Quat::from_rotation_z(ship_rotation.as_radians() as f32);
 
-        for (weapon_entity, mounted_on, weapon, ammo) in &weapons {
+        for (weapon_entity, mounted_on, weapon, ammo, group) in &weapons {
             if mounted_on.parent_entity_id != ship_guid.0 {
                 continue;
             }
+            if !fired_groups.contains(&group.copied().unwrap_or_default().group) {
+                continue;
+            }
             if weapon.uses_projectile_entities() {
                 continue;
             }
@@ -124,6 +124,11 @@ fn activate_destruction_effect(
             Vec4::new(1.0, 0.58, 0.18, 1.0),
             Vec4::new(0.22, 0.14, 0.10, 1.0),
         ),
+        "missile_detonation" => (
+            Vec4::new(0.96, 0.97, 1.0, 1.0),
+            Vec4::new(1.0, 0.72, 0.32, 1.0),
+            Vec4::new(0.18, 0.16, 0.15, 1.0),
+        ),
         _ => (
             Vec4::new(1.0, 0.94, 0.76, 1.0),
             Vec4::new(1.0, 0.58, 0.18, 1.0),
@@ -390,4 +395,3 @@ fn streamed_visual_needs_rebuild(
 ) -> bool {
     attached_kind != Some(desired_kind.attachment_kind())
 }
-
@@ -777,3 +777,91 @@ mod tests {
         );
     }
 }
+
+/// Guided missiles arrive as replicated world entities whose transform is
+/// already driven by interpolation; they only need a body sprite.
+#[allow(clippy::type_complexity)]
+pub(super) fn attach_guided_missile_visuals_system(
+    mut commands: Commands<'_, '_>,
+    missiles: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            Has<SuppressedPredictedDuplicateVisual>,
+            Option<&'_ Transform>,
+        ),
+        (With<GuidedMissile>, Without<GuidedMissileVisualAttached>),
+    >,
+) {
+    for (entity, is_suppressed, existing_transform) in &missiles {
+        let mut transform = existing_transform.copied().unwrap_or_default();
+        transform.translation.z = PROJECTILE_VISUAL_Z;
+        commands.entity(entity).insert((
+            GuidedMissileVisualAttached,
+            Sprite {
+                color: Color::srgb(0.92, 0.95, 1.0),
+                custom_size: Some(Vec2::new(MISSILE_VISUAL_WIDTH_M, MISSILE_VISUAL_LENGTH_M)),
+                ..default()
+            },
+            transform,
+            if is_suppressed {
+                Visibility::Hidden
+            } else {
+                Visibility::Visible
+            },
+        ));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn sync_beam_weapon_visuals_system(
+    mut commands: Commands<'_, '_>,
+    beams: Query<
+        '_,
+        '_,
+        (Entity, &'_ BeamWeaponState, Option<&'_ BeamWeaponVisual>),
+        With<BeamWeapon>,
+    >,
+    mut beam_sprites: Query<'_, '_, (&'_ mut Transform, &'_ mut Sprite), Without<BeamWeapon>>,
+) {
+    for (weapon_entity, state, visual) in &beams {
+        let beam_length_m = state.beam_length_m.max(0.0);
+        let firing = state.firing && beam_length_m > 0.0;
+        // The beam quad is centered, so shift it forward by half its length to
+        // start at the muzzle.
+        let beam_transform = Transform::from_xyz(0.0, beam_length_m * 0.5, PROJECTILE_VISUAL_Z);
+        let beam_size = Vec2::new(BEAM_VISUAL_WIDTH_M, beam_length_m);
+        match (firing, visual) {
+            (true, Some(visual)) => {
+                if let Ok((mut transform, mut sprite)) = beam_sprites.get_mut(visual.beam_entity) {
+                    *transform = beam_transform;
+                    sprite.custom_size = Some(beam_size);
+                }
+            }
+            (true, None) => {
+                let beam_entity = commands
+                    .spawn((
+                        Name::new("BeamWeaponVisual"),
+                        Sprite {
+                            color: Color::srgba(1.0, 0.35, 0.25, 0.85),
+                            custom_size: Some(beam_size),
+                            ..default()
+                        },
+                        beam_transform,
+                        Visibility::Visible,
+                        ChildOf(weapon_entity),
+                    ))
+                    .id();
+                commands
+                    .entity(weapon_entity)
+                    .insert(BeamWeaponVisual { beam_entity });
+            }
+            (false, Some(visual)) => {
+                queue_despawn_if_exists(&mut commands, visual.beam_entity);
+                commands.entity(weapon_entity).remove::<BeamWeaponVisual>();
+            }
+            (false, None) => {}
+        }
+    }
+}
@@ -12,11 +12,12 @@ use lightyear::prelude::Confirmed;
 use lightyear::prelude::input::native::ActionState;
 use sidereal_game::{
     AfterburnerCapability, AfterburnerState, AmmoCount, BallisticProjectile, BallisticWeapon,
-    ControlledEntityGuid, EntityAction, EntityGuid, EntityLabels, FlightComputer, Hardpoint,
-    MountedOn, ParentGuid, PlanetBodyShaderSettings, PlayerTag, ProceduralSprite,
-    RuntimeRenderLayerDefinition, RuntimeWorldVisualPassDefinition, RuntimeWorldVisualStack, SizeM,
-    ThrusterPlumeShaderSettings, WorldPosition, WorldRotation,
-    generate_procedural_sprite_image_set, resolve_world_position, resolve_world_rotation_rad,
+    BeamWeapon, BeamWeaponState, ControlledEntityGuid, EntityGuid, EntityLabels, FlightComputer,
+    GuidedMissile, Hardpoint, MountedOn, ParentGuid, PlanetBodyShaderSettings, PlayerTag,
+    ProceduralSprite, RuntimeRenderLayerDefinition, RuntimeWorldVisualPassDefinition,
+    RuntimeWorldVisualStack, SizeM, ThrusterPlumeShaderSettings, WeaponGroup, WorldPosition,
+    WorldRotation, generate_procedural_sprite_image_set, resolve_world_position,
+    resolve_world_rotation_rad,
 };
 use sidereal_net::PlayerInput;
 use std::collections::HashMap;
@@ -31,15 +32,15 @@ use super::backdrop::{
     StreamedSpriteShaderMaterial,
 };
 use super::components::{
-    BallisticProjectileVisualAttached, CanonicalPresentationEntity, ControlledEntity,
-    PendingInitialVisualReady, PendingVisibilityFadeIn, PlanetBodyCamera,
-    ResolvedRuntimeRenderLayer, RuntimeWorldVisualFamily, RuntimeWorldVisualPass,
-    RuntimeWorldVisualPassKind, RuntimeWorldVisualPassSet,
-    StreamedProceduralSpriteVisualFingerprint, StreamedSpriteShaderAssetId, StreamedVisualAssetId,
-    StreamedVisualAttached, StreamedVisualAttachmentKind, StreamedVisualChild,
-    SuppressedPredictedDuplicateVisual, WeaponImpactExplosion, WeaponImpactExplosionPool,
-    WeaponImpactSpark, WeaponImpactSparkPool, WeaponTracerBolt, WeaponTracerCooldowns,
-    WeaponTracerPool, WorldEntity,
+    BallisticProjectileVisualAttached, BeamWeaponVisual, CanonicalPresentationEntity,
+    ControlledEntity, GuidedMissileVisualAttached, PendingInitialVisualReady,
+    PendingVisibilityFadeIn, PlanetBodyCamera, ResolvedRuntimeRenderLayer,
+    RuntimeWorldVisualFamily, RuntimeWorldVisualPass, RuntimeWorldVisualPassKind,
+    RuntimeWorldVisualPassSet, StreamedProceduralSpriteVisualFingerprint,
+    StreamedSpriteShaderAssetId, StreamedVisualAssetId, StreamedVisualAttached,
+    StreamedVisualAttachmentKind, StreamedVisualChild, SuppressedPredictedDuplicateVisual,
+    WeaponImpactExplosion, WeaponImpactExplosionPool, WeaponImpactSpark, WeaponImpactSparkPool,
+    WeaponTracerBolt, WeaponTracerCooldowns, WeaponTracerPool, WorldEntity,
 };
 use super::ecs_util::queue_despawn_if_exists;
 use super::lighting::{CameraLocalLightSet, WorldLightingState};
@@ -118,6 +119,9 @@ const STREAMED_VISUAL_BASE_LAYER_Z: f32 = 0.2;
 const PROJECTILE_VISUAL_WIDTH_M: f32 = 0.45;
 const PROJECTILE_VISUAL_LENGTH_M: f32 = 2.8;
 const PROJECTILE_VISUAL_Z: f32 = 0.38;
+const MISSILE_VISUAL_WIDTH_M: f32 = 0.9;
+const MISSILE_VISUAL_LENGTH_M: f32 = 3.6;
+const BEAM_VISUAL_WIDTH_M: f32 = 0.45;
 const DESTRUCTION_EXPLOSION_TTL_S: f32 = 0.65;
 const DESTRUCTION_EXPLOSION_BASE_SCALE: f32 = 8.0;
 const DESTRUCTION_EXPLOSION_GROWTH_SCALE: f32 = 18.0;
@@ -0,0 +1,62 @@
+use sidereal_game::{MarketQuote, MarketTradeSide};
+use sidereal_net::ServerMarketSnapshotMessage;
+
+use crate::runtime::MarketSnapshotCache;
+use crate::runtime::market::{
+    apply_market_snapshot, market_for_trade, prune_stale_market_snapshots,
+};
+
+const PLAYER_ID: &str = "11111111-1111-1111-1111-111111111111";
+
+fn snapshot(market_entity_id: &str, buy_price: Option<u64>) -> ServerMarketSnapshotMessage {
+    ServerMarketSnapshotMessage {
+        player_entity_id: PLAYER_ID.to_string(),
+        market_entity_id: market_entity_id.to_string(),
+        station_entity_id: "station".to_string(),
+        market_id: "market.test".to_string(),
+        display_name: "Test".to_string(),
+        quotes: vec![MarketQuote {
+            item_id: "resource.iron_ore".to_string(),
+            stock: 10,
+            max_stock: 100,
+            buy_price,
+            sell_price: Some(9),
+        }],
+    }
+}
+
+#[test]
+fn trades_route_to_the_freshest_market_quoting_the_item() {
+    let mut cache = MarketSnapshotCache::default();
+    apply_market_snapshot(&mut cache, PLAYER_ID, snapshot("market-a", Some(11)), 1.0);
+    apply_market_snapshot(&mut cache, PLAYER_ID, snapshot("market-b", None), 2.0);
+    apply_market_snapshot(&mut cache, PLAYER_ID, snapshot("market-c", Some(12)), 1.5);
+
+    assert_eq!(
+        market_for_trade(&cache, "resource.iron_ore", MarketTradeSide::Buy),
+        Some("market-c")
+    );
+    assert_eq!(
+        market_for_trade(&cache, "resource.iron_ore", MarketTradeSide::Sell),
+        Some("market-b")
+    );
+    assert_eq!(
+        market_for_trade(&cache, "resource.nickel_ore", MarketTradeSide::Sell),
+        None
+    );
+}
+
+#[test]
+fn stale_and_foreign_snapshots_are_not_cached() {
+    let mut cache = MarketSnapshotCache::default();
+    let mut foreign = snapshot("market-x", Some(11));
+    foreign.player_entity_id = "22222222-2222-2222-2222-222222222222".to_string();
+    apply_market_snapshot(&mut cache, PLAYER_ID, foreign, 1.0);
+    apply_market_snapshot(&mut cache, PLAYER_ID, snapshot("market-a", Some(11)), 1.0);
+    apply_market_snapshot(&mut cache, PLAYER_ID, snapshot("market-b", Some(11)), 4.5);
+
+    prune_stale_market_snapshots(&mut cache, 5.0);
+
+    assert_eq!(cache.markets_by_entity_id.len(), 1);
+    assert!(cache.markets_by_entity_id.contains_key("market-b"));
+}
@@ -0,0 +1,3 @@
+//! Tests for client runtime helpers that need crate-private access.
+
+mod market;
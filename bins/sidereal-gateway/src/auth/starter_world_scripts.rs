@@ -1486,6 +1486,9 @@ return PlayerInit
             })
             .collect::<std::collections::HashSet<_>>();
         assert!(component_kinds.contains("contact_resolution_m"));
+        assert!(component_kinds.contains("credit_wallet"));
+        assert!(component_kinds.contains("credit_ledger"));
+        assert!(component_kinds.contains("mission_log"));
     }
 
     #[test]
@@ -1601,5 +1604,32 @@ return PlayerInit
             .collect::<std::collections::HashSet<_>>();
         assert!(component_kinds.contains("visibility_range_buff_m"));
         assert!(component_kinds.contains("signal_signature"));
+
+        let station_id = "0012ebad-0000-0000-0000-000000000030";
+        assert!(
+            records.iter().any(|record| record.entity_id == station_id),
+            "world init should spawn the starter station"
+        );
+        let station_modules = records
+            .iter()
+            .filter(|record| {
+                record.components.iter().any(|component| {
+                    component.component_kind == "mounted_on"
+                        && component.properties["parent_entity_id"] == station_id
+                })
+            })
+            .flat_map(|record| {
+                record
+                    .components
+                    .iter()
+                    .map(|component| component.component_kind.as_str())
+            })
+            .collect::<std::collections::HashSet<_>>();
+        for kind in ["station_service", "market", "docking_port"] {
+            assert!(
+                station_modules.contains(kind),
+                "starter station should mount a {kind} module"
+            );
+        }
     }
 }
@@ -6,8 +6,9 @@ mod replication;
 mod tui;
 use crate::config::CliAction;
 use crate::replication::{
-    assets, auth, control, health, input, lifecycle, notifications, owner_manifest, persistence,
-    runtime_scripting, runtime_state, scripting, simulation_entities, tactical, visibility,
+    assets, auth, combat, control, crafting, economy_audit, health, input, lifecycle, markets,
+    notifications, owner_manifest, persistence, runtime_scripting, runtime_state, scripting,
+    simulation_entities, tactical, visibility,
 };
 use avian2d::prelude::{Gravity, PhysicsInterpolationPlugin, PhysicsPlugins, PhysicsSystems};
 use bevy::app::ScheduleRunnerPlugin;
@@ -208,6 +209,7 @@ fn init_resources(app: &mut App) {
     owner_manifest::init_resources(app);
     tactical::init_resources(app);
     notifications::init_resources(app);
+    economy_audit::init_resources(app);
     lifecycle::init_resources(app);
     health::init_resources(app);
 }
@@ -236,6 +238,11 @@ fn register_plugins(app: &mut App) {
             assets::poll_runtime_asset_catalog_changes_system,
             input::receive_latest_realtime_input_messages,
             control::receive_client_control_requests,
+            (
+                combat::receive_weapon_group_assignments,
+                markets::receive_market_trade_requests,
+                crafting::receive_crafting_job_requests,
+            ),
             visibility::receive_client_local_view_mode_messages,
             notifications::receive_notification_dismissals,
             notifications::process_notification_commands,
@@ -7,8 +7,9 @@ use crate::replication::persistence::{
     mark_dirty_persistable_entities, mark_dirty_persistable_entities_spatial,
 };
 use crate::replication::{
-    admin, assets, combat, health, input, lifecycle, notifications, owner_manifest, persistence,
-    runtime_scripting, runtime_state, simulation_entities, tactical, visibility,
+    admin, assets, cargo, combat, economy_audit, health, input, lifecycle, markets, missions,
+    notifications, owner_manifest, persistence, runtime_scripting, runtime_state,
+    simulation_entities, tactical, visibility,
 };
 
 pub(crate) struct ReplicationLifecyclePlugin;
@@ -20,6 +21,7 @@ impl Plugin for ReplicationLifecyclePlugin {
             (
                 simulation_entities::hydrate_simulation_entities,
                 notifications::start_notification_persistence_worker,
+                economy_audit::start_economy_audit_worker,
                 lifecycle::start_lightyear_server,
                 health::start_health_server,
             )
@@ -47,6 +49,7 @@ impl Plugin for ReplicationDiagnosticsPlugin {
         app.add_systems(
             Update,
             (
+                health::record_script_quarantines,
                 health::update_health_snapshot,
                 health::update_world_explorer_snapshot,
                 health::update_world_map_snapshot,
@@ -90,7 +93,10 @@ impl Plugin for ReplicationControlPlugin {
                     .after(sidereal_game::process_weapon_fire_actions),
                 combat::configure_ballistic_projectile_replication
                     .after(combat::mark_new_ballistic_projectiles_prespawned),
+                combat::configure_guided_missile_replication
+                    .after(sidereal_game::update_guided_missiles),
                 combat::broadcast_weapon_fired_messages.after(sidereal_game::resolve_shot_impacts),
+                combat::broadcast_beam_weapon_messages.after(sidereal_game::update_beam_weapons),
                 combat::broadcast_entity_destruction_messages
                     .after(sidereal_game::begin_pending_destructions),
                 combat::enqueue_runtime_script_events_from_combat_messages
@@ -99,6 +105,20 @@ impl Plugin for ReplicationControlPlugin {
                 .chain()
                 .after(PhysicsSystems::Writeback),
         );
+        app.add_systems(
+            FixedPostUpdate,
+            (
+                notifications::enqueue_mining_notifications,
+                notifications::enqueue_crafting_notifications,
+                notifications::enqueue_cargo_collected_notifications,
+                notifications::enqueue_station_service_notifications,
+                notifications::enqueue_market_trade_notifications,
+                notifications::enqueue_mission_notifications,
+                economy_audit::record_credit_ledger_entries,
+                cargo::configure_cargo_container_replication,
+                markets::stream_market_snapshots,
+            ),
+        );
     }
 }
 
@@ -232,11 +252,15 @@ impl Plugin for ReplicationRuntimeScriptingPlugin {
             FixedUpdate,
             (
                 runtime_scripting::refresh_script_world_snapshot,
+                runtime_scripting::fire_script_timers,
                 runtime_scripting::run_script_intervals,
+                missions::start_queued_missions,
+                missions::evaluate_missions,
                 runtime_scripting::run_script_events,
                 runtime_scripting::apply_script_intents
                     .before(sidereal_game::apply_navigation_targets_to_flight_computers)
                     .before(sidereal_game::process_flight_actions),
+                runtime_scripting::apply_script_bundle_spawns,
             )
                 .chain()
                 .before(PhysicsSystems::Prepare),
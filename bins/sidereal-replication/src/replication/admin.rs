@@ -397,13 +397,14 @@ fn format_admin_command_result(
         ),
         AdminCommand::Health => match health_snapshot {
             Some(snapshot) => format!(
-                "health status={} users_online={} sessions={} entities={} physics_bodies={} lua_errors={}",
+                "health status={} users_online={} sessions={} entities={} physics_bodies={} lua_errors={} lua_quarantined={}",
                 snapshot.status,
                 snapshot.users_online,
                 snapshot.session_count,
                 snapshot.world_entity_count,
                 snapshot.physics_body_count,
-                snapshot.lua_runtime.error_count
+                snapshot.lua_runtime.error_count,
+                snapshot.lua_runtime.quarantined_handler_count
             ),
             None => "health snapshot unavailable".to_string(),
         },
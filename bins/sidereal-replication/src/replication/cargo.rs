@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use lightyear::prelude::{InterpolationTarget, NetworkTarget, Replicate};
+use sidereal_game::CargoContainer;
+
+/// Runtime-spawned cargo containers get the same replication targets hydrated
+/// world entities receive; visibility filtering then applies per client.
+pub fn configure_cargo_container_replication(
+    mut commands: Commands<'_, '_>,
+    containers: Query<'_, '_, Entity, (With<CargoContainer>, Without<Replicate>)>,
+) {
+    for entity in &containers {
+        commands.entity(entity).insert((
+            Replicate::to_clients(NetworkTarget::All),
+            InterpolationTarget::to_clients(NetworkTarget::All),
+        ));
+    }
+}
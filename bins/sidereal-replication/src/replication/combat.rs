@@ -1,18 +1,22 @@
 use bevy::prelude::*;
 use lightyear::prelude::server::{ClientOf, LinkOf};
 use lightyear::prelude::{
-    InterpolationTarget, LocalTimeline, NetworkTarget, PreSpawned, RemoteId, Replicate,
-    ReplicationState, Server, ServerMultiMessageSender,
+    InterpolationTarget, LocalTimeline, MessageReceiver, NetworkTarget, PreSpawned, RemoteId,
+    Replicate, ReplicationState, Server, ServerMultiMessageSender,
 };
 use serde_json::json;
 use sidereal_game::{
-    BallisticProjectile, BallisticProjectileSpawnedEvent, BallisticWeapon, EntityDestroyedEvent,
-    EntityDestructionStartedEvent, EntityGuid, OwnerId, PublicVisibility, ShotFiredEvent,
-    ShotHitEvent, ShotImpactResolvedEvent,
+    BallisticProjectile, BallisticProjectileSpawnedEvent, BallisticWeapon, BeamStartedEvent,
+    BeamStoppedEvent, BeamWeapon, EntityDestroyedEvent, EntityDestructionStartedEvent, EntityGuid,
+    GuidedMissile, MountedOn, OwnerId, PublicVisibility, ShotFiredEvent, ShotHitEvent,
+    ShotImpactResolvedEvent, WeaponGroup,
 };
 use sidereal_net::{
-    InputChannel, PlayerEntityId, ServerEntityDestructionMessage, ServerWeaponFiredMessage,
+    ClientWeaponGroupAssignmentMessage, InputChannel, ManifestChannel, PlayerEntityId,
+    ServerBeamWeaponMessage, ServerEntityDestructionMessage, ServerWeaponFiredMessage,
 };
+use std::collections::HashMap;
+use uuid::Uuid;
 
 use crate::replication::auth::AuthenticatedClientBindings;
 use crate::replication::control::owner_prediction_target;
@@ -23,6 +27,79 @@ const TRACER_VISUAL_MIN_TTL_S: f64 = 0.01;
 
 pub fn init_resources(_app: &mut App) {}
 
+/// Applies client weapon-group edits to weapons mounted on ships the bound
+/// player owns.
+#[allow(clippy::type_complexity)]
+pub fn receive_weapon_group_assignments(
+    mut commands: Commands<'_, '_>,
+    bindings: Res<'_, AuthenticatedClientBindings>,
+    mut receivers: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ mut MessageReceiver<ClientWeaponGroupAssignmentMessage>,
+        ),
+        With<ClientOf>,
+    >,
+    ships: Query<'_, '_, (&'_ EntityGuid, &'_ OwnerId)>,
+    weapons: Query<'_, '_, (Entity, &'_ MountedOn, Option<&'_ WeaponGroup>), With<BallisticWeapon>>,
+) {
+    for (client_entity, mut receiver) in &mut receivers {
+        let Some(bound_player_id) = bindings
+            .by_client_entity
+            .get(&client_entity)
+            .and_then(|bound_player| PlayerEntityId::parse(bound_player.as_str()))
+        else {
+            continue;
+        };
+        for message in receiver.receive() {
+            if PlayerEntityId::parse(message.player_entity_id.as_str()) != Some(bound_player_id) {
+                warn!(
+                    "dropped weapon group assignment for mismatched player claimed={} client={:?}",
+                    message.player_entity_id, client_entity
+                );
+                continue;
+            }
+            if !WeaponGroup::is_valid_group(message.group) {
+                warn!(
+                    "dropped weapon group assignment with invalid group={} ship={} hardpoint={}",
+                    message.group, message.ship_entity_id, message.hardpoint_id
+                );
+                continue;
+            }
+            let Ok(ship_guid) = uuid::Uuid::parse_str(&message.ship_entity_id) else {
+                continue;
+            };
+            let owns_ship = ships.iter().any(|(guid, owner)| {
+                guid.0 == ship_guid
+                    && PlayerEntityId::parse(owner.0.as_str()) == Some(bound_player_id)
+            });
+            if !owns_ship {
+                warn!(
+                    "dropped weapon group assignment for ship={} not owned by player={}",
+                    message.ship_entity_id, message.player_entity_id
+                );
+                continue;
+            }
+            let Some((weapon_entity, _, current_group)) =
+                weapons.iter().find(|(_, mounted_on, _)| {
+                    mounted_on.parent_entity_id == ship_guid
+                        && mounted_on.hardpoint_id == message.hardpoint_id
+                })
+            else {
+                continue;
+            };
+            if current_group.is_some_and(|current| current.group == message.group) {
+                continue;
+            }
+            commands.entity(weapon_entity).insert(WeaponGroup {
+                group: message.group,
+            });
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn mark_new_ballistic_projectiles_prespawned(
     mut commands: Commands<'_, '_>,
@@ -80,6 +157,20 @@ pub fn configure_ballistic_projectile_replication(
     }
 }
 
+/// Guided missiles are server-only simulations, so every client interpolates
+/// them, including the shooter.
+pub fn configure_guided_missile_replication(
+    mut commands: Commands<'_, '_>,
+    missiles: Query<'_, '_, Entity, (With<GuidedMissile>, Without<Replicate>)>,
+) {
+    for entity in &missiles {
+        commands.entity(entity).insert((
+            Replicate::to_clients(NetworkTarget::All),
+            InterpolationTarget::to_clients(NetworkTarget::All),
+        ));
+    }
+}
+
 pub fn broadcast_weapon_fired_messages(
     server_query: Query<'_, '_, &'_ Server>,
     mut sender: ServerMultiMessageSender<'_, '_, With<lightyear::prelude::client::Connected>>,
@@ -184,6 +275,144 @@ fn resolve_weapon_fire_profile_id(weapon: &BallisticWeapon) -> Option<&str> {
     (weapon.weapon_name == "Ballistic Gatling").then_some("weapon.ballistic_gatling")
 }
 
+/// A beam start already sent to clients, kept until its stop goes out.
+#[derive(Debug)]
+pub struct ActiveBeamBroadcast {
+    shooter_guid: Uuid,
+    weapon_entity: Entity,
+    recipients: Vec<Entity>,
+}
+
+/// Sends beam start/stop transitions on an ordered reliable channel. Stops go
+/// to every client that saw the start, and beams whose emitter despawns while
+/// firing get a synthesized stop so no client keeps a loop running.
+#[allow(clippy::too_many_arguments)]
+pub fn broadcast_beam_weapon_messages(
+    mut active_broadcasts: Local<'_, HashMap<Uuid, ActiveBeamBroadcast>>,
+    server_query: Query<'_, '_, &'_ Server>,
+    mut sender: ServerMultiMessageSender<'_, '_, With<lightyear::prelude::client::Connected>>,
+    mut started_events: MessageReader<'_, '_, BeamStartedEvent>,
+    mut stopped_events: MessageReader<'_, '_, BeamStoppedEvent>,
+    beams: Query<'_, '_, &'_ BeamWeapon>,
+    client_remotes: Query<'_, '_, (Entity, &'_ LinkOf, &'_ RemoteId), With<ClientOf>>,
+    replicated_entities: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ EntityGuid,
+            Option<&'_ OwnerId>,
+            &'_ ReplicationState,
+        ),
+    >,
+    bindings: Res<'_, AuthenticatedClientBindings>,
+) {
+    let mut send_to = |client_entity: Entity, message: &ServerBeamWeaponMessage| {
+        let Ok((_, link_of, remote_id)) = client_remotes.get(client_entity) else {
+            return;
+        };
+        let Ok(server) = server_query.get(link_of.server) else {
+            return;
+        };
+        let target = NetworkTarget::Single(remote_id.0);
+        let _ = sender.send::<ServerBeamWeaponMessage, ManifestChannel>(message, server, &target);
+    };
+
+    // Stops first: a stop and a restart of the same emitter in one tick must
+    // reach clients in that order.
+    let mut stopped_weapons = stopped_events
+        .read()
+        .map(|stopped| (stopped.weapon_guid, stopped.shooter_guid))
+        .collect::<Vec<_>>();
+    stopped_weapons.extend(
+        active_broadcasts
+            .iter()
+            .filter(|(_, active)| beams.get(active.weapon_entity).is_err())
+            .map(|(weapon_guid, active)| (*weapon_guid, active.shooter_guid)),
+    );
+    for (weapon_guid, shooter_guid) in stopped_weapons {
+        let Some(active) = active_broadcasts.remove(&weapon_guid) else {
+            continue;
+        };
+        let message = ServerBeamWeaponMessage {
+            shooter_entity_id: shooter_guid.to_string(),
+            weapon_guid: weapon_guid.to_string(),
+            audio_profile_id: None,
+            origin_xy: [0.0, 0.0],
+            active: false,
+        };
+        for client_entity in active.recipients {
+            send_to(client_entity, &message);
+        }
+    }
+
+    let started = started_events.read().cloned().collect::<Vec<_>>();
+    if started.is_empty() {
+        return;
+    }
+    let client_player_ids = bindings
+        .by_client_entity
+        .iter()
+        .filter_map(|(client_entity, player_entity_id)| {
+            PlayerEntityId::parse(player_entity_id.as_str())
+                .map(|id| (*client_entity, id.canonical_wire_id()))
+        })
+        .collect::<HashMap<_, _>>();
+    let shooter_entity_by_guid = replicated_entities
+        .iter()
+        .map(|(entity, guid, owner_id, _)| {
+            let owner_player_id = owner_id
+                .and_then(|owner| PlayerEntityId::parse(owner.0.as_str()))
+                .map(|id| id.canonical_wire_id());
+            (guid.0, (entity, owner_player_id))
+        })
+        .collect::<HashMap<_, _>>();
+
+    for started in started {
+        let Some((shooter_entity, shooter_owner_player_id)) =
+            shooter_entity_by_guid.get(&started.shooter_guid)
+        else {
+            continue;
+        };
+        let Ok((_, _, _, shooter_replication_state)) = replicated_entities.get(*shooter_entity)
+        else {
+            continue;
+        };
+        let message = ServerBeamWeaponMessage {
+            shooter_entity_id: started.shooter_guid.to_string(),
+            weapon_guid: started.weapon_guid.to_string(),
+            audio_profile_id: beams
+                .get(started.weapon_entity)
+                .ok()
+                .and_then(|beam| beam.fire_audio_profile_id.clone()),
+            origin_xy: [started.origin.x, started.origin.y],
+            active: true,
+        };
+        let mut recipients = Vec::new();
+        for (client_entity, _, _) in &client_remotes {
+            let is_shooter_owner_client =
+                shooter_owner_player_id.as_ref().is_some_and(|owner_id| {
+                    client_player_ids
+                        .get(&client_entity)
+                        .is_some_and(|client_player_id| client_player_id == owner_id)
+                });
+            if !is_shooter_owner_client && !shooter_replication_state.is_visible(client_entity) {
+                continue;
+            }
+            send_to(client_entity, &message);
+            recipients.push(client_entity);
+        }
+        active_broadcasts.insert(
+            started.weapon_guid,
+            ActiveBeamBroadcast {
+                shooter_guid: started.shooter_guid,
+                weapon_entity: started.weapon_entity,
+                recipients,
+            },
+        );
+    }
+}
+
 pub fn broadcast_entity_destruction_messages(
     server_query: Query<'_, '_, &'_ Server>,
     mut sender: ServerMultiMessageSender<'_, '_, With<lightyear::prelude::client::Connected>>,
@@ -272,6 +501,7 @@ pub fn enqueue_runtime_script_events_from_combat_messages(
                 "damage_per_shot": fired.damage_per_shot,
             }),
             target_entity_id: None,
+            handler_name: None,
         });
     }
     for resolved in resolved_events.read() {
@@ -285,6 +515,7 @@ pub fn enqueue_runtime_script_events_from_combat_messages(
                 "damage_per_shot": resolved.damage_per_shot,
             }),
             target_entity_id: None,
+            handler_name: None,
         });
     }
     for hit in hit_events.read() {
@@ -295,8 +526,11 @@ pub fn enqueue_runtime_script_events_from_combat_messages(
                 "target_entity_id": hit.target_guid.map(|guid| guid.to_string()),
                 "weapon_entity_id": hit.weapon_guid.to_string(),
                 "damage": hit.damage,
+                "shield_absorbed": hit.shield_absorbed,
+                "damage_type": hit.damage_type,
             }),
             target_entity_id: None,
+            handler_name: None,
         });
     }
     for started in destruction_started_events.read() {
@@ -313,11 +547,13 @@ pub fn enqueue_runtime_script_events_from_combat_messages(
             event_name: "health_depleted".to_string(),
             payload: payload.clone(),
             target_entity_id: Some(started.entity_guid.to_string()),
+            handler_name: None,
         });
         script_events.pending.push(ScriptEvent {
             event_name: "before_destroy".to_string(),
             payload,
             target_entity_id: Some(started.entity_guid.to_string()),
+            handler_name: None,
         });
     }
     for destroyed in destroyed_events.read() {
@@ -332,6 +568,7 @@ pub fn enqueue_runtime_script_events_from_combat_messages(
                 },
             }),
             target_entity_id: Some(destroyed.entity_guid.to_string()),
+            handler_name: None,
         });
     }
 }
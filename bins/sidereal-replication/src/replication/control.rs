@@ -6,8 +6,8 @@ use lightyear::prelude::{
     RemoteId, Replicate, ReplicationState, Server, ServerMultiMessageSender,
 };
 use sidereal_game::{
-    ActionQueue, AfterburnerState, ControlledEntityGuid, EntityGuid, FlightComputer, OwnerId,
-    PlayerTag,
+    ActionQueue, AfterburnerState, AutopilotState, ControlledEntityGuid, EntityGuid,
+    FlightComputer, OwnerId, PlayerTag,
 };
 use std::collections::{HashMap, HashSet};
 
@@ -179,6 +179,12 @@ pub(crate) fn neutralize_control_intent(world: &mut World, controlled_entity: En
         afterburner_state.active = false;
         changed = true;
     }
+    if let Some(mut autopilot) = world.get_mut::<AutopilotState>(controlled_entity)
+        && autopilot.engaged
+    {
+        autopilot.disengage();
+        changed = true;
+    }
     changed
 }
 
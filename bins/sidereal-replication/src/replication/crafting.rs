@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use lightyear::prelude::MessageReceiver;
+use lightyear::prelude::server::ClientOf;
+use sidereal_game::CraftingJobRequest;
+use sidereal_net::{ClientCraftingJobMessage, PlayerEntityId};
+use uuid::Uuid;
+
+use crate::replication::auth::AuthenticatedClientBindings;
+
+/// Turns client crafting messages into `CraftingJobRequest`s for the bound
+/// player. The simulation re-checks facility ownership and the recipe.
+pub fn receive_crafting_job_requests(
+    bindings: Res<'_, AuthenticatedClientBindings>,
+    mut receivers: Query<
+        '_,
+        '_,
+        (Entity, &'_ mut MessageReceiver<ClientCraftingJobMessage>),
+        With<ClientOf>,
+    >,
+    mut requests: MessageWriter<'_, CraftingJobRequest>,
+) {
+    for (client_entity, mut receiver) in &mut receivers {
+        let Some(bound_player_id) = bindings
+            .by_client_entity
+            .get(&client_entity)
+            .and_then(|bound_player| PlayerEntityId::parse(bound_player.as_str()))
+        else {
+            continue;
+        };
+        for message in receiver.receive() {
+            match crafting_job_request_from_message(bound_player_id, &message) {
+                Ok(request) => {
+                    requests.write(request);
+                }
+                Err(reason) => warn!(
+                    "dropped crafting job client={:?} player={} facility={}: {reason}",
+                    client_entity, message.player_entity_id, message.facility_entity_id
+                ),
+            }
+        }
+    }
+}
+
+pub(crate) fn crafting_job_request_from_message(
+    bound_player_id: PlayerEntityId,
+    message: &ClientCraftingJobMessage,
+) -> Result<CraftingJobRequest, &'static str> {
+    if PlayerEntityId::parse(message.player_entity_id.as_str()) != Some(bound_player_id) {
+        return Err("mismatched player");
+    }
+    if message.recipe_id.is_empty() {
+        return Err("missing recipe_id");
+    }
+    let facility_guid =
+        Uuid::parse_str(&message.facility_entity_id).map_err(|_| "invalid facility id")?;
+    Ok(CraftingJobRequest {
+        facility_guid,
+        recipe_id: message.recipe_id.clone(),
+        job_id: Uuid::new_v4(),
+        requested_by_player_id: Some(bound_player_id.canonical_wire_id()),
+    })
+}
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::{Arc, RwLock};
 use std::thread;
@@ -11,7 +12,9 @@ use crate::replication::input::{
 };
 use crate::replication::lifecycle::ClientLastActivity;
 use crate::replication::persistence::PersistenceWorkerState;
-use crate::replication::runtime_scripting::ScriptRuntimeMetrics;
+use crate::replication::runtime_scripting::{
+    ScriptHandlerMetrics, ScriptHandlerQuarantinedEvent, ScriptRuntimeMetrics,
+};
 use crate::replication::simulation_entities::PlayerControlledEntityMap;
 use crate::replication::visibility::VisibilityRuntimeMetrics;
 use avian2d::prelude::Position;
@@ -30,6 +33,7 @@ use sidereal_game::{
 };
 
 const DEFAULT_HEALTH_BIND: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 15716);
+const RECENT_SCRIPT_QUARANTINES_MAX: usize = 16;
 
 #[derive(Debug, Clone, Resource)]
 pub struct ReplicationHealthServerConfig {
@@ -84,6 +88,69 @@ pub struct LuaRuntimeHealthSnapshot {
     pub reload_count: u64,
     pub last_interval_run_ms: Option<f64>,
     pub last_event_run_ms: Option<f64>,
+    pub quarantined_handler_count: usize,
+    pub handlers: Vec<LuaHandlerHealthSnapshot>,
+    /// Newest last; survives script reloads, unlike per-handler metrics.
+    pub recent_quarantines: Vec<LuaQuarantineHealthSnapshot>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LuaQuarantineHealthSnapshot {
+    pub handler_name: String,
+    pub reason: String,
+    pub consecutive_failures: u32,
+    pub last_error: String,
+    pub quarantined_at_unix_ms: u128,
+}
+
+/// Handler quarantines reported by the script runtime, capped at
+/// `RECENT_SCRIPT_QUARANTINES_MAX`.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct RecentScriptQuarantines {
+    pub entries: VecDeque<LuaQuarantineHealthSnapshot>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LuaHandlerHealthSnapshot {
+    pub handler_name: String,
+    pub runs: u64,
+    pub error_count: u64,
+    pub instruction_limit_hits: u64,
+    pub memory_limit_hits: u64,
+    pub consecutive_failures: u32,
+    pub last_run_ms: Option<f64>,
+    pub avg_run_ms: f64,
+    pub max_run_ms: f64,
+    pub last_run_instructions: u64,
+    pub max_run_instructions: u64,
+    pub memory_bytes: u64,
+    pub quarantined: bool,
+    pub quarantine_reason: Option<String>,
+}
+
+impl From<&ScriptHandlerMetrics> for LuaHandlerHealthSnapshot {
+    fn from(metrics: &ScriptHandlerMetrics) -> Self {
+        Self {
+            handler_name: metrics.handler_name.clone(),
+            runs: metrics.runs,
+            error_count: metrics.error_count,
+            instruction_limit_hits: metrics.instruction_limit_hits,
+            memory_limit_hits: metrics.memory_limit_hits,
+            consecutive_failures: metrics.consecutive_failures,
+            last_run_ms: metrics.last_run_ms,
+            avg_run_ms: if metrics.runs > 0 {
+                metrics.total_run_ms / metrics.runs as f64
+            } else {
+                0.0
+            },
+            max_run_ms: metrics.max_run_ms,
+            last_run_instructions: metrics.last_run_instructions,
+            max_run_instructions: metrics.max_run_instructions,
+            memory_bytes: metrics.memory_bytes,
+            quarantined: metrics.quarantine_reason.is_some(),
+            quarantine_reason: metrics.quarantine_reason.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Resource, Serialize)]
@@ -265,6 +332,7 @@ pub struct HealthSnapshotInputs<'w, 's> {
     visibility_metrics: Res<'w, VisibilityRuntimeMetrics>,
     persistence_state: Res<'w, PersistenceWorkerState>,
     script_metrics: Option<Res<'w, ScriptRuntimeMetrics>>,
+    recent_script_quarantines: Res<'w, RecentScriptQuarantines>,
     all_entities: Query<'w, 's, Entity>,
     physics_entities: Query<'w, 's, Entity, With<RigidBody>>,
     scripted_entities: Query<'w, 's, Entity, With<ScriptState>>,
@@ -337,6 +405,7 @@ pub fn init_resources(app: &mut App) {
     app.insert_resource(DiagnosticsSnapshotCadence::default());
     app.insert_resource(DiagnosticsSnapshotState::default());
     app.insert_resource(FixedStepRuntimeMetrics::default());
+    app.insert_resource(RecentScriptQuarantines::default());
     app.insert_resource(ReplicationHealthSnapshot::default());
     app.insert_resource(SharedHealthSnapshot::new());
     app.insert_resource(WorldMapSnapshot::default());
@@ -431,6 +500,24 @@ pub fn end_fixed_step_diagnostics(mut metrics: ResMut<'_, FixedStepRuntimeMetric
 }
 
 #[allow(clippy::too_many_arguments)]
+pub fn record_script_quarantines(
+    mut quarantines: MessageReader<'_, '_, ScriptHandlerQuarantinedEvent>,
+    mut recent: ResMut<'_, RecentScriptQuarantines>,
+) {
+    for event in quarantines.read() {
+        if recent.entries.len() >= RECENT_SCRIPT_QUARANTINES_MAX {
+            recent.entries.pop_front();
+        }
+        recent.entries.push_back(LuaQuarantineHealthSnapshot {
+            handler_name: event.handler_name.clone(),
+            reason: event.reason.clone(),
+            consecutive_failures: event.consecutive_failures,
+            last_error: event.last_error.clone(),
+            quarantined_at_unix_ms: unix_time_ms(),
+        });
+    }
+}
+
 pub fn update_health_snapshot(
     time: Res<'_, Time<Real>>,
     cadence: Res<'_, DiagnosticsSnapshotCadence>,
@@ -537,6 +624,22 @@ pub fn update_health_snapshot(
             reload_count: current_script_metrics.reload_count,
             last_interval_run_ms: current_script_metrics.last_interval_run_ms,
             last_event_run_ms: current_script_metrics.last_event_run_ms,
+            quarantined_handler_count: current_script_metrics
+                .handlers
+                .iter()
+                .filter(|handler| handler.quarantine_reason.is_some())
+                .count(),
+            handlers: current_script_metrics
+                .handlers
+                .iter()
+                .map(LuaHandlerHealthSnapshot::from)
+                .collect(),
+            recent_quarantines: inputs
+                .recent_script_quarantines
+                .entries
+                .iter()
+                .cloned()
+                .collect(),
         },
     };
     *snapshot = next_snapshot.clone();
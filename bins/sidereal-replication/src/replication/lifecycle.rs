@@ -14,10 +14,12 @@ use lightyear::prelude::{Identity, LocalAddr};
 use sidereal_core::SIM_TICK_HZ;
 use sidereal_core::remote_inspect::RemoteInspectConfig;
 use sidereal_net::{
-    ClientAuthMessage, ClientControlRequestMessage, ClientDisconnectNotifyMessage,
-    ClientLocalViewModeMessage, ClientNotificationDismissedMessage, ClientRealtimeInputMessage,
-    ClientTacticalResnapshotRequestMessage, ServerControlAckMessage, ServerControlRejectMessage,
-    ServerNotificationMessage, ServerSessionDeniedMessage, ServerSessionReadyMessage,
+    ClientAuthMessage, ClientControlRequestMessage, ClientCraftingJobMessage,
+    ClientDisconnectNotifyMessage, ClientLocalViewModeMessage, ClientMarketTradeMessage,
+    ClientNotificationDismissedMessage, ClientRealtimeInputMessage,
+    ClientTacticalResnapshotRequestMessage, ClientWeaponGroupAssignmentMessage,
+    ServerControlAckMessage, ServerControlRejectMessage, ServerNotificationMessage,
+    ServerSessionDeniedMessage, ServerSessionReadyMessage,
 };
 use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
@@ -275,6 +277,9 @@ pub fn prime_client_link_transport_on_insert(
         MessageReceiver::<ClientLocalViewModeMessage>::default(),
         MessageReceiver::<ClientTacticalResnapshotRequestMessage>::default(),
         MessageReceiver::<ClientNotificationDismissedMessage>::default(),
+        MessageReceiver::<ClientWeaponGroupAssignmentMessage>::default(),
+        MessageReceiver::<ClientMarketTradeMessage>::default(),
+        MessageReceiver::<ClientCraftingJobMessage>::default(),
         MessageSender::<ServerSessionReadyMessage>::default(),
         MessageSender::<ServerSessionDeniedMessage>::default(),
         MessageSender::<ServerControlAckMessage>::default(),
@@ -338,6 +343,11 @@ pub fn ensure_server_message_components(
             Has<MessageReceiver<ClientLocalViewModeMessage>>,
             Has<MessageReceiver<ClientTacticalResnapshotRequestMessage>>,
             Has<MessageReceiver<ClientNotificationDismissedMessage>>,
+            Has<MessageReceiver<ClientWeaponGroupAssignmentMessage>>,
+            (
+                Has<MessageReceiver<ClientMarketTradeMessage>>,
+                Has<MessageReceiver<ClientCraftingJobMessage>>,
+            ),
             Has<MessageSender<ServerSessionReadyMessage>>,
             Has<MessageSender<ServerSessionDeniedMessage>>,
             Has<MessageSender<ServerControlAckMessage>>,
@@ -356,6 +366,8 @@ pub fn ensure_server_message_components(
         has_view_mode_recv,
         has_tactical_resnapshot_recv,
         has_notification_dismissed_recv,
+        has_weapon_group_assignment_recv,
+        (has_market_trade_recv, has_crafting_job_recv),
         has_session_ready_send,
         has_session_denied_send,
         has_control_ack_send,
@@ -396,6 +408,19 @@ pub fn ensure_server_message_components(
                 .insert(MessageReceiver::<ClientNotificationDismissedMessage>::default());
             patched.push("recv:ClientNotificationDismissedMessage");
         }
+        if !has_weapon_group_assignment_recv {
+            entity_commands
+                .insert(MessageReceiver::<ClientWeaponGroupAssignmentMessage>::default());
+            patched.push("recv:ClientWeaponGroupAssignmentMessage");
+        }
+        if !has_market_trade_recv {
+            entity_commands.insert(MessageReceiver::<ClientMarketTradeMessage>::default());
+            patched.push("recv:ClientMarketTradeMessage");
+        }
+        if !has_crafting_job_recv {
+            entity_commands.insert(MessageReceiver::<ClientCraftingJobMessage>::default());
+            patched.push("recv:ClientCraftingJobMessage");
+        }
         if !has_session_ready_send {
             entity_commands.insert(MessageSender::<ServerSessionReadyMessage>::default());
             patched.push("send:ServerSessionReadyMessage");
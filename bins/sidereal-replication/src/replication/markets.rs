@@ -0,0 +1,194 @@
+use avian2d::prelude::Position;
+use bevy::prelude::*;
+use lightyear::prelude::client::Connected;
+use lightyear::prelude::server::{ClientOf, LinkOf};
+use lightyear::prelude::{
+    MessageReceiver, NetworkTarget, RemoteId, Server, ServerMultiMessageSender,
+};
+use sidereal_game::{
+    ControlledEntityGuid, DockingState, EntityGuid, Market, MarketDefinition, MarketRegistry,
+    MarketTradeCompletedEvent, MarketTradeRequest, MountedOn, PlayerTag, market_quotes,
+    ship_in_trade_range,
+};
+use sidereal_net::{
+    ClientMarketTradeMessage, ManifestChannel, PlayerEntityId, ServerMarketSnapshotMessage,
+};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::replication::auth::AuthenticatedClientBindings;
+
+const MARKET_SNAPSHOT_INTERVAL_S: f64 = 1.0;
+
+/// Turns client trade messages into `MarketTradeRequest`s for the bound
+/// player. The simulation re-checks ship ownership, range, stock, cargo and
+/// credits.
+pub fn receive_market_trade_requests(
+    bindings: Res<'_, AuthenticatedClientBindings>,
+    mut receivers: Query<
+        '_,
+        '_,
+        (Entity, &'_ mut MessageReceiver<ClientMarketTradeMessage>),
+        With<ClientOf>,
+    >,
+    mut requests: MessageWriter<'_, MarketTradeRequest>,
+) {
+    for (client_entity, mut receiver) in &mut receivers {
+        let Some(bound_player_id) = bindings
+            .by_client_entity
+            .get(&client_entity)
+            .and_then(|bound_player| PlayerEntityId::parse(bound_player.as_str()))
+        else {
+            continue;
+        };
+        for message in receiver.receive() {
+            match market_trade_request_from_message(bound_player_id, &message) {
+                Ok(request) => {
+                    requests.write(request);
+                }
+                Err(reason) => warn!(
+                    "dropped market trade client={:?} player={} market={}: {reason}",
+                    client_entity, message.player_entity_id, message.market_entity_id
+                ),
+            }
+        }
+    }
+}
+
+pub(crate) fn market_trade_request_from_message(
+    bound_player_id: PlayerEntityId,
+    message: &ClientMarketTradeMessage,
+) -> Result<MarketTradeRequest, &'static str> {
+    if PlayerEntityId::parse(message.player_entity_id.as_str()) != Some(bound_player_id) {
+        return Err("mismatched player");
+    }
+    if message.quantity == 0 {
+        return Err("zero quantity");
+    }
+    if message.item_id.is_empty() {
+        return Err("missing item_id");
+    }
+    let ship_guid = Uuid::parse_str(&message.ship_entity_id).map_err(|_| "invalid ship id")?;
+    let market_guid =
+        Uuid::parse_str(&message.market_entity_id).map_err(|_| "invalid market id")?;
+    Ok(MarketTradeRequest {
+        player_guid: bound_player_id.0,
+        ship_guid,
+        market_guid,
+        item_id: message.item_id.clone(),
+        side: message.side,
+        quantity: message.quantity,
+    })
+}
+
+/// Sends each connected player a snapshot of every market their controlled
+/// ship can trade with, once per interval and right after they trade.
+#[allow(clippy::too_many_arguments)]
+pub fn stream_market_snapshots(
+    mut last_sent_at_s: Local<'_, Option<f64>>,
+    time: Res<'_, Time>,
+    server_query: Query<'_, '_, &'_ Server>,
+    mut sender: ServerMultiMessageSender<'_, '_, With<Connected>>,
+    bindings: Res<'_, AuthenticatedClientBindings>,
+    client_remotes: Query<'_, '_, (Entity, &'_ LinkOf, &'_ RemoteId), With<ClientOf>>,
+    registry: Option<Res<'_, MarketRegistry>>,
+    players: Query<'_, '_, (&'_ EntityGuid, &'_ ControlledEntityGuid), With<PlayerTag>>,
+    ships: Query<'_, '_, (&'_ EntityGuid, &'_ Position, Option<&'_ DockingState>)>,
+    stations: Query<'_, '_, (&'_ EntityGuid, &'_ Position), Without<MountedOn>>,
+    markets: Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ Market)>,
+    mut completed_events: MessageReader<'_, '_, MarketTradeCompletedEvent>,
+) {
+    let traded_players = completed_events
+        .read()
+        .map(|event| event.player_guid)
+        .collect::<HashSet<_>>();
+    let now_s = time.elapsed_secs_f64();
+    let interval_due =
+        last_sent_at_s.is_none_or(|last_s| now_s - last_s >= MARKET_SNAPSHOT_INTERVAL_S);
+    if !interval_due && traded_players.is_empty() {
+        return;
+    }
+    if interval_due {
+        *last_sent_at_s = Some(now_s);
+    }
+    let Some(registry) = registry else {
+        return;
+    };
+    if markets.is_empty() {
+        return;
+    }
+
+    let controlled_ship_by_player = players
+        .iter()
+        .filter_map(|(guid, controlled)| {
+            let ship_guid = controlled
+                .0
+                .as_deref()
+                .and_then(|id| Uuid::parse_str(id).ok())?;
+            Some((guid.0, ship_guid))
+        })
+        .collect::<HashMap<_, _>>();
+    let station_positions = stations
+        .iter()
+        .map(|(guid, position)| (guid.0, position.0))
+        .collect::<HashMap<_, _>>();
+
+    for (client_entity, link_of, remote_id) in &client_remotes {
+        let Some(player_id) = bindings
+            .by_client_entity
+            .get(&client_entity)
+            .and_then(|bound_player| PlayerEntityId::parse(bound_player.as_str()))
+        else {
+            continue;
+        };
+        if !interval_due && !traded_players.contains(&player_id.0) {
+            continue;
+        }
+        let Some((_, ship_position, docking)) = controlled_ship_by_player
+            .get(&player_id.0)
+            .and_then(|ship_guid| ships.iter().find(|(guid, ..)| guid.0 == *ship_guid))
+        else {
+            continue;
+        };
+        let Ok(server) = server_query.get(link_of.server) else {
+            continue;
+        };
+        let target = NetworkTarget::Single(remote_id.0);
+        for (market_guid, mounted_on, market) in &markets {
+            let station_guid = mounted_on.parent_entity_id;
+            if !ship_in_trade_range(
+                market,
+                station_guid,
+                station_positions.get(&station_guid).copied(),
+                ship_position.0,
+                docking,
+            ) {
+                continue;
+            }
+            let Some(definition) = registry.market(&market.market_id) else {
+                continue;
+            };
+            let message =
+                market_snapshot_message(player_id, market_guid.0, station_guid, market, definition);
+            let _ = sender
+                .send::<ServerMarketSnapshotMessage, ManifestChannel>(&message, server, &target);
+        }
+    }
+}
+
+pub(crate) fn market_snapshot_message(
+    player_id: PlayerEntityId,
+    market_guid: Uuid,
+    station_guid: Uuid,
+    market: &Market,
+    definition: &MarketDefinition,
+) -> ServerMarketSnapshotMessage {
+    ServerMarketSnapshotMessage {
+        player_entity_id: player_id.canonical_wire_id(),
+        market_entity_id: market_guid.to_string(),
+        station_entity_id: station_guid.to_string(),
+        market_id: market.market_id.clone(),
+        display_name: definition.display_name.clone(),
+        quotes: market_quotes(market, definition),
+    }
+}
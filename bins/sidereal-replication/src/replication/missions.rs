@@ -0,0 +1,350 @@
+use avian2d::prelude::Position;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use serde_json::json;
+use sidereal_game::{
+    ActiveMission, ControlledEntityGuid, CreditLedger, CreditWallet, DiscoveredStaticLandmarks,
+    EntityGuid, EntityKillCreditedEvent, FactionRegistry, FactionReputation, Inventory, MassDirty,
+    MissionCompletedEvent, MissionLog, MissionObjectiveKind, MissionRegistry, PlayerTag,
+    WorldPosition, advance_mission_objective, ledger_tick, pay_mission_reward, start_mission,
+    sync_active_mission, take_completed_missions,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::replication::runtime_scripting::{ScriptEvent, ScriptEventQueue};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissionStartRequest {
+    pub player_guid: Uuid,
+    pub mission_id: String,
+}
+
+/// Missions queued by the `start_mission` script intent, started on the next
+/// fixed tick.
+#[derive(Resource, Debug, Default)]
+pub struct MissionStartQueue {
+    pub pending: Vec<MissionStartRequest>,
+}
+
+type MissionPlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static ControlledEntityGuid>,
+        &'static mut MissionLog,
+        Option<&'static DiscoveredStaticLandmarks>,
+        Option<(&'static mut CreditWallet, &'static mut CreditLedger)>,
+        Option<&'static mut FactionReputation>,
+    ),
+    With<PlayerTag>,
+>;
+
+type MissionWorldQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static mut Inventory>,
+    ),
+    Without<PlayerTag>,
+>;
+
+pub fn start_queued_missions(
+    mut commands: Commands<'_, '_>,
+    mut queue: ResMut<'_, MissionStartQueue>,
+    registry: Option<Res<'_, MissionRegistry>>,
+    time: Res<'_, Time<Fixed>>,
+    mut players: Query<
+        '_,
+        '_,
+        (Entity, &'_ EntityGuid, Option<&'_ mut MissionLog>),
+        With<PlayerTag>,
+    >,
+) {
+    if queue.pending.is_empty() {
+        return;
+    }
+    let requests = std::mem::take(&mut queue.pending);
+    let Some(registry) = registry else {
+        warn!(
+            "dropped {} mission start(s); mission registry unavailable",
+            requests.len()
+        );
+        return;
+    };
+    let tick = ledger_tick(&time);
+    let mut new_logs = HashMap::<Entity, MissionLog>::new();
+    for request in requests {
+        let Some(definition) = registry.mission(&request.mission_id) else {
+            warn!(
+                "mission start refused player={} mission={}: unknown mission",
+                request.player_guid, request.mission_id
+            );
+            continue;
+        };
+        let Some((entity, _, log)) = players
+            .iter_mut()
+            .find(|(_, guid, _)| guid.0 == request.player_guid)
+        else {
+            warn!(
+                "mission start refused player={} mission={}: unknown player",
+                request.player_guid, request.mission_id
+            );
+            continue;
+        };
+        let started = match log {
+            Some(mut log) => start_mission(&mut log, definition, tick),
+            None => start_mission(new_logs.entry(entity).or_default(), definition, tick),
+        };
+        if let Err(err) = started {
+            let reason = err.as_str();
+            warn!(
+                "mission start refused player={} mission={}: {reason}",
+                request.player_guid, request.mission_id
+            );
+        }
+    }
+    for (entity, log) in new_logs {
+        commands.entity(entity).insert(log);
+    }
+}
+
+/// Advances every active mission once per fixed tick. Kills come from the
+/// `EntityKillCreditedEvent`s shared with kill reputation rather than from
+/// script events, which handlers can raise under any name through timers;
+/// reach, deliver and scan objectives are checked against the player's
+/// controlled ship and discovered landmarks. Finished missions are paid out,
+/// reported as `MissionCompletedEvent`s and broadcast to scripts as
+/// `mission_completed`.
+#[allow(clippy::too_many_arguments)]
+pub fn evaluate_missions(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    mut script_events: ResMut<'_, ScriptEventQueue>,
+    registry: Option<Res<'_, MissionRegistry>>,
+    faction_registry: Option<Res<'_, FactionRegistry>>,
+    mut kill_events: MessageReader<'_, '_, EntityKillCreditedEvent>,
+    mut players: MissionPlayerQuery<'_, '_>,
+    mut world_entities: MissionWorldQuery<'_, '_>,
+    mut completed_events: MessageWriter<'_, MissionCompletedEvent>,
+) {
+    let kills = kill_events.read().cloned().collect::<Vec<_>>();
+    let Some(registry) = registry else {
+        return;
+    };
+    if players
+        .iter()
+        .all(|(_, _, _, log, ..)| log.active.is_empty())
+    {
+        return;
+    }
+
+    let entity_by_guid = world_entities
+        .iter()
+        .map(|(entity, guid, ..)| (guid.0, entity))
+        .collect::<HashMap<_, _>>();
+    let reputation_rules = faction_registry
+        .as_deref()
+        .map(|registry| &registry.reputation);
+    let tick = ledger_tick(&time);
+
+    for (player_entity, player_guid, controlled, mut log, discovered, mut wallet, mut reputation) in
+        &mut players
+    {
+        if log.active.is_empty() {
+            continue;
+        }
+        let ship = controlled
+            .and_then(|controlled| controlled.0.as_deref())
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .and_then(|ship_guid| entity_by_guid.get(&ship_guid).copied());
+        let mut changed = false;
+        let log_ref = log.bypass_change_detection();
+        for mission in &mut log_ref.active {
+            let Some(definition) = registry.mission(&mission.mission_id) else {
+                continue;
+            };
+            changed |= sync_active_mission(mission, definition);
+            for objective in &definition.objectives {
+                let remaining = objective_remaining(mission, &objective.objective_id);
+                if remaining == 0 {
+                    continue;
+                }
+                let amount = match &objective.kind {
+                    MissionObjectiveKind::ReachLocation { x, y, radius_m } => {
+                        let reached = ship
+                            .and_then(|ship| entity_position(&world_entities, ship))
+                            .is_some_and(|position| {
+                                position.distance(DVec2::new(*x, *y)) <= *radius_m
+                            });
+                        u32::from(reached)
+                    }
+                    MissionObjectiveKind::DestroyEntity {
+                        target_entity_id,
+                        faction_id,
+                        ..
+                    } => kills
+                        .iter()
+                        .filter(|kill| kill.player_entity == player_entity)
+                        .filter(|kill| match (target_entity_id, faction_id) {
+                            (Some(target), _) => kill.victim_guid == *target,
+                            (None, Some(faction_id)) => {
+                                kill.victim_faction.as_deref() == Some(faction_id.as_str())
+                            }
+                            (None, None) => false,
+                        })
+                        .count()
+                        .try_into()
+                        .unwrap_or(u32::MAX),
+                    MissionObjectiveKind::DeliverItem {
+                        item_id,
+                        destination_entity_id,
+                        range_m,
+                        ..
+                    } => ship.map_or(0, |ship| {
+                        deliver_cargo(
+                            &mut commands,
+                            &mut world_entities,
+                            &entity_by_guid,
+                            ship,
+                            *destination_entity_id,
+                            *range_m,
+                            item_id,
+                            remaining,
+                        )
+                    }),
+                    MissionObjectiveKind::ScanLandmark { landmark_entity_id } => u32::from(
+                        discovered
+                            .is_some_and(|discovered| discovered.contains(*landmark_entity_id)),
+                    ),
+                };
+                if amount > 0 {
+                    changed |=
+                        advance_mission_objective(mission, &objective.objective_id, amount) > 0;
+                }
+            }
+        }
+
+        let completed = take_completed_missions(log_ref);
+        if changed || !completed.is_empty() {
+            log.set_changed();
+        }
+        let mut created_reputation = None::<FactionReputation>;
+        for mission in completed {
+            let credits = registry
+                .mission(&mission.mission_id)
+                .map_or(0, |definition| {
+                    let reputation = match reputation.as_deref_mut() {
+                        Some(reputation) => reputation,
+                        None => created_reputation.get_or_insert_default(),
+                    };
+                    let wallet = wallet
+                        .as_mut()
+                        .map(|(wallet, ledger)| (&mut **wallet, &mut **ledger));
+                    pay_mission_reward(
+                        &definition.reward,
+                        wallet,
+                        reputation,
+                        reputation_rules,
+                        tick,
+                    )
+                    .unwrap_or_else(|err| {
+                        warn!(
+                            "mission reward credits refused player={} mission={}: {}",
+                            player_guid.0,
+                            mission.mission_id,
+                            err.as_str()
+                        );
+                        0
+                    })
+                });
+            script_events.pending.push(mission_completed_script_event(
+                player_guid.0,
+                &mission,
+                credits,
+            ));
+            completed_events.write(MissionCompletedEvent {
+                player_guid: player_guid.0,
+                mission_id: mission.mission_id,
+                display_name: mission.display_name,
+                credits,
+            });
+        }
+        if let Some(reputation) = created_reputation.filter(|value| !value.entries.is_empty()) {
+            commands.entity(player_entity).insert(reputation);
+        }
+    }
+}
+
+fn objective_remaining(mission: &ActiveMission, objective_id: &str) -> u32 {
+    mission
+        .objectives
+        .iter()
+        .find(|objective| objective.objective_id == objective_id)
+        .map_or(0, |objective| {
+            objective.required.saturating_sub(objective.current)
+        })
+}
+
+fn entity_position(world_entities: &MissionWorldQuery<'_, '_>, entity: Entity) -> Option<DVec2> {
+    let (_, _, position, world_position, _) = world_entities.get(entity).ok()?;
+    position
+        .map(|position| position.0)
+        .or_else(|| world_position.map(|world_position| world_position.0))
+        .filter(|position| position.is_finite())
+}
+
+/// Hands over up to `remaining` units of `item_id` from the ship's hold when
+/// it is within `range_m` of the destination. Returns the units delivered.
+#[allow(clippy::too_many_arguments)]
+fn deliver_cargo(
+    commands: &mut Commands<'_, '_>,
+    world_entities: &mut MissionWorldQuery<'_, '_>,
+    entity_by_guid: &HashMap<Uuid, Entity>,
+    ship: Entity,
+    destination_guid: Uuid,
+    range_m: f64,
+    item_id: &str,
+    remaining: u32,
+) -> u32 {
+    let in_range = entity_by_guid
+        .get(&destination_guid)
+        .and_then(|destination| entity_position(world_entities, *destination))
+        .zip(entity_position(world_entities, ship))
+        .is_some_and(|(destination, ship)| destination.distance(ship) <= range_m);
+    if !in_range {
+        return 0;
+    }
+    let Ok((.., Some(mut inventory))) = world_entities.get_mut(ship) else {
+        return 0;
+    };
+    let delivered = inventory.quantity_of(item_id).min(remaining);
+    if delivered == 0 || !inventory.remove_stack(item_id, delivered) {
+        return 0;
+    }
+    commands.entity(ship).insert(MassDirty);
+    delivered
+}
+
+fn mission_completed_script_event(
+    player_guid: Uuid,
+    mission: &ActiveMission,
+    credits: u64,
+) -> ScriptEvent {
+    ScriptEvent {
+        event_name: "mission_completed".to_string(),
+        payload: json!({
+            "player_entity_id": player_guid.to_string(),
+            "mission_id": mission.mission_id,
+            "credits": credits,
+        }),
+        target_entity_id: None,
+        handler_name: None,
+    }
+}
@@ -1,11 +1,16 @@
 pub mod admin;
 pub mod assets;
 pub mod auth;
+pub mod cargo;
 pub mod combat;
 pub mod control;
+pub mod crafting;
+pub mod economy_audit;
 pub mod health;
 pub mod input;
 pub mod lifecycle;
+pub mod markets;
+pub mod missions;
 pub mod notifications;
 pub mod owner_manifest;
 pub mod persistence;
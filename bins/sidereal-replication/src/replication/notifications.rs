@@ -4,6 +4,12 @@ use lightyear::prelude::server::{ClientOf, LinkOf};
 use lightyear::prelude::{
     MessageReceiver, NetworkTarget, PeerId, RemoteId, Server, ServerMultiMessageSender,
 };
+use sidereal_game::{
+    CargoCollectedEvent, CraftingFailureReason, CraftingJobCompletedEvent, CraftingJobFailedEvent,
+    MarketTradeCompletedEvent, MarketTradeFailedEvent, MarketTradeFailureReason, MarketTradeSide,
+    MiningStatus, MiningStatusChangedEvent, MissionCompletedEvent, StationServiceFailedEvent,
+    StationServiceFailureReason, StationServiceKind, StationServicePurchasedEvent,
+};
 use sidereal_net::{
     ClientNotificationDismissedMessage, NotificationChannel, NotificationImageRef,
     NotificationPayload, NotificationPlacement, NotificationSeverity, PlayerEntityId,
@@ -23,6 +29,15 @@ const DEFAULT_SUCCESS_DISMISS_S: f32 = 5.0;
 const DEFAULT_WARNING_DISMISS_S: f32 = 7.0;
 const DEFAULT_ERROR_DISMISS_S: f32 = 9.0;
 const PLAYER_ENTERED_WORLD_EVENT_TYPE: &str = "player_entered_world";
+const MINING_STATUS_EVENT_TYPE: &str = "mining_status";
+const CARGO_COLLECTED_EVENT_TYPE: &str = "cargo_collected";
+const CRAFTING_JOB_FAILED_EVENT_TYPE: &str = "crafting_job_failed";
+const CRAFTING_JOB_COMPLETED_EVENT_TYPE: &str = "crafting_job_completed";
+const STATION_SERVICE_PURCHASED_EVENT_TYPE: &str = "station_service_purchased";
+const STATION_SERVICE_FAILED_EVENT_TYPE: &str = "station_service_failed";
+const MARKET_TRADE_COMPLETED_EVENT_TYPE: &str = "market_trade_completed";
+const MARKET_TRADE_FAILED_EVENT_TYPE: &str = "market_trade_failed";
+const MISSION_COMPLETED_EVENT_TYPE: &str = "mission_completed";
 
 #[derive(Debug, Clone)]
 pub struct NotificationCommand {
@@ -198,6 +213,383 @@ pub(crate) fn enqueue_player_entered_world_notifications(
     recipient_count
 }
 
+pub fn enqueue_mining_notifications(
+    mut status_events: MessageReader<'_, '_, MiningStatusChangedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in status_events.read() {
+        let Some(owner_id) = event.miner_owner_id.as_deref() else {
+            continue;
+        };
+        queue.push(mining_status_notification(owner_id, event));
+    }
+}
+
+fn mining_status_notification(
+    player_entity_id: &str,
+    event: &MiningStatusChangedEvent,
+) -> NotificationCommand {
+    let (status, body, severity) = match event.status {
+        MiningStatus::Started => (
+            "started",
+            "Mining laser active.",
+            NotificationSeverity::Info,
+        ),
+        MiningStatus::Stopped => ("stopped", "Mining laser off.", NotificationSeverity::Info),
+        MiningStatus::HoldFull => (
+            "hold_full",
+            "Cargo hold full. Mining laser shut down.",
+            NotificationSeverity::Warning,
+        ),
+    };
+    NotificationCommand {
+        player_entity_id: canonical_player_entity_id(player_entity_id),
+        title: "Mining".to_string(),
+        body: body.to_string(),
+        severity,
+        placement: NotificationPlacement::BottomRight,
+        image: None,
+        payload: NotificationPayload::Generic {
+            event_type: MINING_STATUS_EVENT_TYPE.to_string(),
+            data: serde_json::json!({
+                "miner_entity_id": event.miner_guid.to_string(),
+                "status": status,
+            }),
+        },
+        auto_dismiss_after_s: None,
+    }
+}
+
+pub fn enqueue_cargo_collected_notifications(
+    mut collected_events: MessageReader<'_, '_, CargoCollectedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in collected_events.read() {
+        let Some(owner_id) = event.collector_owner_id.as_deref() else {
+            continue;
+        };
+        queue.push(NotificationCommand {
+            player_entity_id: canonical_player_entity_id(owner_id),
+            title: "Cargo".to_string(),
+            body: format!("Scooped {} x {}.", event.quantity, event.item_id),
+            severity: NotificationSeverity::Info,
+            placement: NotificationPlacement::BottomRight,
+            image: None,
+            payload: NotificationPayload::Generic {
+                event_type: CARGO_COLLECTED_EVENT_TYPE.to_string(),
+                data: serde_json::json!({
+                    "collector_entity_id": event.collector_guid.to_string(),
+                    "container_entity_id": event.container_guid.to_string(),
+                    "item_id": event.item_id,
+                    "quantity": event.quantity,
+                }),
+            },
+            auto_dismiss_after_s: None,
+        });
+    }
+}
+
+pub fn enqueue_crafting_notifications(
+    mut failed_events: MessageReader<'_, '_, CraftingJobFailedEvent>,
+    mut completed_events: MessageReader<'_, '_, CraftingJobCompletedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in failed_events.read() {
+        let Some(player_id) = event
+            .requested_by_player_id
+            .as_deref()
+            .or(event.facility_owner_id.as_deref())
+        else {
+            continue;
+        };
+        queue.push(crafting_job_failed_notification(player_id, event));
+    }
+    for event in completed_events.read() {
+        let Some(player_id) = event
+            .requested_by_player_id
+            .as_deref()
+            .or(event.facility_owner_id.as_deref())
+        else {
+            continue;
+        };
+        queue.push(crafting_job_completed_notification(player_id, event));
+    }
+}
+
+fn crafting_job_failed_notification(
+    player_entity_id: &str,
+    event: &CraftingJobFailedEvent,
+) -> NotificationCommand {
+    let body = match event.reason {
+        CraftingFailureReason::UnknownRecipe => {
+            format!("Recipe {} is not available.", event.recipe_id)
+        }
+        CraftingFailureReason::FacilityMismatch => {
+            format!("Recipe {} cannot run at this facility.", event.recipe_id)
+        }
+        CraftingFailureReason::NotFacilityOwner => {
+            "You do not own this crafting facility.".to_string()
+        }
+        CraftingFailureReason::MissingInputs => {
+            format!(
+                "Missing inputs for {}; the job was cancelled.",
+                event.recipe_id
+            )
+        }
+        CraftingFailureReason::CargoFull => {
+            format!("Cargo is full; output of {} is on hold.", event.recipe_id)
+        }
+    };
+    NotificationCommand {
+        player_entity_id: canonical_player_entity_id(player_entity_id),
+        title: "Crafting".to_string(),
+        body,
+        severity: NotificationSeverity::Warning,
+        placement: NotificationPlacement::BottomRight,
+        image: None,
+        payload: NotificationPayload::Generic {
+            event_type: CRAFTING_JOB_FAILED_EVENT_TYPE.to_string(),
+            data: serde_json::json!({
+                "facility_entity_id": event.facility_guid.to_string(),
+                "job_id": event.job_id.to_string(),
+                "recipe_id": event.recipe_id,
+                "reason": event.reason.as_str(),
+            }),
+        },
+        auto_dismiss_after_s: None,
+    }
+}
+
+fn crafting_job_completed_notification(
+    player_entity_id: &str,
+    event: &CraftingJobCompletedEvent,
+) -> NotificationCommand {
+    NotificationCommand {
+        player_entity_id: canonical_player_entity_id(player_entity_id),
+        title: "Crafting".to_string(),
+        body: format!(
+            "Produced {} x {}.",
+            event.output_quantity, event.output_item_id
+        ),
+        severity: NotificationSeverity::Success,
+        placement: NotificationPlacement::BottomRight,
+        image: None,
+        payload: NotificationPayload::Generic {
+            event_type: CRAFTING_JOB_COMPLETED_EVENT_TYPE.to_string(),
+            data: serde_json::json!({
+                "facility_entity_id": event.facility_guid.to_string(),
+                "job_id": event.job_id.to_string(),
+                "recipe_id": event.recipe_id,
+                "item_id": event.output_item_id,
+                "quantity": event.output_quantity,
+            }),
+        },
+        auto_dismiss_after_s: None,
+    }
+}
+
+pub fn enqueue_station_service_notifications(
+    mut purchased_events: MessageReader<'_, '_, StationServicePurchasedEvent>,
+    mut failed_events: MessageReader<'_, '_, StationServiceFailedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in purchased_events.read() {
+        queue.push(station_service_purchased_notification(event));
+    }
+    for event in failed_events.read() {
+        let Some(player_guid) = event.player_guid else {
+            continue;
+        };
+        let body = match (event.reason, event.kind) {
+            (StationServiceFailureReason::NoServiceInRange, StationServiceKind::HullRepair) => {
+                "No repair bay in range.".to_string()
+            }
+            (StationServiceFailureReason::NoServiceInRange, StationServiceKind::Refuel) => {
+                "No fuel depot in range.".to_string()
+            }
+            (StationServiceFailureReason::NothingToService, StationServiceKind::HullRepair) => {
+                "Hull is already at full strength.".to_string()
+            }
+            (StationServiceFailureReason::NothingToService, StationServiceKind::Refuel) => {
+                "Fuel tanks are already full.".to_string()
+            }
+            (StationServiceFailureReason::NoWallet, _) => {
+                "No credit account is linked to this ship.".to_string()
+            }
+            (StationServiceFailureReason::InsufficientCredits, _) => {
+                "Insufficient credits.".to_string()
+            }
+        };
+        queue.push(NotificationCommand {
+            player_entity_id: canonical_player_entity_id(&player_guid.to_string()),
+            title: "Station Services".to_string(),
+            body,
+            severity: NotificationSeverity::Warning,
+            placement: NotificationPlacement::BottomRight,
+            image: None,
+            payload: NotificationPayload::Generic {
+                event_type: STATION_SERVICE_FAILED_EVENT_TYPE.to_string(),
+                data: serde_json::json!({
+                    "ship_entity_id": event.ship_guid.to_string(),
+                    "service_kind": event.kind.as_str(),
+                    "reason": event.reason.as_str(),
+                }),
+            },
+            auto_dismiss_after_s: None,
+        });
+    }
+}
+
+fn station_service_purchased_notification(
+    event: &StationServicePurchasedEvent,
+) -> NotificationCommand {
+    let body = match event.kind {
+        StationServiceKind::HullRepair => format!(
+            "Repaired {} hull for {} credits.",
+            event.units, event.credits_charged
+        ),
+        StationServiceKind::Refuel => format!(
+            "Loaded {} kg of fuel for {} credits.",
+            event.units, event.credits_charged
+        ),
+    };
+    NotificationCommand {
+        player_entity_id: canonical_player_entity_id(&event.player_guid.to_string()),
+        title: "Station Services".to_string(),
+        body,
+        severity: NotificationSeverity::Success,
+        placement: NotificationPlacement::BottomRight,
+        image: None,
+        payload: NotificationPayload::Generic {
+            event_type: STATION_SERVICE_PURCHASED_EVENT_TYPE.to_string(),
+            data: serde_json::json!({
+                "transaction_id": event.transaction_id.to_string(),
+                "ship_entity_id": event.ship_guid.to_string(),
+                "station_entity_id": event.station_guid.to_string(),
+                "service_kind": event.kind.as_str(),
+                "units": event.units,
+                "credits_charged": event.credits_charged,
+                "balance_after": event.balance_after,
+            }),
+        },
+        auto_dismiss_after_s: None,
+    }
+}
+
+pub fn enqueue_market_trade_notifications(
+    mut completed_events: MessageReader<'_, '_, MarketTradeCompletedEvent>,
+    mut failed_events: MessageReader<'_, '_, MarketTradeFailedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in completed_events.read() {
+        queue.push(market_trade_completed_notification(event));
+    }
+    for event in failed_events.read() {
+        let body = match event.reason {
+            MarketTradeFailureReason::InvalidQuantity => "Invalid trade quantity.",
+            MarketTradeFailureReason::NotShipOwner => "You do not command that ship.",
+            MarketTradeFailureReason::UnknownMarket => "That market is closed.",
+            MarketTradeFailureReason::OutOfRange => "Market is out of trading range.",
+            MarketTradeFailureReason::NotTraded => "The market does not trade that item.",
+            MarketTradeFailureReason::InsufficientStock => "The market is out of stock.",
+            MarketTradeFailureReason::MarketFull => "The market cannot take any more.",
+            MarketTradeFailureReason::InsufficientCargo => "Not enough of that item in the hold.",
+            MarketTradeFailureReason::CargoFull => "Not enough cargo space.",
+            MarketTradeFailureReason::NoWallet => "No credit account is linked to this ship.",
+            MarketTradeFailureReason::InsufficientCredits => "Insufficient credits.",
+        };
+        queue.push(NotificationCommand {
+            player_entity_id: canonical_player_entity_id(&event.player_guid.to_string()),
+            title: "Market".to_string(),
+            body: body.to_string(),
+            severity: NotificationSeverity::Warning,
+            placement: NotificationPlacement::BottomRight,
+            image: None,
+            payload: NotificationPayload::Generic {
+                event_type: MARKET_TRADE_FAILED_EVENT_TYPE.to_string(),
+                data: serde_json::json!({
+                    "ship_entity_id": event.ship_guid.to_string(),
+                    "market_entity_id": event.market_guid.to_string(),
+                    "item_id": event.item_id,
+                    "side": event.side.as_str(),
+                    "quantity": event.quantity,
+                    "reason": event.reason.as_str(),
+                }),
+            },
+            auto_dismiss_after_s: None,
+        });
+    }
+}
+
+fn market_trade_completed_notification(event: &MarketTradeCompletedEvent) -> NotificationCommand {
+    let body = match event.side {
+        MarketTradeSide::Buy => format!(
+            "Bought {} x {} for {} credits.",
+            event.quantity, event.item_id, event.credits
+        ),
+        MarketTradeSide::Sell => format!(
+            "Sold {} x {} for {} credits.",
+            event.quantity, event.item_id, event.credits
+        ),
+    };
+    NotificationCommand {
+        player_entity_id: canonical_player_entity_id(&event.player_guid.to_string()),
+        title: "Market".to_string(),
+        body,
+        severity: NotificationSeverity::Success,
+        placement: NotificationPlacement::BottomRight,
+        image: None,
+        payload: NotificationPayload::Generic {
+            event_type: MARKET_TRADE_COMPLETED_EVENT_TYPE.to_string(),
+            data: serde_json::json!({
+                "transaction_id": event.transaction_id.to_string(),
+                "ship_entity_id": event.ship_guid.to_string(),
+                "station_entity_id": event.station_guid.to_string(),
+                "market_entity_id": event.market_guid.to_string(),
+                "item_id": event.item_id,
+                "side": event.side.as_str(),
+                "quantity": event.quantity,
+                "unit_price": event.unit_price,
+                "credits": event.credits,
+                "balance_after": event.balance_after,
+            }),
+        },
+        auto_dismiss_after_s: None,
+    }
+}
+
+pub fn enqueue_mission_notifications(
+    mut events: MessageReader<'_, '_, MissionCompletedEvent>,
+    mut queue: ResMut<'_, NotificationCommandQueue>,
+) {
+    for event in events.read() {
+        let body = if event.credits > 0 {
+            format!(
+                "{} complete. {} credits paid.",
+                event.display_name, event.credits
+            )
+        } else {
+            format!("{} complete.", event.display_name)
+        };
+        queue.push(NotificationCommand {
+            player_entity_id: canonical_player_entity_id(&event.player_guid.to_string()),
+            title: "Mission Complete".to_string(),
+            body,
+            severity: NotificationSeverity::Success,
+            placement: NotificationPlacement::BottomRight,
+            image: None,
+            payload: NotificationPayload::Generic {
+                event_type: MISSION_COMPLETED_EVENT_TYPE.to_string(),
+                data: serde_json::json!({
+                    "mission_id": event.mission_id,
+                    "credits": event.credits,
+                }),
+            },
+            auto_dismiss_after_s: None,
+        });
+    }
+}
+
 pub fn process_notification_commands(
     mut command_queue: ResMut<'_, NotificationCommandQueue>,
     mut delivery: ResMut<'_, NotificationDeliveryState>,
@@ -442,6 +834,103 @@ mod tests {
         assert_eq!(queue.len(), 1);
     }
 
+    #[test]
+    fn mining_notifications_report_transitions_only() {
+        let mut app = App::new();
+        app.init_resource::<NotificationCommandQueue>();
+        app.add_message::<MiningStatusChangedEvent>();
+        app.add_systems(Update, enqueue_mining_notifications);
+        for status in [MiningStatus::Started, MiningStatus::HoldFull] {
+            app.world_mut().write_message(MiningStatusChangedEvent {
+                miner_entity: Entity::PLACEHOLDER,
+                miner_guid: Uuid::nil(),
+                miner_owner_id: Some("11111111-1111-1111-1111-111111111111".to_string()),
+                status,
+            });
+        }
+        app.world_mut().write_message(MiningStatusChangedEvent {
+            miner_entity: Entity::PLACEHOLDER,
+            miner_guid: Uuid::nil(),
+            miner_owner_id: None,
+            status: MiningStatus::Stopped,
+        });
+
+        app.update();
+
+        let queue = app.world().resource::<NotificationCommandQueue>();
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pending[0].body, "Mining laser active.");
+        let hold_full = &queue.pending[1];
+        assert_eq!(hold_full.severity, NotificationSeverity::Warning);
+        let NotificationPayload::Generic { event_type, data } = &hold_full.payload else {
+            panic!("expected generic payload");
+        };
+        assert_eq!(event_type, MINING_STATUS_EVENT_TYPE);
+        assert_eq!(data["status"], "hold_full");
+    }
+
+    #[test]
+    fn crafting_failure_notification_prefers_requesting_player() {
+        let mut app = App::new();
+        app.init_resource::<NotificationCommandQueue>();
+        app.add_message::<CraftingJobFailedEvent>();
+        app.add_message::<CraftingJobCompletedEvent>();
+        app.world_mut().write_message(CraftingJobFailedEvent {
+            facility_entity: Entity::PLACEHOLDER,
+            facility_guid: Uuid::nil(),
+            facility_owner_id: Some("22222222-2222-2222-2222-222222222222".to_string()),
+            job_id: Uuid::nil(),
+            recipe_id: "recipe.refine_iron_ingot".to_string(),
+            requested_by_player_id: Some("11111111-1111-1111-1111-111111111111".to_string()),
+            reason: CraftingFailureReason::MissingInputs,
+        });
+
+        app.add_systems(Update, enqueue_crafting_notifications);
+        app.update();
+
+        let queue = app.world().resource::<NotificationCommandQueue>();
+        assert_eq!(queue.len(), 1);
+        let command = queue.pending.front().expect("queued notification");
+        assert!(same_player(
+            &command.player_entity_id,
+            "11111111-1111-1111-1111-111111111111"
+        ));
+        let NotificationPayload::Generic { event_type, data } = &command.payload else {
+            panic!("expected generic payload");
+        };
+        assert_eq!(event_type, CRAFTING_JOB_FAILED_EVENT_TYPE);
+        assert_eq!(data["reason"], "missing_inputs");
+    }
+
+    #[test]
+    fn station_service_purchase_notification_reports_charge() {
+        let event = StationServicePurchasedEvent {
+            transaction_id: Uuid::nil(),
+            player_guid: Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap(),
+            ship_guid: Uuid::nil(),
+            station_guid: Uuid::nil(),
+            service_guid: Uuid::nil(),
+            kind: StationServiceKind::Refuel,
+            units: 40.0,
+            unit_price: 3,
+            credits_charged: 120,
+            balance_after: 880,
+        };
+
+        let command = station_service_purchased_notification(&event);
+
+        assert!(same_player(
+            &command.player_entity_id,
+            "11111111-1111-1111-1111-111111111111"
+        ));
+        assert_eq!(command.body, "Loaded 40 kg of fuel for 120 credits.");
+        let NotificationPayload::Generic { event_type, data } = command.payload else {
+            panic!("expected generic payload");
+        };
+        assert_eq!(event_type, STATION_SERVICE_PURCHASED_EVENT_TYPE);
+        assert_eq!(data["balance_after"], 880);
+    }
+
     #[test]
     fn canonical_player_matching_rejects_mismatches() {
         assert!(same_player(
@@ -4,7 +4,8 @@ use bevy::ecs::reflect::AppTypeRegistry;
 use bevy::log::error;
 use bevy::prelude::*;
 use sidereal_game::{
-    BallisticProjectile, EntityGuid, EntityLabels, GeneratedComponentRegistry, MountedOn,
+    BallisticProjectile, EntityGuid, EntityLabels, GeneratedComponentRegistry, GuidedMissile,
+    MountedOn,
 };
 use sidereal_persistence::{GraphEntityRecord, GraphPersistence};
 use sidereal_runtime_sync::serialize_entity_components_to_graph_records;
@@ -190,7 +191,9 @@ fn graph_record_for_entity(
         );
         return None;
     }
-    if world.get::<BallisticProjectile>(entity).is_some() {
+    if world.get::<BallisticProjectile>(entity).is_some()
+        || world.get::<GuidedMissile>(entity).is_some()
+    {
         return None;
     }
 
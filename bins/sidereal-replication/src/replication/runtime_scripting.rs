@@ -1,56 +1,116 @@
-use avian2d::prelude::Position;
+use avian2d::prelude::{LinearVelocity, Position};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::reflect::AppTypeRegistry;
+use bevy::reflect::TypeRegistry;
 use bevy::{math::DVec2, prelude::*};
 use mlua::{Function, Lua, RegistryKey, Table, UserData, UserDataMethods, Value};
 use serde_json::Value as JsonValue;
 use sidereal_game::{
-    EntityGuid, FlightComputer, OwnerId, ScriptNavigationTarget, ScriptState, ScriptValue,
-    WorldPosition,
+    ActionQueue, CargoCapacity, CreditLedger, CreditWallet, DEFAULT_DESTRUCTION_PROFILE_ID,
+    DamageType, Destructible, DirectDamageRequest, EntityAction, EntityGuid, FactionDamagePolicy,
+    FactionId, FactionRegistry, FactionReputation, FactionStanding, FlightComputer,
+    GeneratedComponentRegistry, HealthPool, Inventory, MassDirty, MountedOn, OwnerId,
+    PendingDestruction, PendingDestructionPhase, PlayerTag, ScriptNavigationTarget, ScriptState,
+    ScriptTimer, ScriptTimers, ScriptValue, ScriptWeaponTarget, WEAPON_ACTIONS, WeaponGroup,
+    WorldPosition, debit_credits, grant_credits, ledger_tick,
 };
 use sidereal_net::{
     NotificationImageRef, NotificationPayload, NotificationPlacement, NotificationSeverity,
     PlayerEntityId,
 };
+use sidereal_runtime_sync::{parse_guid_from_entity_id, serialize_component_to_json};
 use sidereal_scripting::{
-    LuaSandboxPolicy, ScriptError, create_sandboxed_lua_vm, inject_script_logger,
-    load_lua_module_into_lua_from_source, lua_value_to_json, reset_lua_instruction_budget,
+    LuaBudgetViolation, LuaSandboxPolicy, ScriptError, create_sandboxed_lua_vm,
+    inject_script_logger, load_lua_module_into_lua_from_source, lua_budget_violation,
+    lua_instructions_used, lua_value_to_json, reset_lua_instruction_budget,
 };
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::time::Instant;
 use uuid::Uuid;
 
+use crate::replication::missions::{MissionStartQueue, MissionStartRequest};
 use crate::replication::notifications::{NotificationCommand, NotificationCommandQueue};
-use crate::replication::scripting::{ScriptCatalogResource, lookup_script_catalog_entry};
+use crate::replication::scripting::{
+    AssetRegistryResource, EntityRegistryResource, ScriptCatalogResource,
+    lookup_script_catalog_entry, scripts_root_dir, spawn_bundle_graph_records_cached,
+};
+use crate::replication::simulation_entities::{
+    PlayerControlledEntityMap, PlayerRuntimeEntityMap, hydrate_records_into_world,
+};
+use crate::replication::visibility::VisibilitySpatialIndex;
 
 const SCRIPT_NOTIFICATION_TITLE_MAX_CHARS: usize = 96;
 const SCRIPT_NOTIFICATION_BODY_MAX_CHARS: usize = 512;
+const SCRIPT_CREDIT_REASON_MAX_CHARS: usize = 64;
+const SCRIPT_CREDIT_TRANSFER_MAX: u64 = 100_000;
+/// Handlers under this catalog path are admin-authored: they may grant
+/// credits, spawn bundles and start missions without acting through an
+/// entity. The catalog is written from disk or through the admin dashboard.
+const SCRIPT_ADMIN_PATH_PREFIX: &str = "ai/admin/";
+const SCRIPT_SPAWN_ALLOWED_OVERRIDE_KEYS: &[&str] = &[
+    "display_name",
+    "owner_id",
+    "spawn_position",
+    "script_state_data",
+];
+const SCRIPT_SPAWN_MAX_OVERRIDE_JSON_BYTES: usize = 4096;
+const SCRIPT_TIMER_MAX_DELAY_S: f64 = 86_400.0;
+const SCRIPT_TIMER_MIN_REPEAT_INTERVAL_S: f64 = 0.1;
+const SCRIPT_TIMER_EVENT_NAME_MAX_CHARS: usize = 64;
+const SCRIPT_TIMERS_MAX_PER_ENTITY: usize = 32;
+/// Radius queries spanning more grid cells than this per axis scan every
+/// entity instead.
+const SCRIPT_SPATIAL_QUERY_MAX_CELL_SPAN: i64 = 64;
 
 #[derive(Clone)]
-struct ScriptEntitySnapshot {
-    guid: String,
-    position: DVec2,
-    script_state: Option<JsonValue>,
+pub(crate) struct ScriptEntitySnapshot {
+    pub(crate) guid: String,
+    pub(crate) position: DVec2,
+    pub(crate) velocity: DVec2,
+    /// Own `FactionId`, else the owning player's.
+    pub(crate) faction_id: Option<String>,
+    /// The entity itself when it is a player, else the player named by its
+    /// `OwnerId`.
+    pub(crate) responsible_player_id: Option<String>,
+    /// Set for modules mounted on a parent entity.
+    pub(crate) parent_entity_id: Option<String>,
+    pub(crate) script_state: Option<JsonValue>,
+    /// Replicated components by component kind, serialized through the
+    /// generated component registry.
+    pub(crate) components: Arc<HashMap<String, JsonValue>>,
+}
+
+impl ScriptEntitySnapshot {
+    fn component_json(&self, component_kind: &str) -> Option<&JsonValue> {
+        if component_kind == "script_state" {
+            return self.script_state.as_ref();
+        }
+        self.components.get(component_kind)
+    }
+}
+
+fn dvec2_table(lua: &Lua, value: DVec2) -> mlua::Result<Table> {
+    let table = lua.create_table()?;
+    table.set("x", value.x)?;
+    table.set("y", value.y)?;
+    Ok(table)
 }
 
 impl UserData for ScriptEntitySnapshot {
     fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
         methods.add_method("guid", |_lua, this, ()| Ok(this.guid.clone()));
-        methods.add_method("position", |lua, this, ()| {
-            let table = lua.create_table()?;
-            table.set("x", this.position.x)?;
-            table.set("y", this.position.y)?;
-            Ok(table)
-        });
+        methods.add_method("position", |lua, this, ()| dvec2_table(lua, this.position));
+        methods.add_method("velocity", |lua, this, ()| dvec2_table(lua, this.velocity));
+        methods.add_method("faction_id", |_lua, this, ()| Ok(this.faction_id.clone()));
         methods.add_method("has", |_lua, this, component_kind: String| {
-            Ok(component_kind == "script_state" && this.script_state.is_some())
+            Ok(this.component_json(&component_kind).is_some())
         });
         methods.add_method("get", |lua, this, component_kind: String| {
-            if component_kind != "script_state" {
-                return Ok(Value::Nil);
-            }
-            match &this.script_state {
+            match this.component_json(&component_kind) {
                 Some(value) => json_to_lua_value(lua, value)
                     .map_err(|err| mlua::Error::runtime(err.to_string())),
                 None => Ok(Value::Nil),
@@ -59,16 +119,352 @@ impl UserData for ScriptEntitySnapshot {
     }
 }
 
-#[derive(Resource, Default)]
+/// Read-only world view handed to scripts, rebuilt every fixed tick.
+/// Entities are bucketed by the visibility spatial index cell they occupy.
+#[derive(Resource, Clone, Default)]
 pub struct ScriptWorldSnapshot {
-    entities_by_guid: HashMap<String, ScriptEntitySnapshot>,
+    pub(crate) entities_by_guid: HashMap<String, ScriptEntitySnapshot>,
+    pub(crate) cell_size_m: f64,
+    pub(crate) guids_by_cell: HashMap<(i64, i64), Vec<String>>,
+    pub(crate) faction_registry: Option<FactionRegistry>,
+    pub(crate) reputation_by_player_id: HashMap<String, FactionReputation>,
+}
+
+/// Optional `filter` table accepted by the world radius queries.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ScriptEntityFilter {
+    pub(crate) component: Option<String>,
+    pub(crate) faction_id: Option<String>,
+    pub(crate) exclude_entity_id: Option<String>,
+    pub(crate) include_mounted: bool,
+    pub(crate) limit: Option<usize>,
+}
+
+impl ScriptEntityFilter {
+    fn matches(&self, entity: &ScriptEntitySnapshot) -> bool {
+        if !self.include_mounted && entity.parent_entity_id.is_some() {
+            return false;
+        }
+        if self
+            .exclude_entity_id
+            .as_deref()
+            .is_some_and(|excluded| excluded == entity.guid)
+        {
+            return false;
+        }
+        if self
+            .component
+            .as_deref()
+            .is_some_and(|kind| entity.component_json(kind).is_none())
+        {
+            return false;
+        }
+        self.faction_id
+            .as_deref()
+            .is_none_or(|faction_id| entity.faction_id.as_deref() == Some(faction_id))
+    }
+}
+
+impl ScriptWorldSnapshot {
+    fn clear(&mut self) {
+        self.entities_by_guid.clear();
+        self.guids_by_cell.clear();
+        self.reputation_by_player_id.clear();
+        self.faction_registry = None;
+    }
+
+    pub(crate) fn insert_entity(&mut self, entity: ScriptEntitySnapshot, cell: Option<(i64, i64)>) {
+        if let Some(cell) = cell.or_else(|| self.cell_for_position(entity.position)) {
+            self.guids_by_cell
+                .entry(cell)
+                .or_default()
+                .push(entity.guid.clone());
+        }
+        self.entities_by_guid.insert(entity.guid.clone(), entity);
+    }
+
+    fn cell_for_position(&self, position: DVec2) -> Option<(i64, i64)> {
+        (self.cell_size_m > 0.0).then(|| {
+            (
+                (position.x / self.cell_size_m).floor() as i64,
+                (position.y / self.cell_size_m).floor() as i64,
+            )
+        })
+    }
+
+    /// Entities within `radius_m` of `center`, nearest first, ties broken by
+    /// guid.
+    pub(crate) fn find_in_radius(
+        &self,
+        center: DVec2,
+        radius_m: f64,
+        filter: &ScriptEntityFilter,
+    ) -> Vec<&ScriptEntitySnapshot> {
+        let radius_m = radius_m.max(0.0);
+        let radius_sq = radius_m * radius_m;
+        let mut found = Vec::new();
+        let cell_span = self
+            .cell_for_position(center)
+            .map(|cell| (cell, (radius_m / self.cell_size_m).ceil() as i64))
+            .filter(|(_, span)| *span <= SCRIPT_SPATIAL_QUERY_MAX_CELL_SPAN);
+        match cell_span {
+            Some(((cx, cy), span)) => {
+                for dx in -span..=span {
+                    for dy in -span..=span {
+                        let Some(guids) = self.guids_by_cell.get(&(cx + dx, cy + dy)) else {
+                            continue;
+                        };
+                        found.extend(
+                            guids
+                                .iter()
+                                .filter_map(|guid| self.entities_by_guid.get(guid)),
+                        );
+                    }
+                }
+            }
+            None => found.extend(self.entities_by_guid.values()),
+        }
+        let mut found = found
+            .into_iter()
+            .filter(|entity| entity.position.distance_squared(center) <= radius_sq)
+            .filter(|entity| filter.matches(entity))
+            .collect::<Vec<_>>();
+        found.sort_by(|left, right| {
+            left.position
+                .distance_squared(center)
+                .total_cmp(&right.position.distance_squared(center))
+                .then_with(|| left.guid.cmp(&right.guid))
+        });
+        if let Some(limit) = filter.limit {
+            found.truncate(limit);
+        }
+        found
+    }
+
+    /// Nearest entity within `radius_m` of `entity_id` whose standing toward
+    /// that entity's faction is hostile. Player-affiliated entities also turn
+    /// hostile through their reputation with the faction.
+    pub(crate) fn find_nearest_hostile(
+        &self,
+        entity_id: &str,
+        radius_m: f64,
+        filter: &ScriptEntityFilter,
+    ) -> Option<&ScriptEntitySnapshot> {
+        let source = self.entities_by_guid.get(entity_id)?;
+        let source_faction = source.faction_id.as_deref()?;
+        let registry = self.faction_registry.as_ref()?;
+        let filter = ScriptEntityFilter {
+            exclude_entity_id: Some(source.guid.clone()),
+            limit: None,
+            ..filter.clone()
+        };
+        self.find_in_radius(source.position, radius_m, &filter)
+            .into_iter()
+            .find(|candidate| {
+                self.standing_toward(registry, candidate, source_faction)
+                    == FactionStanding::Hostile
+            })
+    }
+
+    fn standing_toward(
+        &self,
+        registry: &FactionRegistry,
+        candidate: &ScriptEntitySnapshot,
+        faction_id: &str,
+    ) -> FactionStanding {
+        if let Some(player_id) = candidate.responsible_player_id.as_deref() {
+            let reputation = self
+                .reputation_by_player_id
+                .get(player_id)
+                .and_then(|reputation| reputation.value(faction_id));
+            return registry.standing_toward_player(
+                candidate.faction_id.as_deref(),
+                reputation,
+                faction_id,
+            );
+        }
+        candidate
+            .faction_id
+            .as_deref()
+            .map_or(FactionStanding::Neutral, |candidate_faction| {
+                registry.standing(faction_id, candidate_faction)
+            })
+    }
 }
 
+/// One loaded AI script. Every handler runs in its own sandboxed VM, so the
+/// `LuaSandboxPolicy` instruction and memory limits, its globals and its
+/// failures are accounted to it alone.
 struct ScriptHandler {
     name: String,
+    lua: Lua,
     on_tick_function_key: Option<RegistryKey>,
     default_tick_interval_s: f64,
     on_event_function_keys: HashMap<String, RegistryKey>,
+    admin_authored: bool,
+    quarantine_after_failures: u32,
+    metrics: ScriptHandlerMetrics,
+    /// Set by the failure that quarantines the handler; taken once by the
+    /// next metrics publish.
+    quarantine_notice: Option<ScriptHandlerQuarantinedEvent>,
+}
+
+struct ScriptHandlerRunError {
+    stage: &'static str,
+    message: String,
+}
+
+impl ScriptHandler {
+    fn is_quarantined(&self) -> bool {
+        self.metrics.quarantine_reason.is_some()
+    }
+
+    /// Calls `on_tick` (no `event_name`) or `on_<event_name>` for one entity
+    /// and returns the intents it emitted. Failures count toward quarantine.
+    fn run(
+        &mut self,
+        event_name: Option<&str>,
+        world_view: &Rc<ScriptWorldSnapshot>,
+        entity_guid: &str,
+        payload: &JsonValue,
+    ) -> Result<Vec<ScriptIntent>, ScriptHandlerRunError> {
+        let result = self.call(event_name, world_view, entity_guid, payload);
+        match &result {
+            Ok(_) => self.metrics.consecutive_failures = 0,
+            Err(err) => self.record_failure(err),
+        }
+        result.map_err(|(_, err)| err)
+    }
+
+    fn call(
+        &mut self,
+        event_name: Option<&str>,
+        world_view: &Rc<ScriptWorldSnapshot>,
+        entity_guid: &str,
+        payload: &JsonValue,
+    ) -> Result<Vec<ScriptIntent>, (Option<LuaBudgetViolation>, ScriptHandlerRunError)> {
+        let setup_error =
+            |stage: &'static str, message: String| (None, ScriptHandlerRunError { stage, message });
+        let function_key = match event_name {
+            None => self.on_tick_function_key.as_ref(),
+            Some(event_name) => self.on_event_function_keys.get(event_name),
+        }
+        .ok_or_else(|| setup_error("registry lookup", "no such entry point".to_string()))?;
+        let pending_intents = Rc::new(RefCell::new(Vec::<ScriptIntent>::new()));
+        let ctx = build_script_context(
+            &self.lua,
+            Rc::clone(world_view),
+            pending_intents.clone(),
+            self.name.as_str(),
+            entity_guid,
+        )
+        .map_err(|err| setup_error("context build", err.to_string()))?;
+        let event = json_to_lua_value(&self.lua, payload)
+            .map_err(|err| setup_error("event encode", err.to_string()))?;
+        let function = self
+            .lua
+            .registry_value::<Function>(function_key)
+            .map_err(|err| setup_error("registry decode", err.to_string()))?;
+
+        reset_lua_instruction_budget(&self.lua);
+        let started_at = Instant::now();
+        let result = function.call::<()>((ctx, event));
+        let run_ms = started_at.elapsed().as_secs_f64() * 1000.0;
+        let instructions = lua_instructions_used(&self.lua);
+        let metrics = &mut self.metrics;
+        metrics.runs = metrics.runs.saturating_add(1);
+        metrics.last_run_ms = Some(run_ms);
+        metrics.max_run_ms = metrics.max_run_ms.max(run_ms);
+        metrics.total_run_ms += run_ms;
+        metrics.last_run_instructions = instructions;
+        metrics.max_run_instructions = metrics.max_run_instructions.max(instructions);
+        metrics.memory_bytes = self.lua.used_memory() as u64;
+        result.map_err(|err| {
+            (
+                lua_budget_violation(&err),
+                ScriptHandlerRunError {
+                    stage: "execution",
+                    message: err.to_string(),
+                },
+            )
+        })?;
+        let authority = ScriptIntentAuthority {
+            acting_entity_id: Uuid::parse_str(entity_guid).ok(),
+            admin: self.admin_authored,
+        };
+        let intents = pending_intents
+            .borrow_mut()
+            .drain(..)
+            .map(|mut intent| {
+                match &mut intent {
+                    ScriptIntent::TransferCredits {
+                        authority: stamped, ..
+                    }
+                    | ScriptIntent::SpawnBundle {
+                        authority: stamped, ..
+                    }
+                    | ScriptIntent::StartMission {
+                        authority: stamped, ..
+                    } => *stamped = authority,
+                    ScriptIntent::ApplyDamage {
+                        acting_entity_id: stamped,
+                        ..
+                    } => *stamped = authority.acting_entity_id,
+                    _ => {}
+                }
+                intent
+            })
+            .collect();
+        Ok(intents)
+    }
+
+    fn record_failure(
+        &mut self,
+        (violation, err): &(Option<LuaBudgetViolation>, ScriptHandlerRunError),
+    ) {
+        let metrics = &mut self.metrics;
+        metrics.error_count = metrics.error_count.saturating_add(1);
+        match violation {
+            Some(LuaBudgetViolation::Instructions) => {
+                metrics.instruction_limit_hits = metrics.instruction_limit_hits.saturating_add(1);
+            }
+            Some(LuaBudgetViolation::Memory) => {
+                metrics.memory_limit_hits = metrics.memory_limit_hits.saturating_add(1);
+            }
+            None => {}
+        }
+        metrics.consecutive_failures = metrics.consecutive_failures.saturating_add(1);
+        if self.quarantine_after_failures == 0
+            || metrics.consecutive_failures < self.quarantine_after_failures
+        {
+            return;
+        }
+        let reason = match violation {
+            Some(violation) => violation.as_str().to_string(),
+            None => format!("{} failed", err.stage),
+        };
+        error!(
+            "replication runtime script handler={} quarantined after {} consecutive failures reason={} last_error={}; reload the script to re-enable it",
+            self.name, metrics.consecutive_failures, reason, err.message
+        );
+        self.quarantine_notice = Some(ScriptHandlerQuarantinedEvent {
+            handler_name: self.name.clone(),
+            reason: reason.clone(),
+            consecutive_failures: metrics.consecutive_failures,
+            last_error: err.message.clone(),
+        });
+        metrics.quarantine_reason = Some(reason);
+    }
+}
+
+/// Raised once when a handler is quarantined so the admin health feed can
+/// surface it; reloading the script clears the quarantine.
+#[derive(Debug, Clone, Message)]
+pub struct ScriptHandlerQuarantinedEvent {
+    pub handler_name: String,
+    pub reason: String,
+    pub consecutive_failures: u32,
+    pub last_error: String,
 }
 
 #[derive(Debug, Clone)]
@@ -76,6 +472,9 @@ pub struct ScriptEvent {
     pub event_name: String,
     pub payload: JsonValue,
     pub target_entity_id: Option<String>,
+    /// Delivers to this handler instead of the one named in the entity's
+    /// `event_hooks`; set for timer events.
+    pub handler_name: Option<String>,
 }
 
 #[derive(Resource, Default)]
@@ -93,6 +492,27 @@ pub struct ScriptRuntimeMetrics {
     pub reload_count: u64,
     pub last_interval_run_ms: Option<f64>,
     pub last_event_run_ms: Option<f64>,
+    /// Sorted by handler name; refreshed after every script pass.
+    pub handlers: Vec<ScriptHandlerMetrics>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScriptHandlerMetrics {
+    pub handler_name: String,
+    pub runs: u64,
+    pub error_count: u64,
+    pub instruction_limit_hits: u64,
+    pub memory_limit_hits: u64,
+    pub consecutive_failures: u32,
+    pub last_run_ms: Option<f64>,
+    pub max_run_ms: f64,
+    pub total_run_ms: f64,
+    /// Counted in steps of the sandbox hook interval.
+    pub last_run_instructions: u64,
+    pub max_run_instructions: u64,
+    pub memory_bytes: u64,
+    /// Set when the handler is quarantined; cleared by reloading its script.
+    pub quarantine_reason: Option<String>,
 }
 
 impl Default for ScriptRuntimeMetrics {
@@ -107,6 +527,7 @@ impl Default for ScriptRuntimeMetrics {
             reload_count: 0,
             last_interval_run_ms: None,
             last_event_run_ms: None,
+            handlers: Vec::new(),
         }
     }
 }
@@ -128,10 +549,104 @@ pub(crate) enum ScriptIntent {
     NotifyPlayer {
         command: NotificationCommand,
     },
+    /// Moves credits in a player's wallet; recorded in their ledger as
+    /// `script.<reason>`. `authority` is stamped by the emitting handler.
+    TransferCredits {
+        player_entity_id: Uuid,
+        direction: CreditDirection,
+        amount: u64,
+        reason: String,
+        counterparty: Option<Uuid>,
+        authority: ScriptIntentAuthority,
+    },
+    /// Queues a fire action for `weapon_group` on the shooter and names the
+    /// target in `ScriptWeaponTarget`, which aims its ballistic weapons and
+    /// locks its missiles.
+    FireWeapons {
+        entity_id: Uuid,
+        target_entity_id: Uuid,
+        weapon_group: u8,
+    },
+    /// Spawns an NPC-owned bundle through `spawn_bundle_graph_records`.
+    /// Needs an admin handler or one running for a script-controlled entity.
+    SpawnBundle {
+        spawn: ScriptBundleSpawn,
+        authority: ScriptIntentAuthority,
+    },
+    /// Destroys the entity through `PendingDestruction`, so destruction
+    /// listeners see `EntityDestroyedEvent`, and despawns its mounted modules.
+    Despawn {
+        entity_id: Uuid,
+    },
+    SetFaction {
+        entity_id: Uuid,
+        faction_id: Option<String>,
+    },
+    /// Moves cargo between two script-controlled inventories and marks both
+    /// for a mass recompute.
+    TransferInventory {
+        from_entity_id: Uuid,
+        to_entity_id: Uuid,
+        item_id: String,
+        quantity: u32,
+    },
+    /// Weaponless damage from the handler's own script-controlled entity.
+    /// Passes the faction gate here, then shields and resistances through
+    /// `DirectDamageRequest`. `acting_entity_id` is stamped by the handler.
+    ApplyDamage {
+        source_entity_id: Uuid,
+        target_entity_id: Uuid,
+        amount: f32,
+        damage_type: DamageType,
+        acting_entity_id: Option<Uuid>,
+    },
+    ScheduleTimer {
+        entity_id: Uuid,
+        timer: ScriptTimer,
+    },
+    CancelTimer {
+        entity_id: Uuid,
+        timer_id: Uuid,
+    },
+    /// Queued for `start_queued_missions`, which refuses missions that are
+    /// already active, completed and not repeatable, or over the log limit.
+    /// Needs an admin handler or one acting for that player.
+    StartMission {
+        player_entity_id: Uuid,
+        mission_id: String,
+        authority: ScriptIntentAuthority,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ScriptBundleSpawn {
+    pub(crate) bundle_id: String,
+    pub(crate) entity_id: Uuid,
+    pub(crate) overrides: serde_json::Map<String, JsonValue>,
+}
+
+/// Bundle spawns wait here for `apply_script_bundle_spawns`, which owns the
+/// registries and hydration state they need.
+#[derive(Resource, Default)]
+pub struct ScriptBundleSpawnQueue {
+    pub(crate) pending: Vec<ScriptBundleSpawn>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CreditDirection {
+    Debit,
+    Grant,
+}
+
+/// Who emitted an intent that is not scoped to the emitting entity: the
+/// entity the handler ran for, and whether the handler is admin-authored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ScriptIntentAuthority {
+    pub acting_entity_id: Option<Uuid>,
+    pub admin: bool,
 }
 
 pub struct ScriptRuntime {
-    lua: Lua,
     handlers: HashMap<String, ScriptHandler>,
     next_tick_run_by_entity_handler: HashMap<String, f64>,
     pending_intents: Vec<ScriptIntent>,
@@ -139,15 +654,15 @@ pub struct ScriptRuntime {
 }
 
 impl ScriptRuntime {
-    fn from_catalog(catalog: &ScriptCatalogResource) -> Result<Self, ScriptError> {
+    pub(crate) fn from_catalog(catalog: &ScriptCatalogResource) -> Result<Self, ScriptError> {
         let policy = LuaSandboxPolicy::from_env();
-        let lua = create_sandboxed_lua_vm(&policy)?;
         let mut handlers = HashMap::new();
 
         for script_rel_path in discover_ai_script_paths(catalog) {
             let entry = lookup_script_catalog_entry(catalog, &script_rel_path)
                 .map_err(ScriptError::Contract)?;
             let module_path = Path::new(&script_rel_path).to_path_buf();
+            let lua = create_sandboxed_lua_vm(&policy)?;
             let module = load_lua_module_into_lua_from_source(&lua, &entry.source, &module_path)?;
             let default_handler_name = Path::new(&script_rel_path)
                 .file_stem()
@@ -193,30 +708,73 @@ impl ScriptRuntime {
                 }
             }
 
+            let metrics = ScriptHandlerMetrics {
+                handler_name: handler_name.clone(),
+                memory_bytes: lua.used_memory() as u64,
+                ..Default::default()
+            };
             handlers.insert(
                 handler_name.clone(),
                 ScriptHandler {
                     name: handler_name,
+                    lua,
                     on_tick_function_key,
                     default_tick_interval_s,
                     on_event_function_keys,
+                    admin_authored: script_rel_path.starts_with(SCRIPT_ADMIN_PATH_PREFIX),
+                    quarantine_after_failures: policy.quarantine_after_failures,
+                    metrics,
+                    quarantine_notice: None,
                 },
             );
         }
 
         Ok(Self {
-            lua,
             handlers,
             next_tick_run_by_entity_handler: HashMap::new(),
             pending_intents: Vec::new(),
             catalog_revision: catalog.revision,
         })
     }
+
+    pub(crate) fn queue_intent(&mut self, intent: ScriptIntent) {
+        self.pending_intents.push(intent);
+    }
+
+    fn has_runnable_handlers(&self) -> bool {
+        self.handlers
+            .values()
+            .any(|handler| !handler.is_quarantined())
+    }
+
+    fn take_quarantine_notices(&mut self) -> Vec<ScriptHandlerQuarantinedEvent> {
+        self.handlers
+            .values_mut()
+            .filter_map(|handler| handler.quarantine_notice.take())
+            .collect()
+    }
+
+    fn publish_handler_metrics(&self, metrics: &mut ScriptRuntimeMetrics) {
+        let mut handlers = self
+            .handlers
+            .values()
+            .map(|handler| ScriptHandlerMetrics {
+                memory_bytes: handler.lua.used_memory() as u64,
+                ..handler.metrics.clone()
+            })
+            .collect::<Vec<_>>();
+        handlers.sort_by(|a, b| a.handler_name.cmp(&b.handler_name));
+        metrics.current_memory_bytes = Some(handlers.iter().map(|h| h.memory_bytes).sum());
+        metrics.handlers = handlers;
+    }
 }
 
 pub fn init_resources(app: &mut App) {
+    app.add_message::<ScriptHandlerQuarantinedEvent>();
     app.insert_resource(ScriptWorldSnapshot::default());
     app.insert_resource(ScriptEventQueue::default());
+    app.insert_resource(ScriptBundleSpawnQueue::default());
+    app.insert_resource(MissionStartQueue::default());
     app.insert_resource(ScriptRuntimeMetrics::default());
     let catalog = app.world().resource::<ScriptCatalogResource>().clone();
     match ScriptRuntime::from_catalog(&catalog) {
@@ -238,37 +796,202 @@ pub fn init_resources(app: &mut App) {
     }
 }
 
+/// Replicated component values per entity, kept across ticks so the script
+/// world view only re-serializes components that were added or changed.
+#[derive(Default)]
+pub struct ScriptComponentCache {
+    replicated_registry: Option<GeneratedComponentRegistry>,
+    components_by_entity: HashMap<Entity, Arc<HashMap<String, JsonValue>>>,
+}
+
+struct ScriptWatchedComponent {
+    component_kind: &'static str,
+    type_path: &'static str,
+    component_id: ComponentId,
+}
+
+/// Rebuilds the script world view. Guid, position, faction and spatial cell
+/// are read every tick; component values come from `ScriptComponentCache`,
+/// which goes through the generated component registry so every replicated
+/// component is readable by kind without per-component plumbing. Skipped
+/// while no handlers are loaded.
 #[allow(clippy::type_complexity)]
 pub fn refresh_script_world_snapshot(
-    mut snapshot: ResMut<'_, ScriptWorldSnapshot>,
-    query: Query<
-        '_,
-        '_,
-        (
-            &'_ EntityGuid,
-            Option<&'_ Position>,
-            Option<&'_ WorldPosition>,
-            Option<&'_ Transform>,
-            Option<&'_ ScriptState>,
-        ),
-    >,
+    world: &mut World,
+    mut cache: Local<'_, ScriptComponentCache>,
 ) {
-    snapshot.entities_by_guid.clear();
-    for (guid, position, world_position, transform, script_state) in &query {
+    let has_handlers = world
+        .get_non_send_resource::<ScriptRuntime>()
+        .is_some_and(|runtime| runtime.has_runnable_handlers());
+    // Dropping last tick's view leaves the cached maps uniquely owned, so
+    // changed values are updated in place.
+    world.resource_mut::<ScriptWorldSnapshot>().clear();
+    if !has_handlers {
+        cache.components_by_entity.clear();
+        return;
+    }
+    if cache.replicated_registry.is_none() {
+        cache.replicated_registry = world
+            .get_resource::<GeneratedComponentRegistry>()
+            .map(GeneratedComponentRegistry::replicated_subset);
+    }
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let watched = cache
+        .replicated_registry
+        .as_ref()
+        .map(|registry| {
+            registry
+                .entries
+                .iter()
+                .filter_map(|entry| {
+                    let registration = type_registry.get_with_type_path(entry.type_path)?;
+                    Some(ScriptWatchedComponent {
+                        component_kind: entry.component_kind,
+                        type_path: entry.type_path,
+                        component_id: world.components().get_id(registration.type_id())?,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let last_change_tick = world.last_change_tick();
+    let read_change_tick = world.read_change_tick();
+    let mut cached_components = std::mem::take(&mut cache.components_by_entity);
+
+    let mut query = world.query::<(
+        Entity,
+        &'_ EntityGuid,
+        Option<&'_ Position>,
+        Option<&'_ WorldPosition>,
+        Option<&'_ Transform>,
+        Option<&'_ LinearVelocity>,
+        Option<&'_ FactionId>,
+        Option<&'_ OwnerId>,
+        Has<PlayerTag>,
+        Option<&'_ MountedOn>,
+        Option<&'_ ScriptState>,
+    )>();
+    let mut players = world.query_filtered::<(
+        &'_ EntityGuid,
+        Option<&'_ FactionId>,
+        Option<&'_ FactionReputation>,
+    ), With<PlayerTag>>();
+    let mut player_factions = HashMap::<Uuid, Option<String>>::new();
+    let mut reputation_by_player_id = HashMap::new();
+    for (guid, faction_id, reputation) in players.iter(world) {
+        player_factions.insert(guid.0, faction_id.map(|faction_id| faction_id.0.clone()));
+        if let Some(reputation) = reputation {
+            reputation_by_player_id.insert(guid.0.to_string(), reputation.clone());
+        }
+    }
+
+    let spatial_index = world.get_resource::<VisibilitySpatialIndex>();
+    let mut snapshot = ScriptWorldSnapshot {
+        cell_size_m: spatial_index.map_or(0.0, |index| f64::from(index.cell_size_m())),
+        faction_registry: world.get_resource::<FactionRegistry>().cloned(),
+        reputation_by_player_id,
+        ..default()
+    };
+    for (
+        entity,
+        guid,
+        position,
+        world_position,
+        transform,
+        velocity,
+        faction_id,
+        owner_id,
+        is_player,
+        mounted_on,
+        script_state,
+    ) in query.iter(world)
+    {
         let pos = position
             .map(|v| v.0)
             .or_else(|| world_position.map(|v| v.0))
             .or_else(|| transform.map(|t| t.translation.truncate().as_dvec2()))
             .unwrap_or(DVec2::ZERO);
-        snapshot.entities_by_guid.insert(
-            guid.0.to_string(),
+        let entity_id = guid.0.to_string();
+        let owner_player = owner_id
+            .and_then(|owner| Uuid::parse_str(&owner.0).ok())
+            .filter(|owner| player_factions.contains_key(owner));
+        let responsible_player = if is_player {
+            Some(guid.0)
+        } else {
+            owner_player
+        };
+        let faction_id = faction_id
+            .map(|faction_id| faction_id.0.clone())
+            .or_else(|| {
+                owner_player.and_then(|owner| player_factions.get(&owner).cloned().flatten())
+            });
+        let entity_ref = world.entity(entity);
+        let mut cached = cached_components.remove(&entity);
+        let is_new = cached.is_none();
+        let components = cached.get_or_insert_default();
+        refresh_script_components(
+            components,
+            entity_ref,
+            &watched,
+            &type_registry,
+            |component_id| {
+                is_new
+                    || entity_ref
+                        .get_change_ticks_by_id(component_id)
+                        .is_some_and(|ticks| ticks.is_changed(last_change_tick, read_change_tick))
+            },
+        );
+        cache
+            .components_by_entity
+            .insert(entity, Arc::clone(components));
+        let cell = spatial_index.and_then(|index| index.cell_of_entity(entity));
+        snapshot.insert_entity(
             ScriptEntitySnapshot {
-                guid: guid.0.to_string(),
+                guid: entity_id,
                 position: pos,
+                velocity: velocity.map_or(DVec2::ZERO, |v| v.0),
+                faction_id,
+                responsible_player_id: responsible_player.map(|player| player.to_string()),
+                parent_entity_id: mounted_on.map(|mounted| mounted.parent_entity_id.to_string()),
                 script_state: script_state.map(script_state_to_json),
+                components: Arc::clone(components),
             },
+            cell,
         );
     }
+    world.insert_resource(snapshot);
+}
+
+/// Re-serializes the watched components `is_changed` reports and drops the
+/// ones the entity no longer has. Untouched values are reused as they are.
+fn refresh_script_components(
+    components: &mut Arc<HashMap<String, JsonValue>>,
+    entity_ref: EntityRef<'_>,
+    watched: &[ScriptWatchedComponent],
+    type_registry: &TypeRegistry,
+    is_changed: impl Fn(ComponentId) -> bool,
+) {
+    for watched in watched {
+        if !entity_ref.contains_id(watched.component_id) {
+            if components.contains_key(watched.component_kind) {
+                Arc::make_mut(components).remove(watched.component_kind);
+            }
+            continue;
+        }
+        if !is_changed(watched.component_id) {
+            continue;
+        }
+        let components = Arc::make_mut(components);
+        match serialize_component_to_json(entity_ref, watched.type_path, type_registry) {
+            Some(value) => {
+                components.insert(watched.component_kind.to_string(), value);
+            }
+            None => {
+                components.remove(watched.component_kind);
+            }
+        }
+    }
 }
 
 pub fn run_script_intervals(
@@ -277,6 +1000,7 @@ pub fn run_script_intervals(
     snapshot: Res<'_, ScriptWorldSnapshot>,
     time: Res<'_, Time>,
     mut metrics: ResMut<'_, ScriptRuntimeMetrics>,
+    mut quarantines: MessageWriter<'_, ScriptHandlerQuarantinedEvent>,
 ) {
     let Some(mut runtime) = runtime else { return };
     if runtime.catalog_revision != catalog.revision {
@@ -296,24 +1020,17 @@ pub fn run_script_intervals(
         }
     }
     let now_s = time.elapsed_secs_f64();
-    if runtime.handlers.is_empty() {
+    if !runtime.has_runnable_handlers() {
         return;
     }
+    let runtime = &mut *runtime;
     let interval_started_at = Instant::now();
-    let snapshot_map = Rc::new(snapshot.entities_by_guid.clone());
-    for (entity_guid, entity) in &*snapshot_map {
+    let world_view = Rc::new(snapshot.clone());
+    for (entity_guid, entity) in &world_view.entities_by_guid {
         let Some((handler_name, interval_s)) = parse_tick_handler_config(entity) else {
             continue;
         };
-        let Some((handler_log_name, default_tick_interval_s, has_on_tick)) =
-            runtime.handlers.get(&handler_name).map(|handler| {
-                (
-                    handler.name.clone(),
-                    handler.default_tick_interval_s,
-                    handler.on_tick_function_key.is_some(),
-                )
-            })
-        else {
+        let Some(handler) = runtime.handlers.get_mut(&handler_name) else {
             metrics.error_count = metrics.error_count.saturating_add(1);
             warn!(
                 "replication runtime scripting entity={} references unknown on_tick_handler={}",
@@ -321,14 +1038,14 @@ pub fn run_script_intervals(
             );
             continue;
         };
-        if !has_on_tick {
+        if handler.on_tick_function_key.is_none() || handler.is_quarantined() {
             continue;
         }
-        let interval_s = interval_s.unwrap_or(default_tick_interval_s);
+        let interval_s = interval_s.unwrap_or(handler.default_tick_interval_s);
         if interval_s <= 0.0 {
             continue;
         }
-        let schedule_key = format!("{}::{}", handler_log_name, entity_guid);
+        let schedule_key = format!("{}::{}", handler.name, entity_guid);
         if let Some(next_run_s) = runtime.next_tick_run_by_entity_handler.get(&schedule_key)
             && now_s < *next_run_s
         {
@@ -338,69 +1055,24 @@ pub fn run_script_intervals(
             .next_tick_run_by_entity_handler
             .insert(schedule_key, now_s + interval_s);
 
-        let pending_intents = Rc::new(RefCell::new(Vec::<ScriptIntent>::new()));
-        let ctx = match build_script_context(
-            &runtime.lua,
-            Rc::clone(&snapshot_map),
-            pending_intents.clone(),
-            handler_log_name.as_str(),
-        ) {
-            Ok(v) => v,
-            Err(err) => {
-                metrics.error_count = metrics.error_count.saturating_add(1);
-                warn!(
-                    "replication runtime script on_tick handler={} entity={} context build failed: {}",
-                    handler_log_name, entity_guid, err
-                );
-                continue;
-            }
-        };
         let event_payload = serde_json::json!({ "entity_id": entity_guid });
-        let event = match json_to_lua_value(&runtime.lua, &event_payload) {
-            Ok(v) => v,
-            Err(err) => {
-                metrics.error_count = metrics.error_count.saturating_add(1);
-                warn!(
-                    "replication runtime script on_tick handler={} entity={} event encode failed: {}",
-                    handler_log_name, entity_guid, err
-                );
-                continue;
+        match handler.run(None, &world_view, entity_guid, &event_payload) {
+            Ok(intents) => {
+                metrics.interval_runs = metrics.interval_runs.saturating_add(1);
+                runtime.pending_intents.extend(intents);
             }
-        };
-        let Some(function_key) = runtime
-            .handlers
-            .get(&handler_name)
-            .and_then(|handler| handler.on_tick_function_key.as_ref())
-        else {
-            continue;
-        };
-        let function = match runtime.lua.registry_value::<Function>(function_key) {
-            Ok(v) => v,
             Err(err) => {
                 metrics.error_count = metrics.error_count.saturating_add(1);
                 warn!(
-                    "replication runtime script on_tick handler={} entity={} registry decode failed: {}",
-                    handler_log_name, entity_guid, err
+                    "replication runtime script on_tick handler={} entity={} {} failed: {}",
+                    handler.name, entity_guid, err.stage, err.message
                 );
-                continue;
             }
-        };
-        reset_lua_instruction_budget(&runtime.lua);
-        if let Err(err) = function.call::<()>((ctx, event)) {
-            metrics.error_count = metrics.error_count.saturating_add(1);
-            warn!(
-                "replication runtime script on_tick handler={} entity={} execution failed: {}",
-                handler_log_name, entity_guid, err
-            );
-            continue;
         }
-        metrics.interval_runs = metrics.interval_runs.saturating_add(1);
-        runtime
-            .pending_intents
-            .extend(pending_intents.borrow_mut().drain(..));
     }
     metrics.last_interval_run_ms = Some(interval_started_at.elapsed().as_secs_f64() * 1000.0);
-    metrics.current_memory_bytes = None;
+    quarantines.write_batch(runtime.take_quarantine_notices());
+    runtime.publish_handler_metrics(&mut metrics);
 }
 
 pub fn run_script_events(
@@ -409,7 +1081,11 @@ pub fn run_script_events(
     snapshot: Res<'_, ScriptWorldSnapshot>,
     mut event_queue: ResMut<'_, ScriptEventQueue>,
     mut metrics: ResMut<'_, ScriptRuntimeMetrics>,
+    mut quarantines: MessageWriter<'_, ScriptHandlerQuarantinedEvent>,
 ) {
+    // Drained every tick, delivered or not, so events raised while no runtime
+    // is loaded do not pile up and replay once one is.
+    let events = std::mem::take(&mut event_queue.pending);
     let Some(mut runtime) = runtime else { return };
     if runtime.catalog_revision != catalog.revision {
         match ScriptRuntime::from_catalog(&catalog) {
@@ -427,26 +1103,34 @@ pub fn run_script_events(
             }
         }
     }
-    if runtime.handlers.is_empty() || event_queue.pending.is_empty() {
+    if !runtime.has_runnable_handlers() || events.is_empty() {
         return;
     }
+    let runtime = &mut *runtime;
     let events_started_at = Instant::now();
-    let snapshot_map = Rc::new(snapshot.entities_by_guid.clone());
-    let events = std::mem::take(&mut event_queue.pending);
+    let world_view = Rc::new(snapshot.clone());
     for event in events {
         let target_entities = if let Some(entity_id) = &event.target_entity_id {
             vec![entity_id.clone()]
         } else {
-            snapshot_map.keys().cloned().collect::<Vec<_>>()
+            world_view
+                .entities_by_guid
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
         };
         for entity_guid in target_entities {
-            let Some(entity) = snapshot_map.get(&entity_guid) else {
+            let Some(entity) = world_view.entities_by_guid.get(&entity_guid) else {
                 continue;
             };
-            let Some(handler_name) = parse_event_handler_config(entity, &event.event_name) else {
+            let Some(handler_name) = event
+                .handler_name
+                .clone()
+                .or_else(|| parse_event_handler_config(entity, &event.event_name))
+            else {
                 continue;
             };
-            let Some(handler) = runtime.handlers.get(&handler_name) else {
+            let Some(handler) = runtime.handlers.get_mut(&handler_name) else {
                 metrics.error_count = metrics.error_count.saturating_add(1);
                 warn!(
                     "replication runtime scripting entity={} event={} references unknown handler={}",
@@ -454,27 +1138,14 @@ pub fn run_script_events(
                 );
                 continue;
             };
-            let Some(function_key) = handler.on_event_function_keys.get(&event.event_name) else {
+            if handler.is_quarantined()
+                || !handler
+                    .on_event_function_keys
+                    .contains_key(&event.event_name)
+            {
                 continue;
-            };
+            }
 
-            let pending_intents = Rc::new(RefCell::new(Vec::<ScriptIntent>::new()));
-            let ctx = match build_script_context(
-                &runtime.lua,
-                Rc::clone(&snapshot_map),
-                pending_intents.clone(),
-                handler_name.as_str(),
-            ) {
-                Ok(v) => v,
-                Err(err) => {
-                    metrics.error_count = metrics.error_count.saturating_add(1);
-                    warn!(
-                        "replication runtime script on_{} handler={} entity={} context build failed: {}",
-                        event.event_name, handler.name, entity_guid, err
-                    );
-                    continue;
-                }
-            };
             let mut event_payload = event.payload.clone();
             let has_entity_id = event_payload
                 .as_object()
@@ -487,105 +1158,100 @@ pub fn run_script_events(
                 );
                 event_payload = JsonValue::Object(payload_map);
             }
-            let event_lua = match json_to_lua_value(&runtime.lua, &event_payload) {
-                Ok(v) => v,
-                Err(err) => {
-                    metrics.error_count = metrics.error_count.saturating_add(1);
-                    warn!(
-                        "replication runtime script on_{} handler={} entity={} event encode failed: {}",
-                        event.event_name, handler.name, entity_guid, err
-                    );
-                    continue;
+            match handler.run(
+                Some(&event.event_name),
+                &world_view,
+                &entity_guid,
+                &event_payload,
+            ) {
+                Ok(intents) => {
+                    metrics.event_runs = metrics.event_runs.saturating_add(1);
+                    runtime.pending_intents.extend(intents);
                 }
-            };
-            let function = match runtime.lua.registry_value::<Function>(function_key) {
-                Ok(v) => v,
                 Err(err) => {
                     metrics.error_count = metrics.error_count.saturating_add(1);
                     warn!(
-                        "replication runtime script on_{} handler={} entity={} registry decode failed: {}",
-                        event.event_name, handler.name, entity_guid, err
+                        "replication runtime script on_{} handler={} entity={} {} failed: {}",
+                        event.event_name, handler.name, entity_guid, err.stage, err.message
                     );
-                    continue;
                 }
-            };
-            reset_lua_instruction_budget(&runtime.lua);
-            if let Err(err) = function.call::<()>((ctx, event_lua)) {
-                metrics.error_count = metrics.error_count.saturating_add(1);
-                warn!(
-                    "replication runtime script on_{} handler={} entity={} execution failed: {}",
-                    event.event_name, handler.name, entity_guid, err
-                );
-                continue;
             }
-            metrics.event_runs = metrics.event_runs.saturating_add(1);
-            runtime
-                .pending_intents
-                .extend(pending_intents.borrow_mut().drain(..));
         }
     }
     metrics.last_event_run_ms = Some(events_started_at.elapsed().as_secs_f64() * 1000.0);
-    metrics.current_memory_bytes = None;
+    quarantines.write_batch(runtime.take_quarantine_notices());
+    runtime.publish_handler_metrics(&mut metrics);
 }
 
-#[allow(clippy::type_complexity)]
+type ScriptIntentTargetQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static OwnerId>,
+        Option<&'static mut ScriptState>,
+        Option<&'static mut FlightComputer>,
+        Option<&'static mut ActionQueue>,
+        Option<&'static mut Inventory>,
+        Option<&'static CargoCapacity>,
+        Option<&'static mut HealthPool>,
+        Option<&'static MountedOn>,
+        Option<&'static mut ScriptTimers>,
+    ),
+>;
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn apply_script_intents(
     runtime: Option<NonSendMut<'_, ScriptRuntime>>,
     mut notification_queue: ResMut<'_, NotificationCommandQueue>,
+    mut bundle_spawns: ResMut<'_, ScriptBundleSpawnQueue>,
+    mut mission_starts: ResMut<'_, MissionStartQueue>,
+    faction_registry: Option<Res<'_, FactionRegistry>>,
+    time: Res<'_, Time<Fixed>>,
     mut commands: Commands<'_, '_>,
-    mut query: Query<
+    mut wallets: Query<
         '_,
         '_,
-        (
-            Entity,
-            &'_ EntityGuid,
-            Option<&'_ OwnerId>,
-            Option<&'_ mut ScriptState>,
-            Option<&'_ mut FlightComputer>,
-        ),
+        (&'_ EntityGuid, &'_ mut CreditWallet, &'_ mut CreditLedger),
+        With<PlayerTag>,
     >,
+    mut query: ScriptIntentTargetQuery<'_, '_>,
+    destructibles: Query<'_, '_, (Option<&'_ Destructible>, Has<PendingDestruction>)>,
+    faction_policy: FactionDamagePolicy<'_, '_>,
+    mut damage_requests: MessageWriter<'_, DirectDamageRequest>,
 ) {
     let Some(mut runtime) = runtime else { return };
     if runtime.pending_intents.is_empty() {
         return;
     }
     let intents = std::mem::take(&mut runtime.pending_intents);
+    let faction_gate = faction_policy.gate();
+    let mut new_timer_components = HashMap::<Entity, ScriptTimers>::new();
     for intent in intents {
         match intent {
             ScriptIntent::SetNavigationTarget {
                 entity_id,
                 target_position,
             } => {
-                for (entity, guid, owner_id, script_state, computer) in &mut query {
-                    if guid.0 != entity_id {
-                        continue;
-                    }
-                    if !is_script_controllable(owner_id, script_state.as_deref()) {
-                        break;
-                    }
-                    if computer.is_some() {
-                        commands
-                            .entity(entity)
-                            .insert(ScriptNavigationTarget { target_position });
-                    }
-                    break;
+                let Ok(entity) = controllable_intent_entity(&query, entity_id) else {
+                    continue;
+                };
+                if let Ok((_, _, _, _, Some(_computer), ..)) = query.get(entity) {
+                    commands
+                        .entity(entity)
+                        .insert(ScriptNavigationTarget { target_position });
                 }
             }
             ScriptIntent::Stop { entity_id } => {
-                for (entity, guid, owner_id, script_state, computer) in &mut query {
-                    if guid.0 != entity_id {
-                        continue;
-                    }
-                    if !is_script_controllable(owner_id, script_state.as_deref()) {
-                        break;
-                    }
-                    commands.entity(entity).remove::<ScriptNavigationTarget>();
-                    if let Some(mut computer) = computer {
-                        computer.throttle = 0.0;
-                        computer.yaw_input = 0.0;
-                        computer.brake_active = true;
-                    }
-                    break;
+                let Ok(entity) = controllable_intent_entity(&query, entity_id) else {
+                    continue;
+                };
+                commands.entity(entity).remove::<ScriptNavigationTarget>();
+                if let Ok((_, _, _, _, Some(mut computer), ..)) = query.get_mut(entity) {
+                    computer.throttle = 0.0;
+                    computer.yaw_input = 0.0;
+                    computer.brake_active = true;
                 }
             }
             ScriptIntent::SetScriptState {
@@ -593,27 +1259,524 @@ pub fn apply_script_intents(
                 key,
                 value,
             } => {
-                for (_entity, guid, owner_id, script_state, _computer) in &mut query {
-                    if guid.0 != entity_id {
+                let Ok(entity) = controllable_intent_entity(&query, entity_id) else {
+                    continue;
+                };
+                if let Ok((_, _, _, Some(mut script_state), ..)) = query.get_mut(entity) {
+                    script_state.data.insert(key, json_to_script_value(&value));
+                }
+            }
+            ScriptIntent::NotifyPlayer { command } => {
+                notification_queue.push(command);
+            }
+            ScriptIntent::TransferCredits {
+                player_entity_id,
+                direction,
+                amount,
+                reason,
+                counterparty,
+                authority,
+            } => {
+                if let Err(refusal) =
+                    authorize_credit_transfer(&query, player_entity_id, direction, authority)
+                {
+                    warn_refused_intent("transfer_credits", player_entity_id, refusal);
+                    continue;
+                }
+                let Some((_, mut wallet, mut ledger)) = wallets
+                    .iter_mut()
+                    .find(|(guid, _, _)| guid.0 == player_entity_id)
+                else {
+                    warn!(
+                        "script credit transfer skipped; no wallet for player={player_entity_id}"
+                    );
+                    continue;
+                };
+                let reason = format!("script.{reason}");
+                let tick = ledger_tick(&time);
+                let result = match direction {
+                    CreditDirection::Debit => debit_credits(
+                        &mut wallet,
+                        &mut ledger,
+                        amount,
+                        &reason,
+                        counterparty,
+                        tick,
+                    ),
+                    CreditDirection::Grant => grant_credits(
+                        &mut wallet,
+                        &mut ledger,
+                        amount,
+                        &reason,
+                        counterparty,
+                        tick,
+                    ),
+                };
+                if let Err(err) = result {
+                    warn!(
+                        "script credit transfer refused player={player_entity_id} amount={amount} reason={reason} error={}",
+                        err.as_str()
+                    );
+                }
+            }
+            ScriptIntent::FireWeapons {
+                entity_id,
+                target_entity_id,
+                weapon_group,
+            } => {
+                let result = controllable_intent_entity(&query, entity_id).and_then(|shooter| {
+                    if target_entity_id == entity_id
+                        || intent_entity(&query, target_entity_id).is_none()
+                    {
+                        return Err("unknown target");
+                    }
+                    let Ok((_, _, _, _, _, Some(mut queue), ..)) = query.get_mut(shooter) else {
+                        return Err("shooter has no action queue");
+                    };
+                    let action = fire_action_for_weapon_group(weapon_group)
+                        .ok_or("unsupported weapon group")?;
+                    queue.push(action);
+                    commands.entity(shooter).insert(ScriptWeaponTarget {
+                        target_guid: target_entity_id,
+                    });
+                    Ok(())
+                });
+                if let Err(reason) = result {
+                    warn_refused_intent("fire_weapons", entity_id, reason);
+                }
+            }
+            ScriptIntent::SpawnBundle { spawn, authority } => {
+                if let Err(reason) = authorize_world_intent(&query, authority) {
+                    warn_refused_intent("spawn_bundle", spawn.entity_id, reason);
+                    continue;
+                }
+                bundle_spawns.pending.push(spawn);
+            }
+            ScriptIntent::Despawn { entity_id } => {
+                let entity = match controllable_intent_entity(&query, entity_id) {
+                    Ok(entity) => entity,
+                    Err(reason) => {
+                        warn_refused_intent("despawn", entity_id, reason);
                         continue;
                     }
-                    let Some(mut script_state) = script_state else {
-                        break;
+                };
+                let Ok((destructible, already_pending)) = destructibles.get(entity) else {
+                    continue;
+                };
+                if already_pending {
+                    continue;
+                }
+                for (module, .., mounted_on, _) in &query {
+                    if mounted_on.is_some_and(|mounted_on| mounted_on.parent_entity_id == entity_id)
+                    {
+                        commands.entity(module).despawn();
+                    }
+                }
+                commands.entity(entity).insert(PendingDestruction {
+                    destruction_profile_id: destructible.map_or_else(
+                        || DEFAULT_DESTRUCTION_PROFILE_ID.to_string(),
+                        |destructible| destructible.destruction_profile_id.clone(),
+                    ),
+                    remaining_delay_s: 0.0,
+                    phase: PendingDestructionPhase::EffectDelay,
+                });
+            }
+            ScriptIntent::SetFaction {
+                entity_id,
+                faction_id,
+            } => {
+                let result = controllable_intent_entity(&query, entity_id).and_then(|entity| {
+                    let Some(faction_id) = faction_id else {
+                        commands.entity(entity).remove::<FactionId>();
+                        return Ok(());
                     };
-                    if !is_script_controllable(owner_id, Some(&script_state)) {
-                        break;
+                    if faction_registry
+                        .as_deref()
+                        .is_some_and(|registry| registry.faction(&faction_id).is_none())
+                    {
+                        return Err("unknown faction");
                     }
-                    script_state
-                        .data
-                        .insert(key.clone(), json_to_script_value(&value));
-                    break;
+                    commands.entity(entity).insert(FactionId(faction_id));
+                    Ok(())
+                });
+                if let Err(reason) = result {
+                    warn_refused_intent("set_faction", entity_id, reason);
                 }
             }
-            ScriptIntent::NotifyPlayer { command } => {
-                notification_queue.push(command);
+            ScriptIntent::TransferInventory {
+                from_entity_id,
+                to_entity_id,
+                item_id,
+                quantity,
+            } => {
+                let result = controllable_intent_entity(&query, from_entity_id).and_then(|from| {
+                    let to = controllable_intent_entity(&query, to_entity_id)
+                        .map_err(|_| "target is not script-controllable")?;
+                    transfer_script_inventory(&mut query, from, to, &item_id, quantity)?;
+                    commands.entity(from).insert(MassDirty);
+                    commands.entity(to).insert(MassDirty);
+                    Ok(())
+                });
+                if let Err(reason) = result {
+                    warn_refused_intent("transfer_inventory", from_entity_id, reason);
+                }
+            }
+            ScriptIntent::ApplyDamage {
+                source_entity_id,
+                target_entity_id,
+                amount,
+                damage_type,
+                acting_entity_id,
+            } => {
+                let result =
+                    controllable_intent_entity(&query, source_entity_id).and_then(|source| {
+                        if acting_entity_id != Some(source_entity_id) {
+                            return Err("source is not the handler's entity");
+                        }
+                        let target =
+                            intent_entity(&query, target_entity_id).ok_or("unknown target")?;
+                        if target == source {
+                            return Err("source cannot damage itself");
+                        }
+                        let Ok((.., Some(_), _, _)) = query.get(target) else {
+                            return Err("target has no health pool");
+                        };
+                        if !faction_gate.allows(Some(source), target) {
+                            return Err("faction policy forbids damaging target");
+                        }
+                        damage_requests.write(DirectDamageRequest {
+                            source_entity: source,
+                            source_guid: source_entity_id,
+                            target_entity: target,
+                            damage: amount,
+                            damage_type,
+                        });
+                        Ok(())
+                    });
+                if let Err(reason) = result {
+                    warn_refused_intent("apply_damage", source_entity_id, reason);
+                }
+            }
+            ScriptIntent::ScheduleTimer { entity_id, timer } => {
+                let result = controllable_intent_entity(&query, entity_id).and_then(|entity| {
+                    let Ok((.., Some(mut timers))) = query.get_mut(entity) else {
+                        let timers = new_timer_components.entry(entity).or_default();
+                        return push_script_timer(timers, timer);
+                    };
+                    push_script_timer(&mut timers, timer)
+                });
+                if let Err(reason) = result {
+                    warn_refused_intent("schedule", entity_id, reason);
+                }
+            }
+            ScriptIntent::CancelTimer {
+                entity_id,
+                timer_id,
+            } => {
+                let Ok(entity) = controllable_intent_entity(&query, entity_id) else {
+                    continue;
+                };
+                if let Ok((.., Some(mut timers))) = query.get_mut(entity) {
+                    timers.cancel(timer_id);
+                }
+                if let Some(timers) = new_timer_components.get_mut(&entity) {
+                    timers.cancel(timer_id);
+                }
+            }
+            ScriptIntent::StartMission {
+                player_entity_id,
+                mission_id,
+                authority,
+            } => {
+                if !authority.admin
+                    && let Err(reason) =
+                        authorize_player_context(&query, player_entity_id, authority)
+                {
+                    warn_refused_intent("start_mission", player_entity_id, reason);
+                    continue;
+                }
+                mission_starts.pending.push(MissionStartRequest {
+                    player_guid: player_entity_id,
+                    mission_id,
+                });
+            }
+        }
+    }
+    for (entity, timers) in new_timer_components {
+        commands.entity(entity).insert(timers);
+    }
+}
+
+fn push_script_timer(timers: &mut ScriptTimers, timer: ScriptTimer) -> Result<(), &'static str> {
+    if timers.timers.len() >= SCRIPT_TIMERS_MAX_PER_ENTITY {
+        return Err("too many pending timers");
+    }
+    timers.timers.push(timer);
+    Ok(())
+}
+
+/// Counts script timers down in simulation time and queues the fired ones as
+/// events for the handler that scheduled them.
+pub fn fire_script_timers(
+    time: Res<'_, Time<Fixed>>,
+    mut event_queue: ResMut<'_, ScriptEventQueue>,
+    mut timers: Query<'_, '_, (&'_ EntityGuid, &'_ mut ScriptTimers)>,
+) {
+    let dt_s = time.delta_secs_f64();
+    if dt_s <= 0.0 {
+        return;
+    }
+    for (guid, mut entity_timers) in &mut timers {
+        if entity_timers.timers.is_empty() {
+            continue;
+        }
+        for timer in entity_timers.advance(dt_s) {
+            event_queue.pending.push(script_timer_event(guid.0, timer));
+        }
+    }
+}
+
+pub(crate) fn script_timer_event(entity_id: Uuid, timer: ScriptTimer) -> ScriptEvent {
+    let mut payload = match script_value_to_json(&timer.payload) {
+        JsonValue::Object(payload) => payload,
+        JsonValue::Null => serde_json::Map::new(),
+        value => serde_json::Map::from_iter([("value".to_string(), value)]),
+    };
+    payload.insert(
+        "timer_id".to_string(),
+        JsonValue::String(timer.timer_id.to_string()),
+    );
+    payload.insert(
+        "entity_id".to_string(),
+        JsonValue::String(entity_id.to_string()),
+    );
+    ScriptEvent {
+        event_name: timer.event_name,
+        payload: JsonValue::Object(payload),
+        target_entity_id: Some(entity_id.to_string()),
+        handler_name: Some(timer.handler_name),
+    }
+}
+
+/// Hydrates queued script bundle spawns the same way admin spawns are
+/// hydrated. Persistence picks the new entities up as dirty on the next
+/// flush.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_script_bundle_spawns(
+    mut queue: ResMut<'_, ScriptBundleSpawnQueue>,
+    mut commands: Commands<'_, '_>,
+    mut controlled_entity_map: ResMut<'_, PlayerControlledEntityMap>,
+    mut player_entity_map: ResMut<'_, PlayerRuntimeEntityMap>,
+    mut script_events: ResMut<'_, ScriptEventQueue>,
+    component_registry: Res<'_, GeneratedComponentRegistry>,
+    app_type_registry: Res<'_, AppTypeRegistry>,
+    script_catalog: Res<'_, ScriptCatalogResource>,
+    entity_registry: Res<'_, EntityRegistryResource>,
+    asset_registry: Res<'_, AssetRegistryResource>,
+    all_guids: Query<'_, '_, &'_ EntityGuid>,
+    mut cached_scripts_root: Local<'_, Option<PathBuf>>,
+) {
+    if queue.pending.is_empty() {
+        return;
+    }
+    let scripts_root = cached_scripts_root
+        .get_or_insert_with(scripts_root_dir)
+        .clone();
+    let mut existing_guids = all_guids.iter().map(|guid| guid.0).collect::<HashSet<_>>();
+    for mut spawn in std::mem::take(&mut queue.pending) {
+        if !entity_registry
+            .entries
+            .iter()
+            .any(|entry| entry.entity_id == spawn.bundle_id)
+        {
+            warn!(
+                "script spawn_bundle refused bundle_id={}: unknown bundle",
+                spawn.bundle_id
+            );
+            continue;
+        }
+        if existing_guids.contains(&spawn.entity_id) {
+            warn!(
+                "script spawn_bundle refused bundle_id={} entity_id={}: entity already exists",
+                spawn.bundle_id, spawn.entity_id
+            );
+            continue;
+        }
+        spawn.overrides.insert(
+            "entity_id".to_string(),
+            JsonValue::String(spawn.entity_id.to_string()),
+        );
+        let records = match spawn_bundle_graph_records_cached(
+            &scripts_root,
+            script_catalog.as_ref(),
+            entity_registry.as_ref(),
+            asset_registry.as_ref(),
+            &spawn.bundle_id,
+            &spawn.overrides,
+        ) {
+            Ok(records) => records,
+            Err(err) => {
+                warn!(
+                    "script spawn_bundle failed bundle_id={}: {err}",
+                    spawn.bundle_id
+                );
+                continue;
             }
+        };
+        if records
+            .first()
+            .and_then(|record| parse_guid_from_entity_id(&record.entity_id))
+            != Some(spawn.entity_id)
+        {
+            warn!(
+                "script spawn_bundle failed bundle_id={}: root entity_id mismatch requested={}",
+                spawn.bundle_id, spawn.entity_id
+            );
+            continue;
         }
+        hydrate_records_into_world(
+            &mut commands,
+            &records,
+            &component_registry,
+            &app_type_registry,
+            &existing_guids,
+            &mut player_entity_map,
+            &mut controlled_entity_map,
+        );
+        existing_guids.extend(
+            records
+                .iter()
+                .filter_map(|record| parse_guid_from_entity_id(&record.entity_id)),
+        );
+        script_events.pending.push(ScriptEvent {
+            event_name: "bundle_spawned".to_string(),
+            payload: serde_json::json!({
+                "bundle_id": spawn.bundle_id,
+                "entity_id": spawn.entity_id.to_string(),
+            }),
+            target_entity_id: None,
+            handler_name: None,
+        });
+    }
+}
+
+fn intent_entity(query: &ScriptIntentTargetQuery<'_, '_>, entity_id: Uuid) -> Option<Entity> {
+    query
+        .iter()
+        .find(|(_, guid, ..)| guid.0 == entity_id)
+        .map(|(entity, ..)| entity)
+}
+
+fn controllable_intent_entity(
+    query: &ScriptIntentTargetQuery<'_, '_>,
+    entity_id: Uuid,
+) -> Result<Entity, &'static str> {
+    let (entity, _, owner_id, script_state, ..) = query
+        .iter()
+        .find(|(_, guid, ..)| guid.0 == entity_id)
+        .ok_or("unknown entity")?;
+    if !is_script_controllable(owner_id, script_state) {
+        return Err("entity is not script-controllable");
     }
+    Ok(entity)
+}
+
+/// All-or-nothing: refuses when the source is short or the target's
+/// `CargoCapacity` cannot hold the whole quantity.
+fn transfer_script_inventory(
+    query: &mut ScriptIntentTargetQuery<'_, '_>,
+    from: Entity,
+    to: Entity,
+    item_id: &str,
+    quantity: u32,
+) -> Result<(), &'static str> {
+    let Ok([from_item, to_item]) = query.get_many_mut([from, to]) else {
+        return Err("unknown entity");
+    };
+    let (_, _, _, _, _, _, Some(mut source), ..) = from_item else {
+        return Err("source has no inventory");
+    };
+    let (_, _, _, _, _, _, Some(mut target), capacity, ..) = to_item else {
+        return Err("target has no inventory");
+    };
+    let Some(unit_mass_kg) = source
+        .entries
+        .iter()
+        .find(|entry| entry.item_id == item_id)
+        .map(|entry| entry.unit_mass_kg)
+    else {
+        return Err("insufficient cargo");
+    };
+    if capacity.is_some_and(|capacity| {
+        !capacity.has_room_for(target.total_mass_kg(), unit_mass_kg * quantity as f32)
+    }) {
+        return Err("target cargo full");
+    }
+    if !source.remove_stack(item_id, quantity) {
+        return Err("insufficient cargo");
+    }
+    target.add_stack(item_id, quantity, unit_mass_kg);
+    Ok(())
+}
+
+fn warn_refused_intent(action: &str, entity_id: Uuid, reason: &str) {
+    warn!("script intent refused action={action} entity_id={entity_id}: {reason}");
+}
+
+/// Grants need an admin-authored handler. Debits need the handler to be
+/// running for the player or for an entity the player owns.
+fn authorize_credit_transfer(
+    query: &ScriptIntentTargetQuery<'_, '_>,
+    player_entity_id: Uuid,
+    direction: CreditDirection,
+    authority: ScriptIntentAuthority,
+) -> Result<(), &'static str> {
+    match direction {
+        CreditDirection::Grant if authority.admin => Ok(()),
+        CreditDirection::Grant => Err("handler may not grant credits"),
+        CreditDirection::Debit => authorize_player_context(query, player_entity_id, authority),
+    }
+}
+
+/// The handler must be running for the player or for an entity they own.
+fn authorize_player_context(
+    query: &ScriptIntentTargetQuery<'_, '_>,
+    player_entity_id: Uuid,
+    authority: ScriptIntentAuthority,
+) -> Result<(), &'static str> {
+    let acting_entity_id = authority
+        .acting_entity_id
+        .ok_or("intent has no acting entity")?;
+    if acting_entity_id == player_entity_id {
+        return Ok(());
+    }
+    let acting_owner = query
+        .iter()
+        .find(|(_, guid, ..)| guid.0 == acting_entity_id)
+        .and_then(|(_, _, owner_id, ..)| owner_id)
+        .and_then(|owner| PlayerEntityId::parse(owner.0.as_str()));
+    if acting_owner == Some(PlayerEntityId(player_entity_id)) {
+        Ok(())
+    } else {
+        Err("acting entity does not belong to the player")
+    }
+}
+
+/// World-level intents come from admin handlers, or from a handler running
+/// for a script-controlled entity.
+fn authorize_world_intent(
+    query: &ScriptIntentTargetQuery<'_, '_>,
+    authority: ScriptIntentAuthority,
+) -> Result<(), &'static str> {
+    if authority.admin {
+        return Ok(());
+    }
+    let acting_entity_id = authority
+        .acting_entity_id
+        .ok_or("intent has no acting entity")?;
+    controllable_intent_entity(query, acting_entity_id)
+        .map(|_| ())
+        .map_err(|_| "acting entity is not script-controllable")
 }
 
 fn is_script_controllable(owner_id: Option<&OwnerId>, script_state: Option<&ScriptState>) -> bool {
@@ -623,11 +1786,12 @@ fn is_script_controllable(owner_id: Option<&OwnerId>, script_state: Option<&Scri
     owner_id.is_some_and(|owner| PlayerEntityId::parse(owner.0.as_str()).is_none())
 }
 
-fn build_script_context(
+pub(crate) fn build_script_context(
     lua: &Lua,
-    snapshot_map: Rc<HashMap<String, ScriptEntitySnapshot>>,
+    world_view: Rc<ScriptWorldSnapshot>,
     pending_intents: Rc<RefCell<Vec<ScriptIntent>>>,
     script_label: &str,
+    entity_guid: &str,
 ) -> Result<Table, ScriptError> {
     let ctx = lua
         .create_table()
@@ -637,15 +1801,60 @@ fn build_script_context(
         .create_table()
         .map_err(|err| ScriptError::Runtime(format!("create script world failed: {err}")))?;
 
-    let entities_for_lookup = Rc::clone(&snapshot_map);
+    let entities_for_lookup = Rc::clone(&world_view);
     let find_entity = lua
         .create_function(move |_lua, (_world, guid): (Table, String)| {
-            Ok(entities_for_lookup.get(&guid).cloned())
+            Ok(entities_for_lookup.entities_by_guid.get(&guid).cloned())
         })
         .map_err(|err| ScriptError::Runtime(format!("create find_entity failed: {err}")))?;
     world
         .set("find_entity", find_entity)
         .map_err(|err| ScriptError::Runtime(format!("set find_entity failed: {err}")))?;
+
+    let entities_in_radius = Rc::clone(&world_view);
+    let find_in_radius = lua
+        .create_function(
+            move |lua, (_world, x, y, radius_m, filter): (Table, f64, f64, f64, Value)| {
+                if !(x.is_finite() && y.is_finite() && radius_m.is_finite()) {
+                    return Err(mlua::Error::runtime(
+                        "find_in_radius requires finite x, y and radius",
+                    ));
+                }
+                let filter = parse_script_entity_filter(filter).map_err(mlua::Error::runtime)?;
+                lua.create_sequence_from(
+                    entities_in_radius
+                        .find_in_radius(DVec2::new(x, y), radius_m, &filter)
+                        .into_iter()
+                        .cloned(),
+                )
+            },
+        )
+        .map_err(|err| ScriptError::Runtime(format!("create find_in_radius failed: {err}")))?;
+    world
+        .set("find_in_radius", find_in_radius)
+        .map_err(|err| ScriptError::Runtime(format!("set find_in_radius failed: {err}")))?;
+
+    let entities_for_hostiles = Rc::clone(&world_view);
+    let find_nearest_hostile = lua
+        .create_function(
+            move |_lua, (_world, entity_id, radius_m, filter): (Table, String, f64, Value)| {
+                if !radius_m.is_finite() {
+                    return Err(mlua::Error::runtime(
+                        "find_nearest_hostile requires a finite radius",
+                    ));
+                }
+                let filter = parse_script_entity_filter(filter).map_err(mlua::Error::runtime)?;
+                Ok(entities_for_hostiles
+                    .find_nearest_hostile(&entity_id, radius_m, &filter)
+                    .cloned())
+            },
+        )
+        .map_err(|err| {
+            ScriptError::Runtime(format!("create find_nearest_hostile failed: {err}"))
+        })?;
+    world
+        .set("find_nearest_hostile", find_nearest_hostile)
+        .map_err(|err| ScriptError::Runtime(format!("set find_nearest_hostile failed: {err}")))?;
     ctx.set("world", world)
         .map_err(|err| ScriptError::Runtime(format!("set world failed: {err}")))?;
 
@@ -681,9 +1890,153 @@ fn build_script_context(
         .map_err(|err| ScriptError::Runtime(format!("create notify_player failed: {err}")))?;
     ctx.set("notify_player", notify_player)
         .map_err(|err| ScriptError::Runtime(format!("set notify_player failed: {err}")))?;
+    inject_script_timer_fns(lua, &ctx, pending_intents, script_label, entity_guid)?;
     Ok(ctx)
 }
 
+/// `ctx:schedule`, `ctx:schedule_repeating` and `ctx:cancel_timer`. Timers
+/// belong to the entity the handler is running for and fire back into the
+/// same handler as `on_<event_name>`.
+fn inject_script_timer_fns(
+    lua: &Lua,
+    ctx: &Table,
+    pending_intents: Rc<RefCell<Vec<ScriptIntent>>>,
+    handler_name: &str,
+    entity_guid: &str,
+) -> Result<(), ScriptError> {
+    for (fn_name, repeating) in [("schedule", false), ("schedule_repeating", true)] {
+        let intents = Rc::clone(&pending_intents);
+        let handler_name = handler_name.to_string();
+        let entity_guid = entity_guid.to_string();
+        let schedule = lua
+            .create_function(
+                move |_lua, (_ctx, delay_s, event_name, payload): (Table, f64, String, Value)| {
+                    let payload = lua_value_to_json(payload).map_err(|err| {
+                        mlua::Error::runtime(format!("timer payload decode failed: {err}"))
+                    })?;
+                    let (entity_id, timer) = parse_schedule_timer(
+                        &entity_guid,
+                        &handler_name,
+                        delay_s,
+                        repeating,
+                        &event_name,
+                        &payload,
+                    )
+                    .map_err(mlua::Error::runtime)?;
+                    let timer_id = timer.timer_id.to_string();
+                    intents
+                        .borrow_mut()
+                        .push(ScriptIntent::ScheduleTimer { entity_id, timer });
+                    Ok(timer_id)
+                },
+            )
+            .map_err(|err| ScriptError::Runtime(format!("create {fn_name} failed: {err}")))?;
+        ctx.set(fn_name, schedule)
+            .map_err(|err| ScriptError::Runtime(format!("set {fn_name} failed: {err}")))?;
+    }
+    let entity_guid = entity_guid.to_string();
+    let cancel_timer = lua
+        .create_function(move |_lua, (_ctx, timer_id): (Table, String)| {
+            let entity_id = parse_uuid(&entity_guid).map_err(mlua::Error::runtime)?;
+            let timer_id = parse_uuid(&timer_id).map_err(mlua::Error::runtime)?;
+            pending_intents
+                .borrow_mut()
+                .push(ScriptIntent::CancelTimer {
+                    entity_id,
+                    timer_id,
+                });
+            Ok(())
+        })
+        .map_err(|err| ScriptError::Runtime(format!("create cancel_timer failed: {err}")))?;
+    ctx.set("cancel_timer", cancel_timer)
+        .map_err(|err| ScriptError::Runtime(format!("set cancel_timer failed: {err}")))?;
+    Ok(())
+}
+
+fn parse_schedule_timer(
+    entity_guid: &str,
+    handler_name: &str,
+    delay_s: f64,
+    repeating: bool,
+    event_name: &str,
+    payload: &JsonValue,
+) -> Result<(Uuid, ScriptTimer), String> {
+    let entity_id = parse_uuid(entity_guid)?;
+    if !delay_s.is_finite() || !(0.0..=SCRIPT_TIMER_MAX_DELAY_S).contains(&delay_s) {
+        return Err(format!(
+            "timer delay_s must be within 0..={SCRIPT_TIMER_MAX_DELAY_S}"
+        ));
+    }
+    if repeating && delay_s < SCRIPT_TIMER_MIN_REPEAT_INTERVAL_S {
+        return Err(format!(
+            "repeating timer interval must be at least {SCRIPT_TIMER_MIN_REPEAT_INTERVAL_S}s"
+        ));
+    }
+    if event_name.is_empty()
+        || event_name.chars().count() > SCRIPT_TIMER_EVENT_NAME_MAX_CHARS
+        || !event_name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(format!(
+            "timer event_name must be 1..={SCRIPT_TIMER_EVENT_NAME_MAX_CHARS} characters of [a-z0-9_]"
+        ));
+    }
+    Ok((
+        entity_id,
+        ScriptTimer {
+            timer_id: Uuid::new_v4(),
+            handler_name: handler_name.to_string(),
+            event_name: event_name.to_string(),
+            payload: json_to_script_value(payload),
+            remaining_s: delay_s,
+            repeat_interval_s: repeating.then_some(delay_s),
+        },
+    ))
+}
+
+pub(crate) fn parse_script_entity_filter(filter: Value) -> Result<ScriptEntityFilter, String> {
+    if filter.is_nil() {
+        return Ok(ScriptEntityFilter::default());
+    }
+    let filter = lua_value_to_json(filter).map_err(|err| format!("filter decode failed: {err}"))?;
+    if filter.as_array().is_some_and(Vec::is_empty) {
+        return Ok(ScriptEntityFilter::default());
+    }
+    let Some(fields) = filter.as_object() else {
+        return Err("filter must be a table".to_string());
+    };
+    let optional_string = |key: &str| -> Result<Option<String>, String> {
+        match fields.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(value)) => Ok(Some(value.clone())),
+            Some(_) => Err(format!("filter.{key} must be a string")),
+        }
+    };
+    let include_mounted = match fields.get("include_mounted") {
+        None | Some(JsonValue::Null) => false,
+        Some(JsonValue::Bool(value)) => *value,
+        Some(_) => return Err("filter.include_mounted must be a boolean".to_string()),
+    };
+    let limit = match fields.get("limit") {
+        None | Some(JsonValue::Null) => None,
+        Some(value) => Some(
+            value
+                .as_u64()
+                .filter(|limit| *limit > 0)
+                .and_then(|limit| usize::try_from(limit).ok())
+                .ok_or_else(|| "filter.limit must be a positive integer".to_string())?,
+        ),
+    };
+    Ok(ScriptEntityFilter {
+        component: optional_string("component")?,
+        faction_id: optional_string("faction_id")?,
+        exclude_entity_id: optional_string("exclude_entity_id")?,
+        include_mounted,
+        limit,
+    })
+}
+
 pub(crate) fn parse_intent(action: &str, payload: &JsonValue) -> Result<ScriptIntent, String> {
     match action {
         "set_navigation_target" => {
@@ -746,10 +2099,242 @@ pub(crate) fn parse_intent(action: &str, payload: &JsonValue) -> Result<ScriptIn
                 value,
             })
         }
+        "debit_credits" => parse_credit_transfer(CreditDirection::Debit, payload),
+        "grant_credits" => parse_credit_transfer(CreditDirection::Grant, payload),
+        "fire_weapons" => {
+            let entity_id = intent_uuid(payload, action, "entity_id")?;
+            let target_entity_id = intent_uuid(payload, action, "target_entity_id")?;
+            let weapon_group = match payload.get("weapon_group") {
+                None | Some(JsonValue::Null) => WeaponGroup::PRIMARY,
+                Some(value) => value
+                    .as_u64()
+                    .and_then(|group| u8::try_from(group).ok())
+                    .filter(|group| fire_action_for_weapon_group(*group).is_some())
+                    .ok_or_else(|| "fire_weapons weapon_group must be 1..=4".to_string())?,
+            };
+            Ok(ScriptIntent::FireWeapons {
+                entity_id,
+                target_entity_id,
+                weapon_group,
+            })
+        }
+        "spawn_bundle" => parse_bundle_spawn(payload).map(|spawn| ScriptIntent::SpawnBundle {
+            spawn,
+            authority: ScriptIntentAuthority::default(),
+        }),
+        "despawn" => Ok(ScriptIntent::Despawn {
+            entity_id: intent_uuid(payload, action, "entity_id")?,
+        }),
+        "set_faction" => {
+            let entity_id = intent_uuid(payload, action, "entity_id")?;
+            let faction_id = match payload.get("faction_id") {
+                Some(JsonValue::Null) => None,
+                Some(JsonValue::String(faction_id)) if !faction_id.trim().is_empty() => {
+                    Some(faction_id.clone())
+                }
+                _ => {
+                    return Err("set_faction faction_id must be a non-empty string or nil".into());
+                }
+            };
+            Ok(ScriptIntent::SetFaction {
+                entity_id,
+                faction_id,
+            })
+        }
+        "transfer_inventory" => {
+            let from_entity_id = intent_uuid(payload, action, "from_entity_id")?;
+            let to_entity_id = intent_uuid(payload, action, "to_entity_id")?;
+            if from_entity_id == to_entity_id {
+                return Err("transfer_inventory requires two different entities".to_string());
+            }
+            let item_id = payload
+                .get("item_id")
+                .and_then(|v| v.as_str())
+                .filter(|item_id| !item_id.is_empty())
+                .ok_or_else(|| "transfer_inventory requires payload.item_id".to_string())?
+                .to_string();
+            let quantity = payload
+                .get("quantity")
+                .and_then(|v| v.as_u64())
+                .and_then(|quantity| u32::try_from(quantity).ok())
+                .filter(|quantity| *quantity > 0)
+                .ok_or_else(|| {
+                    "transfer_inventory quantity must be a positive integer".to_string()
+                })?;
+            Ok(ScriptIntent::TransferInventory {
+                from_entity_id,
+                to_entity_id,
+                item_id,
+                quantity,
+            })
+        }
+        "apply_damage" => {
+            let source_entity_id = intent_uuid(payload, action, "source_entity_id")?;
+            let target_entity_id = intent_uuid(payload, action, "target_entity_id")?;
+            let amount = payload
+                .get("amount")
+                .and_then(|v| v.as_f64())
+                .map(|amount| amount as f32)
+                .filter(|amount| amount.is_finite() && *amount > 0.0)
+                .ok_or_else(|| {
+                    "apply_damage amount must be a positive finite number".to_string()
+                })?;
+            let damage_type = payload
+                .get("damage_type")
+                .map(|value| {
+                    serde_json::from_value::<DamageType>(value.clone())
+                        .map_err(|_| format!("apply_damage damage_type {value} is not known"))
+                })
+                .transpose()?
+                .unwrap_or_default();
+            Ok(ScriptIntent::ApplyDamage {
+                source_entity_id,
+                target_entity_id,
+                amount,
+                damage_type,
+                acting_entity_id: None,
+            })
+        }
+        "start_mission" => {
+            let player_entity_id = payload
+                .get("player_entity_id")
+                .and_then(|v| v.as_str())
+                .and_then(PlayerEntityId::parse)
+                .map(|id| id.0)
+                .ok_or_else(|| {
+                    "start_mission player_entity_id must be a valid player UUID".to_string()
+                })?;
+            let mission_id = payload
+                .get("mission_id")
+                .and_then(|v| v.as_str())
+                .filter(|mission_id| !mission_id.is_empty())
+                .ok_or_else(|| "start_mission requires payload.mission_id".to_string())?
+                .to_string();
+            Ok(ScriptIntent::StartMission {
+                player_entity_id,
+                mission_id,
+                authority: ScriptIntentAuthority::default(),
+            })
+        }
         other => Err(format!("unsupported intent action={other}")),
     }
 }
 
+fn intent_uuid(payload: &JsonValue, action: &str, key: &str) -> Result<Uuid, String> {
+    payload
+        .get(key)
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("{action} requires payload.{key}"))
+        .and_then(parse_uuid)
+}
+
+fn fire_action_for_weapon_group(weapon_group: u8) -> Option<EntityAction> {
+    WEAPON_ACTIONS
+        .into_iter()
+        .find(|action| action.fired_weapon_group() == Some(weapon_group))
+}
+
+/// Scripts may only spawn NPC-owned bundles; `owner_id` must not name a
+/// player.
+fn parse_bundle_spawn(payload: &JsonValue) -> Result<ScriptBundleSpawn, String> {
+    let bundle_id = payload
+        .get("bundle_id")
+        .and_then(|v| v.as_str())
+        .filter(|bundle_id| !bundle_id.is_empty())
+        .ok_or_else(|| "spawn_bundle requires payload.bundle_id".to_string())?
+        .to_string();
+    let entity_id = match payload.get("entity_id") {
+        None | Some(JsonValue::Null) => Uuid::new_v4(),
+        Some(_) => intent_uuid(payload, "spawn_bundle", "entity_id")?,
+    };
+    let overrides = match payload.get("overrides") {
+        None | Some(JsonValue::Null) => serde_json::Map::new(),
+        Some(JsonValue::Object(overrides)) => overrides.clone(),
+        // Empty Lua tables decode as arrays.
+        Some(JsonValue::Array(values)) if values.is_empty() => serde_json::Map::new(),
+        Some(_) => return Err("spawn_bundle overrides must be a table".to_string()),
+    };
+    if let Some(key) = overrides
+        .keys()
+        .find(|key| !SCRIPT_SPAWN_ALLOWED_OVERRIDE_KEYS.contains(&key.as_str()))
+    {
+        return Err(format!("spawn_bundle override key not allowed key={key}"));
+    }
+    let override_bytes = serde_json::to_vec(&overrides)
+        .map(|bytes| bytes.len())
+        .unwrap_or(usize::MAX);
+    if override_bytes > SCRIPT_SPAWN_MAX_OVERRIDE_JSON_BYTES {
+        return Err(format!(
+            "spawn_bundle overrides exceed {SCRIPT_SPAWN_MAX_OVERRIDE_JSON_BYTES} bytes"
+        ));
+    }
+    match overrides.get("owner_id") {
+        None => {}
+        Some(JsonValue::String(owner_id)) if PlayerEntityId::parse(owner_id).is_none() => {}
+        Some(_) => {
+            return Err("spawn_bundle owner_id must be a non-player owner string".to_string());
+        }
+    }
+    Ok(ScriptBundleSpawn {
+        bundle_id,
+        entity_id,
+        overrides,
+    })
+}
+
+fn parse_credit_transfer(
+    direction: CreditDirection,
+    payload: &JsonValue,
+) -> Result<ScriptIntent, String> {
+    let action = match direction {
+        CreditDirection::Debit => "debit_credits",
+        CreditDirection::Grant => "grant_credits",
+    };
+    let player_entity_id = payload
+        .get("player_entity_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| format!("{action} requires payload.player_entity_id"))?;
+    let player_entity_id = PlayerEntityId::parse(player_entity_id)
+        .map(|id| id.0)
+        .ok_or_else(|| format!("{action} player_entity_id must be a valid player UUID"))?;
+    let amount = payload
+        .get("amount")
+        .and_then(|v| v.as_u64())
+        .filter(|amount| (1..=SCRIPT_CREDIT_TRANSFER_MAX).contains(amount))
+        .ok_or_else(|| {
+            format!("{action} amount must be an integer in 1..={SCRIPT_CREDIT_TRANSFER_MAX}")
+        })?;
+    let reason = payload
+        .get("reason")
+        .and_then(|v| v.as_str())
+        .filter(|reason| {
+            !reason.is_empty()
+                && reason.chars().count() <= SCRIPT_CREDIT_REASON_MAX_CHARS
+                && reason
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
+        })
+        .ok_or_else(|| {
+            format!(
+                "{action} reason must be 1..={SCRIPT_CREDIT_REASON_MAX_CHARS} characters of [a-z0-9_.]"
+            )
+        })?
+        .to_string();
+    let counterparty = payload
+        .get("counterparty_entity_id")
+        .and_then(|v| v.as_str())
+        .map(parse_uuid)
+        .transpose()?;
+    Ok(ScriptIntent::TransferCredits {
+        player_entity_id,
+        direction,
+        amount,
+        reason,
+        counterparty,
+        authority: ScriptIntentAuthority::default(),
+    })
+}
+
 fn parse_notification_command(payload: &JsonValue) -> Result<NotificationCommand, String> {
     let player_entity_id = required_string(payload, "player_entity_id")?;
     if PlayerEntityId::parse(&player_entity_id).is_none() {
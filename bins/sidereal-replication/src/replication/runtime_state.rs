@@ -6,8 +6,9 @@ use lightyear::prelude::{
     ControlledBy, InterpolationTarget, PredictionTarget, RemoteId, Replicate, ReplicationState,
 };
 use sidereal_game::{
-    ControlledEntityGuid, EntityGuid, MountedOn, PlayerTag, VisibilityRangeBuffM, VisibilityRangeM,
-    WorldPosition, resolve_world_position, total_visibility_range_for_parent,
+    ControlledEntityGuid, EntityGuid, ModuleHealth, MountedOn, PlayerTag, VisibilityRangeBuffM,
+    VisibilityRangeM, WorldPosition, module_performance, resolve_world_position,
+    total_visibility_range_for_parent,
 };
 
 use crate::replication::visibility::ClientObserverAnchorPositionMap;
@@ -226,7 +227,11 @@ pub fn compute_controlled_entity_visibility_ranges(
     visibility_range_buffs: Query<
         '_,
         '_,
-        (&'_ MountedOn, &'_ VisibilityRangeBuffM),
+        (
+            &'_ MountedOn,
+            &'_ VisibilityRangeBuffM,
+            Option<&'_ ModuleHealth>,
+        ),
         Without<SimulatedControlledEntity>,
     >,
     rollback_query: Query<'_, '_, (), With<lightyear::prelude::Rollback>>,
@@ -234,11 +239,22 @@ pub fn compute_controlled_entity_visibility_ranges(
     if is_in_rollback(rollback_query) {
         return;
     }
+    // Damaged sensor modules contribute range in proportion to their health.
+    let mounted_buffs = visibility_range_buffs
+        .iter()
+        .map(|(mounted_on, buff, module_health)| {
+            let mut buff = *buff;
+            buff.additive_m *= module_performance(module_health);
+            (mounted_on, buff)
+        })
+        .collect::<Vec<_>>();
     for (entity, entity_guid, visibility_range, own_buff) in &mut controlled_entities {
         let total_range = total_visibility_range_for_parent(
             entity_guid.0,
             own_buff,
-            visibility_range_buffs.iter(),
+            mounted_buffs
+                .iter()
+                .map(|(mounted_on, buff)| (*mounted_on, buff)),
         );
         if total_range > 0.0 {
             if let Some(mut visibility_range) = visibility_range {
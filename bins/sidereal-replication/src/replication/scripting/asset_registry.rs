@@ -71,6 +71,108 @@ pub fn load_ship_registry_from_catalog(
     .map_err(map_script_err)
 }
 
+/// Economy catalogs derived from one script catalog revision.
+#[derive(Debug, Clone, Default)]
+pub struct EconomyRegistries {
+    pub items: sidereal_game::ItemRegistry,
+    pub recipes: sidereal_game::RecipeRegistry,
+    pub facilities: sidereal_game::FacilityRegistry,
+    pub loot_tables: sidereal_game::LootTableRegistry,
+    pub markets: sidereal_game::MarketRegistry,
+}
+
+/// Loads the item, facility, recipe, loot table and market catalogs together so
+/// cross-references are validated against the same catalog revision.
+pub fn load_economy_registries_from_catalog(
+    catalog: &ScriptCatalogResource,
+) -> Result<EconomyRegistries, String> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_entry = lookup_script_catalog_entry(catalog, ITEM_REGISTRY_SCRIPT_REL_PATH)?;
+    let items = load_item_registry_from_source(
+        &item_entry.source,
+        Path::new(ITEM_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+    .map_err(map_script_err)?;
+    let facility_entry = lookup_script_catalog_entry(catalog, FACILITY_REGISTRY_SCRIPT_REL_PATH)?;
+    let facilities = load_facility_registry_from_source(
+        &facility_entry.source,
+        Path::new(FACILITY_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+    .map_err(map_script_err)?;
+    let recipe_entry = lookup_script_catalog_entry(catalog, RECIPE_REGISTRY_SCRIPT_REL_PATH)?;
+    let recipes = load_recipe_registry_from_source(
+        &recipe_entry.source,
+        Path::new(RECIPE_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &items,
+        &facilities,
+    )
+    .map_err(map_script_err)?;
+    let loot_table_entry =
+        lookup_script_catalog_entry(catalog, LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH)?;
+    let loot_tables = load_loot_table_registry_from_source(
+        &loot_table_entry.source,
+        Path::new(LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &items,
+    )
+    .map_err(map_script_err)?;
+    let market_entry = lookup_script_catalog_entry(catalog, MARKET_REGISTRY_SCRIPT_REL_PATH)?;
+    let markets = load_market_registry_from_source(
+        &market_entry.source,
+        Path::new(MARKET_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &items,
+    )
+    .map_err(map_script_err)?;
+    Ok(EconomyRegistries {
+        items,
+        recipes,
+        facilities,
+        loot_tables,
+        markets,
+    })
+}
+
+pub fn load_faction_registry_from_catalog(
+    catalog: &ScriptCatalogResource,
+) -> Result<sidereal_game::FactionRegistry, String> {
+    let registry_entry = lookup_script_catalog_entry(catalog, FACTION_REGISTRY_SCRIPT_REL_PATH)?;
+    load_faction_registry_from_source(
+        &registry_entry.source,
+        Path::new(FACTION_REGISTRY_SCRIPT_REL_PATH),
+        &LuaSandboxPolicy::from_env(),
+    )
+    .map_err(map_script_err)
+}
+
+/// Loads the mission catalog, validating item and faction references against
+/// the same catalog revision.
+pub fn load_mission_registry_from_catalog(
+    catalog: &ScriptCatalogResource,
+) -> Result<sidereal_game::MissionRegistry, String> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_entry = lookup_script_catalog_entry(catalog, ITEM_REGISTRY_SCRIPT_REL_PATH)?;
+    let items = load_item_registry_from_source(
+        &item_entry.source,
+        Path::new(ITEM_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+    .map_err(map_script_err)?;
+    let factions = load_faction_registry_from_catalog(catalog)?;
+    let registry_entry = lookup_script_catalog_entry(catalog, MISSION_REGISTRY_SCRIPT_REL_PATH)?;
+    load_mission_registry_from_source(
+        &registry_entry.source,
+        Path::new(MISSION_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &items,
+        &factions,
+    )
+    .map_err(map_script_err)
+}
+
 fn load_asset_registry_data_from_catalog(
     catalog: &ScriptCatalogResource,
 ) -> Result<
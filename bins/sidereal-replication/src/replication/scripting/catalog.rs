@@ -4,6 +4,9 @@ pub fn init_resources(app: &mut App) {
     let planet_registry_script_path = scripts_root.join(PLANET_REGISTRY_SCRIPT_REL_PATH);
     let ship_module_registry_script_path = scripts_root.join(SHIP_MODULE_REGISTRY_SCRIPT_REL_PATH);
     let ship_registry_script_path = scripts_root.join(SHIP_REGISTRY_SCRIPT_REL_PATH);
+    let recipe_registry_script_path = scripts_root.join(RECIPE_REGISTRY_SCRIPT_REL_PATH);
+    let faction_registry_script_path = scripts_root.join(FACTION_REGISTRY_SCRIPT_REL_PATH);
+    let mission_registry_script_path = scripts_root.join(MISSION_REGISTRY_SCRIPT_REL_PATH);
     let load_outcome = match load_script_catalog_from_database_or_disk(&scripts_root) {
         Ok(outcome) => outcome,
         Err(err) => {
@@ -91,6 +94,36 @@ pub fn init_resources(app: &mut App) {
             sidereal_game::ShipRegistry::default()
         }
     };
+    let economy_registries = match load_economy_registries_from_catalog(&catalog) {
+        Ok(registries) => registries,
+        Err(err) => {
+            bevy::log::warn!(
+                "replication economy registries initial derive failed: {}",
+                err
+            );
+            Default::default()
+        }
+    };
+    let faction_registry = match load_faction_registry_from_catalog(&catalog) {
+        Ok(registry) => registry,
+        Err(err) => {
+            bevy::log::warn!(
+                "replication faction registry initial derive failed: {}",
+                err
+            );
+            sidereal_game::FactionRegistry::default()
+        }
+    };
+    let mission_registry = match load_mission_registry_from_catalog(&catalog) {
+        Ok(registry) => registry,
+        Err(err) => {
+            bevy::log::warn!(
+                "replication mission registry initial derive failed: {}",
+                err
+            );
+            sidereal_game::MissionRegistry::default()
+        }
+    };
     app.register_type::<ScriptCatalogEntry>();
     app.register_type::<ScriptCatalogResource>();
     app.register_type::<ScriptCatalogControlResource>();
@@ -128,6 +161,13 @@ pub fn init_resources(app: &mut App) {
     app.insert_resource(planet_registry);
     app.insert_resource(ship_module_registry);
     app.insert_resource(ship_registry);
+    app.insert_resource(economy_registries.items);
+    app.insert_resource(economy_registries.recipes);
+    app.insert_resource(economy_registries.facilities);
+    app.insert_resource(economy_registries.loot_tables);
+    app.insert_resource(economy_registries.markets);
+    app.insert_resource(faction_registry);
+    app.insert_resource(mission_registry);
     if let Some(mut generated_registry) = app
         .world_mut()
         .get_resource_mut::<GeneratedComponentRegistry>()
@@ -158,6 +198,18 @@ pub fn init_resources(app: &mut App) {
         registry_script_path: ship_registry_script_path,
         last_catalog_revision: 0,
     });
+    app.insert_resource(EconomyRegistrySyncState {
+        registry_script_path: recipe_registry_script_path,
+        last_catalog_revision: 0,
+    });
+    app.insert_resource(FactionRegistrySyncState {
+        registry_script_path: faction_registry_script_path,
+        last_catalog_revision: 0,
+    });
+    app.insert_resource(MissionRegistrySyncState {
+        registry_script_path: mission_registry_script_path,
+        last_catalog_revision: 0,
+    });
     app.add_systems(
         Update,
         (
@@ -169,6 +221,9 @@ pub fn init_resources(app: &mut App) {
             sync_planet_registry_resource_system,
             sync_ship_module_registry_resource_system,
             sync_ship_registry_resource_system,
+            sync_economy_registry_resources_system,
+            sync_faction_registry_resource_system,
+            sync_mission_registry_resource_system,
         )
             .chain(),
     );
@@ -14,13 +14,20 @@ use sidereal_persistence::{
     load_active_script_catalog, replace_active_script_catalog,
 };
 use sidereal_scripting::{
-    LuaSandboxPolicy, PLANET_REGISTRY_SCRIPT_REL_PATH, SHIP_MODULE_REGISTRY_SCRIPT_REL_PATH,
-    SHIP_REGISTRY_SCRIPT_REL_PATH, ScriptAssetRegistryEntry, ScriptError,
-    WORLD_INIT_SCRIPT_REL_PATH, WorldInitScriptConfig, decode_graph_entity_records,
-    inject_script_logger, load_asset_registry_from_source, load_lua_module_from_source,
-    load_planet_registry_from_sources, load_ship_module_registry_from_sources,
-    load_ship_registry_from_sources, load_world_init_config_from_source, lua_value_to_json,
-    resolve_scripts_root, table_get_required_string, table_get_required_string_list,
+    FACILITY_REGISTRY_SCRIPT_REL_PATH, FACTION_REGISTRY_SCRIPT_REL_PATH,
+    ITEM_REGISTRY_SCRIPT_REL_PATH, LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH, LuaSandboxPolicy,
+    MARKET_REGISTRY_SCRIPT_REL_PATH, MISSION_REGISTRY_SCRIPT_REL_PATH,
+    PLANET_REGISTRY_SCRIPT_REL_PATH, RECIPE_REGISTRY_SCRIPT_REL_PATH,
+    SHIP_MODULE_REGISTRY_SCRIPT_REL_PATH, SHIP_REGISTRY_SCRIPT_REL_PATH, ScriptAssetRegistryEntry,
+    ScriptError, WORLD_INIT_SCRIPT_REL_PATH, WorldInitScriptConfig, decode_graph_entity_records,
+    inject_script_logger, load_asset_registry_from_source, load_facility_registry_from_source,
+    load_faction_registry_from_source, load_item_registry_from_source,
+    load_loot_table_registry_from_source, load_lua_module_from_source,
+    load_market_registry_from_source, load_mission_registry_from_source,
+    load_planet_registry_from_sources, load_recipe_registry_from_source,
+    load_ship_module_registry_from_sources, load_ship_registry_from_sources,
+    load_world_init_config_from_source, lua_value_to_json, resolve_scripts_root,
+    table_get_required_string, table_get_required_string_list,
     validate_runtime_render_graph_records,
 };
 use std::collections::{HashMap, HashSet, hash_map::DefaultHasher};
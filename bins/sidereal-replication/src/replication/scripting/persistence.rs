@@ -283,3 +283,103 @@ fn sync_ship_registry_resource_system(
         }
     }
 }
+
+fn sync_faction_registry_resource_system(
+    catalog: Res<'_, ScriptCatalogResource>,
+    mut sync_state: ResMut<'_, FactionRegistrySyncState>,
+    mut registry: ResMut<'_, sidereal_game::FactionRegistry>,
+) {
+    if sync_state.last_catalog_revision == catalog.revision {
+        return;
+    }
+    sync_state.last_catalog_revision = catalog.revision;
+    match load_faction_registry_from_catalog(&catalog) {
+        Ok(next_registry) => {
+            *registry = next_registry;
+            bevy::log::info!(
+                "replication faction registry reloaded from script catalog script={} catalog_revision={} factions={} relations={}",
+                sync_state.registry_script_path.display(),
+                catalog.revision,
+                registry.factions.len(),
+                registry.relations.len()
+            );
+        }
+        Err(err) => {
+            bevy::log::warn!(
+                "replication faction registry reload failed script={}: {}",
+                sync_state.registry_script_path.display(),
+                err
+            );
+        }
+    }
+}
+
+fn sync_mission_registry_resource_system(
+    catalog: Res<'_, ScriptCatalogResource>,
+    mut sync_state: ResMut<'_, MissionRegistrySyncState>,
+    mut registry: ResMut<'_, sidereal_game::MissionRegistry>,
+) {
+    if sync_state.last_catalog_revision == catalog.revision {
+        return;
+    }
+    sync_state.last_catalog_revision = catalog.revision;
+    match load_mission_registry_from_catalog(&catalog) {
+        Ok(next_registry) => {
+            *registry = next_registry;
+            bevy::log::info!(
+                "replication mission registry reloaded from script catalog script={} catalog_revision={} missions={}",
+                sync_state.registry_script_path.display(),
+                catalog.revision,
+                registry.missions.len()
+            );
+        }
+        Err(err) => {
+            bevy::log::warn!(
+                "replication mission registry reload failed script={}: {}",
+                sync_state.registry_script_path.display(),
+                err
+            );
+        }
+    }
+}
+
+fn sync_economy_registry_resources_system(
+    catalog: Res<'_, ScriptCatalogResource>,
+    mut sync_state: ResMut<'_, EconomyRegistrySyncState>,
+    mut item_registry: ResMut<'_, sidereal_game::ItemRegistry>,
+    mut recipe_registry: ResMut<'_, sidereal_game::RecipeRegistry>,
+    mut facility_registry: ResMut<'_, sidereal_game::FacilityRegistry>,
+    mut loot_table_registry: ResMut<'_, sidereal_game::LootTableRegistry>,
+    mut market_registry: ResMut<'_, sidereal_game::MarketRegistry>,
+) {
+    if sync_state.last_catalog_revision == catalog.revision {
+        return;
+    }
+    sync_state.last_catalog_revision = catalog.revision;
+    match load_economy_registries_from_catalog(&catalog) {
+        Ok(next) => {
+            *item_registry = next.items;
+            *recipe_registry = next.recipes;
+            *facility_registry = next.facilities;
+            *loot_table_registry = next.loot_tables;
+            *market_registry = next.markets;
+            bevy::log::info!(
+                "replication economy registries reloaded from script catalog script={} catalog_revision={} items={} recipes={} facilities={} loot_tables={} markets={}",
+                sync_state.registry_script_path.display(),
+                catalog.revision,
+                item_registry.items.len(),
+                recipe_registry.recipes.len(),
+                facility_registry.facilities.len(),
+                loot_table_registry.tables.len(),
+                market_registry.markets.len()
+            );
+        }
+        Err(err) => {
+            bevy::log::warn!(
+                "replication economy registries reload failed script={}: {}",
+                sync_state.registry_script_path.display(),
+                err
+            );
+        }
+    }
+}
@@ -92,6 +92,24 @@ struct ShipRegistrySyncState {
     last_catalog_revision: u64,
 }
 
+#[derive(Resource, Debug, Clone)]
+struct EconomyRegistrySyncState {
+    registry_script_path: PathBuf,
+    last_catalog_revision: u64,
+}
+
+#[derive(Resource, Debug, Clone)]
+struct FactionRegistrySyncState {
+    registry_script_path: PathBuf,
+    last_catalog_revision: u64,
+}
+
+#[derive(Resource, Debug, Clone)]
+struct MissionRegistrySyncState {
+    registry_script_path: PathBuf,
+    last_catalog_revision: u64,
+}
+
 #[derive(Resource, Debug, Clone, Default)]
 struct ScriptCatalogPersistenceState {
     last_persisted_catalog_revision: u64,
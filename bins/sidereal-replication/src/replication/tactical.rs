@@ -8,10 +8,11 @@ use lightyear::prelude::{
     NetworkTarget, RemoteId, ReplicationState, Server, ServerMultiMessageSender,
 };
 use sidereal_game::{
-    ContactResolutionM, EntityGuid, EntityLabels, FactionId, MapIcon, MountedOn,
-    PlayerExploredCells, PlayerExploredCellsChunk, PlayerExploredCellsChunkEncoding, PlayerTag,
-    ScannerComponent, ScannerContactDetailTier, SignalSignature, SizeM, StaticLandmark,
-    TotalMassKg, VisibilityRangeM, VisibilityRangeSource, WorldPosition,
+    ContactResolutionM, EntityGuid, EntityLabels, FactionId, FactionRegistry, FactionReputation,
+    FactionStanding, MapIcon, MountedOn, OwnerId, PlayerExploredCells, PlayerExploredCellsChunk,
+    PlayerExploredCellsChunkEncoding, PlayerTag, ScannerComponent, ScannerContactDetailTier,
+    SignalSignature, SizeM, StaticLandmark, TotalMassKg, VisibilityRangeM, VisibilityRangeSource,
+    WorldPosition,
 };
 use sidereal_net::{
     ClientTacticalResnapshotRequestMessage, GridCell, NotificationPayload, NotificationPlacement,
@@ -177,6 +178,7 @@ fn contact_changed_for_delta(previous: &TacticalContact, current: &TacticalConta
         || previous.kind != current.kind
         || previous.map_icon_asset_id != current.map_icon_asset_id
         || previous.faction_id != current.faction_id
+        || previous.standing != current.standing
         || previous.position_xy != current.position_xy
         || previous.size_m != current.size_m
         || previous.mass_kg != current.mass_kg
@@ -189,6 +191,29 @@ fn contact_changed_for_delta(previous: &TacticalContact, current: &TacticalConta
         || previous.position_accuracy_m != current.position_accuracy_m
 }
 
+/// IFF standing of a live contact toward the viewing player. Contacts owned by
+/// the viewer are always allied; unaffiliated contacts carry no standing.
+fn contact_standing(
+    registry: Option<&FactionRegistry>,
+    viewer_guid: uuid::Uuid,
+    viewer_faction: Option<&str>,
+    viewer_reputation: Option<&FactionReputation>,
+    contact_owner_guid: Option<uuid::Uuid>,
+    contact_faction: Option<&str>,
+) -> Option<String> {
+    if contact_owner_guid == Some(viewer_guid) {
+        return Some(FactionStanding::Allied.as_str().to_string());
+    }
+    let registry = registry?;
+    let contact_faction = contact_faction?;
+    let standing = registry.standing_toward_player(
+        viewer_faction,
+        viewer_reputation.and_then(|reputation| reputation.value(contact_faction)),
+        contact_faction,
+    );
+    Some(standing.as_str().to_string())
+}
+
 fn stable_signal_contact_id(player_entity_id: &str, target_entity_id: &str) -> String {
     let mut hash = 0xcbf29ce484222325_u64;
     for byte in player_entity_id
@@ -892,12 +917,15 @@ pub fn stream_tactical_snapshot_messages(
     client_remotes: Query<'_, '_, (&'_ LinkOf, &'_ RemoteId), With<ClientOf>>,
     player_entities: Res<'_, PlayerRuntimeEntityMap>,
     controlled_entity_map: Res<'_, PlayerControlledEntityMap>,
+    faction_registry: Option<Res<'_, FactionRegistry>>,
     mut player_visibility: Query<
         '_,
         '_,
         (
             Option<&'_ ContactResolutionM>,
             Option<&'_ mut PlayerExploredCells>,
+            Option<&'_ FactionId>,
+            Option<&'_ FactionReputation>,
         ),
         With<PlayerTag>,
     >,
@@ -909,7 +937,7 @@ pub fn stream_tactical_snapshot_messages(
         (
             Option<&'_ EntityGuid>,
             Option<&'_ EntityLabels>,
-            Option<&'_ FactionId>,
+            (Option<&'_ FactionId>, Option<&'_ OwnerId>),
             Option<&'_ MapIcon>,
             Option<&'_ Position>,
             Option<&'_ WorldPosition>,
@@ -929,6 +957,14 @@ pub fn stream_tactical_snapshot_messages(
     let generated_at_tick = stream_state.tick;
     let now_s = time.elapsed_secs_f64();
 
+    // Owned entities without their own `FactionId` fly under their owning
+    // player's faction.
+    let player_faction_by_guid = replicated_entities
+        .iter()
+        .filter(|(.., player_tag, _)| player_tag.is_some())
+        .filter_map(|(guid, _, (faction_id, _), ..)| Some((guid?.0, faction_id?.0.clone())))
+        .collect::<HashMap<_, _>>();
+
     for (client_entity, bound_player_id) in &bindings.by_client_entity {
         let Some(player_entity_id) =
             PlayerEntityId::parse(bound_player_id.as_str()).map(|id| id.canonical_wire_id())
@@ -950,10 +986,13 @@ pub fn stream_tactical_snapshot_messages(
         else {
             continue;
         };
-        let Ok((contact_resolution, explored_component)) = player_visibility.get_mut(player_entity)
+        let Ok((contact_resolution, explored_component, viewer_faction, viewer_reputation)) =
+            player_visibility.get_mut(player_entity)
         else {
             continue;
         };
+        let viewer_faction = viewer_faction.map(|faction_id| faction_id.0.clone());
+        let viewer_reputation = viewer_reputation.cloned();
         let contact_resolution_m = contact_resolution
             .map(|value| value.0)
             .unwrap_or(DEFAULT_CONTACT_RESOLUTION_M)
@@ -1034,7 +1073,7 @@ pub fn stream_tactical_snapshot_messages(
             for (
                 guid,
                 labels,
-                faction_id,
+                (faction_id, owner_id),
                 map_icon,
                 position,
                 world_position,
@@ -1070,13 +1109,27 @@ pub fn stream_tactical_snapshot_messages(
                             extent_m,
                         )
                     });
+                    let owner_guid =
+                        owner_id.and_then(|owner_id| uuid::Uuid::parse_str(&owner_id.0).ok());
+                    let contact_faction = faction_id.map(|id| id.0.clone()).or_else(|| {
+                        owner_guid.and_then(|owner| player_faction_by_guid.get(&owner).cloned())
+                    });
+                    let standing = contact_standing(
+                        faction_registry.as_deref(),
+                        player_id.0,
+                        viewer_faction.as_deref(),
+                        viewer_reputation.as_ref(),
+                        owner_guid,
+                        contact_faction.as_deref(),
+                    );
                     contacts_by_entity_id.insert(
                         entity_id.clone(),
                         TacticalContact {
                             entity_id: guid.0.to_string(),
                             kind: contact_kind_from_labels(labels),
                             map_icon_asset_id: map_icon.map(|icon| icon.asset_id.clone()),
-                            faction_id: faction_id.map(|id| id.0.clone()),
+                            faction_id: contact_faction,
+                            standing,
                             position_xy: [world.x, world.y],
                             size_m: size.map(|value| [value.length, value.width, value.height]),
                             mass_kg: total_mass.map(|value| value.0),
@@ -1140,6 +1193,7 @@ pub fn stream_tactical_snapshot_messages(
                     kind: "unknown".to_string(),
                     map_icon_asset_id: Some(UNKNOWN_CONTACT_ICON_ASSET_ID.to_string()),
                     faction_id: None,
+                    standing: None,
                     position_xy: contact_memory.approximate_position_xy,
                     size_m: None,
                     mass_kg: None,
@@ -1270,15 +1324,16 @@ mod tests {
         SignalContactMemory, apply_live_cells_to_explored_memory,
         approximate_signal_contact_position, best_effective_scanner_candidate,
         build_live_cells_from_visibility_sources, circle_intersects_grid_cell,
-        compute_contacts_delta, compute_live_cells_delta, enforce_contact_budget,
+        compute_contacts_delta, compute_live_cells_delta, contact_standing, enforce_contact_budget,
         ensure_explored_memory_shape, materialize_explored_cells, scanner_candidate,
         scanner_detects_world, scanner_effective_range, should_update_signal_memory,
         strongest_signal_detection,
     };
     use bevy::math::DVec3;
     use sidereal_game::{
-        PlayerExploredCells, ScannerComponent, ScannerContactDetailTier, SignalSignature,
-        VisibilityRangeM, VisibilityRangeSource,
+        FactionDefinition, FactionRegistry, FactionRelationDefinition, FactionReputation,
+        FactionStanding, PlayerExploredCells, ScannerComponent, ScannerContactDetailTier,
+        SignalSignature, VisibilityRangeM, VisibilityRangeSource,
     };
     use sidereal_net::{GridCell, TacticalContact};
     use std::collections::{HashMap, HashSet};
@@ -1291,6 +1346,7 @@ mod tests {
     ) -> ScannerComponent {
         ScannerComponent {
             base_range_m,
+            rated_range_m: None,
             level,
             detail_tier,
             supports_density: false,
@@ -1305,6 +1361,7 @@ mod tests {
             kind: "ship".to_string(),
             map_icon_asset_id: Some("map_icon_ship_svg".to_string()),
             faction_id: None,
+            standing: None,
             position_xy,
             size_m: None,
             mass_kg: None,
@@ -1561,4 +1618,63 @@ mod tests {
         assert!(!cells.contains(&GridCell { x: 1, y: 1 }));
         assert!(!cells.contains(&GridCell { x: -1, y: -1 }));
     }
+
+    #[test]
+    fn contact_standing_follows_registry_reputation_and_ownership() {
+        let registry = FactionRegistry {
+            schema_version: 1,
+            factions: ["faction.alpha", "faction.beta"]
+                .into_iter()
+                .map(|faction_id| FactionDefinition {
+                    faction_id: faction_id.to_string(),
+                    display_name: faction_id.to_string(),
+                    member_kill_reputation_delta: -10.0,
+                })
+                .collect(),
+            relations: vec![FactionRelationDefinition {
+                faction_a: "faction.alpha".to_string(),
+                faction_b: "faction.beta".to_string(),
+                standing: FactionStanding::Allied,
+            }],
+            ..Default::default()
+        };
+        let viewer = uuid::Uuid::from_u128(7);
+
+        assert_eq!(
+            contact_standing(
+                Some(&registry),
+                viewer,
+                Some("faction.alpha"),
+                None,
+                None,
+                Some("faction.beta"),
+            )
+            .as_deref(),
+            Some("allied")
+        );
+
+        let mut reputation = FactionReputation::default();
+        reputation.adjust("faction.beta", -500.0, -1000.0, 1000.0);
+        assert_eq!(
+            contact_standing(
+                Some(&registry),
+                viewer,
+                Some("faction.alpha"),
+                Some(&reputation),
+                None,
+                Some("faction.beta"),
+            )
+            .as_deref(),
+            Some("hostile")
+        );
+
+        assert_eq!(
+            contact_standing(Some(&registry), viewer, None, None, Some(viewer), None).as_deref(),
+            Some("allied")
+        );
+        assert_eq!(
+            contact_standing(Some(&registry), viewer, None, None, None, None),
+            None
+        );
+    }
 }
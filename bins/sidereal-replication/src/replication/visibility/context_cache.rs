@@ -92,6 +92,14 @@ impl VisibilitySpatialIndex {
         self.cell_by_entity.clear();
     }
 
+    pub fn cell_size_m(&self) -> f32 {
+        self.cell_size_m
+    }
+
+    pub fn cell_of_entity(&self, entity: Entity) -> Option<(i64, i64)> {
+        self.cell_by_entity.get(&entity).copied()
+    }
+
     pub fn entities_under_root(&self, root: Entity) -> Option<Vec<Entity>> {
         let mut entities = self
             .entities_by_root
@@ -0,0 +1,36 @@
+use sidereal_net::{ClientCraftingJobMessage, PlayerEntityId};
+use uuid::Uuid;
+
+use crate::replication::crafting::crafting_job_request_from_message;
+
+fn crafting_message(player_id: PlayerEntityId, recipe_id: &str) -> ClientCraftingJobMessage {
+    ClientCraftingJobMessage {
+        player_entity_id: player_id.canonical_wire_id(),
+        facility_entity_id: Uuid::new_v4().to_string(),
+        recipe_id: recipe_id.to_string(),
+    }
+}
+
+#[test]
+fn crafting_messages_are_bound_to_the_authenticated_player() {
+    let player_id = PlayerEntityId(Uuid::new_v4());
+    let message = crafting_message(player_id, "recipe.refine_iron_ingot");
+
+    let request = crafting_job_request_from_message(player_id, &message)
+        .expect("bound player job should pass");
+    assert_eq!(
+        request.facility_guid.to_string(),
+        message.facility_entity_id
+    );
+    assert_eq!(request.recipe_id, "recipe.refine_iron_ingot");
+    assert_eq!(
+        request.requested_by_player_id,
+        Some(player_id.canonical_wire_id())
+    );
+
+    let other_player = PlayerEntityId(Uuid::new_v4());
+    assert!(crafting_job_request_from_message(other_player, &message).is_err());
+    assert!(
+        crafting_job_request_from_message(player_id, &crafting_message(player_id, "")).is_err()
+    );
+}
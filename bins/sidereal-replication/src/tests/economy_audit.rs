@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use sidereal_game::{CreditLedger, CreditLedgerEntry, EntityGuid};
+use sidereal_persistence::EconomyAuditRecord;
+use std::sync::mpsc::{Receiver, sync_channel};
+use uuid::Uuid;
+
+use crate::replication::economy_audit::{
+    EconomyAuditWorker, ledger_audit_record, record_credit_ledger_entries,
+};
+
+fn ledger_entry(sequence: u64, delta: i64) -> CreditLedgerEntry {
+    CreditLedgerEntry {
+        transaction_id: Uuid::new_v4(),
+        sequence,
+        tick: 40,
+        reason: "station_service.hull_repair".to_string(),
+        counterparty: None,
+        delta,
+        balance_after: 880,
+    }
+}
+
+#[test]
+fn ledger_entries_map_to_signed_audit_records() {
+    let station_guid = Uuid::new_v4();
+    let entry = CreditLedgerEntry {
+        counterparty: Some(station_guid),
+        ..ledger_entry(3, -120)
+    };
+
+    let record = ledger_audit_record(Uuid::new_v4(), &entry, 1_714_000_000);
+
+    assert_eq!(record.transaction_id, entry.transaction_id.to_string());
+    assert_eq!(record.transaction_kind, "station_service.hull_repair");
+    assert_eq!(record.credits_delta, -120);
+    assert_eq!(record.balance_after, 880);
+    assert_eq!(
+        record.counterparty_entity_id,
+        Some(station_guid.to_string())
+    );
+    assert_eq!(record.payload["sequence"], 3);
+}
+
+fn audit_app(channel_capacity: usize) -> (App, Receiver<EconomyAuditRecord>) {
+    let (sender, receiver) = sync_channel::<EconomyAuditRecord>(channel_capacity);
+    let mut app = App::new();
+    app.insert_resource(EconomyAuditWorker {
+        sender: Some(sender),
+        deferred_records: 0,
+    });
+    app.add_systems(Update, record_credit_ledger_entries);
+    (app, receiver)
+}
+
+#[test]
+fn entries_are_recorded_from_first_sight_and_then_only_when_appended() {
+    let (mut app, receiver) = audit_app(16);
+    let player = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            CreditLedger {
+                entries: vec![ledger_entry(1, 1_000)],
+                ..Default::default()
+            },
+        ))
+        .id();
+
+    let opening = app.world().get::<CreditLedger>(player).unwrap().entries[0].clone();
+
+    app.update();
+    let record = receiver
+        .try_recv()
+        .expect("first-tick entry should be recorded");
+    assert_eq!(record.transaction_id, opening.transaction_id.to_string());
+    assert!(receiver.try_recv().is_err());
+
+    let appended = ledger_entry(2, -120);
+    app.world_mut()
+        .get_mut::<CreditLedger>(player)
+        .unwrap()
+        .entries
+        .push(appended.clone());
+    app.update();
+
+    let record = receiver.try_recv().expect("new entry should be recorded");
+    assert_eq!(record.transaction_id, appended.transaction_id.to_string());
+    assert!(receiver.try_recv().is_err());
+    assert_eq!(
+        app.world()
+            .get::<CreditLedger>(player)
+            .unwrap()
+            .audited_sequence,
+        2
+    );
+}
+
+#[test]
+fn entries_the_writer_cannot_take_stay_on_the_ledger_and_are_retried() {
+    let (mut app, receiver) = audit_app(1);
+    let entries = (1..=3)
+        .map(|sequence| ledger_entry(sequence, 10))
+        .collect::<Vec<_>>();
+    let player = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            CreditLedger {
+                entries: entries.clone(),
+                ..Default::default()
+            },
+        ))
+        .id();
+    let audited_sequence = |app: &App| {
+        app.world()
+            .get::<CreditLedger>(player)
+            .unwrap()
+            .audited_sequence
+    };
+
+    let mut recorded = Vec::new();
+    for expected_sequence in 1..=3 {
+        app.update();
+        assert_eq!(audited_sequence(&app), expected_sequence);
+        recorded.push(receiver.try_recv().expect("one record per free slot"));
+    }
+    app.update();
+
+    assert!(receiver.try_recv().is_err());
+    assert_eq!(
+        recorded
+            .iter()
+            .map(|record| record.transaction_id.clone())
+            .collect::<Vec<_>>(),
+        entries
+            .iter()
+            .map(|entry| entry.transaction_id.to_string())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        app.world()
+            .resource::<EconomyAuditWorker>()
+            .deferred_records,
+        2
+    );
+    assert_eq!(
+        app.world()
+            .get::<CreditLedger>(player)
+            .unwrap()
+            .entries
+            .len(),
+        3
+    );
+}
@@ -0,0 +1,79 @@
+use sidereal_game::{
+    Market, MarketDefinition, MarketListing, MarketListingDefinition, MarketTradeSide,
+};
+use sidereal_net::{ClientMarketTradeMessage, PlayerEntityId};
+use uuid::Uuid;
+
+use crate::replication::markets::{market_snapshot_message, market_trade_request_from_message};
+
+fn trade_message(player_id: PlayerEntityId, quantity: u32) -> ClientMarketTradeMessage {
+    ClientMarketTradeMessage {
+        player_entity_id: player_id.canonical_wire_id(),
+        ship_entity_id: Uuid::new_v4().to_string(),
+        market_entity_id: Uuid::new_v4().to_string(),
+        item_id: "resource.iron_ore".to_string(),
+        side: MarketTradeSide::Sell,
+        quantity,
+    }
+}
+
+#[test]
+fn trade_messages_are_bound_to_the_authenticated_player() {
+    let player_id = PlayerEntityId(Uuid::new_v4());
+    let message = trade_message(player_id, 12);
+
+    let request = market_trade_request_from_message(player_id, &message)
+        .expect("bound player trade should pass");
+    assert_eq!(request.player_guid, player_id.0);
+    assert_eq!(request.market_guid.to_string(), message.market_entity_id);
+    assert_eq!(request.side, MarketTradeSide::Sell);
+    assert_eq!(request.quantity, 12);
+
+    let other_player = PlayerEntityId(Uuid::new_v4());
+    assert!(market_trade_request_from_message(other_player, &message).is_err());
+    assert!(market_trade_request_from_message(player_id, &trade_message(player_id, 0)).is_err());
+}
+
+#[test]
+fn snapshots_quote_each_defined_listing_with_spread() {
+    let definition = MarketDefinition {
+        market_id: "market.test".to_string(),
+        display_name: "Test Market".to_string(),
+        spread: 0.2,
+        listings: vec![MarketListingDefinition {
+            item_id: "resource.iron_ore".to_string(),
+            base_price: 10,
+            sells: false,
+            initial_stock: 40,
+            target_stock: 40,
+            max_stock: 100,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let market = Market {
+        market_id: "market.test".to_string(),
+        trade_range_m: 100.0,
+        listings: vec![MarketListing {
+            item_id: "resource.iron_ore".to_string(),
+            stock: 40,
+            price: 10.0,
+            restock_progress: 0.0,
+        }],
+    };
+    let player_id = PlayerEntityId(Uuid::new_v4());
+
+    let message = market_snapshot_message(
+        player_id,
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &market,
+        &definition,
+    );
+
+    assert_eq!(message.display_name, "Test Market");
+    assert_eq!(message.quotes.len(), 1);
+    assert_eq!(message.quotes[0].stock, 40);
+    assert_eq!(message.quotes[0].buy_price, None);
+    assert_eq!(message.quotes[0].sell_price, Some(8));
+}
@@ -0,0 +1,231 @@
+use avian2d::prelude::Position;
+use bevy::ecs::message::Messages;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use serde_json::json;
+use sidereal_game::{
+    ControlledEntityGuid, CreditLedger, CreditWallet, EntityGuid, EntityKillCreditedEvent,
+    Inventory, MassDirty, MissionCompletedEvent, MissionDefinition, MissionLog,
+    MissionObjectiveDefinition, MissionObjectiveKind, MissionRegistry, MissionReward, OwnerId,
+    PlayerTag, WorldPosition,
+};
+use uuid::Uuid;
+
+use crate::replication::missions::{
+    MissionStartQueue, MissionStartRequest, evaluate_missions, start_queued_missions,
+};
+use crate::replication::runtime_scripting::{ScriptEvent, ScriptEventQueue};
+
+const STATION_GUID: Uuid = Uuid::from_u128(0x51);
+
+fn registry() -> MissionRegistry {
+    let mission = |mission_id: &str, kind: MissionObjectiveKind, credits: u64| MissionDefinition {
+        mission_id: mission_id.to_string(),
+        display_name: mission_id.to_string(),
+        objectives: vec![MissionObjectiveDefinition {
+            objective_id: "objective".to_string(),
+            description: String::new(),
+            kind,
+        }],
+        reward: MissionReward {
+            credits,
+            reputation: Vec::new(),
+        },
+        ..Default::default()
+    };
+    MissionRegistry {
+        schema_version: 1,
+        missions: vec![
+            mission(
+                "mission.bounty",
+                MissionObjectiveKind::DestroyEntity {
+                    target_entity_id: None,
+                    faction_id: Some("faction.raiders".to_string()),
+                    count: 2,
+                },
+                300,
+            ),
+            mission(
+                "mission.delivery",
+                MissionObjectiveKind::DeliverItem {
+                    item_id: "resource.iron_ore".to_string(),
+                    quantity: 10,
+                    destination_entity_id: STATION_GUID,
+                    range_m: 500.0,
+                },
+                100,
+            ),
+        ],
+    }
+}
+
+struct Fixture {
+    world: World,
+    player: Entity,
+    player_guid: Uuid,
+    ship: Entity,
+    ship_guid: Uuid,
+}
+
+fn fixture() -> Fixture {
+    let mut world = World::new();
+    world.insert_resource(Time::<Fixed>::default());
+    world.insert_resource(ScriptEventQueue::default());
+    world.insert_resource(MissionStartQueue::default());
+    world.insert_resource(registry());
+    world.init_resource::<Messages<MissionCompletedEvent>>();
+    world.init_resource::<Messages<EntityKillCreditedEvent>>();
+
+    let player_guid = Uuid::new_v4();
+    let ship_guid = Uuid::new_v4();
+    let player = world
+        .spawn((
+            PlayerTag,
+            EntityGuid(player_guid),
+            ControlledEntityGuid(Some(ship_guid.to_string())),
+            CreditWallet { balance: 0 },
+            CreditLedger::default(),
+        ))
+        .id();
+    let ship = world
+        .spawn((
+            EntityGuid(ship_guid),
+            OwnerId(player_guid.to_string()),
+            Position(DVec2::new(100.0, 0.0)),
+        ))
+        .id();
+    world.spawn((EntityGuid(STATION_GUID), WorldPosition(DVec2::ZERO)));
+    Fixture {
+        world,
+        player,
+        player_guid,
+        ship,
+        ship_guid,
+    }
+}
+
+fn start(fixture: &mut Fixture, mission_id: &str) {
+    fixture
+        .world
+        .resource_mut::<MissionStartQueue>()
+        .pending
+        .push(MissionStartRequest {
+            player_guid: fixture.player_guid,
+            mission_id: mission_id.to_string(),
+        });
+    fixture
+        .world
+        .run_system_once(start_queued_missions)
+        .expect("start queued missions");
+}
+
+fn evaluate(fixture: &mut Fixture) -> Vec<ScriptEvent> {
+    fixture
+        .world
+        .run_system_once(evaluate_missions)
+        .expect("evaluate missions");
+    fixture
+        .world
+        .resource_mut::<Messages<EntityKillCreditedEvent>>()
+        .clear();
+    std::mem::take(&mut fixture.world.resource_mut::<ScriptEventQueue>().pending)
+}
+
+fn credit_kill(fixture: &mut Fixture, victim_guid: Uuid, victim_faction: &str) {
+    fixture.world.write_message(EntityKillCreditedEvent {
+        victim_entity: Entity::PLACEHOLDER,
+        victim_guid,
+        victim_faction: Some(victim_faction.to_string()),
+        shooter_guid: fixture.ship_guid,
+        player_entity: fixture.player,
+    });
+}
+
+#[test]
+fn credited_faction_kills_complete_bounties_and_pay_once() {
+    let mut fixture = fixture();
+    start(&mut fixture, "mission.bounty");
+    assert!(
+        fixture
+            .world
+            .get::<MissionLog>(fixture.player)
+            .and_then(|log| log.active_mission("mission.bounty"))
+            .is_some()
+    );
+
+    // Only kills of the bounty's faction count toward it.
+    credit_kill(&mut fixture, Uuid::new_v4(), "faction.traders");
+    credit_kill(&mut fixture, Uuid::new_v4(), "faction.raiders");
+    evaluate(&mut fixture);
+    let log = fixture.world.get::<MissionLog>(fixture.player).unwrap();
+    assert_eq!(
+        log.active_mission("mission.bounty").unwrap().objectives[0].current,
+        1
+    );
+
+    credit_kill(&mut fixture, Uuid::new_v4(), "faction.raiders");
+    let drained = evaluate(&mut fixture);
+    let completed = drained
+        .iter()
+        .find(|event| event.event_name == "mission_completed")
+        .expect("mission_completed script event");
+    assert_eq!(completed.payload["mission_id"], json!("mission.bounty"));
+    assert_eq!(completed.payload["credits"], json!(300));
+
+    let log = fixture.world.get::<MissionLog>(fixture.player).unwrap();
+    assert!(log.active.is_empty());
+    assert!(log.has_completed("mission.bounty"));
+    let wallet = fixture.world.get::<CreditWallet>(fixture.player).unwrap();
+    assert_eq!(wallet.balance, 300);
+    assert_eq!(
+        fixture
+            .world
+            .resource::<Messages<MissionCompletedEvent>>()
+            .len(),
+        1
+    );
+
+    evaluate(&mut fixture);
+    let wallet = fixture.world.get::<CreditWallet>(fixture.player).unwrap();
+    assert_eq!(wallet.balance, 300);
+}
+
+#[test]
+fn deliveries_hand_over_cargo_only_within_range() {
+    let mut fixture = fixture();
+    start(&mut fixture, "mission.delivery");
+    let mut inventory = Inventory::default();
+    inventory.add_stack("resource.iron_ore", 6, 2.5);
+    fixture
+        .world
+        .entity_mut(fixture.ship)
+        .insert((inventory, Position(DVec2::new(2_000.0, 0.0))));
+
+    evaluate(&mut fixture);
+    let ship = fixture.world.entity(fixture.ship);
+    assert_eq!(
+        ship.get::<Inventory>()
+            .unwrap()
+            .quantity_of("resource.iron_ore"),
+        6
+    );
+    assert!(!ship.contains::<MassDirty>());
+
+    fixture
+        .world
+        .entity_mut(fixture.ship)
+        .insert(Position(DVec2::new(300.0, 0.0)));
+    evaluate(&mut fixture);
+    let ship = fixture.world.entity(fixture.ship);
+    assert_eq!(
+        ship.get::<Inventory>()
+            .unwrap()
+            .quantity_of("resource.iron_ore"),
+        0
+    );
+    assert!(ship.contains::<MassDirty>());
+    let log = fixture.world.get::<MissionLog>(fixture.player).unwrap();
+    let objective = &log.active_mission("mission.delivery").unwrap().objectives[0];
+    assert_eq!((objective.current, objective.required), (6, 10));
+}
@@ -1,8 +1,13 @@
-//! Tests for the replication binary (remote inspect, auth, control, input, visibility).
+//! Tests for the replication binary (remote inspect, auth, control, crafting, economy audit,
+//! input, markets, missions, visibility).
 
 mod auth;
 mod control;
+mod crafting;
+mod economy_audit;
 mod input;
+mod markets;
+mod missions;
 mod runtime_scripting;
 mod visibility;
 
@@ -1,8 +1,65 @@
+use bevy::ecs::system::RunSystemOnce;
 use bevy::math::DVec2;
+use bevy::prelude::*;
 use serde_json::json;
+use sidereal_game::{
+    ActionQueue, CreditLedger, CreditWallet, DamageType, DirectDamageRequest, EntityAction,
+    EntityGuid, FactionDefinition, FactionId, FactionRegistry, FactionRelationDefinition,
+    FactionReputation, FactionReputationEntry, FactionStanding, HealthPool, Inventory, MassDirty,
+    MountedOn, OwnerId, PendingDestruction, PlayerTag, ScriptState, ScriptTimers, ScriptValue,
+    ScriptWeaponTarget,
+};
+use sidereal_scripting::{LuaSandboxPolicy, create_sandboxed_lua_vm};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
-use crate::replication::runtime_scripting::{ScriptIntent, parse_intent};
+use crate::replication::missions::MissionStartQueue;
+use crate::replication::notifications::NotificationCommandQueue;
+use crate::replication::runtime_scripting::{
+    CreditDirection, ScriptBundleSpawn, ScriptBundleSpawnQueue, ScriptEntityFilter,
+    ScriptEntitySnapshot, ScriptEventQueue, ScriptHandlerQuarantinedEvent, ScriptIntent,
+    ScriptIntentAuthority, ScriptRuntime, ScriptRuntimeMetrics, ScriptWorldSnapshot,
+    apply_script_intents, build_script_context, fire_script_timers, parse_intent,
+    run_script_intervals,
+};
+use crate::replication::scripting::{ScriptCatalogEntry, ScriptCatalogResource};
+
+fn script_entity(guid: &str, x: f64, y: f64, faction_id: Option<&str>) -> ScriptEntitySnapshot {
+    ScriptEntitySnapshot {
+        guid: guid.to_string(),
+        position: DVec2::new(x, y),
+        velocity: DVec2::ZERO,
+        faction_id: faction_id.map(str::to_string),
+        responsible_player_id: None,
+        parent_entity_id: None,
+        script_state: None,
+        components: Arc::new(HashMap::new()),
+    }
+}
+
+fn script_world(cell_size_m: f64, entities: Vec<ScriptEntitySnapshot>) -> ScriptWorldSnapshot {
+    let mut world = ScriptWorldSnapshot {
+        cell_size_m,
+        faction_registry: Some(FactionRegistry {
+            schema_version: 1,
+            relations: vec![FactionRelationDefinition {
+                faction_a: "faction.pirates".to_string(),
+                faction_b: "faction.frontier".to_string(),
+                standing: FactionStanding::Hostile,
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    for entity in entities {
+        world.insert_entity(entity, None);
+    }
+    world
+}
 
 #[test]
 fn set_navigation_target_preserves_f64_coordinates() {
@@ -50,3 +107,866 @@ fn set_navigation_target_rejects_non_finite_coordinates() {
 
     assert!(err.contains("target_position.x must be finite number"));
 }
+
+#[test]
+fn grant_credits_parses_player_amount_and_counterparty() {
+    let player_entity_id = Uuid::new_v4();
+    let counterparty = Uuid::new_v4();
+    let intent = parse_intent(
+        "grant_credits",
+        &json!({
+            "player_entity_id": player_entity_id.to_string(),
+            "amount": 250,
+            "reason": "bounty.pirate_kill",
+            "counterparty_entity_id": counterparty.to_string(),
+        }),
+    )
+    .unwrap();
+
+    let ScriptIntent::TransferCredits {
+        player_entity_id: parsed_player,
+        direction,
+        amount,
+        reason,
+        counterparty: parsed_counterparty,
+        authority,
+    } = intent
+    else {
+        panic!("expected credit transfer intent");
+    };
+    assert_eq!(parsed_player, player_entity_id);
+    assert_eq!(direction, CreditDirection::Grant);
+    assert_eq!(amount, 250);
+    assert_eq!(reason, "bounty.pirate_kill");
+    assert_eq!(parsed_counterparty, Some(counterparty));
+    assert_eq!(authority, ScriptIntentAuthority::default());
+}
+
+#[test]
+fn debit_credits_rejects_non_positive_amounts_and_free_form_reasons() {
+    let player_entity_id = Uuid::new_v4().to_string();
+    let zero = parse_intent(
+        "debit_credits",
+        &json!({ "player_entity_id": player_entity_id, "amount": 0, "reason": "toll" }),
+    )
+    .unwrap_err();
+    assert!(zero.contains("amount must be an integer"));
+
+    let oversized = parse_intent(
+        "grant_credits",
+        &json!({ "player_entity_id": player_entity_id, "amount": 10_000_000, "reason": "toll" }),
+    )
+    .unwrap_err();
+    assert!(oversized.contains("amount must be an integer"));
+
+    let reason = parse_intent(
+        "debit_credits",
+        &json!({ "player_entity_id": player_entity_id, "amount": 5, "reason": "Gate Toll!" }),
+    )
+    .unwrap_err();
+    assert!(reason.contains("reason must be"));
+}
+
+#[test]
+fn find_in_radius_returns_nearest_first_with_the_same_result_from_grid_and_scan() {
+    let mut mounted = script_entity("mounted", 5.0, 0.0, None);
+    mounted.parent_entity_id = Some("near".to_string());
+    let mut armored = script_entity("armored", 0.0, 900.0, None);
+    armored.components = Arc::new(HashMap::from([(
+        "health_pool".to_string(),
+        json!({ "current": 50.0, "maximum": 100.0 }),
+    )]));
+    let entities = vec![
+        script_entity("near", 10.0, 0.0, None),
+        script_entity("far", 2_500.0, 0.0, None),
+        mounted,
+        armored,
+    ];
+
+    for cell_size_m in [0.0, 1_000.0] {
+        let world = script_world(cell_size_m, entities.clone());
+        let found = world
+            .find_in_radius(DVec2::ZERO, 1_000.0, &ScriptEntityFilter::default())
+            .into_iter()
+            .map(|entity| entity.guid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec!["near", "armored"], "cell_size_m={cell_size_m}");
+
+        let with_health = ScriptEntityFilter {
+            component: Some("health_pool".to_string()),
+            ..Default::default()
+        };
+        let found = world.find_in_radius(DVec2::ZERO, 1_000.0, &with_health);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].guid, "armored");
+
+        let with_mounted = ScriptEntityFilter {
+            include_mounted: true,
+            limit: Some(2),
+            ..Default::default()
+        };
+        let found = world
+            .find_in_radius(DVec2::ZERO, 1_000.0, &with_mounted)
+            .into_iter()
+            .map(|entity| entity.guid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(found, vec!["mounted", "near"]);
+    }
+}
+
+#[test]
+fn find_nearest_hostile_uses_faction_standing_and_player_reputation() {
+    let pirate = script_entity("pirate", 0.0, 0.0, Some("faction.pirates"));
+    let wingman = script_entity("wingman", 5.0, 0.0, Some("faction.pirates"));
+    let trader = script_entity("trader", 40.0, 0.0, None);
+    let patrol = script_entity("patrol", 80.0, 0.0, Some("faction.frontier"));
+    let mut world = script_world(1_000.0, vec![pirate, wingman, trader, patrol]);
+
+    let hostile = world.find_nearest_hostile("pirate", 500.0, &ScriptEntityFilter::default());
+    assert_eq!(hostile.map(|entity| entity.guid.as_str()), Some("patrol"));
+    assert!(
+        world
+            .find_nearest_hostile("pirate", 50.0, &ScriptEntityFilter::default())
+            .is_none()
+    );
+
+    let player_id = Uuid::new_v4().to_string();
+    let mut bounty_hunter = script_entity("bounty_hunter", 20.0, 0.0, None);
+    bounty_hunter.responsible_player_id = Some(player_id.clone());
+    world.insert_entity(bounty_hunter, None);
+    world.reputation_by_player_id.insert(
+        player_id,
+        FactionReputation {
+            entries: vec![FactionReputationEntry {
+                faction_id: "faction.pirates".to_string(),
+                value: -200.0,
+            }],
+        },
+    );
+    let hostile = world.find_nearest_hostile("pirate", 500.0, &ScriptEntityFilter::default());
+    assert_eq!(
+        hostile.map(|entity| entity.guid.as_str()),
+        Some("bounty_hunter")
+    );
+}
+
+#[test]
+fn lua_world_reads_registry_components_and_radius_queries() {
+    let mut target = script_entity("target", 30.0, 40.0, Some("faction.frontier"));
+    target.components = Arc::new(HashMap::from([(
+        "health_pool".to_string(),
+        json!({ "current": 75.0, "maximum": 100.0 }),
+    )]));
+    let world = Rc::new(script_world(
+        1_000.0,
+        vec![
+            script_entity("pirate", 0.0, 0.0, Some("faction.pirates")),
+            target,
+        ],
+    ));
+    let lua = create_sandboxed_lua_vm(&LuaSandboxPolicy::from_env()).unwrap();
+    let ctx = build_script_context(
+        &lua,
+        world,
+        Rc::new(RefCell::new(Vec::new())),
+        "world_api_test",
+        "pirate",
+    )
+    .unwrap();
+    lua.globals().set("ctx", ctx).unwrap();
+
+    let (current, found, hostile, faction): (f64, i64, String, String) = lua
+        .load(
+            r#"
+            local target = ctx.world:find_entity("target")
+            local nearby = ctx.world:find_in_radius(0, 0, 100, { component = "health_pool" })
+            local hostile = ctx.world:find_nearest_hostile("pirate", 100)
+            return target:get("health_pool").current, #nearby, hostile:guid(), target:faction_id()
+            "#,
+        )
+        .eval()
+        .unwrap();
+
+    assert_eq!(current, 75.0);
+    assert_eq!(found, 1);
+    assert_eq!(hostile, "target");
+    assert_eq!(faction, "faction.frontier");
+}
+
+#[test]
+fn expanded_intents_validate_payloads() {
+    let npc = Uuid::new_v4();
+    let other = Uuid::new_v4();
+
+    let intent = parse_intent(
+        "fire_weapons",
+        &json!({ "entity_id": npc.to_string(), "target_entity_id": other.to_string() }),
+    )
+    .unwrap();
+    assert!(matches!(
+        intent,
+        ScriptIntent::FireWeapons {
+            weapon_group: 1,
+            ..
+        }
+    ));
+    assert!(
+        parse_intent(
+            "fire_weapons",
+            &json!({
+                "entity_id": npc.to_string(),
+                "target_entity_id": other.to_string(),
+                "weapon_group": 9,
+            }),
+        )
+        .is_err()
+    );
+
+    let ScriptIntent::SpawnBundle { spawn, authority } = parse_intent(
+        "spawn_bundle",
+        &json!({
+            "bundle_id": "ship.corvette",
+            "overrides": { "owner_id": "npc:ambush", "spawn_position": [10.0, 20.0] },
+        }),
+    )
+    .unwrap() else {
+        panic!("expected spawn_bundle intent");
+    };
+    assert_eq!(spawn.bundle_id, "ship.corvette");
+    assert_eq!(authority, ScriptIntentAuthority::default());
+    let player_owned = parse_intent(
+        "spawn_bundle",
+        &json!({ "bundle_id": "ship.corvette", "overrides": { "owner_id": other.to_string() } }),
+    )
+    .unwrap_err();
+    assert!(player_owned.contains("non-player owner"));
+    assert!(
+        parse_intent(
+            "spawn_bundle",
+            &json!({ "bundle_id": "ship.corvette", "overrides": { "entity_id": "x" } }),
+        )
+        .unwrap_err()
+        .contains("key not allowed")
+    );
+
+    assert!(
+        parse_intent(
+            "transfer_inventory",
+            &json!({
+                "from_entity_id": npc.to_string(),
+                "to_entity_id": npc.to_string(),
+                "item_id": "resource.iron_ore",
+                "quantity": 1,
+            }),
+        )
+        .is_err()
+    );
+    assert!(
+        parse_intent(
+            "apply_damage",
+            &json!({
+                "source_entity_id": npc.to_string(),
+                "target_entity_id": other.to_string(),
+                "amount": -5.0,
+            }),
+        )
+        .is_err()
+    );
+    assert!(matches!(
+        parse_intent(
+            "set_faction",
+            &json!({ "entity_id": npc.to_string(), "faction_id": null }),
+        )
+        .unwrap(),
+        ScriptIntent::SetFaction {
+            faction_id: None,
+            ..
+        }
+    ));
+
+    let ScriptIntent::StartMission {
+        player_entity_id,
+        mission_id,
+        ..
+    } = parse_intent(
+        "start_mission",
+        &json!({ "player_entity_id": other.to_string(), "mission_id": "mission.raider_bounty" }),
+    )
+    .unwrap()
+    else {
+        panic!("expected start_mission intent");
+    };
+    assert_eq!(player_entity_id, other);
+    assert_eq!(mission_id, "mission.raider_bounty");
+    assert!(
+        parse_intent(
+            "start_mission",
+            &json!({ "player_entity_id": "npc:ambush", "mission_id": "mission.raider_bounty" }),
+        )
+        .is_err()
+    );
+}
+
+struct IntentFixture {
+    npc: Entity,
+    npc_guid: Uuid,
+    npc_module: Entity,
+    player_ship: Entity,
+    player_ship_guid: Uuid,
+}
+
+fn intent_app() -> (App, IntentFixture) {
+    let mut app = App::new();
+    app.insert_resource(Time::<Fixed>::from_hz(30.0));
+    app.insert_resource(NotificationCommandQueue::default());
+    app.insert_resource(ScriptBundleSpawnQueue::default());
+    app.insert_resource(MissionStartQueue::default());
+    app.insert_resource(ScriptEventQueue::default());
+    app.add_message::<DirectDamageRequest>();
+    app.insert_resource(FactionRegistry {
+        schema_version: 1,
+        factions: vec![FactionDefinition {
+            faction_id: "faction.pirates".to_string(),
+            display_name: "Pirates".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+    app.insert_non_send_resource(
+        ScriptRuntime::from_catalog(&ScriptCatalogResource::default()).unwrap(),
+    );
+    let mut cargo = Inventory::default();
+    cargo.add_stack("resource.iron_ore", 10, 2.0);
+    let npc_guid = Uuid::new_v4();
+    let npc = app
+        .world_mut()
+        .spawn((
+            EntityGuid(npc_guid),
+            OwnerId("npc:pirate_patrol_1".to_string()),
+            ScriptState::default(),
+            ActionQueue::default(),
+            cargo.clone(),
+            HealthPool {
+                current: 100.0,
+                maximum: 100.0,
+            },
+        ))
+        .id();
+    let npc_module = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: npc_guid,
+                hardpoint_id: "weapon_fore".to_string(),
+            },
+        ))
+        .id();
+    let player_ship_guid = Uuid::new_v4();
+    let player_ship = app
+        .world_mut()
+        .spawn((
+            EntityGuid(player_ship_guid),
+            OwnerId(Uuid::new_v4().to_string()),
+            ScriptState::default(),
+            ActionQueue::default(),
+            cargo,
+            HealthPool {
+                current: 100.0,
+                maximum: 100.0,
+            },
+        ))
+        .id();
+    (
+        app,
+        IntentFixture {
+            npc,
+            npc_guid,
+            npc_module,
+            player_ship,
+            player_ship_guid,
+        },
+    )
+}
+
+fn apply_intents(app: &mut App, intents: Vec<ScriptIntent>) {
+    {
+        let mut runtime = app.world_mut().non_send_resource_mut::<ScriptRuntime>();
+        for intent in intents {
+            runtime.queue_intent(intent);
+        }
+    }
+    app.world_mut()
+        .run_system_once(apply_script_intents)
+        .expect("apply script intents");
+}
+
+#[test]
+fn expanded_intents_only_act_through_script_controlled_entities() {
+    let (mut app, fixture) = intent_app();
+    let item_id = "resource.iron_ore".to_string();
+    let hauler_guid = Uuid::new_v4();
+    let hauler = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hauler_guid),
+            OwnerId("npc:pirate_hauler_1".to_string()),
+            ScriptState::default(),
+            Inventory::default(),
+        ))
+        .id();
+
+    apply_intents(
+        &mut app,
+        vec![
+            ScriptIntent::FireWeapons {
+                entity_id: fixture.npc_guid,
+                target_entity_id: fixture.player_ship_guid,
+                weapon_group: 2,
+            },
+            ScriptIntent::FireWeapons {
+                entity_id: fixture.player_ship_guid,
+                target_entity_id: fixture.npc_guid,
+                weapon_group: 1,
+            },
+            ScriptIntent::TransferInventory {
+                from_entity_id: fixture.npc_guid,
+                to_entity_id: hauler_guid,
+                item_id: item_id.clone(),
+                quantity: 4,
+            },
+            ScriptIntent::TransferInventory {
+                from_entity_id: fixture.npc_guid,
+                to_entity_id: fixture.player_ship_guid,
+                item_id: item_id.clone(),
+                quantity: 2,
+            },
+            ScriptIntent::TransferInventory {
+                from_entity_id: fixture.player_ship_guid,
+                to_entity_id: fixture.npc_guid,
+                item_id: item_id.clone(),
+                quantity: 14,
+            },
+            ScriptIntent::ApplyDamage {
+                source_entity_id: fixture.npc_guid,
+                target_entity_id: fixture.player_ship_guid,
+                amount: 30.0,
+                damage_type: DamageType::Energy,
+                acting_entity_id: Some(fixture.npc_guid),
+            },
+            ScriptIntent::ApplyDamage {
+                source_entity_id: fixture.npc_guid,
+                target_entity_id: fixture.player_ship_guid,
+                amount: 30.0,
+                damage_type: DamageType::Energy,
+                acting_entity_id: Some(fixture.player_ship_guid),
+            },
+            ScriptIntent::ApplyDamage {
+                source_entity_id: fixture.player_ship_guid,
+                target_entity_id: fixture.npc_guid,
+                amount: 30.0,
+                damage_type: DamageType::Energy,
+                acting_entity_id: Some(fixture.player_ship_guid),
+            },
+            ScriptIntent::SetFaction {
+                entity_id: fixture.npc_guid,
+                faction_id: Some("faction.unknown".to_string()),
+            },
+            ScriptIntent::SetFaction {
+                entity_id: fixture.npc_guid,
+                faction_id: Some("faction.pirates".to_string()),
+            },
+            ScriptIntent::SetFaction {
+                entity_id: fixture.player_ship_guid,
+                faction_id: Some("faction.pirates".to_string()),
+            },
+        ],
+    );
+
+    let world = app.world();
+    assert_eq!(
+        world.get::<ActionQueue>(fixture.npc).unwrap().pending,
+        vec![EntityAction::FireSecondary]
+    );
+    assert!(
+        world
+            .get::<ActionQueue>(fixture.player_ship)
+            .unwrap()
+            .pending
+            .is_empty()
+    );
+    assert_eq!(
+        world.get::<ScriptWeaponTarget>(fixture.npc),
+        Some(&ScriptWeaponTarget {
+            target_guid: fixture.player_ship_guid,
+        })
+    );
+    assert!(
+        world
+            .get::<ScriptWeaponTarget>(fixture.player_ship)
+            .is_none()
+    );
+    assert_eq!(
+        world
+            .get::<Inventory>(fixture.npc)
+            .unwrap()
+            .quantity_of(&item_id),
+        6
+    );
+    assert_eq!(
+        world
+            .get::<Inventory>(hauler)
+            .unwrap()
+            .quantity_of(&item_id),
+        4
+    );
+    assert_eq!(
+        world
+            .get::<Inventory>(fixture.player_ship)
+            .unwrap()
+            .quantity_of(&item_id),
+        10
+    );
+    assert!(world.get::<MassDirty>(fixture.npc).is_some());
+    assert!(world.get::<MassDirty>(hauler).is_some());
+    assert!(world.get::<MassDirty>(fixture.player_ship).is_none());
+    let damage_requests = world
+        .resource::<Messages<DirectDamageRequest>>()
+        .iter_current_update_messages()
+        .map(|request| (request.source_entity, request.target_entity, request.damage))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        damage_requests,
+        vec![(fixture.npc, fixture.player_ship, 30.0)]
+    );
+    assert_eq!(
+        world.get::<FactionId>(fixture.npc),
+        Some(&FactionId("faction.pirates".to_string()))
+    );
+    assert!(world.get::<FactionId>(fixture.player_ship).is_none());
+
+    apply_intents(
+        &mut app,
+        vec![
+            ScriptIntent::Despawn {
+                entity_id: fixture.player_ship_guid,
+            },
+            ScriptIntent::Despawn {
+                entity_id: fixture.npc_guid,
+            },
+        ],
+    );
+
+    assert!(
+        app.world()
+            .get::<PendingDestruction>(fixture.player_ship)
+            .is_none()
+    );
+    assert!(app.world().get::<PendingDestruction>(fixture.npc).is_some());
+    assert!(app.world().get_entity(fixture.npc_module).is_err());
+}
+
+fn credit_transfer(
+    player_entity_id: Uuid,
+    direction: CreditDirection,
+    authority: ScriptIntentAuthority,
+) -> ScriptIntent {
+    ScriptIntent::TransferCredits {
+        player_entity_id,
+        direction,
+        amount: 40,
+        reason: "toll".to_string(),
+        counterparty: None,
+        authority,
+    }
+}
+
+#[test]
+fn credit_transfers_need_grant_authority_or_the_debited_players_context() {
+    let (mut app, fixture) = intent_app();
+    let owner = app
+        .world()
+        .get::<OwnerId>(fixture.player_ship)
+        .unwrap()
+        .0
+        .clone();
+    let player_guid = Uuid::parse_str(&owner).unwrap();
+    let player = app
+        .world_mut()
+        .spawn((
+            EntityGuid(player_guid),
+            PlayerTag,
+            CreditWallet { balance: 100 },
+        ))
+        .id();
+    let untrusted_npc = ScriptIntentAuthority {
+        acting_entity_id: Some(fixture.npc_guid),
+        admin: false,
+    };
+    let own_ship = ScriptIntentAuthority {
+        acting_entity_id: Some(fixture.player_ship_guid),
+        admin: false,
+    };
+    let admin = ScriptIntentAuthority {
+        acting_entity_id: None,
+        admin: true,
+    };
+
+    apply_intents(
+        &mut app,
+        vec![
+            credit_transfer(player_guid, CreditDirection::Grant, untrusted_npc),
+            credit_transfer(player_guid, CreditDirection::Debit, untrusted_npc),
+            credit_transfer(player_guid, CreditDirection::Grant, own_ship),
+        ],
+    );
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        100
+    );
+
+    apply_intents(
+        &mut app,
+        vec![
+            credit_transfer(player_guid, CreditDirection::Debit, own_ship),
+            credit_transfer(player_guid, CreditDirection::Grant, admin),
+        ],
+    );
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        100
+    );
+    assert_eq!(
+        app.world()
+            .get::<CreditLedger>(player)
+            .unwrap()
+            .entries
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn spawn_and_mission_intents_need_an_admin_handler_or_an_authorized_acting_entity() {
+    let (mut app, fixture) = intent_app();
+    let owner = app
+        .world()
+        .get::<OwnerId>(fixture.player_ship)
+        .unwrap()
+        .0
+        .clone();
+    let player_guid = Uuid::parse_str(&owner).unwrap();
+    let spawn = |authority| ScriptIntent::SpawnBundle {
+        spawn: ScriptBundleSpawn {
+            bundle_id: "ship.corvette".to_string(),
+            entity_id: Uuid::new_v4(),
+            overrides: serde_json::Map::new(),
+        },
+        authority,
+    };
+    let start = |authority| ScriptIntent::StartMission {
+        player_entity_id: player_guid,
+        mission_id: "mission.raider_bounty".to_string(),
+        authority,
+    };
+    let acting = |entity_id| ScriptIntentAuthority {
+        acting_entity_id: Some(entity_id),
+        admin: false,
+    };
+    let admin = ScriptIntentAuthority {
+        acting_entity_id: None,
+        admin: true,
+    };
+
+    apply_intents(
+        &mut app,
+        vec![
+            spawn(acting(fixture.player_ship_guid)),
+            spawn(ScriptIntentAuthority::default()),
+            spawn(acting(fixture.npc_guid)),
+            spawn(admin),
+            start(acting(fixture.npc_guid)),
+            start(ScriptIntentAuthority::default()),
+            start(acting(fixture.player_ship_guid)),
+            start(admin),
+        ],
+    );
+
+    assert_eq!(
+        app.world()
+            .resource::<ScriptBundleSpawnQueue>()
+            .pending
+            .len(),
+        2
+    );
+    let starts = &app.world().resource::<MissionStartQueue>().pending;
+    assert_eq!(starts.len(), 2);
+    assert!(starts.iter().all(|start| start.player_guid == player_guid));
+}
+
+#[test]
+fn scheduled_timers_persist_on_the_entity_and_fire_back_into_their_handler() {
+    let (mut app, fixture) = intent_app();
+    let lua = create_sandboxed_lua_vm(&LuaSandboxPolicy::from_env()).unwrap();
+    let pending_intents = Rc::new(RefCell::new(Vec::new()));
+    let ctx = build_script_context(
+        &lua,
+        Rc::new(ScriptWorldSnapshot::default()),
+        pending_intents.clone(),
+        "ambush_director",
+        &fixture.npc_guid.to_string(),
+    )
+    .unwrap();
+    lua.globals().set("ctx", ctx).unwrap();
+    lua.load(
+        r#"
+        ctx:schedule(1.0, "spawn_wave", { wave = 2 })
+        ctx:schedule_repeating(0.5, "pulse", nil)
+        local doomed = ctx:schedule(0.25, "never", nil)
+        ctx:cancel_timer(doomed)
+        "#,
+    )
+    .exec()
+    .unwrap();
+    assert!(
+        lua.load(r#"ctx:schedule_repeating(0.0, "too_fast", nil)"#)
+            .exec()
+            .is_err()
+    );
+    assert!(
+        lua.load(r#"ctx:schedule(1.0, "Bad Name", nil)"#)
+            .exec()
+            .is_err()
+    );
+
+    let intents = pending_intents.borrow_mut().drain(..).collect::<Vec<_>>();
+    assert_eq!(intents.len(), 4);
+    apply_intents(&mut app, intents);
+    let timers = app.world().get::<ScriptTimers>(fixture.npc).unwrap();
+    assert_eq!(
+        timers
+            .timers
+            .iter()
+            .map(|timer| timer.event_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["spawn_wave", "pulse"]
+    );
+
+    let mut fired = Vec::new();
+    for _ in 0..4 {
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .advance_by(Duration::from_millis(300));
+        app.world_mut()
+            .run_system_once(fire_script_timers)
+            .expect("fire script timers");
+        fired.extend(std::mem::take(
+            &mut app.world_mut().resource_mut::<ScriptEventQueue>().pending,
+        ));
+    }
+
+    let names = fired
+        .iter()
+        .map(|event| event.event_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["pulse", "spawn_wave", "pulse"]);
+    let wave = &fired[1];
+    assert_eq!(wave.handler_name.as_deref(), Some("ambush_director"));
+    assert_eq!(
+        wave.target_entity_id.as_deref(),
+        Some(fixture.npc_guid.to_string().as_str())
+    );
+    assert_eq!(wave.payload["wave"], json!(2.0));
+    assert!(wave.payload["timer_id"].is_string());
+    let remaining = app.world().get::<ScriptTimers>(fixture.npc).unwrap();
+    assert_eq!(remaining.timers.len(), 1);
+    assert_eq!(remaining.timers[0].payload, ScriptValue::Null);
+}
+
+#[test]
+fn failing_handlers_are_quarantined_without_stopping_their_neighbours() {
+    let script = |script_path: &str, source: &str| ScriptCatalogEntry {
+        script_path: script_path.to_string(),
+        source: source.to_string(),
+        ..Default::default()
+    };
+    let catalog = ScriptCatalogResource {
+        entries: vec![
+            script(
+                "ai/runaway.lua",
+                "local M = {} function M.on_tick(ctx, event) while true do end end return M",
+            ),
+            script(
+                "ai/steady.lua",
+                "local M = {} function M.on_tick(ctx, event) local t = {} t[1] = event.entity_id end return M",
+            ),
+        ],
+        ..Default::default()
+    };
+    let ticking = |guid: &str, handler: &str| ScriptEntitySnapshot {
+        script_state: Some(json!({
+            "data": { "on_tick_handler": handler, "tick_interval_s": 0.1 },
+        })),
+        ..script_entity(guid, 0.0, 0.0, None)
+    };
+
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    world.insert_resource(ScriptRuntimeMetrics::default());
+    world.init_resource::<Messages<ScriptHandlerQuarantinedEvent>>();
+    world.insert_resource(script_world(
+        1_000.0,
+        vec![
+            ticking("runaway-npc", "runaway"),
+            ticking("steady-npc", "steady"),
+        ],
+    ));
+    world.insert_non_send_resource(ScriptRuntime::from_catalog(&catalog).unwrap());
+    world.insert_resource(catalog);
+
+    let quarantine_after = LuaSandboxPolicy::from_env().quarantine_after_failures;
+    assert!(quarantine_after > 0);
+    for _ in 0..quarantine_after + 2 {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(200));
+        world
+            .run_system_once(run_script_intervals)
+            .expect("run script intervals");
+    }
+
+    let metrics = world.resource::<ScriptRuntimeMetrics>();
+    let handler = |name: &str| {
+        metrics
+            .handlers
+            .iter()
+            .find(|handler| handler.handler_name == name)
+            .unwrap_or_else(|| panic!("missing metrics for {name}"))
+    };
+    let runaway = handler("runaway");
+    assert_eq!(runaway.runs, u64::from(quarantine_after));
+    assert_eq!(runaway.instruction_limit_hits, u64::from(quarantine_after));
+    assert_eq!(
+        runaway.quarantine_reason.as_deref(),
+        Some("instruction_limit")
+    );
+    assert!(runaway.max_run_instructions > LuaSandboxPolicy::from_env().instruction_limit);
+
+    let steady = handler("steady");
+    assert_eq!(steady.runs, u64::from(quarantine_after + 2));
+    assert_eq!(steady.error_count, 0);
+    assert!(steady.quarantine_reason.is_none());
+    assert!(steady.last_run_ms.is_some());
+    assert!(steady.memory_bytes > 0);
+    assert_eq!(metrics.interval_runs, u64::from(quarantine_after + 2));
+    assert_eq!(metrics.error_count, u64::from(quarantine_after));
+
+    let notices = world
+        .resource::<Messages<ScriptHandlerQuarantinedEvent>>()
+        .iter_current_update_messages()
+        .collect::<Vec<_>>();
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].handler_name, "runaway");
+    assert_eq!(notices[0].reason, "instruction_limit");
+    assert_eq!(notices[0].consecutive_failures, quarantine_after);
+}
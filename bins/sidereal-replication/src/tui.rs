@@ -1163,7 +1163,7 @@ fn render_health(
     health: &crate::replication::health::ReplicationHealthSnapshot,
     area: ratatui::layout::Rect,
 ) {
-    let lines = vec![
+    let mut lines = vec![
         Line::from(format!("status: {}", health.status)),
         Line::from(format!("uptime_s: {}", health.uptime_seconds)),
         Line::from(format!("entities: {}", health.world_entity_count)),
@@ -1203,11 +1203,43 @@ fn render_health(
             "lua mem limit: {}",
             health.lua_runtime.memory_limit_bytes
         )),
+        Line::from(format!(
+            "lua handlers/quarantined: {}/{}",
+            health.lua_runtime.handlers.len(),
+            health.lua_runtime.quarantined_handler_count
+        )),
+    ];
+    for handler in &health.lua_runtime.handlers {
+        let line = format!(
+            " {} runs={} err={} avg/max ms={:.2}/{:.2} instr={} mem={}KiB",
+            handler.handler_name,
+            handler.runs,
+            handler.error_count,
+            handler.avg_run_ms,
+            handler.max_run_ms,
+            handler.max_run_instructions,
+            handler.memory_bytes / 1024
+        );
+        lines.push(match &handler.quarantine_reason {
+            Some(reason) => Line::from(Span::styled(
+                format!("{line} QUARANTINED({reason})"),
+                Style::default().fg(Color::Rgb(248, 113, 113)),
+            )),
+            None => Line::from(line),
+        });
+    }
+    if let Some(latest) = health.lua_runtime.recent_quarantines.last() {
+        lines.push(Line::from(format!(
+            "lua last quarantine: {} ({}) {}",
+            latest.handler_name, latest.reason, latest.last_error
+        )));
+    }
+    lines.extend([
         Line::from(""),
         Line::from(format!("tui frame ms: {:.2}", app.tui_last_frame_ms)),
         Line::from(format!("tui log read ms: {:.2}", app.tui_last_log_read_ms)),
         Line::from(format!("tui max frame ms: {:.2}", app.tui_max_frame_ms)),
-    ];
+    ]);
     frame.render_widget(
         Paragraph::new(lines).block(panel_block(
             "health",
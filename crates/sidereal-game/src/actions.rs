@@ -17,7 +17,7 @@ use bevy::prelude::*;
 use bevy::reflect::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{ActionCapabilities, ActionQueue};
+use crate::{ActionCapabilities, ActionQueue, WeaponGroup};
 
 /// High-level action that can be sent to any entity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
@@ -50,6 +50,12 @@ pub enum EntityAction {
     FirePrimary,
     /// Fire secondary weapon group
     FireSecondary,
+    /// Fire weapon group 3
+    FireWeaponGroup3,
+    /// Fire weapon group 4
+    FireWeaponGroup4,
+    /// Reload mounted weapons from compatible cargo ammo
+    ReloadWeapons,
     /// Activate shield
     ActivateShield,
     /// Deactivate shield
@@ -60,10 +66,16 @@ pub enum EntityAction {
     ActivateTractor,
     /// Deactivate tractor beam
     DeactivateTractor,
+    /// Activate mining laser
+    ActivateMiningLaser,
+    /// Deactivate mining laser
+    DeactivateMiningLaser,
     /// Activate scanner
     ActivateScanner,
     /// Deploy cargo
     DeployCargo,
+    /// Field-repair damaged mounted modules with cargo repair kits
+    RepairModules,
 
     // === Navigation (future) ===
     /// Engage autopilot to target
@@ -72,10 +84,29 @@ pub enum EntityAction {
     DisengageAutopilot,
     /// Dock with target
     InitiateDocking,
+
+    // === Station services ===
+    /// Buy hull repairs from a station the ship is docked at or near
+    PurchaseHullRepair,
+    /// Buy fuel from a station the ship is docked at or near
+    PurchaseRefuel,
 }
 
 pub const MAX_PENDING_ACTIONS: usize = 128;
 
+impl EntityAction {
+    /// `WeaponGroup::group` fired by this action, if it is a fire action.
+    pub fn fired_weapon_group(self) -> Option<u8> {
+        match self {
+            Self::FirePrimary => Some(WeaponGroup::PRIMARY),
+            Self::FireSecondary => Some(WeaponGroup::SECONDARY),
+            Self::FireWeaponGroup3 => Some(3),
+            Self::FireWeaponGroup4 => Some(4),
+            _ => None,
+        }
+    }
+}
+
 impl ActionQueue {
     pub fn push(&mut self, action: EntityAction) {
         if self.pending.len() >= MAX_PENDING_ACTIONS {
@@ -105,8 +136,30 @@ pub const FLIGHT_CONTROL_ACTIONS: [EntityAction; 9] = [
     EntityAction::AfterburnerOn,
     EntityAction::AfterburnerOff,
 ];
-pub const WEAPON_ACTIONS: [EntityAction; 2] =
-    [EntityAction::FirePrimary, EntityAction::FireSecondary];
+pub const WEAPON_ACTIONS: [EntityAction; 5] = [
+    EntityAction::FirePrimary,
+    EntityAction::FireSecondary,
+    EntityAction::FireWeaponGroup3,
+    EntityAction::FireWeaponGroup4,
+    EntityAction::ReloadWeapons,
+];
+pub const SHIELD_ACTIONS: [EntityAction; 2] =
+    [EntityAction::ActivateShield, EntityAction::DeactivateShield];
+pub const TRACTOR_ACTIONS: [EntityAction; 2] = [
+    EntityAction::ActivateTractor,
+    EntityAction::DeactivateTractor,
+];
+pub const MINING_ACTIONS: [EntityAction; 2] = [
+    EntityAction::ActivateMiningLaser,
+    EntityAction::DeactivateMiningLaser,
+];
+
+pub const CARGO_ACTIONS: [EntityAction; 1] = [EntityAction::DeployCargo];
+pub const REPAIR_ACTIONS: [EntityAction; 1] = [EntityAction::RepairModules];
+pub const STATION_SERVICE_ACTIONS: [EntityAction; 2] = [
+    EntityAction::PurchaseHullRepair,
+    EntityAction::PurchaseRefuel,
+];
 
 pub fn is_flight_control_action(action: EntityAction) -> bool {
     FLIGHT_CONTROL_ACTIONS.contains(&action)
@@ -115,6 +168,10 @@ pub fn is_flight_control_action(action: EntityAction) -> bool {
 pub fn default_flight_action_capabilities() -> ActionCapabilities {
     let mut supported = FLIGHT_CONTROL_ACTIONS.to_vec();
     supported.extend(WEAPON_ACTIONS);
+    supported.extend(SHIELD_ACTIONS);
+    supported.extend(TRACTOR_ACTIONS);
+    supported.extend(MINING_ACTIONS);
+    supported.extend(CARGO_ACTIONS);
     ActionCapabilities { supported }
 }
 
@@ -0,0 +1,131 @@
+//! Weapon reloads from cargo
+//!
+//! Weapons with an `AmmoFeed` refill their `AmmoCount` from compatible ammo
+//! stacks in the parent hull's `Inventory`. `ReloadWeapons` starts a reload on
+//! every fed weapon mounted on the hull, and weapons with `auto_reload` start
+//! one on their own once the magazine runs dry. Cargo is drawn when the reload
+//! timer completes: the magazine fills as far as the stacks allow, which may be
+//! a partial reload, and the hull is marked `MassDirty` for the lighter hold.
+//! Weapons cannot fire while `AmmoFeed::is_reloading`.
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, AmmoCount, AmmoFeed, CombatAuthorityEnabled, EntityAction, EntityGuid, Inventory,
+    MassDirty, MountedOn,
+};
+
+type ReloadHullQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static mut ActionQueue>,
+        Option<&'static mut Inventory>,
+    ),
+    Without<AmmoFeed>,
+>;
+
+pub fn process_weapon_reloads(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut hulls: ReloadHullQuery<'_, '_>,
+    mut weapons: Query<'_, '_, (&'_ MountedOn, &'_ mut AmmoCount, &'_ mut AmmoFeed)>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let dt_s = time.delta_secs().max(0.0);
+    let mut hull_by_guid = HashMap::<Uuid, Entity>::new();
+    let mut reload_requested = HashSet::<Uuid>::new();
+    for (entity, guid, queue, _) in &mut hulls {
+        hull_by_guid.insert(guid.0, entity);
+        if let Some(mut queue) = queue
+            && drain_reload_weapons_action(&mut queue)
+        {
+            reload_requested.insert(guid.0);
+        }
+    }
+
+    for (mounted_on, mut ammo, mut feed) in &mut weapons {
+        let Some(&hull_entity) = hull_by_guid.get(&mounted_on.parent_entity_id) else {
+            continue;
+        };
+        let Ok((_, _, _, Some(mut inventory))) = hulls.get_mut(hull_entity) else {
+            continue;
+        };
+
+        if feed.is_reloading() {
+            feed.reload_remaining_s = (feed.reload_remaining_s - dt_s).max(0.0);
+            if !feed.is_reloading()
+                && load_ammo_from_inventory(&mut ammo, &feed, &mut inventory) > 0
+            {
+                commands.entity(hull_entity).insert(MassDirty);
+            }
+            continue;
+        }
+
+        let wants_reload = reload_requested.contains(&mounted_on.parent_entity_id)
+            || (feed.auto_reload && ammo.current == 0);
+        if !wants_reload {
+            continue;
+        }
+        let missing_items = feed.items_to_fill(ammo.capacity.saturating_sub(ammo.current));
+        let has_stock = feed
+            .ammo_item_ids
+            .iter()
+            .any(|item_id| inventory.quantity_of(item_id) > 0);
+        if missing_items == 0 || !has_stock {
+            continue;
+        }
+        if feed.reload_time_s > 0.0 {
+            feed.reload_remaining_s = feed.reload_time_s;
+        } else if load_ammo_from_inventory(&mut ammo, &feed, &mut inventory) > 0 {
+            commands.entity(hull_entity).insert(MassDirty);
+        }
+    }
+}
+
+/// Moves as many whole ammo items as fit from `inventory` into the magazine,
+/// trying `ammo_item_ids` in order. Returns the number of items consumed.
+fn load_ammo_from_inventory(
+    ammo: &mut AmmoCount,
+    feed: &AmmoFeed,
+    inventory: &mut Inventory,
+) -> u32 {
+    let rounds_per_item = feed.rounds_per_item.max(1);
+    let mut consumed = 0;
+    for item_id in &feed.ammo_item_ids {
+        let wanted = feed.items_to_fill(ammo.capacity.saturating_sub(ammo.current));
+        if wanted == 0 {
+            break;
+        }
+        let taken = wanted.min(inventory.quantity_of(item_id));
+        if taken == 0 || !inventory.remove_stack(item_id, taken) {
+            continue;
+        }
+        ammo.current = ammo
+            .current
+            .saturating_add(taken.saturating_mul(rounds_per_item))
+            .min(ammo.capacity);
+        consumed += taken;
+    }
+    consumed
+}
+
+fn drain_reload_weapons_action(queue: &mut ActionQueue) -> bool {
+    let mut wants_reload = false;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        if action == EntityAction::ReloadWeapons {
+            wants_reload = true;
+        } else {
+            queue.pending.push(action);
+        }
+    }
+    wants_reload
+}
@@ -7,11 +7,11 @@ use uuid::Uuid;
 
 use crate::{
     AsteroidField, AsteroidFieldDamageState, AsteroidFieldMember, AsteroidFractureProfile,
-    AsteroidMemberStateEntry, AsteroidMemberStateKind, AsteroidSizeTier, CollisionAabbM,
-    CollisionProfile, Destructible, DisplayName, EntityDestroyedEvent,
-    EntityDestructionStartedEvent, EntityGuid, EntityLabels, HealthPool, MassKg, OwnerId,
-    PendingDestruction, ProceduralSprite, SizeM, SpriteShaderAssetId, VisualAssetId,
-    compute_collision_half_extents_from_procedural_sprite,
+    AsteroidMemberStateEntry, AsteroidMemberStateKind, AsteroidResourceProfile, AsteroidSizeTier,
+    AsteroidYieldEntry, CollisionAabbM, CollisionProfile, Destructible, DisplayName,
+    EntityDestroyedEvent, EntityDestructionStartedEvent, EntityGuid, EntityLabels, HealthPool,
+    MassKg, OwnerId, PendingDestruction, ProceduralSprite, SizeM, SpriteShaderAssetId,
+    VisualAssetId, compute_collision_half_extents_from_procedural_sprite,
     generate_rdp_collision_outline_from_procedural_sprite,
 };
 
@@ -166,6 +166,98 @@ pub fn build_fracture_child_plans(
         .collect()
 }
 
+/// Deterministic extraction roll for one mining cycle against `member_key`.
+///
+/// `cycle_index` distinguishes successive cycles on the same member. Returns
+/// the chosen yield entry (weighted by `weight`) and a unit count in
+/// `min_units..=max_units`, or `None` when the table has no positive weights.
+pub fn roll_asteroid_extraction_yield<'a>(
+    profile: &'a AsteroidResourceProfile,
+    member_key: &str,
+    cycle_index: u64,
+) -> Option<(&'a AsteroidYieldEntry, f32)> {
+    let total_weight = profile
+        .yield_table
+        .iter()
+        .map(|entry| entry.weight.max(0.0))
+        .sum::<f32>();
+    if total_weight <= 0.0 {
+        return None;
+    }
+    let roll_key = format!("{member_key}#{cycle_index}");
+    let mut pick = hash01(&roll_key, 31) * total_weight;
+    let mut chosen = None;
+    for entry in &profile.yield_table {
+        let weight = entry.weight.max(0.0);
+        if weight <= 0.0 {
+            continue;
+        }
+        chosen = Some(entry);
+        if pick < weight {
+            break;
+        }
+        pick -= weight;
+    }
+    let entry = chosen?;
+    let min_units = entry.min_units.max(0.0);
+    let max_units = entry.max_units.max(min_units);
+    let units = min_units + (max_units - min_units) * hash01(&roll_key, 37);
+    Some((entry, units))
+}
+
+/// Resource units already extracted from `member_key` on its field root.
+pub fn asteroid_member_resource_units_consumed(
+    damage_state: &AsteroidFieldDamageState,
+    member_key: &str,
+) -> f32 {
+    damage_state
+        .entries
+        .iter()
+        .find(|entry| entry.member_key == member_key)
+        .map(|entry| entry.resource_units_consumed)
+        .unwrap_or(0.0)
+}
+
+/// Records `units` extracted from `member` against the field damage state and
+/// marks the member `Harvested` once `depletion_pool_units` is exhausted.
+/// Returns true when the member's pool is exhausted.
+pub fn record_asteroid_member_extraction(
+    damage_state: &mut AsteroidFieldDamageState,
+    member: &AsteroidFieldMember,
+    units: f32,
+    depletion_pool_units: f32,
+) -> bool {
+    let index = match damage_state
+        .entries
+        .iter()
+        .position(|entry| entry.member_key == member.member_key)
+    {
+        Some(index) => index,
+        None => {
+            damage_state.entries.push(AsteroidMemberStateEntry {
+                member_key: member.member_key.clone(),
+                parent_member_key: member.parent_member_key.clone(),
+                state: AsteroidMemberStateKind::Activated,
+                size_tier: member.size_tier,
+                fracture_depth: member.fracture_depth,
+                remaining_health: None,
+                remaining_mass_kg: None,
+                spawned_children: Vec::new(),
+                resource_units_consumed: 0.0,
+                last_update_tick: None,
+            });
+            damage_state.entries.len() - 1
+        }
+    };
+    let entry = &mut damage_state.entries[index];
+    entry.resource_units_consumed += units.max(0.0);
+    let exhausted = entry.resource_units_consumed >= depletion_pool_units.max(0.0);
+    if exhausted {
+        entry.state = AsteroidMemberStateKind::Harvested;
+    }
+    exhausted
+}
+
 fn hash_u64(key: &str, salt: u64) -> u64 {
     let mut hash = 0xcbf29ce484222325u64 ^ salt;
     for byte in key.as_bytes() {
@@ -341,6 +433,8 @@ fn upsert_member_damage_state(
     remaining_mass_kg: Option<f32>,
     spawned_children: Vec<String>,
 ) {
+    let resource_units_consumed =
+        asteroid_member_resource_units_consumed(damage_state, &member.member_key);
     let entry = AsteroidMemberStateEntry {
         member_key: member.member_key.clone(),
         parent_member_key: member.parent_member_key.clone(),
@@ -350,7 +444,7 @@ fn upsert_member_damage_state(
         remaining_health: Some(health.current),
         remaining_mass_kg,
         spawned_children,
-        resource_units_consumed: 0.0,
+        resource_units_consumed,
         last_update_tick: None,
     };
     if let Some(existing) = damage_state
@@ -417,6 +511,7 @@ fn spawn_fracture_child(
         destructible.cloned().unwrap_or_else(|| Destructible {
             destruction_profile_id: "destruction.asteroid.default".to_string(),
             destroy_delay_s: 0.18,
+            ..Default::default()
         }),
         MassKg(child.mass_kg),
         SizeM {
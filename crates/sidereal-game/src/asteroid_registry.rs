@@ -43,6 +43,12 @@ pub struct AsteroidResourceYieldDefinition {
     pub weight: f32,
     pub min_units: f32,
     pub max_units: f32,
+    #[serde(default = "default_yield_unit_mass_kg")]
+    pub unit_mass_kg: f32,
+}
+
+fn default_yield_unit_mass_kg() -> f32 {
+    1.0
 }
 
 #[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
@@ -0,0 +1,321 @@
+//! Player autopilot
+//!
+//! `EngageAutopilot` locks the target selected (or focused) by the player that
+//! controls the hull and stores it in `AutopilotState`. While engaged, the
+//! autopilot overrides `FlightComputer` intent after manual actions are applied:
+//! it closes on the target at a speed it can still brake from, then matches the
+//! target's velocity inside the arrival distance. Any manual thrust, turn or
+//! brake input, `DisengageAutopilot`, or losing the target disengages it.
+//!
+//! Runs in both server authority and client prediction so autopilot flight
+//! reconciles like normal input-driven flight.
+
+use avian2d::prelude::*;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, AutopilotState, ControlledEntityGuid, EntityAction, EntityGuid, FlightComputer,
+    FlightControlAuthority, FlightTuning, FocusedEntityGuid, MaxVelocityMps, PlayerTag,
+    SelectedEntityGuid, SimulationMotionWriter, WorldPosition, resolve_world_position,
+};
+
+/// Relative speed (m/s) under which the autopilot coasts instead of correcting.
+const VELOCITY_MATCH_EPSILON_MPS: f64 = 1.0;
+/// Heading alignment (cosine) required before the autopilot applies throttle.
+const THRUST_ALIGNMENT_COS: f64 = 0.9;
+/// Cross-product dead zone before the autopilot commands yaw.
+const YAW_DEAD_ZONE: f64 = 0.08;
+const DEFAULT_AUTOPILOT_BRAKE_ACCEL_MPS2: f32 = 16.0;
+const DEFAULT_AUTOPILOT_MAX_SPEED_MPS: f32 = 100.0;
+
+type AutopilotHullQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static EntityGuid,
+        &'static mut AutopilotState,
+        &'static mut FlightComputer,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static Rotation>,
+        Option<&'static LinearVelocity>,
+        Option<&'static FlightTuning>,
+        Option<&'static MaxVelocityMps>,
+    ),
+    (With<FlightControlAuthority>, With<SimulationMotionWriter>),
+>;
+
+type AutopilotTargetQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static EntityGuid,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static LinearVelocity>,
+    ),
+>;
+
+/// Flight-computer intent produced by one autopilot step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutopilotControl {
+    pub throttle: f32,
+    pub yaw_input: f32,
+    pub brake_active: bool,
+    /// True once the hull is inside the arrival distance with matched velocity.
+    pub arrived: bool,
+}
+
+/// Resolves engage/disengage requests and cancels on manual flight input.
+///
+/// Must run before `process_flight_actions` so manual actions are still in the
+/// queue when checked.
+#[allow(clippy::type_complexity)]
+pub fn process_autopilot_actions(
+    mut hulls: Query<
+        '_,
+        '_,
+        (&'_ EntityGuid, &'_ mut ActionQueue, &'_ mut AutopilotState),
+        (With<FlightControlAuthority>, With<SimulationMotionWriter>),
+    >,
+    players: Query<
+        '_,
+        '_,
+        (
+            &'_ ControlledEntityGuid,
+            Option<&'_ SelectedEntityGuid>,
+            Option<&'_ FocusedEntityGuid>,
+        ),
+        With<PlayerTag>,
+    >,
+) {
+    for (guid, mut queue, mut autopilot) in &mut hulls {
+        let mut engage = false;
+        let mut disengage = false;
+        let mut manual = false;
+        let pending = std::mem::take(&mut queue.pending);
+        for action in pending {
+            match action {
+                EntityAction::EngageAutopilot => engage = true,
+                EntityAction::DisengageAutopilot => disengage = true,
+                EntityAction::Forward
+                | EntityAction::Backward
+                | EntityAction::Left
+                | EntityAction::Right
+                | EntityAction::Brake => {
+                    manual = true;
+                    queue.pending.push(action);
+                }
+                _ => queue.pending.push(action),
+            }
+        }
+
+        if disengage || manual {
+            if autopilot.engaged || autopilot.target_guid.is_some() {
+                autopilot.disengage();
+            }
+            continue;
+        }
+        if !engage || autopilot.engaged {
+            continue;
+        }
+        let Some(target_guid) = selected_target_for_hull(guid.0, &players) else {
+            continue;
+        };
+        autopilot.engaged = true;
+        autopilot.target_guid = Some(target_guid);
+    }
+}
+
+/// Steers engaged hulls toward their autopilot target.
+///
+/// Runs after `process_flight_actions` so autopilot intent replaces the
+/// neutral axes carried by every latest-intent input snapshot.
+pub fn apply_autopilot_to_flight_computers(
+    mut hulls: AutopilotHullQuery<'_, '_>,
+    targets: AutopilotTargetQuery<'_, '_>,
+) {
+    let mut engaged_targets = hulls
+        .iter()
+        .filter(|(_, autopilot, ..)| autopilot.engaged)
+        .filter_map(|(_, autopilot, ..)| autopilot.target_guid)
+        .map(|guid| (guid, None))
+        .collect::<HashMap<Uuid, Option<(DVec2, DVec2)>>>();
+    if engaged_targets.is_empty() {
+        return;
+    }
+    for (guid, position, world_position, velocity) in &targets {
+        let Some(slot) = engaged_targets.get_mut(&guid.0) else {
+            continue;
+        };
+        let Some(position) = resolve_world_position(position, world_position) else {
+            continue;
+        };
+        *slot = Some((
+            position,
+            velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO),
+        ));
+    }
+
+    for (
+        guid,
+        mut autopilot,
+        mut computer,
+        position,
+        world_position,
+        rotation,
+        velocity,
+        tuning,
+        max_velocity,
+    ) in &mut hulls
+    {
+        if !autopilot.engaged {
+            continue;
+        }
+        let target = autopilot
+            .target_guid
+            .filter(|target_guid| *target_guid != guid.0)
+            .and_then(|target_guid| engaged_targets.get(&target_guid).copied().flatten());
+        let (Some((target_position, target_velocity)), Some(hull_position)) =
+            (target, resolve_world_position(position, world_position))
+        else {
+            autopilot.disengage();
+            continue;
+        };
+        let heading_rad = rotation
+            .map(|rotation| rotation.as_radians())
+            .unwrap_or(0.0);
+        let control = compute_autopilot_controls(
+            hull_position,
+            velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO),
+            DVec2::new(-heading_rad.sin(), heading_rad.cos()),
+            target_position,
+            target_velocity,
+            autopilot.arrival_distance_m,
+            tuning
+                .map(|tuning| tuning.active_brake_accel_mps2)
+                .unwrap_or(DEFAULT_AUTOPILOT_BRAKE_ACCEL_MPS2),
+            max_velocity
+                .map(|max_velocity| max_velocity.0)
+                .unwrap_or(DEFAULT_AUTOPILOT_MAX_SPEED_MPS),
+        );
+        computer.throttle = control.throttle;
+        computer.yaw_input = control.yaw_input;
+        computer.brake_active = control.brake_active;
+    }
+}
+
+/// Approach-and-match-velocity plan for one tick.
+///
+/// The desired velocity relative to the target points at it with the largest
+/// speed the hull can still shed over the remaining distance to the arrival
+/// ring; inside the ring the desired relative velocity is zero. Stationary
+/// targets use the flight computer's active brake to shed speed, moving targets
+/// are matched by turning into the velocity error and thrusting.
+#[allow(clippy::too_many_arguments)]
+pub fn compute_autopilot_controls(
+    hull_position: DVec2,
+    hull_velocity: DVec2,
+    hull_forward: DVec2,
+    target_position: DVec2,
+    target_velocity: DVec2,
+    arrival_distance_m: f32,
+    brake_accel_mps2: f32,
+    max_speed_mps: f32,
+) -> AutopilotControl {
+    let to_target = target_position - hull_position;
+    let distance_m = to_target.length();
+    let remaining_m = (distance_m - f64::from(arrival_distance_m.max(0.0))).max(0.0);
+    let closing_speed_mps = (2.0 * f64::from(brake_accel_mps2.max(0.1)) * remaining_m)
+        .sqrt()
+        .min(f64::from(max_speed_mps.max(0.0)));
+    let desired_relative_velocity = to_target
+        .try_normalize()
+        .map(|direction| direction * closing_speed_mps)
+        .unwrap_or(DVec2::ZERO);
+    let relative_velocity = hull_velocity - target_velocity;
+    let velocity_error = desired_relative_velocity - relative_velocity;
+
+    let coast = AutopilotControl {
+        throttle: 0.0,
+        yaw_input: 0.0,
+        brake_active: false,
+        arrived: remaining_m <= 0.0,
+    };
+    if velocity_error.length() <= VELOCITY_MATCH_EPSILON_MPS {
+        return coast;
+    }
+
+    let target_stationary = target_velocity.length() <= VELOCITY_MATCH_EPSILON_MPS;
+    if target_stationary && velocity_error.dot(hull_velocity) < 0.0 {
+        let overshooting = desired_relative_velocity == DVec2::ZERO
+            || hull_velocity.length() > desired_relative_velocity.length();
+        if overshooting {
+            return AutopilotControl {
+                brake_active: true,
+                arrived: false,
+                ..coast
+            };
+        }
+    }
+
+    let Some(desired_heading) = velocity_error.try_normalize() else {
+        return coast;
+    };
+    let forward = hull_forward.try_normalize().unwrap_or(DVec2::Y);
+    let cross = forward.perp_dot(desired_heading);
+    let yaw_input = if cross > YAW_DEAD_ZONE {
+        1.0
+    } else if cross < -YAW_DEAD_ZONE {
+        -1.0
+    } else {
+        0.0
+    };
+    let throttle = if forward.dot(desired_heading) >= THRUST_ALIGNMENT_COS {
+        1.0
+    } else {
+        0.0
+    };
+    AutopilotControl {
+        throttle,
+        yaw_input,
+        brake_active: false,
+        arrived: false,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn selected_target_for_hull(
+    hull_guid: Uuid,
+    players: &Query<
+        '_,
+        '_,
+        (
+            &'_ ControlledEntityGuid,
+            Option<&'_ SelectedEntityGuid>,
+            Option<&'_ FocusedEntityGuid>,
+        ),
+        With<PlayerTag>,
+    >,
+) -> Option<Uuid> {
+    players.iter().find_map(|(controlled, selected, focused)| {
+        let controlled_guid = controlled
+            .0
+            .as_deref()
+            .and_then(|id| Uuid::parse_str(id).ok())?;
+        if controlled_guid != hull_guid {
+            return None;
+        }
+        [
+            selected.and_then(|value| value.0.as_deref()),
+            focused.and_then(|value| value.0.as_deref()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|id| Uuid::parse_str(id).ok())
+        .find(|guid| *guid != hull_guid)
+    })
+}
@@ -0,0 +1,235 @@
+//! Beam weapon subsystem
+//!
+//! `BeamWeapon` modules answer the same weapon-group fire actions as ballistic
+//! weapons, but instead of discrete shots `process_weapon_fire_actions` only
+//! marks the emitter's trigger as held for the tick. `update_beam_weapons` then
+//! raycasts from the muzzle every fixed tick, applies damage-per-second to the
+//! first collider hit through shields and resistances, and accumulates heat.
+//! An emitter that reaches `max_heat` shuts off until it cools back to its
+//! recovery threshold, and one whose hull cannot cover its `PowerConsumer`
+//! draw for the tick stops with `BeamStopReason::PowerLoss`. Transitions are
+//! reported as `BeamStartedEvent` / `BeamStoppedEvent` so the replication
+//! server can tell clients when to start and release the looping beam
+//! presentation.
+
+use avian2d::prelude::{Position, Rotation, SpatialQuery, SpatialQueryFilter};
+use bevy::{math::DVec2, prelude::*};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::combat::{ResistanceProfileQuery, mitigate_hull_damage, resolve_muzzle};
+use crate::factions::FactionDamagePolicy;
+use crate::power::draw_sustained_power;
+use crate::{
+    BeamWeapon, BeamWeaponState, CombatAuthorityEnabled, EntityGuid, Hardpoint, HealthPool,
+    MountedOn, ParentGuid, PowerConsumer, ShieldGenerator, ShipPowerState, ShotHitEvent,
+    absorb_damage_with_mounted_shields,
+};
+
+#[derive(Debug, Clone, Message)]
+pub struct BeamStartedEvent {
+    pub shooter_guid: Uuid,
+    pub weapon_entity: Entity,
+    pub weapon_guid: Uuid,
+    pub origin: DVec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeamStopReason {
+    /// The fire group was released or the muzzle could not be resolved.
+    Released,
+    Overheated,
+    /// The hull's `ShipPowerState` could not cover this tick's draw.
+    PowerLoss,
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct BeamStoppedEvent {
+    pub shooter_guid: Uuid,
+    pub weapon_entity: Entity,
+    pub weapon_guid: Uuid,
+    pub reason: BeamStopReason,
+}
+
+pub fn bootstrap_beam_weapon_state(
+    mut commands: Commands<'_, '_>,
+    needs_state: Query<'_, '_, Entity, (With<BeamWeapon>, Without<BeamWeaponState>)>,
+) {
+    for entity in &needs_state {
+        commands.entity(entity).insert(BeamWeaponState::default());
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_beam_weapons(
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut shooters: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ EntityGuid,
+            &'_ Position,
+            &'_ Rotation,
+            Option<&'_ mut ShipPowerState>,
+        ),
+    >,
+    hardpoints: Query<'_, '_, (&'_ ParentGuid, &'_ Hardpoint)>,
+    mut beams: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ EntityGuid,
+            &'_ MountedOn,
+            &'_ BeamWeapon,
+            &'_ mut BeamWeaponState,
+            Option<&'_ PowerConsumer>,
+        ),
+    >,
+    guid_entities: Query<'_, '_, &'_ EntityGuid>,
+    spatial_query: SpatialQuery<'_, '_>,
+    mut health_pools: Query<'_, '_, &'_ mut HealthPool>,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+    resistance_profiles: ResistanceProfileQuery<'_, '_>,
+    faction_policy: FactionDamagePolicy<'_, '_>,
+    mut shot_hit_events: MessageWriter<'_, ShotHitEvent>,
+    mut started_events: MessageWriter<'_, BeamStartedEvent>,
+    mut stopped_events: MessageWriter<'_, BeamStoppedEvent>,
+) {
+    let dt_s = time.delta_secs();
+    if dt_s <= 0.0 || !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+
+    let shooter_by_guid = shooters
+        .iter()
+        .map(|(entity, guid, position, rotation, _)| {
+            (guid.0, (entity, position.0, rotation.as_radians() as f32))
+        })
+        .collect::<HashMap<_, _>>();
+    let faction_gate = faction_policy.gate();
+    let mut hardpoint_by_mount = HashMap::<(Uuid, String), (Vec2, Quat)>::new();
+    for (parent_guid, hardpoint) in &hardpoints {
+        hardpoint_by_mount.insert(
+            (parent_guid.0, hardpoint.hardpoint_id.clone()),
+            (hardpoint.offset_m.truncate(), hardpoint.local_rotation),
+        );
+    }
+
+    for (weapon_entity, weapon_guid, mounted_on, weapon, mut state, power_consumer) in &mut beams {
+        let trigger_held = std::mem::take(&mut state.trigger_held);
+        let was_firing = state.firing;
+        let shooter = shooter_by_guid.get(&mounted_on.parent_entity_id);
+        let power_starved = trigger_held
+            && !state.overheated
+            && shooter
+                .and_then(|(shooter_entity, _, _)| shooters.get_mut(*shooter_entity).ok())
+                .is_some_and(|(_, _, _, _, mut power)| {
+                    !draw_sustained_power(power.as_deref_mut(), power_consumer, dt_s)
+                });
+        let muzzle = (trigger_held && !state.overheated && !power_starved)
+            .then(|| {
+                let (_, shooter_position, shooter_heading_rad) = shooter?;
+                let (hardpoint_offset, hardpoint_rotation) = hardpoint_by_mount
+                    .get(&(mounted_on.parent_entity_id, mounted_on.hardpoint_id.clone()))?;
+                resolve_muzzle(
+                    *shooter_position,
+                    Quat::from_rotation_z(*shooter_heading_rad),
+                    *hardpoint_offset,
+                    *hardpoint_rotation,
+                )
+            })
+            .flatten();
+
+        let Some((origin, direction, _)) = muzzle else {
+            state.heat = (state.heat - weapon.cooling_per_second.max(0.0) * dt_s).max(0.0);
+            if state.overheated && state.heat <= weapon.recovery_heat() {
+                state.overheated = false;
+            }
+            state.firing = false;
+            state.beam_length_m = 0.0;
+            state.target_guid = None;
+            if was_firing {
+                stopped_events.write(BeamStoppedEvent {
+                    shooter_guid: mounted_on.parent_entity_id,
+                    weapon_entity,
+                    weapon_guid: weapon_guid.0,
+                    reason: if state.overheated {
+                        BeamStopReason::Overheated
+                    } else if power_starved {
+                        BeamStopReason::PowerLoss
+                    } else {
+                        BeamStopReason::Released
+                    },
+                });
+            }
+            continue;
+        };
+
+        state.heat += weapon.heat_per_second.max(0.0) * dt_s;
+        if state.heat >= weapon.max_heat.max(0.0) {
+            state.heat = weapon.max_heat.max(0.0);
+            state.overheated = true;
+        }
+        state.firing = true;
+        if !was_firing {
+            started_events.write(BeamStartedEvent {
+                shooter_guid: mounted_on.parent_entity_id,
+                weapon_entity,
+                weapon_guid: weapon_guid.0,
+                origin,
+            });
+        }
+
+        let range_m = f64::from(weapon.range_m.max(1.0));
+        let Ok(ray_direction) = Dir2::new(direction) else {
+            continue;
+        };
+        let filter = shooter.map_or_else(SpatialQueryFilter::default, |(shooter_entity, _, _)| {
+            SpatialQueryFilter::from_excluded_entities([*shooter_entity, weapon_entity])
+        });
+        let hit = spatial_query.cast_ray(origin, ray_direction, range_m, true, &filter);
+        state.beam_length_m = hit.map_or(range_m, |hit| hit.distance) as f32;
+        state.target_guid = hit.and_then(|hit| guid_entities.get(hit.entity).ok().map(|g| g.0));
+        let Some(hit) = hit else {
+            continue;
+        };
+        if !faction_gate.allows(
+            shooter.map(|(shooter_entity, _, _)| *shooter_entity),
+            hit.entity,
+        ) {
+            continue;
+        }
+        let Ok(mut health_pool) = health_pools.get_mut(hit.entity) else {
+            continue;
+        };
+        let damage = weapon.damage_per_second.max(0.0) * dt_s;
+        let after_shields = absorb_damage_with_mounted_shields(
+            state.target_guid,
+            damage,
+            weapon.damage_type,
+            &mut shields,
+        );
+        let hull_damage = mitigate_hull_damage(
+            hit.entity,
+            state.target_guid,
+            after_shields,
+            weapon.damage_type,
+            &resistance_profiles,
+        );
+        health_pool.current = (health_pool.current - hull_damage).max(0.0);
+        shot_hit_events.write(ShotHitEvent {
+            shooter_guid: mounted_on.parent_entity_id,
+            target_entity: hit.entity,
+            target_guid: state.target_guid,
+            weapon_entity,
+            weapon_guid: weapon_guid.0,
+            impact_pos: origin + ray_direction.as_vec2().as_dvec2() * hit.distance,
+            damage: hull_damage,
+            shield_absorbed: damage - after_shields,
+            damage_type: weapon.damage_type,
+        });
+    }
+}
@@ -0,0 +1,474 @@
+//! Cargo containers
+//!
+//! `DeployCargo` jettisons the hull's `CargoJettisonSelection` (or its whole
+//! `Inventory`) into a new `CargoContainer` entity spawned behind the hull.
+//! When `EntityDestroyedEvent` fires, the destroyed entity's `Inventory` plus
+//! the loot rolled from its `LootTableRegistry` table drop as one container.
+//! Containers are ordinary dynamic bodies with a `SignalSignature`, so
+//! scanners and visibility treat them like any other contact. Each tick the
+//! nearest ship in `loot_range_m` scoops as much of the contents as its
+//! `CargoCapacity` allows; containers despawn once empty or when their
+//! lifetime expires.
+
+use avian2d::prelude::*;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, CargoCapacity, CargoContainer, CargoJettisonSelection, CollisionAabbM,
+    CollisionProfile, Destructible, DisplayName, EntityAction, EntityDestroyedEvent, EntityGuid,
+    EntityLabels, Inventory, InventoryEntry, ItemRegistry, LootTableRegistry, MapIcon, MassDirty,
+    MassKg, OwnerId, PendingDestruction, ShipTag, SignalSignature, SimulationMotionWriter, SizeM,
+    WorldPosition, resolve_world_position,
+};
+
+/// Tuning for spawned cargo containers.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CargoContainerSettings {
+    pub lifetime_s: f32,
+    pub pickup_delay_s: f32,
+    pub loot_range_m: f32,
+    /// Speed added along the hull's aft axis when cargo is jettisoned.
+    pub eject_speed_mps: f32,
+    pub empty_mass_kg: f32,
+    pub size_m: f32,
+    pub signal_strength: f32,
+    pub signal_detection_radius_m: f32,
+}
+
+impl Default for CargoContainerSettings {
+    fn default() -> Self {
+        Self {
+            lifetime_s: 600.0,
+            pickup_delay_s: 2.0,
+            loot_range_m: 40.0,
+            eject_speed_mps: 8.0,
+            empty_mass_kg: 150.0,
+            size_m: 2.0,
+            signal_strength: 0.4,
+            signal_detection_radius_m: 1500.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct CargoContainerSpawnedEvent {
+    pub container_entity: Entity,
+    pub container_guid: Uuid,
+    pub dropped_by_guid: Option<Uuid>,
+    pub position: DVec2,
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct CargoCollectedEvent {
+    pub collector_entity: Entity,
+    pub collector_guid: Uuid,
+    pub collector_owner_id: Option<String>,
+    pub container_guid: Uuid,
+    pub item_id: String,
+    pub quantity: u32,
+}
+
+type JettisonHullQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static mut ActionQueue,
+        Option<&'static mut Inventory>,
+        Option<&'static CargoJettisonSelection>,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static Rotation>,
+        Option<&'static LinearVelocity>,
+        Option<&'static SizeM>,
+    ),
+    (With<SimulationMotionWriter>, Without<CargoContainer>),
+>;
+
+type ContainerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static mut CargoContainer,
+        &'static mut Inventory,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+    ),
+>;
+
+type CollectorQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static mut Inventory>,
+        Option<&'static CargoCapacity>,
+        Option<&'static OwnerId>,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+    ),
+    (
+        With<ShipTag>,
+        Without<CargoContainer>,
+        Without<PendingDestruction>,
+    ),
+>;
+
+/// Turns `DeployCargo` presses into container drops.
+///
+/// Input is a latest-intent snapshot, so the action repeats while held; only
+/// the first tick of a press jettisons cargo.
+pub fn process_cargo_jettison_actions(
+    mut held_last_tick: Local<'_, HashSet<Uuid>>,
+    mut commands: Commands<'_, '_>,
+    settings: Option<Res<'_, CargoContainerSettings>>,
+    mut hulls: JettisonHullQuery<'_, '_>,
+    mut spawned_events: MessageWriter<'_, CargoContainerSpawnedEvent>,
+) {
+    let settings = settings.as_deref().copied().unwrap_or_default();
+    let mut held_now = HashSet::<Uuid>::new();
+    for (
+        entity,
+        guid,
+        mut queue,
+        inventory,
+        selection,
+        position,
+        world_position,
+        rotation,
+        velocity,
+        hull_size,
+    ) in &mut hulls
+    {
+        if !drain_deploy_cargo_action(&mut queue) {
+            continue;
+        }
+        held_now.insert(guid.0);
+        if held_last_tick.contains(&guid.0) {
+            continue;
+        }
+        let Some(mut inventory) = inventory else {
+            continue;
+        };
+        let Some(hull_position) = resolve_world_position(position, world_position) else {
+            continue;
+        };
+        let selected_item_id = selection.and_then(|selection| selection.0.as_deref());
+        let jettisoned = take_inventory_entries(&mut inventory, selected_item_id);
+        if jettisoned.entries.is_empty() {
+            continue;
+        }
+        let heading_rad = rotation
+            .map(|rotation| rotation.as_radians())
+            .unwrap_or(0.0);
+        let aft = -DVec2::new(-heading_rad.sin(), heading_rad.cos());
+        let hull_velocity = velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO);
+        // Clear the hull's own collider before the container body spawns.
+        let hull_extent_m = hull_size
+            .map(|size| size.length.max(size.width) * 0.5)
+            .unwrap_or(0.0);
+        let drop_offset_m = f64::from(hull_extent_m + settings.size_m.max(0.5));
+        let container_position = hull_position + aft * drop_offset_m;
+        let container_velocity = hull_velocity + aft * f64::from(settings.eject_speed_mps);
+        let (container_entity, container_guid) = spawn_cargo_container(
+            &mut commands,
+            &settings,
+            jettisoned,
+            container_position,
+            container_velocity,
+            Some(guid.0),
+        );
+        commands.entity(entity).insert(MassDirty);
+        spawned_events.write(CargoContainerSpawnedEvent {
+            container_entity,
+            container_guid,
+            dropped_by_guid: Some(guid.0),
+            position: container_position,
+        });
+    }
+    *held_last_tick = held_now;
+}
+
+/// Drops the cargo and rolled loot of destroyed entities into a container at
+/// the destruction origin. Runs right after `advance_pending_destructions`,
+/// while the destroyed entity (and its `Inventory`) still exists.
+pub fn drop_cargo_from_destroyed_entities(
+    mut commands: Commands<'_, '_>,
+    settings: Option<Res<'_, CargoContainerSettings>>,
+    loot_tables: Option<Res<'_, LootTableRegistry>>,
+    items: Option<Res<'_, ItemRegistry>>,
+    mut destroyed_events: MessageReader<'_, '_, EntityDestroyedEvent>,
+    sources: Query<
+        '_,
+        '_,
+        (
+            Option<&'_ Inventory>,
+            Option<&'_ Destructible>,
+            Option<&'_ LinearVelocity>,
+        ),
+        Without<CargoContainer>,
+    >,
+    mut spawned_events: MessageWriter<'_, CargoContainerSpawnedEvent>,
+) {
+    let settings = settings.as_deref().copied().unwrap_or_default();
+    for event in destroyed_events.read() {
+        let Ok((inventory, destructible, velocity)) = sources.get(event.entity) else {
+            continue;
+        };
+        let mut dropped = inventory.cloned().unwrap_or_default();
+        dropped.entries.retain(|entry| entry.quantity > 0);
+        let loot_table = loot_tables.as_deref().and_then(|loot_tables| {
+            loot_tables.resolve(
+                destructible.and_then(|destructible| destructible.loot_table_id.as_deref()),
+                &event.destruction_profile_id,
+            )
+        });
+        if let Some(loot_table) = loot_table {
+            let loot = loot_table.roll(&event.entity_guid.to_string(), items.as_deref());
+            for entry in loot.entries {
+                dropped.add_stack(&entry.item_id, entry.quantity, entry.unit_mass_kg);
+            }
+        }
+        if dropped.entries.is_empty() {
+            continue;
+        }
+        let (container_entity, container_guid) = spawn_cargo_container(
+            &mut commands,
+            &settings,
+            dropped,
+            event.effect_origin,
+            velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO),
+            Some(event.entity_guid),
+        );
+        if let Some(wreck_display_name) =
+            loot_table.and_then(|loot_table| loot_table.wreck_display_name.clone())
+        {
+            commands
+                .entity(container_entity)
+                .insert(DisplayName(wreck_display_name));
+        }
+        spawned_events.write(CargoContainerSpawnedEvent {
+            container_entity,
+            container_guid,
+            dropped_by_guid: Some(event.entity_guid),
+            position: event.effect_origin,
+        });
+    }
+}
+
+pub fn collect_cargo_containers(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    settings: Option<Res<'_, CargoContainerSettings>>,
+    mut containers: ContainerQuery<'_, '_>,
+    mut collectors: CollectorQuery<'_, '_>,
+    mut collected_events: MessageWriter<'_, CargoCollectedEvent>,
+) {
+    let settings = settings.as_deref().copied().unwrap_or_default();
+    let dt_s = time.delta_secs().max(0.0);
+    let loot_range_m = f64::from(settings.loot_range_m.max(0.0));
+    let collector_positions = collectors
+        .iter()
+        .filter_map(|(entity, guid, _, _, _, position, world_position)| {
+            resolve_world_position(position, world_position).map(|pos| (entity, guid.0, pos))
+        })
+        .collect::<Vec<_>>();
+
+    let mut new_inventories = HashMap::<Entity, Inventory>::new();
+    for (entity, guid, mut container, mut inventory, position, world_position) in &mut containers {
+        container.lifetime_remaining_s -= dt_s;
+        container.pickup_delay_remaining_s = (container.pickup_delay_remaining_s - dt_s).max(0.0);
+        if container.lifetime_remaining_s <= 0.0 || inventory.entries.is_empty() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let Some(container_position) = resolve_world_position(position, world_position) else {
+            continue;
+        };
+        if let Some(dropped_by_guid) = container.dropped_by_guid {
+            let dropper_in_range = collector_positions.iter().any(|(_, guid, pos)| {
+                *guid == dropped_by_guid && pos.distance(container_position) <= loot_range_m
+            });
+            if !dropper_in_range {
+                container.dropped_by_guid = None;
+            }
+        }
+        if container.pickup_delay_remaining_s > 0.0 {
+            continue;
+        }
+        let Some(&(collector_entity, collector_guid, _)) = collector_positions
+            .iter()
+            .filter(|(_, guid, pos)| {
+                container.dropped_by_guid != Some(*guid)
+                    && pos.distance(container_position) <= loot_range_m
+            })
+            .min_by(|(_, _, a), (_, _, b)| {
+                a.distance_squared(container_position)
+                    .total_cmp(&b.distance_squared(container_position))
+            })
+        else {
+            continue;
+        };
+        let Ok((_, _, collector_inventory, capacity, owner_id, _, _)) =
+            collectors.get_mut(collector_entity)
+        else {
+            continue;
+        };
+        let collected = match collector_inventory {
+            Some(mut collector_inventory) => {
+                transfer_inventory(&mut inventory, &mut collector_inventory, capacity)
+            }
+            None => {
+                // Ships without cargo get an inventory inserted once all containers ran.
+                let target = new_inventories.entry(collector_entity).or_default();
+                transfer_inventory(&mut inventory, target, capacity)
+            }
+        };
+        if collected.is_empty() {
+            continue;
+        }
+        commands.entity(collector_entity).insert(MassDirty);
+        for (item_id, quantity) in collected {
+            collected_events.write(CargoCollectedEvent {
+                collector_entity,
+                collector_guid,
+                collector_owner_id: owner_id.map(|owner| owner.0.clone()),
+                container_guid: guid.0,
+                item_id,
+                quantity,
+            });
+        }
+        if inventory.entries.is_empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (collector_entity, inventory) in new_inventories {
+        commands.entity(collector_entity).insert(inventory);
+    }
+}
+
+/// Spawns a cargo container holding `inventory` and returns its entity and guid.
+pub fn spawn_cargo_container(
+    commands: &mut Commands<'_, '_>,
+    settings: &CargoContainerSettings,
+    inventory: Inventory,
+    position: DVec2,
+    velocity: DVec2,
+    dropped_by_guid: Option<Uuid>,
+) -> (Entity, Uuid) {
+    let container_guid = Uuid::new_v4();
+    let size_m = settings.size_m.max(0.5);
+    let mass_kg = settings.empty_mass_kg.max(1.0) + inventory.total_mass_kg();
+    let entity = commands
+        .spawn((
+            EntityGuid(container_guid),
+            DisplayName("Cargo Container".to_string()),
+            EntityLabels(vec!["CargoContainer".to_string()]),
+            CargoContainer {
+                lifetime_remaining_s: settings.lifetime_s,
+                pickup_delay_remaining_s: settings.pickup_delay_s,
+                dropped_by_guid,
+            },
+            inventory,
+            MassKg(mass_kg),
+            SizeM {
+                length: size_m,
+                width: size_m,
+                height: size_m,
+            },
+            CollisionProfile::solid_aabb(),
+            CollisionAabbM {
+                half_extents: Vec3::splat(size_m * 0.5),
+            },
+            SignalSignature {
+                strength: settings.signal_strength,
+                detection_radius_m: settings.signal_detection_radius_m,
+                use_extent_for_detection: false,
+            },
+            MapIcon {
+                asset_id: "map_icon_unknown_contact_svg".to_string(),
+            },
+        ))
+        .insert((
+            Position(position),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(velocity),
+            AngularVelocity(0.0),
+            RigidBody::Dynamic,
+            LinearDamping(0.0),
+            AngularDamping(0.0),
+        ))
+        .id();
+    (entity, container_guid)
+}
+
+/// Removes the stacks for `item_id` (or every stack when `None`) from
+/// `inventory` and returns them as a new inventory.
+fn take_inventory_entries(inventory: &mut Inventory, item_id: Option<&str>) -> Inventory {
+    let (taken, kept): (Vec<InventoryEntry>, Vec<InventoryEntry>) =
+        std::mem::take(&mut inventory.entries)
+            .into_iter()
+            .partition(|entry| item_id.is_none_or(|item_id| entry.item_id == item_id));
+    inventory.entries = kept;
+    Inventory {
+        entries: taken
+            .into_iter()
+            .filter(|entry| entry.quantity > 0)
+            .collect(),
+    }
+}
+
+/// Moves as many units from `source` into `target` as `capacity` allows and
+/// returns the `(item_id, quantity)` pairs that moved.
+fn transfer_inventory(
+    source: &mut Inventory,
+    target: &mut Inventory,
+    capacity: Option<&CargoCapacity>,
+) -> Vec<(String, u32)> {
+    let mut moved = Vec::new();
+    for entry in &mut source.entries {
+        let fits = match capacity {
+            Some(capacity) if entry.unit_mass_kg > 0.0 => {
+                let free_kg = (capacity.max_mass_kg - target.total_mass_kg()).max(0.0);
+                ((free_kg / entry.unit_mass_kg).floor() as u32).min(entry.quantity)
+            }
+            _ => entry.quantity,
+        };
+        if fits == 0 {
+            continue;
+        }
+        if entry.item_id.is_empty() {
+            target.entries.push(InventoryEntry {
+                quantity: fits,
+                ..entry.clone()
+            });
+        } else {
+            target.add_stack(&entry.item_id, fits, entry.unit_mass_kg);
+        }
+        entry.quantity -= fits;
+        moved.push((entry.item_id.clone(), fits));
+    }
+    source.entries.retain(|entry| entry.quantity > 0);
+    moved
+}
+
+fn drain_deploy_cargo_action(queue: &mut ActionQueue) -> bool {
+    let mut wants_deploy = false;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        if action == EntityAction::DeployCargo {
+            wants_deploy = true;
+        } else {
+            queue.pending.push(action);
+        }
+    }
+    wants_deploy
+}
@@ -1,16 +1,42 @@
-use avian2d::prelude::{LinearVelocity, Position, Rotation, SpatialQuery, SpatialQueryFilter};
+use avian2d::prelude::{
+    Collider, CollisionEventsEnabled, CollisionStart, Collisions, LinearVelocity, Position,
+    Rotation, SpatialQuery, SpatialQueryFilter,
+};
 use bevy::{math::DVec2, prelude::*};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::autopilot::selected_target_for_hull;
+use crate::factions::FactionDamagePolicy;
+use crate::missiles::{MissileLockTargetQuery, has_missile_lock, spawn_guided_missile};
+use crate::power::draw_activation_power;
 use crate::{
-    ActionQueue, AmmoCount, BallisticProjectile, BallisticWeapon, CombatAuthorityEnabled,
-    DamageType, Destructible, EntityAction, EntityGuid, Hardpoint, HealthPool, MountedOn, OwnerId,
-    ParentGuid, PendingDestruction, PendingDestructionPhase, PlayerTag, PublicVisibility,
-    SimulationMotionWriter, WeaponCooldownState, WorldPosition, resolve_world_position,
+    ActionQueue, AmmoCount, AmmoFeed, BallisticProjectile, BallisticWeapon, BeamWeapon,
+    BeamWeaponState, CombatAuthorityEnabled, ControlledEntityGuid, DamageResistanceProfile,
+    DamageType, Destructible, EntityGuid, FocusedEntityGuid, Hardpoint, HealthPool,
+    MissileLauncher, ModuleHealth, MountedOn, OwnerId, ParentGuid, PendingDestruction,
+    PendingDestructionPhase, PlayerTag, PowerConsumer, PublicVisibility, ScriptWeaponTarget,
+    SelectedEntityGuid, ShieldGenerator, ShipPowerState, SimulationMotionWriter, TotalMassKg,
+    WeaponCooldownState, WeaponGroup, WorldPosition, absorb_damage_with_mounted_shields,
+    mitigate_damage_with_profiles, resolve_world_position,
 };
 
 const PROJECTILE_COLLISION_RADIUS_M: f32 = 0.35;
+/// Impacts that change a hull's velocity by less than this do no ram damage,
+/// so docking nudges and resting contacts stay free.
+pub const RAM_DAMAGE_SAFE_DELTA_V_MPS: f32 = 4.0;
+/// `KineticRam` damage per m/s of impact delta-v above the safe threshold.
+pub const RAM_DAMAGE_PER_DELTA_V_MPS: f32 = 10.0;
+
+pub(crate) type ResistanceProfileQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static DamageResistanceProfile,
+        Option<&'static MountedOn>,
+    ),
+>;
 
 #[derive(Debug, Clone, Message)]
 pub struct ShotFiredEvent {
@@ -45,6 +71,24 @@ pub struct ShotHitEvent {
     pub target_guid: Option<Uuid>,
     pub weapon_entity: Entity,
     pub weapon_guid: Uuid,
+    /// World-space impact point, or the blast centre for area damage.
+    pub impact_pos: DVec2,
+    /// Damage applied to the target's hull after shields.
+    pub damage: f32,
+    /// Damage soaked by the target's active shields before reaching the hull.
+    pub shield_absorbed: f32,
+    pub damage_type: DamageType,
+}
+
+/// Weaponless damage from `source_guid`, such as a scripted ability. The
+/// producer has already applied the faction gate, as `resolve_shot_impacts`
+/// does for shots; shields, resistances and the `ShotHitEvent` follow the
+/// weapon path, with the source standing in for the weapon.
+#[derive(Debug, Clone, Message)]
+pub struct DirectDamageRequest {
+    pub source_entity: Entity,
+    pub source_guid: Uuid,
+    pub target_entity: Entity,
     pub damage: f32,
     pub damage_type: DamageType,
 }
@@ -73,9 +117,31 @@ pub struct EntityDestroyedEvent {
     pub effect_origin: DVec2,
 }
 
+/// An entity starting destruction, credited to the player behind the last
+/// damaging hit on it. Emitted by `credit_entity_kills`; kill reputation and
+/// mission objectives both read this rather than attributing kills
+/// themselves.
+#[derive(Debug, Clone, Message)]
+pub struct EntityKillCreditedEvent {
+    pub victim_entity: Entity,
+    pub victim_guid: Uuid,
+    /// The victim's faction when destruction started, if it had one.
+    pub victim_faction: Option<String>,
+    pub shooter_guid: Uuid,
+    pub player_entity: Entity,
+}
+
 pub fn bootstrap_weapon_cooldown_state(
     mut commands: Commands<'_, '_>,
-    needs_cooldown: Query<'_, '_, Entity, (With<BallisticWeapon>, Without<WeaponCooldownState>)>,
+    needs_cooldown: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            Or<(With<BallisticWeapon>, With<MissileLauncher>)>,
+            Without<WeaponCooldownState>,
+        ),
+    >,
 ) {
     for entity in &needs_cooldown {
         commands
@@ -100,6 +166,7 @@ pub fn tick_weapon_cooldowns(
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn process_weapon_fire_actions(
     mut commands: Commands<'_, '_>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
     mut shooter_entities: Query<
         '_,
         '_,
@@ -112,9 +179,12 @@ pub fn process_weapon_fire_actions(
             Option<&OwnerId>,
             Option<&PlayerTag>,
             &mut ActionQueue,
+            Option<&mut ShipPowerState>,
+            Option<&ScriptWeaponTarget>,
         ),
         With<SimulationMotionWriter>,
     >,
+    target_positions: Query<'_, '_, (&'_ EntityGuid, &'_ Position)>,
     hardpoints: Query<'_, '_, (&'_ ParentGuid, &'_ Hardpoint)>,
     mut weapons: Query<
         '_,
@@ -126,11 +196,54 @@ pub fn process_weapon_fire_actions(
             &'_ BallisticWeapon,
             &'_ mut WeaponCooldownState,
             Option<&'_ mut AmmoCount>,
+            Option<&'_ AmmoFeed>,
+            Option<&'_ WeaponGroup>,
+            Option<&'_ PowerConsumer>,
+            Option<&'_ ModuleHealth>,
         ),
     >,
+    mut launchers: Query<
+        '_,
+        '_,
+        (
+            &'_ EntityGuid,
+            &'_ MountedOn,
+            &'_ MissileLauncher,
+            &'_ mut WeaponCooldownState,
+            Option<&'_ mut AmmoCount>,
+            Option<&'_ AmmoFeed>,
+            Option<&'_ WeaponGroup>,
+            Option<&'_ PowerConsumer>,
+            Option<&'_ ModuleHealth>,
+        ),
+        Without<BallisticWeapon>,
+    >,
+    mut beams: Query<
+        '_,
+        '_,
+        (
+            &'_ MountedOn,
+            &'_ mut BeamWeaponState,
+            Option<&'_ WeaponGroup>,
+            Option<&'_ ModuleHealth>,
+        ),
+        With<BeamWeapon>,
+    >,
+    players: Query<
+        '_,
+        '_,
+        (
+            &'_ ControlledEntityGuid,
+            Option<&'_ SelectedEntityGuid>,
+            Option<&'_ FocusedEntityGuid>,
+        ),
+        With<PlayerTag>,
+    >,
+    lock_targets: MissileLockTargetQuery<'_, '_>,
     mut shot_fired_events: MessageWriter<'_, ShotFiredEvent>,
     mut projectile_spawned_events: MessageWriter<'_, BallisticProjectileSpawnedEvent>,
 ) {
+    let authority_enabled = authority_enabled.is_none_or(|value| value.0);
     let mut hardpoint_by_mount = HashMap::<(Uuid, String), (Vec2, Quat)>::new();
     for (parent_guid, hardpoint) in &hardpoints {
         hardpoint_by_mount.insert(
@@ -148,20 +261,45 @@ pub fn process_weapon_fire_actions(
         shooter_owner_id,
         shooter_player_tag,
         mut queue,
+        mut power,
+        script_target,
     ) in &mut shooter_entities
     {
-        let wants_fire_primary = drain_fire_primary_action(&mut queue);
-        if !wants_fire_primary {
+        let fired_groups = drain_weapon_fire_actions(&mut queue);
+        if fired_groups.is_empty() {
             continue;
         }
+        let script_target_position = script_target.and_then(|script_target| {
+            target_positions
+                .iter()
+                .find(|(guid, _)| guid.0 == script_target.target_guid)
+                .map(|(_, position)| position.0)
+        });
 
         let shooter_quat = Quat::from_rotation_z(shooter_rotation.as_radians() as f32);
-        for (weapon_entity, weapon_guid, mounted_on, weapon, mut cooldown, ammo_opt) in &mut weapons
+        for (
+            weapon_entity,
+            weapon_guid,
+            mounted_on,
+            weapon,
+            mut cooldown,
+            ammo_opt,
+            ammo_feed,
+            group,
+            power_consumer,
+            module_health,
+        ) in &mut weapons
         {
-            if mounted_on.parent_entity_id != shooter_guid.0 {
+            if mounted_on.parent_entity_id != shooter_guid.0
+                || module_health.is_some_and(ModuleHealth::is_destroyed)
+            {
+                continue;
+            }
+            let group = group.copied().unwrap_or_default().group;
+            if !fired_groups.contains(&group) {
                 continue;
             }
-            if cooldown.remaining_s > 0.0 {
+            if cooldown.remaining_s > 0.0 || ammo_feed.is_some_and(AmmoFeed::is_reloading) {
                 continue;
             }
 
@@ -178,14 +316,23 @@ pub fn process_weapon_fire_actions(
                 continue;
             }
 
-            let muzzle_quat = shooter_quat * *hardpoint_rotation;
-            let local_forward = (muzzle_quat * Vec3::Y).truncate();
-            if local_forward.length_squared() <= f32::EPSILON {
+            let Some((origin, mut direction, mut muzzle_heading_rad)) = resolve_muzzle(
+                shooter_position.0,
+                shooter_quat,
+                *hardpoint_offset,
+                *hardpoint_rotation,
+            ) else {
+                continue;
+            };
+            if let Some((aim_direction, aim_heading_rad)) =
+                script_target_position.and_then(|target| aim_at(origin, target))
+            {
+                direction = aim_direction;
+                muzzle_heading_rad = aim_heading_rad;
+            }
+            if !draw_activation_power(power.as_deref_mut(), power_consumer) {
                 continue;
             }
-            let direction = local_forward.normalize();
-            let origin =
-                shooter_position.0 + rotate_vec2(shooter_quat, *hardpoint_offset).as_dvec2();
 
             if let Some(ammo) = ammo_opt.as_deref_mut() {
                 let _ = ammo.consume(1);
@@ -197,7 +344,6 @@ pub fn process_weapon_fire_actions(
                     .unwrap_or(DVec2::ZERO);
                 let projectile_velocity =
                     shooter_velocity + (direction * weapon.projectile_speed_mps).as_dvec2();
-                let (_, _, muzzle_heading_rad) = muzzle_quat.to_euler(EulerRot::XYZ);
                 let projectile_entity = commands
                     .spawn((
                         Name::new("BallisticProjectile"),
@@ -243,6 +389,95 @@ pub fn process_weapon_fire_actions(
 
             cooldown.remaining_s = weapon.cooldown_seconds();
         }
+
+        // Missiles and beams are server-owned; predicting clients leave launcher
+        // cooldown, ammo and beam emitter state to the authoritative server.
+        if !authority_enabled {
+            continue;
+        }
+        for (mounted_on, mut beam_state, group, module_health) in &mut beams {
+            if mounted_on.parent_entity_id == shooter_guid.0
+                && !module_health.is_some_and(ModuleHealth::is_destroyed)
+                && fired_groups.contains(&group.copied().unwrap_or_default().group)
+            {
+                beam_state.trigger_held = true;
+            }
+        }
+        let lock_target = script_target
+            .map(|script_target| script_target.target_guid)
+            .or_else(|| selected_target_for_hull(shooter_guid.0, &players));
+        for (
+            launcher_guid,
+            mounted_on,
+            launcher,
+            mut cooldown,
+            mut ammo_opt,
+            ammo_feed,
+            group,
+            power_consumer,
+            module_health,
+        ) in &mut launchers
+        {
+            if mounted_on.parent_entity_id != shooter_guid.0
+                || module_health.is_some_and(ModuleHealth::is_destroyed)
+            {
+                continue;
+            }
+            let group = group.copied().unwrap_or_default().group;
+            if !fired_groups.contains(&group)
+                || cooldown.remaining_s > 0.0
+                || ammo_feed.is_some_and(AmmoFeed::is_reloading)
+            {
+                continue;
+            }
+            let Some(target_guid) = lock_target.filter(|target_guid| {
+                has_missile_lock(launcher, shooter_position.0, *target_guid, &lock_targets)
+            }) else {
+                continue;
+            };
+            let Some((hardpoint_offset, hardpoint_rotation)) = hardpoint_by_mount
+                .get(&(mounted_on.parent_entity_id, mounted_on.hardpoint_id.clone()))
+            else {
+                continue;
+            };
+            if ammo_opt.as_ref().is_some_and(|ammo| !ammo.can_consume(1)) {
+                continue;
+            }
+            let Some((origin, direction, heading_rad)) = resolve_muzzle(
+                shooter_position.0,
+                shooter_quat,
+                *hardpoint_offset,
+                *hardpoint_rotation,
+            ) else {
+                continue;
+            };
+            if !draw_activation_power(power.as_deref_mut(), power_consumer) {
+                continue;
+            }
+            if let Some(ammo) = ammo_opt.as_deref_mut() {
+                let _ = ammo.consume(1);
+            }
+            let shooter_velocity = shooter_linear_velocity
+                .map(|value| value.0)
+                .unwrap_or(DVec2::ZERO);
+            let owner_id = shooter_owner_id.cloned().or_else(|| {
+                shooter_player_tag
+                    .is_some()
+                    .then(|| OwnerId(shooter_guid.0.to_string()))
+            });
+            spawn_guided_missile(
+                &mut commands,
+                launcher,
+                shooter_guid.0,
+                launcher_guid.0,
+                target_guid,
+                origin,
+                heading_rad,
+                shooter_velocity + (direction * launcher.launch_speed_mps).as_dvec2(),
+                owner_id,
+            );
+            cooldown.remaining_s = launcher.cooldown_seconds();
+        }
     }
 }
 
@@ -271,6 +506,9 @@ pub fn update_ballistic_projectiles(
         ),
     >,
     mut health_pools: Query<'_, '_, &'_ mut HealthPool>,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+    resistance_profiles: ResistanceProfileQuery<'_, '_>,
+    faction_policy: FactionDamagePolicy<'_, '_>,
     mut impact_events: MessageWriter<'_, ShotImpactResolvedEvent>,
     mut shot_hit_events: MessageWriter<'_, ShotHitEvent>,
 ) {
@@ -285,6 +523,7 @@ pub fn update_ballistic_projectiles(
     for (entity, guid) in &guid_entities {
         entity_by_guid.insert(guid.0, entity);
     }
+    let faction_gate = faction_policy.gate();
 
     let projectile_snapshots = projectile_params
         .p1()
@@ -340,16 +579,39 @@ pub fn update_ballistic_projectiles(
                 target_entity: Some(hit.entity),
                 target_guid,
             });
-            if authority_enabled && let Ok(mut health_pool) = health_pools.get_mut(hit.entity) {
-                health_pool.current =
-                    (health_pool.current - projectile_snapshot.damage_per_hit.max(0.0)).max(0.0);
+            if authority_enabled
+                && faction_gate.allows(
+                    entity_by_guid
+                        .get(&projectile_snapshot.shooter_guid)
+                        .copied(),
+                    hit.entity,
+                )
+                && let Ok(mut health_pool) = health_pools.get_mut(hit.entity)
+            {
+                let damage = projectile_snapshot.damage_per_hit.max(0.0);
+                let after_shields = absorb_damage_with_mounted_shields(
+                    target_guid,
+                    damage,
+                    projectile_snapshot.damage_type,
+                    &mut shields,
+                );
+                let hull_damage = mitigate_hull_damage(
+                    hit.entity,
+                    target_guid,
+                    after_shields,
+                    projectile_snapshot.damage_type,
+                    &resistance_profiles,
+                );
+                health_pool.current = (health_pool.current - hull_damage).max(0.0);
                 shot_hit_events.write(ShotHitEvent {
                     shooter_guid: projectile_snapshot.shooter_guid,
                     target_entity: hit.entity,
                     target_guid,
                     weapon_entity: entity,
                     weapon_guid: projectile_snapshot.weapon_guid,
-                    damage: projectile_snapshot.damage_per_hit.max(0.0),
+                    impact_pos,
+                    damage: hull_damage,
+                    shield_absorbed: damage - after_shields,
                     damage_type: projectile_snapshot.damage_type,
                 });
             }
@@ -369,12 +631,14 @@ pub fn resolve_shot_impacts(
     mut fired_events: MessageReader<'_, '_, ShotFiredEvent>,
     guid_entities: Query<'_, '_, (Entity, &'_ EntityGuid)>,
     spatial_query: SpatialQuery<'_, '_>,
+    faction_policy: FactionDamagePolicy<'_, '_>,
     mut resolved_events: MessageWriter<'_, ShotImpactResolvedEvent>,
 ) {
     let mut entity_by_guid = HashMap::<Uuid, Entity>::new();
     for (entity, guid) in &guid_entities {
         entity_by_guid.insert(guid.0, entity);
     }
+    let faction_gate = faction_policy.gate();
 
     for fired in fired_events.read() {
         if fired.direction.length_squared() <= f32::EPSILON {
@@ -405,6 +669,19 @@ pub fn resolve_shot_impacts(
         let target_entity = hit.map(|hit| hit.entity);
         let target_guid =
             target_entity.and_then(|entity| guid_entities.get(entity).ok().map(|g| g.1.0));
+        // Shots blocked by the friendly-fire policy still resolve an impact
+        // for presentation, but carry no damage.
+        let damage_allowed = target_entity.is_none_or(|target_entity| {
+            faction_gate.allows(
+                entity_by_guid.get(&fired.shooter_guid).copied(),
+                target_entity,
+            )
+        });
+        let damage_per_shot = if damage_allowed {
+            fired.damage_per_shot.max(0.0)
+        } else {
+            0.0
+        };
         resolved_events.write(ShotImpactResolvedEvent {
             shooter_guid: fired.shooter_guid,
             weapon_entity: fired.weapon_entity,
@@ -412,7 +689,7 @@ pub fn resolve_shot_impacts(
             origin: fired.origin,
             impact_pos,
             max_range_m: fired.max_range_m.max(1.0),
-            damage_per_shot: fired.damage_per_shot.max(0.0),
+            damage_per_shot,
             damage_type: fired.damage_type,
             target_entity,
             target_guid,
@@ -424,28 +701,175 @@ pub fn resolve_shot_impacts(
 pub fn apply_damage_from_shot_impacts(
     mut resolved_events: MessageReader<'_, '_, ShotImpactResolvedEvent>,
     mut health_pools: Query<'_, '_, &'_ mut HealthPool>,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+    resistance_profiles: ResistanceProfileQuery<'_, '_>,
     mut shot_hit_events: MessageWriter<'_, ShotHitEvent>,
 ) {
     for resolved in resolved_events.read() {
         let Some(target_entity) = resolved.target_entity else {
             continue;
         };
+        if resolved.damage_per_shot <= 0.0 {
+            continue;
+        }
         let Ok(mut health_pool) = health_pools.get_mut(target_entity) else {
             continue;
         };
-        health_pool.current = (health_pool.current - resolved.damage_per_shot).max(0.0);
+        let after_shields = absorb_damage_with_mounted_shields(
+            resolved.target_guid,
+            resolved.damage_per_shot,
+            resolved.damage_type,
+            &mut shields,
+        );
+        let hull_damage = mitigate_hull_damage(
+            target_entity,
+            resolved.target_guid,
+            after_shields,
+            resolved.damage_type,
+            &resistance_profiles,
+        );
+        health_pool.current = (health_pool.current - hull_damage).max(0.0);
         shot_hit_events.write(ShotHitEvent {
             shooter_guid: resolved.shooter_guid,
             target_entity,
             target_guid: resolved.target_guid,
             weapon_entity: resolved.weapon_entity,
             weapon_guid: resolved.weapon_guid,
-            damage: resolved.damage_per_shot,
+            impact_pos: resolved.impact_pos,
+            damage: hull_damage,
+            shield_absorbed: resolved.damage_per_shot - after_shields,
             damage_type: resolved.damage_type,
         });
     }
 }
 
+/// `KineticRam` damage a body of `mass_kg` takes from a contact that resolved
+/// with `normal_impulse` (N·s): the delta-v it experienced above
+/// `RAM_DAMAGE_SAFE_DELTA_V_MPS`, scaled by `RAM_DAMAGE_PER_DELTA_V_MPS`.
+pub fn ram_damage_from_impulse(normal_impulse: f32, mass_kg: f32) -> f32 {
+    if !normal_impulse.is_finite() || !mass_kg.is_finite() || mass_kg <= 0.0 {
+        return 0.0;
+    }
+    let delta_v_mps = normal_impulse.abs() / mass_kg;
+    (delta_v_mps - RAM_DAMAGE_SAFE_DELTA_V_MPS).max(0.0) * RAM_DAMAGE_PER_DELTA_V_MPS
+}
+
+/// Avian only reports `CollisionStart` for colliders that opt in, so opt in
+/// every damageable root body.
+#[allow(clippy::type_complexity)]
+pub fn enable_ram_collision_events(
+    mut commands: Commands<'_, '_>,
+    bodies: Query<
+        '_,
+        '_,
+        Entity,
+        (
+            With<Collider>,
+            With<HealthPool>,
+            Without<MountedOn>,
+            Without<CollisionEventsEnabled>,
+        ),
+    >,
+) {
+    for entity in &bodies {
+        commands.entity(entity).insert(CollisionEventsEnabled);
+    }
+}
+
+/// Turns new contacts between damageable bodies into `KineticRam`
+/// `DirectDamageRequest`s, one per damaged side, using the normal impulse the
+/// solver applied to the pair. Bodies without `HealthPool` (asteroids,
+/// cargo) still deal damage; they just never take it here.
+pub fn request_ram_collision_damage(
+    mut collision_starts: MessageReader<'_, '_, CollisionStart>,
+    collisions: Collisions<'_, '_>,
+    bodies: Query<'_, '_, (&'_ TotalMassKg, Has<HealthPool>)>,
+    guids: Query<'_, '_, &'_ EntityGuid>,
+    mut damage_requests: MessageWriter<'_, DirectDamageRequest>,
+) {
+    for collision in collision_starts.read() {
+        let (Some(body1), Some(body2)) = (collision.body1, collision.body2) else {
+            continue;
+        };
+        let Some(contact_pair) = collisions.get(collision.collider1, collision.collider2) else {
+            continue;
+        };
+        let normal_impulse = contact_pair.total_normal_impulse_magnitude() as f32;
+        for (target_entity, source_entity) in [(body1, body2), (body2, body1)] {
+            let Ok((mass_kg, true)) = bodies.get(target_entity) else {
+                continue;
+            };
+            let damage = ram_damage_from_impulse(normal_impulse, mass_kg.0);
+            if damage <= 0.0 {
+                continue;
+            }
+            damage_requests.write(DirectDamageRequest {
+                source_entity,
+                source_guid: guids.get(source_entity).map_or(Uuid::nil(), |guid| guid.0),
+                target_entity,
+                damage,
+                damage_type: DamageType::KineticRam,
+            });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn apply_direct_damage_requests(
+    mut requests: MessageReader<'_, '_, DirectDamageRequest>,
+    mut targets: Query<
+        '_,
+        '_,
+        (
+            &'_ EntityGuid,
+            &'_ mut HealthPool,
+            Option<&'_ Position>,
+            Option<&'_ WorldPosition>,
+        ),
+        Without<PendingDestruction>,
+    >,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+    resistance_profiles: ResistanceProfileQuery<'_, '_>,
+    mut shot_hit_events: MessageWriter<'_, ShotHitEvent>,
+) {
+    for request in requests.read() {
+        if request.damage <= 0.0 {
+            continue;
+        }
+        let Ok((target_guid, mut health_pool, position, world_position)) =
+            targets.get_mut(request.target_entity)
+        else {
+            continue;
+        };
+        let target_guid = Some(target_guid.0);
+        let after_shields = absorb_damage_with_mounted_shields(
+            target_guid,
+            request.damage,
+            request.damage_type,
+            &mut shields,
+        );
+        let hull_damage = mitigate_hull_damage(
+            request.target_entity,
+            target_guid,
+            after_shields,
+            request.damage_type,
+            &resistance_profiles,
+        );
+        health_pool.current = (health_pool.current - hull_damage).max(0.0);
+        shot_hit_events.write(ShotHitEvent {
+            shooter_guid: request.source_guid,
+            target_entity: request.target_entity,
+            target_guid,
+            weapon_entity: request.source_entity,
+            weapon_guid: request.source_guid,
+            impact_pos: resolve_world_position(position, world_position).unwrap_or(DVec2::ZERO),
+            damage: hull_damage,
+            shield_absorbed: request.damage - after_shields,
+            damage_type: request.damage_type,
+        });
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn begin_pending_destructions(
     mut commands: Commands<'_, '_>,
@@ -527,17 +951,68 @@ pub fn advance_pending_destructions(
     }
 }
 
-fn drain_fire_primary_action(queue: &mut ActionQueue) -> bool {
-    let mut wants_fire_primary = false;
+/// Applies the target's own resistance profile plus any profiles on modules
+/// mounted to it (armor plating) to damage that already passed shields.
+pub(crate) fn mitigate_hull_damage(
+    target_entity: Entity,
+    target_guid: Option<Uuid>,
+    damage: f32,
+    damage_type: DamageType,
+    resistance_profiles: &ResistanceProfileQuery<'_, '_>,
+) -> f32 {
+    let profiles = resistance_profiles
+        .iter()
+        .filter(|(entity, _, mounted_on)| {
+            *entity == target_entity
+                || mounted_on.is_some_and(|mounted_on| {
+                    target_guid.is_some_and(|guid| mounted_on.parent_entity_id == guid)
+                })
+        })
+        .map(|(_, profile, _)| profile);
+    mitigate_damage_with_profiles(profiles, damage, damage_type)
+}
+
+/// Removes every fire action from `queue` and returns the weapon groups they
+/// trigger.
+fn drain_weapon_fire_actions(queue: &mut ActionQueue) -> Vec<u8> {
+    let mut fired_groups = Vec::new();
     let pending = std::mem::take(&mut queue.pending);
     for action in pending {
-        if action == EntityAction::FirePrimary {
-            wants_fire_primary = true;
-        } else {
-            queue.pending.push(action);
+        match action.fired_weapon_group() {
+            Some(group) => {
+                if !fired_groups.contains(&group) {
+                    fired_groups.push(group);
+                }
+            }
+            None => queue.pending.push(action),
         }
     }
-    wants_fire_primary
+    fired_groups
+}
+
+/// World-space muzzle origin, unit fire direction, and heading for a weapon
+/// mounted at `hardpoint_offset`/`hardpoint_rotation` on the shooter.
+pub(crate) fn resolve_muzzle(
+    shooter_position: DVec2,
+    shooter_quat: Quat,
+    hardpoint_offset: Vec2,
+    hardpoint_rotation: Quat,
+) -> Option<(DVec2, Vec2, f32)> {
+    let muzzle_quat = shooter_quat * hardpoint_rotation;
+    let local_forward = (muzzle_quat * Vec3::Y).truncate();
+    if local_forward.length_squared() <= f32::EPSILON {
+        return None;
+    }
+    let origin = shooter_position + rotate_vec2(shooter_quat, hardpoint_offset).as_dvec2();
+    let (_, _, heading_rad) = muzzle_quat.to_euler(EulerRot::XYZ);
+    Some((origin, local_forward.normalize(), heading_rad))
+}
+
+/// Direction and heading from `origin` to a script-named `target`, using the
+/// same +Y-forward convention as `resolve_muzzle`.
+fn aim_at(origin: DVec2, target: DVec2) -> Option<(Vec2, f32)> {
+    let direction = (target - origin).as_vec2().try_normalize()?;
+    Some((direction, (-direction.x).atan2(direction.y)))
 }
 
 fn rotate_vec2(rotation: Quat, vector: Vec2) -> Vec2 {
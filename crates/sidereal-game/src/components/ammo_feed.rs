@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Magazine feed for a weapon with an `AmmoCount`. Reloads draw compatible
+/// ammo stacks from the parent hull's `Inventory`; the weapon cannot fire
+/// while a reload is in progress.
+#[sidereal_component_macros::sidereal_component(
+    kind = "ammo_feed",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct AmmoFeed {
+    /// Catalog `item_id`s this weapon loads, in preference order.
+    pub ammo_item_ids: Vec<String>,
+    /// Rounds added to the magazine per cargo item consumed.
+    #[serde(default = "default_rounds_per_item")]
+    pub rounds_per_item: u32,
+    pub reload_time_s: f32,
+    /// Start a reload automatically once the magazine runs dry.
+    #[serde(default = "default_auto_reload")]
+    pub auto_reload: bool,
+    /// Seconds left on the reload in progress; zero when idle.
+    #[serde(default)]
+    pub reload_remaining_s: f32,
+}
+
+fn default_rounds_per_item() -> u32 {
+    1
+}
+
+fn default_auto_reload() -> bool {
+    true
+}
+
+impl AmmoFeed {
+    pub fn is_reloading(&self) -> bool {
+        self.reload_remaining_s > 0.0
+    }
+
+    /// Whole cargo items needed to top up a magazine short by `missing_rounds`.
+    /// Never overfills, so a magazine less than one item short stays as is.
+    pub fn items_to_fill(&self, missing_rounds: u32) -> u32 {
+        missing_rounds / self.rounds_per_item.max(1)
+    }
+
+    /// Fraction of the current reload completed, for HUD display.
+    pub fn reload_progress(&self) -> f32 {
+        if !self.is_reloading() || self.reload_time_s <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.reload_remaining_s / self.reload_time_s).clamp(0.0, 1.0)
+    }
+}
@@ -9,6 +9,12 @@ pub struct AsteroidYieldEntry {
     pub weight: f32,
     pub min_units: f32,
     pub max_units: f32,
+    #[serde(default = "default_yield_unit_mass_kg")]
+    pub unit_mass_kg: f32,
+}
+
+fn default_yield_unit_mass_kg() -> f32 {
+    1.0
 }
 
 #[sidereal_component_macros::sidereal_component(
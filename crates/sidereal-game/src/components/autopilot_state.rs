@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::EntityGuid;
+
+/// Player autopilot state on a controllable hull.
+///
+/// `EngageAutopilot` locks the controlling player's selected (or focused)
+/// entity; the flight computer then approaches it and matches its velocity
+/// once inside `arrival_distance_m`. Manual flight input disengages.
+#[sidereal_component_macros::sidereal_component(
+    kind = "autopilot_state",
+    persist = true,
+    replicate = true,
+    predict = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct AutopilotState {
+    #[serde(default)]
+    pub engaged: bool,
+    #[serde(default)]
+    pub target_guid: Option<Uuid>,
+    #[serde(default = "default_arrival_distance_m")]
+    pub arrival_distance_m: f32,
+}
+
+fn default_arrival_distance_m() -> f32 {
+    150.0
+}
+
+impl Default for AutopilotState {
+    fn default() -> Self {
+        Self {
+            engaged: false,
+            target_guid: None,
+            arrival_distance_m: default_arrival_distance_m(),
+        }
+    }
+}
+
+impl AutopilotState {
+    pub fn disengage(&mut self) {
+        self.engaged = false;
+        self.target_guid = None;
+    }
+}
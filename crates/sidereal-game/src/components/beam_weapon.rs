@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{DamageType, EntityGuid};
+
+/// Continuous-fire weapon. While its fire group's trigger is held the beam
+/// raycasts every fixed tick out to `range_m` and applies `damage_per_second`
+/// to the first collider hit. Firing builds heat at `heat_per_second`; reaching
+/// `max_heat` overheats the emitter until it cools back below
+/// `overheat_recovery_fraction` of capacity.
+#[sidereal_component_macros::sidereal_component(
+    kind = "beam_weapon",
+    persist = true,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct BeamWeapon {
+    pub weapon_name: String,
+    /// Audio profile whose `fire` cue loops while the beam is held.
+    #[serde(default)]
+    pub fire_audio_profile_id: Option<String>,
+    pub range_m: f32,
+    pub damage_per_second: f32,
+    pub damage_type: DamageType,
+    pub max_heat: f32,
+    pub heat_per_second: f32,
+    pub cooling_per_second: f32,
+    pub overheat_recovery_fraction: f32,
+}
+
+impl BeamWeapon {
+    pub fn pulse_laser() -> Self {
+        Self {
+            weapon_name: "Pulse Laser".to_string(),
+            fire_audio_profile_id: None,
+            range_m: 220.0,
+            damage_per_second: 60.0,
+            damage_type: DamageType::Energy,
+            max_heat: 100.0,
+            heat_per_second: 25.0,
+            cooling_per_second: 18.0,
+            overheat_recovery_fraction: 0.4,
+        }
+    }
+
+    /// Heat level an overheated emitter must cool to before it can fire again.
+    pub fn recovery_heat(&self) -> f32 {
+        self.max_heat.max(0.0) * self.overheat_recovery_fraction.clamp(0.0, 1.0)
+    }
+}
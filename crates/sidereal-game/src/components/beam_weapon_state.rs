@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Live emitter state for a `BeamWeapon`. The server owns every field;
+/// clients read `firing` and `beam_length_m` to draw the sustained beam.
+#[sidereal_component_macros::sidereal_component(
+    kind = "beam_weapon_state",
+    persist = false,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Default, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct BeamWeaponState {
+    /// Set by `process_weapon_fire_actions` for the tick the fire group is held.
+    #[serde(default)]
+    pub trigger_held: bool,
+    #[serde(default)]
+    pub firing: bool,
+    #[serde(default)]
+    pub heat: f32,
+    #[serde(default)]
+    pub overheated: bool,
+    /// Distance from the emitter to the hit point, or full range on a miss.
+    #[serde(default)]
+    pub beam_length_m: f32,
+    #[serde(default)]
+    pub target_guid: Option<Uuid>,
+}
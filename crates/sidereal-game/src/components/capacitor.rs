@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Energy storage module. Capacities of all capacitors mounted on a hull are
+/// pooled into `ShipPowerState::capacity_mj`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "capacitor",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct Capacitor {
+    pub capacity_mj: f32,
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+/// Upper bound on the total item mass an entity's `Inventory` may hold.
+/// Entities without this component have unbounded cargo.
+#[sidereal_component_macros::sidereal_component(
+    kind = "cargo_capacity",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct CargoCapacity {
+    pub max_mass_kg: f32,
+}
+
+impl CargoCapacity {
+    pub fn has_room_for(&self, current_mass_kg: f32, added_mass_kg: f32) -> bool {
+        current_mass_kg + added_mass_kg.max(0.0) <= self.max_mass_kg.max(0.0) + f32::EPSILON
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{EntityGuid, Inventory};
+
+/// Free-floating container holding jettisoned or dropped cargo in its own
+/// `Inventory`. Ships in range scoop its contents; it despawns when emptied or
+/// when `lifetime_remaining_s` runs out.
+#[sidereal_component_macros::sidereal_component(
+    kind = "cargo_container",
+    persist = true,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, Inventory)]
+pub struct CargoContainer {
+    pub lifetime_remaining_s: f32,
+    /// No ship can scoop the container until this reaches zero.
+    #[serde(default)]
+    pub pickup_delay_remaining_s: f32,
+    /// Entity that dropped the container. That ship cannot scoop it until it
+    /// has left loot range once, so jettisoned cargo is not immediately re-collected.
+    #[serde(default)]
+    pub dropped_by_guid: Option<Uuid>,
+}
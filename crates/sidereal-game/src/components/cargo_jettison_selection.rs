@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+/// Catalog `item_id` the hull's next `DeployCargo` action jettisons. `None`
+/// jettisons the whole inventory.
+#[sidereal_component_macros::sidereal_component(
+    kind = "cargo_jettison_selection",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct CargoJettisonSelection(pub Option<String>);
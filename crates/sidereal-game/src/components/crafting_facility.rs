@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{CraftingQueue, EntityGuid, Inventory};
+
+/// Marks an entity (station, outpost, ship module host) as a crafting facility
+/// of the catalog type `facility_id`. Jobs run against the entity's own
+/// `Inventory` and `CraftingQueue`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "crafting_facility",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, CraftingQueue, Inventory)]
+pub struct CraftingFacility {
+    pub facility_id: String,
+}
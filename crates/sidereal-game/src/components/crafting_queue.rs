@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::EntityGuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize, Default)]
+pub enum CraftingJobState {
+    /// Waiting for a free facility slot; inputs are still in the inventory.
+    #[default]
+    Queued,
+    /// Inputs have been consumed and the recipe timer is running.
+    InProgress,
+    /// The timer finished but the output did not fit in cargo; retried each tick.
+    OutputBlocked,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct CraftingJob {
+    pub job_id: Uuid,
+    pub recipe_id: String,
+    #[serde(default)]
+    pub state: CraftingJobState,
+    #[serde(default)]
+    pub progress_s: f32,
+    /// Player that requested the job, when it came from a player session.
+    #[serde(default)]
+    pub requested_by_player_id: Option<String>,
+}
+
+/// Ordered job list for a `CraftingFacility`. Jobs start in FIFO order as slots
+/// free up and persist with their progress so queues survive restarts.
+#[sidereal_component_macros::sidereal_component(
+    kind = "crafting_queue",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct CraftingQueue {
+    #[serde(default)]
+    pub jobs: Vec<CraftingJob>,
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::EntityGuid;
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct CreditLedgerEntry {
+    pub transaction_id: Uuid,
+    /// Position in the owning ledger, starting at 1.
+    pub sequence: u64,
+    /// Fixed simulation tick the transaction was applied on.
+    pub tick: u64,
+    /// Dotted reason code, e.g. `station_service.refuel`.
+    pub reason: String,
+    /// Entity on the other side of the transaction, if any.
+    pub counterparty: Option<Uuid>,
+    pub delta: i64,
+    pub balance_after: u64,
+}
+
+/// Audited entries kept on the component; older ones live only in the
+/// replication server's economy audit log.
+pub const CREDIT_LEDGER_MAX_ENTRIES: usize = 64;
+
+/// Window of a player's most recent `CreditWallet` changes. The full,
+/// append-only history is the economy audit log: entries leave this window
+/// only once `audited_sequence` covers them, so it holds at least the last
+/// `CREDIT_LEDGER_MAX_ENTRIES` entries plus anything not yet audited. Entries
+/// are only added through `credits::debit_credits` /
+/// `credits::grant_credits`, which also move the balance, so the two never
+/// disagree. Server-side only.
+#[sidereal_component_macros::sidereal_component(
+    kind = "credit_ledger",
+    persist = true,
+    replicate = false
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct CreditLedger {
+    #[serde(default)]
+    pub entries: Vec<CreditLedgerEntry>,
+    /// Highest sequence handed to the economy audit log.
+    #[serde(default)]
+    pub audited_sequence: u64,
+}
+
+impl CreditLedger {
+    pub fn last_sequence(&self) -> u64 {
+        self.entries.last().map_or(0, |entry| entry.sequence)
+    }
+
+    /// Appends `entry` and drops the oldest audited entries past the cap.
+    pub fn push(&mut self, entry: CreditLedgerEntry) {
+        self.entries.push(entry);
+        self.trim_audited();
+    }
+
+    /// Records that entries through `sequence` reached the audit log, letting
+    /// them leave the window.
+    pub fn mark_audited(&mut self, sequence: u64) {
+        self.audited_sequence = self.audited_sequence.max(sequence);
+        self.trim_audited();
+    }
+
+    fn trim_audited(&mut self) {
+        let overflow = self.entries.len().saturating_sub(CREDIT_LEDGER_MAX_ENTRIES);
+        let trimmable = self.entries[..overflow]
+            .iter()
+            .take_while(|entry| entry.sequence <= self.audited_sequence)
+            .count();
+        self.entries.drain(..trimmable);
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{CreditLedger, EntityGuid};
+
+/// Spendable credit balance on a player entity. Only server-side systems
+/// change it, through `credits::debit_credits` / `credits::grant_credits` so
+/// every change lands in the paired `CreditLedger`; clients see their own
+/// balance through replication.
+#[sidereal_component_macros::sidereal_component(
+    kind = "credit_wallet",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(
+    Debug, Clone, Copy, Component, Reflect, Serialize, Deserialize, PartialEq, Eq, Default,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, CreditLedger)]
+pub struct CreditWallet {
+    pub balance: u64,
+}
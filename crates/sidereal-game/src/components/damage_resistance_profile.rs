@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{DamageType, EntityGuid};
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct DamageResistanceEntry {
+    pub damage_type: DamageType,
+    /// Fraction of incoming hull damage of this type that is negated (0..=1).
+    pub fraction: f32,
+}
+
+/// Hull resistance/armor profile consulted after shields.
+///
+/// Authored on a ship root or on mounted modules (armor plating); profiles on
+/// modules mounted on a hull stack with the hull's own profile.
+#[sidereal_component_macros::sidereal_component(
+    kind = "damage_resistance_profile",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct DamageResistanceProfile {
+    /// Flat damage removed from every hit after fractional resistances.
+    #[serde(default)]
+    pub armor_rating: f32,
+    #[serde(default)]
+    pub resistances: Vec<DamageResistanceEntry>,
+}
+
+impl DamageResistanceProfile {
+    pub fn resistance_fraction(&self, damage_type: DamageType) -> f32 {
+        self.resistances
+            .iter()
+            .filter(|entry| entry.damage_type == damage_type)
+            .map(|entry| entry.fraction)
+            .fold(0.0_f32, f32::max)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Applies this profile to a single hit.
+    pub fn mitigate(&self, damage: f32, damage_type: DamageType) -> f32 {
+        mitigate_damage_with_profiles(std::iter::once(self), damage, damage_type)
+    }
+}
+
+/// Stacks several profiles: fractional resistances multiply their pass-through
+/// and armor ratings add, so no combination can heal or over-reduce a hit.
+pub fn mitigate_damage_with_profiles<'a>(
+    profiles: impl IntoIterator<Item = &'a DamageResistanceProfile>,
+    damage: f32,
+    damage_type: DamageType,
+) -> f32 {
+    if damage <= 0.0 {
+        return 0.0;
+    }
+    let mut pass_through = 1.0_f32;
+    let mut armor_rating = 0.0_f32;
+    for profile in profiles {
+        pass_through *= 1.0 - profile.resistance_fraction(damage_type);
+        armor_rating += profile.armor_rating.max(0.0);
+    }
+    (damage * pass_through - armor_rating).max(0.0)
+}
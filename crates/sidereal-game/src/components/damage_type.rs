@@ -1,8 +1,23 @@
 use bevy::reflect::Reflect;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 pub enum DamageType {
     #[default]
     Ballistic,
+    Energy,
+    Explosive,
+    Thermal,
+    /// Collision/ramming damage from kinetic impact between bodies.
+    KineticRam,
+}
+
+impl DamageType {
+    pub const ALL: [DamageType; 5] = [
+        DamageType::Ballistic,
+        DamageType::Energy,
+        DamageType::Explosive,
+        DamageType::Thermal,
+        DamageType::KineticRam,
+    ];
 }
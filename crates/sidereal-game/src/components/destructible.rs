@@ -23,6 +23,10 @@ pub struct Destructible {
     pub destruction_profile_id: String,
     #[serde(default = "default_destroy_delay_s")]
     pub destroy_delay_s: f32,
+    /// Explicit `LootTableRegistry` entry; `None` falls back to the table keyed
+    /// by `destruction_profile_id`.
+    #[serde(default)]
+    pub loot_table_id: Option<String>,
 }
 
 fn default_profile_id() -> String {
@@ -34,6 +38,7 @@ impl Default for Destructible {
         Self {
             destruction_profile_id: default_profile_id(),
             destroy_delay_s: default_destroy_delay_s(),
+            loot_table_id: None,
         }
     }
 }
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{EntityGuid, MountedOn};
+
+/// Docking port module mounted on a station or carrier hardpoint.
+///
+/// The port sits at its hardpoint and faces along the hardpoint's forward
+/// axis; ships are captured only from inside the approach corridor in front
+/// of it.
+#[sidereal_component_macros::sidereal_component(
+    kind = "docking_port",
+    persist = true,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct DockingPort {
+    /// Ships within this distance may request the port.
+    pub request_range_m: f32,
+    /// Distance from the port at which an approaching ship is captured.
+    pub capture_range_m: f32,
+    /// Half-angle of the approach cone around the port's forward axis.
+    pub corridor_half_angle_deg: f32,
+    /// Maximum speed relative to the host for capture.
+    pub max_capture_speed_mps: f32,
+    /// Distance in front of the port where undocking ships are released.
+    pub undock_offset_m: f32,
+    #[serde(default)]
+    pub occupant_guid: Option<Uuid>,
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::EntityGuid;
+
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[reflect(Serialize, Deserialize)]
+pub enum DockingPhase {
+    #[default]
+    Undocked,
+    /// `InitiateDocking` was pressed; a free port in range is picked next tick.
+    Requested,
+    /// Port assigned; waiting for the ship to enter the capture corridor.
+    Approaching,
+    Docked,
+    /// `InitiateDocking` was pressed while docked; released next tick.
+    Undocking,
+}
+
+/// Docking sequence state on a ship. Persisted so docked ships reload docked.
+#[sidereal_component_macros::sidereal_component(
+    kind = "docking_state",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct DockingState {
+    #[serde(default)]
+    pub phase: DockingPhase,
+    #[serde(default)]
+    pub port_guid: Option<Uuid>,
+    #[serde(default)]
+    pub host_guid: Option<Uuid>,
+}
+
+impl DockingState {
+    pub fn is_docked(&self) -> bool {
+        self.phase == DockingPhase::Docked
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct FactionReputationEntry {
+    pub faction_id: String,
+    pub value: f32,
+}
+
+/// Per-player reputation with each faction, adjusted by kills.
+#[sidereal_component_macros::sidereal_component(
+    kind = "faction_reputation",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct FactionReputation {
+    pub entries: Vec<FactionReputationEntry>,
+}
+
+impl FactionReputation {
+    pub fn value(&self, faction_id: &str) -> Option<f32> {
+        self.entries
+            .iter()
+            .find(|entry| entry.faction_id == faction_id)
+            .map(|entry| entry.value)
+    }
+
+    /// Adds `delta` to the reputation with `faction_id`, clamped to
+    /// `min..=max`.
+    pub fn adjust(&mut self, faction_id: &str, delta: f32, min: f32, max: f32) {
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.faction_id == faction_id)
+        {
+            entry.value = (entry.value + delta).clamp(min, max);
+            return;
+        }
+        self.entries.push(FactionReputationEntry {
+            faction_id: faction_id.to_string(),
+            value: delta.clamp(min, max),
+        });
+    }
+}
@@ -8,6 +8,27 @@ use crate::EntityGuid;
 #[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
 #[reflect(Component, Serialize, Deserialize)]
 #[require(EntityGuid)]
+#[serde(from = "FuelTankRecord")]
 pub struct FuelTank {
     pub fuel_kg: f32,
+    /// Fill limit for station refuelling; zero marks a tank that cannot be
+    /// refuelled. Tanks persisted without one load with their current fuel as
+    /// the limit.
+    pub capacity_kg: f32,
+}
+
+#[derive(Deserialize)]
+struct FuelTankRecord {
+    fuel_kg: f32,
+    #[serde(default)]
+    capacity_kg: Option<f32>,
+}
+
+impl From<FuelTankRecord> for FuelTank {
+    fn from(record: FuelTankRecord) -> Self {
+        Self {
+            fuel_kg: record.fuel_kg,
+            capacity_kg: record.capacity_kg.unwrap_or(record.fuel_kg),
+        }
+    }
 }
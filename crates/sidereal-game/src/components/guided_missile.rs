@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{DamageType, MissileLauncher};
+
+/// In-flight homing missile. Steering, fuel and fusing are server-authoritative;
+/// clients only interpolate the replicated pose.
+#[sidereal_component_macros::sidereal_component(
+    kind = "guided_missile",
+    persist = false,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct GuidedMissile {
+    pub shooter_guid: Uuid,
+    pub weapon_guid: Uuid,
+    pub target_guid: Uuid,
+    pub thrust_mps2: f32,
+    pub max_speed_mps: f32,
+    pub turn_rate_rad_s: f32,
+    pub remaining_fuel_s: f32,
+    pub proximity_fuse_m: f32,
+    pub blast_radius_m: f32,
+    pub damage: f32,
+    pub damage_type: DamageType,
+}
+
+impl GuidedMissile {
+    pub fn from_launcher(
+        launcher: &MissileLauncher,
+        shooter_guid: Uuid,
+        weapon_guid: Uuid,
+        target_guid: Uuid,
+    ) -> Self {
+        Self {
+            shooter_guid,
+            weapon_guid,
+            target_guid,
+            thrust_mps2: launcher.thrust_mps2,
+            max_speed_mps: launcher.max_speed_mps,
+            turn_rate_rad_s: launcher.turn_rate_rad_s,
+            remaining_fuel_s: launcher.fuel_lifetime_s,
+            proximity_fuse_m: launcher.proximity_fuse_m,
+            blast_radius_m: launcher.blast_radius_m,
+            damage: launcher.damage,
+            damage_type: launcher.damage_type,
+        }
+    }
+
+    /// Damage dealt to something `distance_m` from the blast center.
+    pub fn blast_damage_at(&self, distance_m: f32) -> f32 {
+        if self.blast_radius_m <= 0.0 {
+            return 0.0;
+        }
+        let falloff = (1.0 - distance_m / self.blast_radius_m).clamp(0.0, 1.0);
+        self.damage.max(0.0) * falloff
+    }
+}
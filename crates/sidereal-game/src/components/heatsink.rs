@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Thermal module. Raises the heat a hull can absorb before overheating and
+/// how quickly that heat bleeds off.
+#[sidereal_component_macros::sidereal_component(
+    kind = "heatsink",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct Heatsink {
+    pub heat_capacity: f32,
+    pub dissipation_per_s: f32,
+}
@@ -9,6 +9,9 @@ use crate::EntityGuid;
 #[reflect(Serialize, Deserialize)]
 pub struct InventoryEntry {
     pub item_entity_id: Uuid,
+    /// Catalog `item_id` for stackable goods; empty for legacy UUID-only entries.
+    #[serde(default)]
+    pub item_id: String,
     pub quantity: u32,
     pub unit_mass_kg: f32,
 }
@@ -25,3 +28,84 @@ pub struct InventoryEntry {
 pub struct Inventory {
     pub entries: Vec<InventoryEntry>,
 }
+
+impl Inventory {
+    /// Adds `quantity` units of a stackable catalog item, merging into the
+    /// existing stack for `item_id` when present.
+    pub fn add_stack(&mut self, item_id: &str, quantity: u32, unit_mass_kg: f32) {
+        if quantity == 0 {
+            return;
+        }
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.item_id == item_id)
+        {
+            entry.quantity = entry.quantity.saturating_add(quantity);
+            entry.unit_mass_kg = unit_mass_kg;
+            return;
+        }
+        self.entries.push(InventoryEntry {
+            item_entity_id: stackable_item_uuid(item_id),
+            item_id: item_id.to_string(),
+            quantity,
+            unit_mass_kg,
+        });
+    }
+
+    /// Removes `quantity` units of `item_id`, dropping emptied stacks. Returns
+    /// false without touching the inventory when the stacks are short.
+    pub fn remove_stack(&mut self, item_id: &str, quantity: u32) -> bool {
+        if self.quantity_of(item_id) < quantity {
+            return false;
+        }
+        let mut remaining = quantity;
+        for entry in self
+            .entries
+            .iter_mut()
+            .filter(|entry| entry.item_id == item_id)
+        {
+            let taken = entry.quantity.min(remaining);
+            entry.quantity -= taken;
+            remaining -= taken;
+            if remaining == 0 {
+                break;
+            }
+        }
+        self.entries
+            .retain(|entry| entry.item_id != item_id || entry.quantity > 0);
+        true
+    }
+
+    pub fn total_mass_kg(&self) -> f32 {
+        self.entries
+            .iter()
+            .map(|entry| entry.unit_mass_kg.max(0.0) * entry.quantity as f32)
+            .sum()
+    }
+
+    pub fn quantity_of(&self, item_id: &str) -> u32 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.item_id == item_id)
+            .map(|entry| entry.quantity)
+            .sum()
+    }
+}
+
+/// Deterministic entry UUID for a stackable catalog item so the same `item_id`
+/// resolves to the same stack identity across hulls and reloads.
+pub fn stackable_item_uuid(item_id: &str) -> Uuid {
+    let mut bytes = [0u8; 16];
+    for (half, salt) in [(0usize, 0x53u64), (8usize, 0x71u64)] {
+        let mut hash = 0xcbf29ce484222325u64 ^ salt;
+        for byte in item_id.as_bytes() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        bytes[half..half + 8].copy_from_slice(&hash.to_be_bytes());
+    }
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    Uuid::from_bytes(bytes)
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct MarketListing {
+    pub item_id: String,
+    pub stock: u32,
+    /// Current mid price in credits; drifts toward the equilibrium for the
+    /// stock level every fixed tick.
+    pub price: f64,
+    /// Fractional units of NPC restocking not yet applied to `stock`.
+    #[serde(default)]
+    pub restock_progress: f32,
+}
+
+/// NPC market run by a station module. Listings are seeded from the
+/// `MarketRegistry` definition named by `market_id` and then carry the live
+/// stock and prices, which persist with the module. Ships docked at the host
+/// station, or within `trade_range_m` of it, can trade.
+#[sidereal_component_macros::sidereal_component(kind = "market", persist = true, replicate = false)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct Market {
+    pub market_id: String,
+    /// Undocked ships within this distance of the host station may trade;
+    /// zero limits trading to docked ships.
+    #[serde(default)]
+    pub trade_range_m: f32,
+    #[serde(default)]
+    pub listings: Vec<MarketListing>,
+}
+
+impl Market {
+    pub fn listing(&self, item_id: &str) -> Option<&MarketListing> {
+        self.listings
+            .iter()
+            .find(|listing| listing.item_id == item_id)
+    }
+
+    pub fn listing_mut(&mut self, item_id: &str) -> Option<&mut MarketListing> {
+        self.listings
+            .iter_mut()
+            .find(|listing| listing.item_id == item_id)
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{EntityGuid, MountedOn};
+
+/// Mining module mounted on a hull. While active it locks the nearest asteroid
+/// field member in range (or the assigned `target_guid`) and completes one
+/// extraction cycle every `cycle_time_s`, drawing a yield roll from the member's
+/// resource profile into the hull's `Inventory`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "mining_laser",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct MiningLaser {
+    pub range_m: f32,
+    pub cycle_time_s: f32,
+    /// Scales the units rolled from the yield table each cycle.
+    pub yield_multiplier: f32,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub target_guid: Option<Uuid>,
+    #[serde(default)]
+    pub cycle_progress_s: f32,
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{DamageType, EntityGuid};
+
+/// Launcher module that fires `GuidedMissile` entities. A launch needs a lock:
+/// the shooter's selected target must be within `lock_range_m` and carry a
+/// `SignalSignature` of at least `min_lock_signal_strength`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "missile_launcher",
+    persist = true,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct MissileLauncher {
+    pub weapon_name: String,
+    #[serde(default)]
+    pub fire_audio_profile_id: Option<String>,
+    pub rpm: f32,
+    /// Speed added along the muzzle heading on top of the shooter's velocity.
+    pub launch_speed_mps: f32,
+    pub thrust_mps2: f32,
+    pub max_speed_mps: f32,
+    pub turn_rate_rad_s: f32,
+    /// Burn time before the missile self-detonates.
+    pub fuel_lifetime_s: f32,
+    pub lock_range_m: f32,
+    pub min_lock_signal_strength: f32,
+    pub proximity_fuse_m: f32,
+    pub blast_radius_m: f32,
+    /// Damage at the blast center; falls off linearly to zero at `blast_radius_m`.
+    pub damage: f32,
+    pub damage_type: DamageType,
+}
+
+impl MissileLauncher {
+    pub fn light_seeker_launcher() -> Self {
+        Self {
+            weapon_name: "Light Seeker Launcher".to_string(),
+            fire_audio_profile_id: None,
+            rpm: 30.0,
+            launch_speed_mps: 40.0,
+            thrust_mps2: 120.0,
+            max_speed_mps: 320.0,
+            turn_rate_rad_s: 2.5,
+            fuel_lifetime_s: 8.0,
+            lock_range_m: 1200.0,
+            min_lock_signal_strength: 0.1,
+            proximity_fuse_m: 6.0,
+            blast_radius_m: 18.0,
+            damage: 80.0,
+            damage_type: DamageType::Explosive,
+        }
+    }
+
+    pub fn cooldown_seconds(&self) -> f32 {
+        60.0 / self.rpm.max(1.0)
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct MissionObjectiveProgress {
+    pub objective_id: String,
+    /// Copied from the mission registry so clients can show the log without
+    /// loading it.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub current: u32,
+    pub required: u32,
+}
+
+impl MissionObjectiveProgress {
+    pub fn is_complete(&self) -> bool {
+        self.current >= self.required
+    }
+}
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct ActiveMission {
+    pub mission_id: String,
+    #[serde(default)]
+    pub display_name: String,
+    /// Filled in from the registry by the mission evaluator, so authored
+    /// records may list just the `mission_id`.
+    #[serde(default)]
+    pub objectives: Vec<MissionObjectiveProgress>,
+    #[serde(default)]
+    pub accepted_tick: u64,
+}
+
+impl ActiveMission {
+    pub fn objective_mut(&mut self, objective_id: &str) -> Option<&mut MissionObjectiveProgress> {
+        self.objectives
+            .iter_mut()
+            .find(|objective| objective.objective_id == objective_id)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !self.objectives.is_empty()
+            && self
+                .objectives
+                .iter()
+                .all(MissionObjectiveProgress::is_complete)
+    }
+}
+
+/// Missions a player has in progress and the ones they have finished. Only
+/// the server-side mission evaluator changes progress; the owning client sees
+/// its own log through replication.
+#[sidereal_component_macros::sidereal_component(
+    kind = "mission_log",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct MissionLog {
+    #[serde(default)]
+    pub active: Vec<ActiveMission>,
+    #[serde(default)]
+    pub completed_mission_ids: Vec<String>,
+}
+
+impl MissionLog {
+    pub fn active_mission(&self, mission_id: &str) -> Option<&ActiveMission> {
+        self.active
+            .iter()
+            .find(|mission| mission.mission_id == mission_id)
+    }
+
+    pub fn has_completed(&self, mission_id: &str) -> bool {
+        self.completed_mission_ids
+            .iter()
+            .any(|completed| completed == mission_id)
+    }
+}
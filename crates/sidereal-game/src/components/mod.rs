@@ -4,6 +4,7 @@ pub mod action_queue;
 pub mod afterburner_capability;
 pub mod afterburner_state;
 pub mod ammo_count;
+pub mod ammo_feed;
 pub mod asteroid_field;
 pub mod asteroid_field_ambient;
 pub mod asteroid_field_damage_state;
@@ -12,9 +13,16 @@ pub mod asteroid_field_member;
 pub mod asteroid_field_population;
 pub mod asteroid_fracture_profile;
 pub mod asteroid_resource_profile;
+pub mod autopilot_state;
 pub mod ballistic_projectile;
 pub mod ballistic_weapon;
 pub mod base_mass_kg;
+pub mod beam_weapon;
+pub mod beam_weapon_state;
+pub mod capacitor;
+pub mod cargo_capacity;
+pub mod cargo_container;
+pub mod cargo_jettison_selection;
 pub mod cargo_mass_kg;
 pub mod character_movement_controller;
 pub mod collision_aabb_m;
@@ -24,16 +32,24 @@ pub mod contact_resolution_m;
 pub mod controlled_entity_guid;
 pub mod controlled_start_target;
 pub mod cost;
+pub mod crafting_facility;
+pub mod crafting_queue;
+pub mod credit_ledger;
+pub mod credit_wallet;
+pub mod damage_resistance_profile;
 pub mod damage_type;
 pub mod density;
 pub mod destructible;
 pub mod discovered_static_landmarks;
 pub mod display_name;
+pub mod docking_port;
+pub mod docking_state;
 pub mod engine;
 pub mod entity_guid;
 pub mod entity_labels;
 pub mod environment_lighting_state;
 pub mod faction_id;
+pub mod faction_reputation;
 pub mod faction_visibility;
 pub mod flight_computer;
 pub mod flight_control_authority;
@@ -41,13 +57,20 @@ pub mod flight_tuning;
 pub mod focused_entity_guid;
 pub mod fuel_tank;
 pub mod fullscreen_layer;
+pub mod guided_missile;
 pub mod hardpoint;
 pub mod health_pool;
+pub mod heatsink;
 pub mod inventory;
 pub mod map_icon;
+pub mod market;
 pub mod mass_dirty;
 pub mod mass_kg;
 pub mod max_velocity_mps;
+pub mod mining_laser;
+pub mod missile_launcher;
+pub mod mission_log;
+pub mod module_health;
 pub mod module_mass_kg;
 pub mod module_tag;
 pub mod mounted_on;
@@ -57,8 +80,10 @@ pub mod parent_guid;
 pub mod planet_body_shader_settings;
 pub mod player_explored_cells;
 pub mod player_tag;
+pub mod power_consumer;
 pub mod procedural_sprite;
 pub mod public_visibility;
+pub mod reactor;
 pub mod runtime_post_process_stack;
 pub mod runtime_render_layer_definition;
 pub mod runtime_render_layer_override;
@@ -67,8 +92,12 @@ pub mod runtime_world_visual_stack;
 pub mod scanner_component;
 pub mod script_navigation_target;
 pub mod script_state;
+pub mod script_timers;
+pub mod script_weapon_target;
 pub mod selected_entity_guid;
 pub mod shard_assignment;
+pub mod shield_generator;
+pub mod ship_power_state;
 pub mod ship_tag;
 pub mod signal_signature;
 pub mod simulation_motion_writer;
@@ -77,16 +106,19 @@ pub mod space_background_shader_settings;
 pub mod sprite_shader_asset_id;
 pub mod starfield_shader_settings;
 pub mod static_landmark;
+pub mod station_service;
 pub mod stellar_light_source;
 pub mod tactical_map_ui_settings;
 pub mod tactical_presentation_defaults;
 pub mod thruster_plume_shader_settings;
 pub mod total_mass_kg;
+pub mod tractor_beam;
 pub mod visibility_disclosure;
 pub mod visibility_range_buff_m;
 pub mod visibility_range_m;
 pub mod visibility_spatial_grid;
 pub mod visual_asset_id;
+pub mod weapon_group;
 pub mod weapon_tag;
 pub mod world_position;
 pub mod world_rotation;
@@ -97,6 +129,7 @@ pub use action_queue::ActionQueue;
 pub use afterburner_capability::AfterburnerCapability;
 pub use afterburner_state::AfterburnerState;
 pub use ammo_count::AmmoCount;
+pub use ammo_feed::AmmoFeed;
 pub use asteroid_field::{AsteroidField, AsteroidFieldShape, AsteroidSizeTier};
 pub use asteroid_field_ambient::AsteroidFieldAmbient;
 pub use asteroid_field_damage_state::{
@@ -107,9 +140,16 @@ pub use asteroid_field_member::AsteroidFieldMember;
 pub use asteroid_field_population::{AsteroidFieldPopulation, AsteroidSizeRangeM};
 pub use asteroid_fracture_profile::AsteroidFractureProfile;
 pub use asteroid_resource_profile::{AsteroidResourceProfile, AsteroidYieldEntry};
+pub use autopilot_state::AutopilotState;
 pub use ballistic_projectile::BallisticProjectile;
 pub use ballistic_weapon::{BallisticWeapon, WeaponCooldownState};
 pub use base_mass_kg::BaseMassKg;
+pub use beam_weapon::BeamWeapon;
+pub use beam_weapon_state::BeamWeaponState;
+pub use capacitor::Capacitor;
+pub use cargo_capacity::CargoCapacity;
+pub use cargo_container::CargoContainer;
+pub use cargo_jettison_selection::CargoJettisonSelection;
 pub use cargo_mass_kg::CargoMassKg;
 pub use character_movement_controller::CharacterMovementController;
 pub use collision_aabb_m::CollisionAabbM;
@@ -119,6 +159,13 @@ pub use contact_resolution_m::ContactResolutionM;
 pub use controlled_entity_guid::ControlledEntityGuid;
 pub use controlled_start_target::ControlledStartTarget;
 pub use cost::Cost;
+pub use crafting_facility::CraftingFacility;
+pub use crafting_queue::{CraftingJob, CraftingJobState, CraftingQueue};
+pub use credit_ledger::{CREDIT_LEDGER_MAX_ENTRIES, CreditLedger, CreditLedgerEntry};
+pub use credit_wallet::CreditWallet;
+pub use damage_resistance_profile::{
+    DamageResistanceEntry, DamageResistanceProfile, mitigate_damage_with_profiles,
+};
 pub use damage_type::DamageType;
 pub use density::Density;
 pub use destructible::{
@@ -126,11 +173,14 @@ pub use destructible::{
 };
 pub use discovered_static_landmarks::DiscoveredStaticLandmarks;
 pub use display_name::DisplayName;
+pub use docking_port::DockingPort;
+pub use docking_state::{DockingPhase, DockingState};
 pub use engine::Engine;
 pub use entity_guid::EntityGuid;
 pub use entity_labels::EntityLabels;
 pub use environment_lighting_state::EnvironmentLightingState;
 pub use faction_id::FactionId;
+pub use faction_reputation::{FactionReputation, FactionReputationEntry};
 pub use faction_visibility::FactionVisibility;
 pub use flight_computer::FlightComputer;
 pub use flight_control_authority::FlightControlAuthority;
@@ -138,13 +188,20 @@ pub use flight_tuning::FlightTuning;
 pub use focused_entity_guid::FocusedEntityGuid;
 pub use fuel_tank::FuelTank;
 pub use fullscreen_layer::{FullscreenLayer, SPACE_BACKGROUND_LAYER_KIND, STARFIELD_LAYER_KIND};
+pub use guided_missile::GuidedMissile;
 pub use hardpoint::Hardpoint;
 pub use health_pool::HealthPool;
-pub use inventory::{Inventory, InventoryEntry};
+pub use heatsink::Heatsink;
+pub use inventory::{Inventory, InventoryEntry, stackable_item_uuid};
 pub use map_icon::MapIcon;
+pub use market::{Market, MarketListing};
 pub use mass_dirty::MassDirty;
 pub use mass_kg::MassKg;
 pub use max_velocity_mps::MaxVelocityMps;
+pub use mining_laser::MiningLaser;
+pub use missile_launcher::MissileLauncher;
+pub use mission_log::{ActiveMission, MissionLog, MissionObjectiveProgress};
+pub use module_health::ModuleHealth;
 pub use module_mass_kg::ModuleMassKg;
 pub use module_tag::ModuleTag;
 pub use mounted_on::MountedOn;
@@ -156,8 +213,10 @@ pub use player_explored_cells::{
     PlayerExploredCells, PlayerExploredCellsChunk, PlayerExploredCellsChunkEncoding,
 };
 pub use player_tag::PlayerTag;
+pub use power_consumer::PowerConsumer;
 pub use procedural_sprite::{ProceduralSprite, ProceduralSpriteSurfaceStyle};
 pub use public_visibility::PublicVisibility;
+pub use reactor::Reactor;
 pub use runtime_post_process_stack::{RuntimePostProcessPass, RuntimePostProcessStack};
 pub use runtime_render_layer_definition::{
     RENDER_DOMAIN_FULLSCREEN, RENDER_DOMAIN_POST_PROCESS, RENDER_DOMAIN_WORLD_POLYGON,
@@ -174,8 +233,12 @@ pub use runtime_world_visual_stack::{
 pub use scanner_component::{ScannerComponent, ScannerContactDetailTier};
 pub use script_navigation_target::ScriptNavigationTarget;
 pub use script_state::{ScriptState, ScriptValue};
+pub use script_timers::{ScriptTimer, ScriptTimers};
+pub use script_weapon_target::ScriptWeaponTarget;
 pub use selected_entity_guid::SelectedEntityGuid;
 pub use shard_assignment::ShardAssignment;
+pub use shield_generator::{ShieldDamageAbsorption, ShieldGenerator};
+pub use ship_power_state::ShipPowerState;
 pub use ship_tag::ShipTag;
 pub use signal_signature::SignalSignature;
 pub use simulation_motion_writer::SimulationMotionWriter;
@@ -184,16 +247,19 @@ pub use space_background_shader_settings::SpaceBackgroundShaderSettings;
 pub use sprite_shader_asset_id::SpriteShaderAssetId;
 pub use starfield_shader_settings::StarfieldShaderSettings;
 pub use static_landmark::StaticLandmark;
+pub use station_service::{StationService, StationServiceKind};
 pub use stellar_light_source::StellarLightSource;
 pub use tactical_map_ui_settings::TacticalMapUiSettings;
 pub use tactical_presentation_defaults::{TacticalKindIconBinding, TacticalPresentationDefaults};
 pub use thruster_plume_shader_settings::ThrusterPlumeShaderSettings;
 pub use total_mass_kg::TotalMassKg;
+pub use tractor_beam::TractorBeam;
 pub use visibility_disclosure::{VisibilityDisclosure, VisibilityRangeSource};
 pub use visibility_range_buff_m::VisibilityRangeBuffM;
 pub use visibility_range_m::VisibilityRangeM;
 pub use visibility_spatial_grid::{VisibilityGridCell, VisibilitySpatialGrid};
 pub use visual_asset_id::VisualAssetId;
+pub use weapon_group::{MAX_WEAPON_GROUPS, WeaponGroup};
 pub use weapon_tag::WeaponTag;
 pub use world_position::WorldPosition;
 pub use world_rotation::WorldRotation;
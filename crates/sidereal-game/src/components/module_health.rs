@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Structural integrity of a mounted module, separate from the hull's
+/// `HealthPool`. Impacts near the module's hardpoint wear it down; damaged
+/// modules perform in proportion to `performance()` and destroyed ones stop
+/// working until repaired.
+#[sidereal_component_macros::sidereal_component(
+    kind = "module_health",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct ModuleHealth {
+    pub current: f32,
+    pub maximum: f32,
+    /// Seconds left on a field repair in progress; zero when idle.
+    #[serde(default)]
+    pub repair_remaining_s: f32,
+}
+
+impl ModuleHealth {
+    pub fn new(maximum: f32) -> Self {
+        Self {
+            current: maximum,
+            maximum,
+            repair_remaining_s: 0.0,
+        }
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.maximum > 0.0 && self.current <= 0.0
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.current < self.maximum
+    }
+
+    pub fn is_repairing(&self) -> bool {
+        self.repair_remaining_s > 0.0
+    }
+
+    /// Fraction of rated output the module delivers (0..=1).
+    pub fn performance(&self) -> f32 {
+        if self.maximum <= 0.0 {
+            return 1.0;
+        }
+        (self.current / self.maximum).clamp(0.0, 1.0)
+    }
+
+    pub fn apply_damage(&mut self, damage: f32) {
+        self.current = (self.current - damage.max(0.0)).max(0.0);
+    }
+
+    pub fn restore(&mut self, amount: f32) {
+        self.current = (self.current + amount.max(0.0)).min(self.maximum);
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Power and heat cost of a module, drawn from the parent hull's
+/// `ShipPowerState`. Sustained systems (engines, beams) use the per-second
+/// fields scaled by demand; discrete activations (shots, launches) use the
+/// per-use fields.
+#[sidereal_component_macros::sidereal_component(
+    kind = "power_consumer",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Default, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct PowerConsumer {
+    #[serde(default)]
+    pub draw_mw: f32,
+    #[serde(default)]
+    pub heat_per_s: f32,
+    #[serde(default)]
+    pub energy_per_use_mj: f32,
+    #[serde(default)]
+    pub heat_per_use: f32,
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+/// Power plant module. Every reactor mounted on a hull adds its output to the
+/// hull's `ShipPowerState::generation_mw`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "reactor",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct Reactor {
+    /// Energy generated per second, in megajoules.
+    pub output_mw: f32,
+}
@@ -22,6 +22,10 @@ pub enum ScannerContactDetailTier {
 #[require(EntityGuid)]
 pub struct ScannerComponent {
     pub base_range_m: f32,
+    /// Undamaged `base_range_m`, captured the first time module damage
+    /// shrinks the scanner so repairs can restore it.
+    #[serde(default)]
+    pub rated_range_m: Option<f32>,
     pub level: u8,
     pub detail_tier: ScannerContactDetailTier,
     pub supports_density: bool,
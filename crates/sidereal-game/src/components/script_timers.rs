@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{EntityGuid, ScriptValue};
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct ScriptTimer {
+    pub timer_id: Uuid,
+    /// Handler that scheduled the timer; the event is delivered back to it.
+    pub handler_name: String,
+    pub event_name: String,
+    pub payload: ScriptValue,
+    /// Simulation seconds left before the timer fires. Counting down rather
+    /// than storing a deadline keeps timers valid across restarts.
+    pub remaining_s: f64,
+    /// Set for repeating timers, which re-arm with this delay after firing.
+    #[serde(default)]
+    pub repeat_interval_s: Option<f64>,
+}
+
+/// Pending `ctx:schedule` timers owned by a scripted entity. Server-side only.
+#[sidereal_component_macros::sidereal_component(
+    kind = "script_timers",
+    persist = true,
+    replicate = false
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct ScriptTimers {
+    #[serde(default)]
+    pub timers: Vec<ScriptTimer>,
+}
+
+impl ScriptTimers {
+    pub fn cancel(&mut self, timer_id: Uuid) -> bool {
+        let before = self.timers.len();
+        self.timers.retain(|timer| timer.timer_id != timer_id);
+        self.timers.len() != before
+    }
+
+    /// Counts every timer down by `dt_s` and returns the ones that fired, in
+    /// schedule order. A repeating timer that fell behind fires once per call
+    /// until it catches up.
+    pub fn advance(&mut self, dt_s: f64) -> Vec<ScriptTimer> {
+        let mut fired = Vec::new();
+        for timer in &mut self.timers {
+            timer.remaining_s -= dt_s;
+            if timer.remaining_s > 0.0 {
+                continue;
+            }
+            fired.push(timer.clone());
+            if let Some(interval_s) = timer.repeat_interval_s {
+                timer.remaining_s = (timer.remaining_s + interval_s).max(0.0);
+            }
+        }
+        self.timers
+            .retain(|timer| timer.repeat_interval_s.is_some() || timer.remaining_s > 0.0);
+        fired
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use uuid::Uuid;
+
+use crate::EntityGuid;
+
+/// Runtime-only weapon target named by an authoritative script's
+/// `fire_weapons` intent.
+///
+/// Like `ScriptNavigationTarget` this is neither persisted nor replicated.
+/// Fire processing aims ballistic weapons at the target and locks missiles
+/// onto it in place of a player's selection.
+#[derive(Debug, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component)]
+#[require(EntityGuid)]
+pub struct ScriptWeaponTarget {
+    pub target_guid: Uuid,
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{DamageType, EntityGuid, MountedOn};
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Serialize, Deserialize)]
+pub struct ShieldDamageAbsorption {
+    pub damage_type: DamageType,
+    /// Fraction of incoming damage of this type the shield soaks (0..=1).
+    pub fraction: f32,
+}
+
+/// Shield module mounted on a hull. While active it soaks incoming damage
+/// before the parent's `HealthPool` and regenerates after `regen_delay_s`
+/// without hits.
+#[sidereal_component_macros::sidereal_component(
+    kind = "shield_generator",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct ShieldGenerator {
+    pub capacity: f32,
+    pub current: f32,
+    pub regen_rate_per_s: f32,
+    pub regen_delay_s: f32,
+    #[serde(default)]
+    pub regen_delay_remaining_s: f32,
+    #[serde(default)]
+    pub active: bool,
+    /// Per-damage-type absorption. Damage types not listed are fully absorbed.
+    #[serde(default)]
+    pub absorption: Vec<ShieldDamageAbsorption>,
+}
+
+impl ShieldGenerator {
+    pub fn absorption_fraction(&self, damage_type: DamageType) -> f32 {
+        self.absorption
+            .iter()
+            .find(|entry| entry.damage_type == damage_type)
+            .map(|entry| entry.fraction)
+            .unwrap_or(1.0)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Soaks as much of `damage` as this shield can and returns the remainder
+    /// that should pass through to the hull. Any hit restarts the regen delay.
+    pub fn absorb(&mut self, damage: f32, damage_type: DamageType) -> f32 {
+        if !self.active || damage <= 0.0 {
+            return damage.max(0.0);
+        }
+        self.regen_delay_remaining_s = self.regen_delay_s.max(0.0);
+        let absorbable = damage * self.absorption_fraction(damage_type);
+        let soaked = absorbable.min(self.current.max(0.0));
+        self.current = (self.current - soaked).max(0.0);
+        damage - soaked
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+/// Hull-level power and heat budget aggregated from mounted `Reactor`,
+/// `Capacitor` and `Heatsink` modules. Predicted so the local HUD bars track
+/// the pilot's own draws without waiting for the server.
+#[sidereal_component_macros::sidereal_component(
+    kind = "ship_power_state",
+    persist = true,
+    replicate = true,
+    predict = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Default, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct ShipPowerState {
+    #[serde(default)]
+    pub stored_mj: f32,
+    #[serde(default)]
+    pub capacity_mj: f32,
+    #[serde(default)]
+    pub generation_mw: f32,
+    #[serde(default)]
+    pub heat: f32,
+    #[serde(default)]
+    pub heat_capacity: f32,
+    #[serde(default)]
+    pub dissipation_per_s: f32,
+    /// Set when `heat` reaches `heat_capacity`; cleared once the hull cools
+    /// below the recovery threshold in `ShipPowerSettings`.
+    #[serde(default)]
+    pub overheated: bool,
+}
+
+impl ShipPowerState {
+    /// Draws `energy_mj` and adds `heat` only if the full amount is stored and
+    /// the hull is not overheated.
+    pub fn try_draw(&mut self, energy_mj: f32, heat: f32) -> bool {
+        let energy_mj = energy_mj.max(0.0);
+        if self.overheated || self.stored_mj < energy_mj {
+            return false;
+        }
+        self.stored_mj -= energy_mj;
+        self.add_heat(heat);
+        true
+    }
+
+    /// Draws up to `energy_mj` and returns the fraction delivered (0..=1).
+    /// Heat is added in proportion to the energy actually drawn.
+    pub fn draw_continuous(&mut self, energy_mj: f32, heat: f32) -> f32 {
+        if energy_mj <= 0.0 {
+            self.add_heat(heat);
+            return 1.0;
+        }
+        let drawn = energy_mj.min(self.stored_mj.max(0.0));
+        let fraction = drawn / energy_mj;
+        self.stored_mj -= drawn;
+        self.add_heat(heat * fraction);
+        fraction
+    }
+
+    pub fn add_heat(&mut self, heat: f32) {
+        if heat <= 0.0 {
+            return;
+        }
+        self.heat += heat;
+        if self.heat_capacity > 0.0 && self.heat >= self.heat_capacity {
+            self.heat = self.heat_capacity;
+            self.overheated = true;
+        }
+    }
+
+    pub fn power_ratio(&self) -> f32 {
+        if self.capacity_mj <= 0.0 {
+            return 0.0;
+        }
+        (self.stored_mj / self.capacity_mj).clamp(0.0, 1.0)
+    }
+
+    pub fn heat_ratio(&self) -> f32 {
+        if self.heat_capacity <= 0.0 {
+            return 0.0;
+        }
+        (self.heat / self.heat_capacity).clamp(0.0, 1.0)
+    }
+}
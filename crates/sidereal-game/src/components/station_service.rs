@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{EntityGuid, MountedOn};
+
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[reflect(Serialize, Deserialize)]
+pub enum StationServiceKind {
+    /// Restores the client hull's `HealthPool`, priced per hit point.
+    #[default]
+    HullRepair,
+    /// Fills the client hull's `FuelTank`s, priced per kilogram.
+    Refuel,
+}
+
+impl StationServiceKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::HullRepair => "hull_repair",
+            Self::Refuel => "refuel",
+        }
+    }
+}
+
+/// Paid service offered by a station module. The unit price is the `Cost` on
+/// the same entity; ships docked at the host station, or within
+/// `service_range_m` of it, can buy the service.
+#[sidereal_component_macros::sidereal_component(
+    kind = "station_service",
+    persist = true,
+    replicate = true,
+    visibility = [Public]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct StationService {
+    pub kind: StationServiceKind,
+    /// Undocked ships within this distance of the host station may buy the
+    /// service; zero limits it to docked ships.
+    #[serde(default)]
+    pub service_range_m: f32,
+}
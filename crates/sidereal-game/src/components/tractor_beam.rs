@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{EntityGuid, MountedOn};
+
+/// Tractor module mounted on a hull. While active it locks the nearest towable
+/// body in range (or an explicitly assigned `target_guid`) and pulls it toward
+/// `hold_distance_m` with a force capped by `max_force_n`.
+#[sidereal_component_macros::sidereal_component(
+    kind = "tractor_beam",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, PartialEq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid, MountedOn)]
+pub struct TractorBeam {
+    pub range_m: f32,
+    pub hold_distance_m: f32,
+    pub max_force_n: f32,
+    /// Targets whose `TotalMassKg` exceeds this are never locked.
+    pub max_target_mass_kg: f32,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub target_guid: Option<Uuid>,
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::EntityGuid;
+
+/// Highest fire group a weapon can be assigned to.
+pub const MAX_WEAPON_GROUPS: u8 = 4;
+
+/// Fire group a mounted weapon answers to. Group 1 fires on `FirePrimary`,
+/// group 2 on `FireSecondary`, and groups 3..=`MAX_WEAPON_GROUPS` on the
+/// matching `FireWeaponGroupN` action. Weapons without this component belong
+/// to the primary group.
+#[sidereal_component_macros::sidereal_component(
+    kind = "weapon_group",
+    persist = true,
+    replicate = true,
+    visibility = [OwnerOnly]
+)]
+#[derive(Debug, Clone, Copy, Component, Reflect, Serialize, Deserialize, PartialEq, Eq)]
+#[reflect(Component, Serialize, Deserialize)]
+#[require(EntityGuid)]
+pub struct WeaponGroup {
+    pub group: u8,
+}
+
+impl WeaponGroup {
+    pub const PRIMARY: u8 = 1;
+    pub const SECONDARY: u8 = 2;
+
+    pub fn is_valid_group(group: u8) -> bool {
+        (1..=MAX_WEAPON_GROUPS).contains(&group)
+    }
+}
+
+impl Default for WeaponGroup {
+    fn default() -> Self {
+        Self {
+            group: Self::PRIMARY,
+        }
+    }
+}
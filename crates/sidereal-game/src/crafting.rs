@@ -0,0 +1,306 @@
+//! Crafting subsystem
+//!
+//! Facilities are entities carrying `CraftingFacility`, a `CraftingQueue` and
+//! their own `Inventory`. `CraftingJobRequest` messages append jobs after the
+//! recipe is checked against the facility type and, for player requests, the
+//! facility is checked to belong to that player. Each fixed tick up to the
+//! facility's `queue_slots` jobs run at once: a job consumes every recipe input
+//! from the inventory in one step when it starts, advances `progress_s`, and
+//! deposits its output when `craft_time_s` elapses. Outputs that would exceed
+//! `CargoCapacity` hold the job in `OutputBlocked` until room frees up.
+
+use bevy::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    CargoCapacity, CraftingFacility, CraftingJob, CraftingJobState, CraftingQueue, EntityGuid,
+    FacilityRegistry, Inventory, ItemRegistry, MassDirty, OwnerId, RecipeDefinition,
+    RecipeRegistry,
+};
+
+/// Asks the server to append a recipe job to a facility's queue.
+#[derive(Debug, Clone, Message)]
+pub struct CraftingJobRequest {
+    pub facility_guid: Uuid,
+    pub recipe_id: String,
+    pub job_id: Uuid,
+    pub requested_by_player_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraftingFailureReason {
+    UnknownRecipe,
+    /// The recipe names a different `facility_id` than the target facility.
+    FacilityMismatch,
+    /// A player asked for a job at a facility they do not own.
+    NotFacilityOwner,
+    MissingInputs,
+    CargoFull,
+}
+
+impl CraftingFailureReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UnknownRecipe => "unknown_recipe",
+            Self::FacilityMismatch => "facility_mismatch",
+            Self::NotFacilityOwner => "not_facility_owner",
+            Self::MissingInputs => "missing_inputs",
+            Self::CargoFull => "cargo_full",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct CraftingJobFailedEvent {
+    pub facility_entity: Entity,
+    pub facility_guid: Uuid,
+    pub facility_owner_id: Option<String>,
+    pub job_id: Uuid,
+    pub recipe_id: String,
+    pub requested_by_player_id: Option<String>,
+    pub reason: CraftingFailureReason,
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct CraftingJobCompletedEvent {
+    pub facility_entity: Entity,
+    pub facility_guid: Uuid,
+    pub facility_owner_id: Option<String>,
+    pub job_id: Uuid,
+    pub recipe_id: String,
+    pub requested_by_player_id: Option<String>,
+    pub output_item_id: String,
+    pub output_quantity: u32,
+}
+
+type CraftingFacilityQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static CraftingFacility,
+        &'static mut CraftingQueue,
+        &'static mut Inventory,
+        Option<&'static CargoCapacity>,
+        Option<&'static OwnerId>,
+    ),
+>;
+
+pub fn process_crafting_job_requests(
+    mut requests: MessageReader<'_, '_, CraftingJobRequest>,
+    recipes: Option<Res<'_, RecipeRegistry>>,
+    mut facilities: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ EntityGuid,
+            &'_ CraftingFacility,
+            &'_ mut CraftingQueue,
+            Option<&'_ OwnerId>,
+        ),
+    >,
+    mut failed_events: MessageWriter<'_, CraftingJobFailedEvent>,
+) {
+    for request in requests.read() {
+        let Some((entity, guid, facility, mut queue, owner_id)) = facilities
+            .iter_mut()
+            .find(|(_, guid, _, _, _)| guid.0 == request.facility_guid)
+        else {
+            warn!(
+                "crafting job request for unknown facility guid={} recipe_id={}",
+                request.facility_guid, request.recipe_id
+            );
+            continue;
+        };
+        let recipe = recipes
+            .as_deref()
+            .and_then(|recipes| recipes.recipe(&request.recipe_id));
+        let reason = match recipe {
+            _ if !player_owns_facility(request.requested_by_player_id.as_deref(), owner_id) => {
+                Some(CraftingFailureReason::NotFacilityOwner)
+            }
+            None => Some(CraftingFailureReason::UnknownRecipe),
+            Some(recipe) if recipe.facility_id != facility.facility_id => {
+                Some(CraftingFailureReason::FacilityMismatch)
+            }
+            Some(_) => None,
+        };
+        if let Some(reason) = reason {
+            failed_events.write(CraftingJobFailedEvent {
+                facility_entity: entity,
+                facility_guid: guid.0,
+                facility_owner_id: owner_id.map(|owner| owner.0.clone()),
+                job_id: request.job_id,
+                recipe_id: request.recipe_id.clone(),
+                requested_by_player_id: request.requested_by_player_id.clone(),
+                reason,
+            });
+            continue;
+        }
+        queue.jobs.push(CraftingJob {
+            job_id: request.job_id,
+            recipe_id: request.recipe_id.clone(),
+            state: CraftingJobState::Queued,
+            progress_s: 0.0,
+            requested_by_player_id: request.requested_by_player_id.clone(),
+        });
+    }
+}
+
+pub fn advance_crafting_jobs(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    recipes: Option<Res<'_, RecipeRegistry>>,
+    facility_definitions: Option<Res<'_, FacilityRegistry>>,
+    items: Option<Res<'_, ItemRegistry>>,
+    mut facilities: CraftingFacilityQuery<'_, '_>,
+    mut completed_events: MessageWriter<'_, CraftingJobCompletedEvent>,
+    mut failed_events: MessageWriter<'_, CraftingJobFailedEvent>,
+) {
+    let dt_s = time.delta_secs();
+    let (Some(recipes), Some(facility_definitions), Some(items)) =
+        (recipes, facility_definitions, items)
+    else {
+        return;
+    };
+
+    for (entity, guid, facility, mut queue, mut inventory, capacity, owner_id) in &mut facilities {
+        if queue.jobs.is_empty() {
+            continue;
+        }
+        let queue_slots = facility_definitions
+            .facility(&facility.facility_id)
+            .map(|definition| definition.queue_slots.max(1) as usize)
+            .unwrap_or(1);
+        let owner_id = owner_id.map(|owner| owner.0.clone());
+        let failed = |job: &CraftingJob, reason: CraftingFailureReason| CraftingJobFailedEvent {
+            facility_entity: entity,
+            facility_guid: guid.0,
+            facility_owner_id: owner_id.clone(),
+            job_id: job.job_id,
+            recipe_id: job.recipe_id.clone(),
+            requested_by_player_id: job.requested_by_player_id.clone(),
+            reason,
+        };
+        let mut inventory_changed = false;
+
+        // Start queued jobs in FIFO order while slots are free.
+        let mut active = queue
+            .jobs
+            .iter()
+            .filter(|job| job.state != CraftingJobState::Queued)
+            .count();
+        let mut index = 0;
+        while index < queue.jobs.len() && active < queue_slots {
+            if queue.jobs[index].state != CraftingJobState::Queued {
+                index += 1;
+                continue;
+            }
+            let Some(recipe) = recipes.recipe(&queue.jobs[index].recipe_id) else {
+                let job = queue.jobs.remove(index);
+                failed_events.write(failed(&job, CraftingFailureReason::UnknownRecipe));
+                continue;
+            };
+            if !consume_recipe_inputs(&mut inventory, recipe) {
+                let job = queue.jobs.remove(index);
+                failed_events.write(failed(&job, CraftingFailureReason::MissingInputs));
+                continue;
+            }
+            inventory_changed = true;
+            let job = &mut queue.jobs[index];
+            job.state = CraftingJobState::InProgress;
+            job.progress_s = 0.0;
+            active += 1;
+            index += 1;
+        }
+
+        // Advance running jobs and deposit finished outputs.
+        let mut index = 0;
+        while index < queue.jobs.len() {
+            let state = queue.jobs[index].state;
+            if state == CraftingJobState::Queued {
+                index += 1;
+                continue;
+            }
+            let Some(recipe) = recipes.recipe(&queue.jobs[index].recipe_id) else {
+                let job = queue.jobs.remove(index);
+                failed_events.write(failed(&job, CraftingFailureReason::UnknownRecipe));
+                continue;
+            };
+            if state == CraftingJobState::InProgress {
+                let job = &mut queue.jobs[index];
+                job.progress_s = (job.progress_s + dt_s).min(recipe.craft_time_s);
+                if job.progress_s < recipe.craft_time_s {
+                    index += 1;
+                    continue;
+                }
+            }
+
+            let output = &recipe.output;
+            let unit_mass_kg = items
+                .item(&output.item_id)
+                .map(|item| item.unit_mass_kg)
+                .unwrap_or(1.0);
+            let output_mass_kg = unit_mass_kg * output.amount as f32;
+            let has_room = capacity.is_none_or(|capacity| {
+                capacity.has_room_for(inventory.total_mass_kg(), output_mass_kg)
+            });
+            if !has_room {
+                let job = &mut queue.jobs[index];
+                if job.state != CraftingJobState::OutputBlocked {
+                    job.state = CraftingJobState::OutputBlocked;
+                    failed_events.write(failed(job, CraftingFailureReason::CargoFull));
+                }
+                index += 1;
+                continue;
+            }
+
+            inventory.add_stack(&output.item_id, output.amount, unit_mass_kg);
+            inventory_changed = true;
+            let job = queue.jobs.remove(index);
+            completed_events.write(CraftingJobCompletedEvent {
+                facility_entity: entity,
+                facility_guid: guid.0,
+                facility_owner_id: owner_id.clone(),
+                job_id: job.job_id,
+                recipe_id: job.recipe_id,
+                requested_by_player_id: job.requested_by_player_id,
+                output_item_id: output.item_id.clone(),
+                output_quantity: output.amount,
+            });
+        }
+
+        if inventory_changed {
+            commands.entity(entity).insert(MassDirty);
+        }
+    }
+}
+
+/// Player requests only reach facilities owned by that player; requests with no
+/// player come from trusted server paths.
+fn player_owns_facility(requested_by_player_id: Option<&str>, owner_id: Option<&OwnerId>) -> bool {
+    let Some(player_id) = requested_by_player_id else {
+        return true;
+    };
+    let player_guid = Uuid::parse_str(player_id).ok();
+    let owner_guid = owner_id.and_then(|owner| Uuid::parse_str(&owner.0).ok());
+    player_guid.is_some() && player_guid == owner_guid
+}
+
+/// Removes every recipe input from `inventory`, or nothing when any input is
+/// short.
+fn consume_recipe_inputs(inventory: &mut Inventory, recipe: &RecipeDefinition) -> bool {
+    let has_inputs = recipe
+        .inputs
+        .iter()
+        .all(|input| inventory.quantity_of(&input.item_id) >= input.amount);
+    if !has_inputs {
+        return false;
+    }
+    for input in &recipe.inputs {
+        inventory.remove_stack(&input.item_id, input.amount);
+    }
+    true
+}
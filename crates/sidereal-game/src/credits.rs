@@ -0,0 +1,126 @@
+//! Player credit transactions
+//!
+//! Credits live in a player's `CreditWallet`, which replicates to its owner
+//! for the HUD. Every balance change goes through `debit_credits` or
+//! `grant_credits`: they validate the amount, move the balance and append a
+//! `CreditLedgerEntry` to the player's `CreditLedger` together, so a refused
+//! transaction leaves both untouched. Scripts reach the same functions
+//! through the replication server's `debit_credits` / `grant_credits`
+//! intents.
+
+use bevy::prelude::*;
+use uuid::Uuid;
+
+use crate::{CreditLedger, CreditLedgerEntry, CreditWallet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditTransactionError {
+    ZeroAmount,
+    /// The amount does not fit the ledger's signed delta.
+    AmountTooLarge,
+    InsufficientCredits {
+        balance: u64,
+        requested: u64,
+    },
+    BalanceOverflow,
+}
+
+impl CreditTransactionError {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ZeroAmount => "zero_amount",
+            Self::AmountTooLarge => "amount_too_large",
+            Self::InsufficientCredits { .. } => "insufficient_credits",
+            Self::BalanceOverflow => "balance_overflow",
+        }
+    }
+}
+
+/// Fixed simulation tick used to stamp ledger entries.
+pub fn ledger_tick(time: &Time<Fixed>) -> u64 {
+    let timestep_ns = time.timestep().as_nanos().max(1);
+    u64::try_from(time.elapsed().as_nanos() / timestep_ns).unwrap_or(u64::MAX)
+}
+
+/// Removes `amount` credits, refusing rather than going negative.
+pub fn debit_credits(
+    wallet: &mut CreditWallet,
+    ledger: &mut CreditLedger,
+    amount: u64,
+    reason: &str,
+    counterparty: Option<Uuid>,
+    tick: u64,
+) -> Result<CreditLedgerEntry, CreditTransactionError> {
+    let delta = signed_amount(amount)?;
+    let balance_after =
+        wallet
+            .balance
+            .checked_sub(amount)
+            .ok_or(CreditTransactionError::InsufficientCredits {
+                balance: wallet.balance,
+                requested: amount,
+            })?;
+    Ok(append_entry(
+        wallet,
+        ledger,
+        -delta,
+        balance_after,
+        reason,
+        counterparty,
+        tick,
+    ))
+}
+
+pub fn grant_credits(
+    wallet: &mut CreditWallet,
+    ledger: &mut CreditLedger,
+    amount: u64,
+    reason: &str,
+    counterparty: Option<Uuid>,
+    tick: u64,
+) -> Result<CreditLedgerEntry, CreditTransactionError> {
+    let delta = signed_amount(amount)?;
+    let balance_after = wallet
+        .balance
+        .checked_add(amount)
+        .ok_or(CreditTransactionError::BalanceOverflow)?;
+    Ok(append_entry(
+        wallet,
+        ledger,
+        delta,
+        balance_after,
+        reason,
+        counterparty,
+        tick,
+    ))
+}
+
+fn signed_amount(amount: u64) -> Result<i64, CreditTransactionError> {
+    if amount == 0 {
+        return Err(CreditTransactionError::ZeroAmount);
+    }
+    i64::try_from(amount).map_err(|_| CreditTransactionError::AmountTooLarge)
+}
+
+fn append_entry(
+    wallet: &mut CreditWallet,
+    ledger: &mut CreditLedger,
+    delta: i64,
+    balance_after: u64,
+    reason: &str,
+    counterparty: Option<Uuid>,
+    tick: u64,
+) -> CreditLedgerEntry {
+    let entry = CreditLedgerEntry {
+        transaction_id: Uuid::new_v4(),
+        sequence: ledger.last_sequence() + 1,
+        tick,
+        reason: reason.to_string(),
+        counterparty,
+        delta,
+        balance_after,
+    };
+    wallet.balance = balance_after;
+    ledger.push(entry.clone());
+    entry
+}
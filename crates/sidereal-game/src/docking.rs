@@ -0,0 +1,348 @@
+//! Docking subsystem
+//!
+//! Stations and carriers expose `DockingPort` modules mounted on hardpoints.
+//! A ship's `InitiateDocking` action toggles its `DockingState`:
+//!
+//! 1. `Requested`: the nearest free port within `request_range_m` is assigned.
+//! 2. `Approaching`: the ship must enter the port's approach corridor (inside
+//!    `capture_range_m`, within the cone around the port's forward axis, below
+//!    `max_capture_speed_mps` relative to the host).
+//! 3. `Docked`: the ship is re-parented to the host through `ParentGuid` (so
+//!    `sync_mounted_hierarchy` links it on clients), its rigid body and
+//!    colliders are disabled, and it is pinned to the port pose every tick.
+//! 4. `Undocking`: the ship is released `undock_offset_m` in front of the port
+//!    with the host's velocity and its physics body is re-enabled.
+//!
+//! Docking state and port occupancy are persisted; docked ships that reload
+//! without their runtime physics markers are re-frozen on the next tick.
+
+use avian2d::prelude::*;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, DockingPhase, DockingPort, DockingState, EntityAction, EntityGuid, Hardpoint,
+    MountedOn, ParentGuid, SimulationMotionWriter,
+};
+
+type DockingBodyPoseQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static EntityGuid,
+        &'static Position,
+        &'static Rotation,
+        Option<&'static LinearVelocity>,
+    ),
+>;
+
+type DockingShipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static mut DockingState,
+        &'static mut Position,
+        &'static mut Rotation,
+        &'static mut LinearVelocity,
+        Option<&'static mut AngularVelocity>,
+        Has<RigidBodyDisabled>,
+        Has<ParentGuid>,
+    ),
+>;
+
+/// World-space pose and tuning of one docking port for the current tick.
+#[derive(Debug, Clone, Copy)]
+struct PortPose {
+    host_guid: Uuid,
+    position: DVec2,
+    forward: DVec2,
+    heading_rad: f64,
+    host_velocity: DVec2,
+    request_range_m: f64,
+    capture_range_m: f64,
+    corridor_cos: f64,
+    max_capture_speed_mps: f64,
+    undock_offset_m: f64,
+    occupant_guid: Option<Uuid>,
+}
+
+/// Turns `InitiateDocking` presses into docking phase transitions.
+///
+/// Input is a latest-intent snapshot, so the action repeats while held; only
+/// the first tick of a press toggles the sequence.
+pub fn process_docking_actions(
+    mut held_last_tick: Local<'_, HashSet<Uuid>>,
+    mut ships: Query<
+        '_,
+        '_,
+        (&'_ EntityGuid, &'_ mut ActionQueue, &'_ mut DockingState),
+        With<SimulationMotionWriter>,
+    >,
+) {
+    let mut held_now = HashSet::<Uuid>::new();
+    for (guid, mut queue, mut docking) in &mut ships {
+        if !drain_docking_action(&mut queue) {
+            continue;
+        }
+        held_now.insert(guid.0);
+        if held_last_tick.contains(&guid.0) {
+            continue;
+        }
+        match docking.phase {
+            DockingPhase::Undocked => docking.phase = DockingPhase::Requested,
+            DockingPhase::Requested | DockingPhase::Approaching => docking.reset(),
+            DockingPhase::Docked => docking.phase = DockingPhase::Undocking,
+            DockingPhase::Undocking => {}
+        }
+    }
+    *held_last_tick = held_now;
+}
+
+#[allow(clippy::type_complexity)]
+pub fn advance_docking_sequences(
+    mut commands: Commands<'_, '_>,
+    mut ports: Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ mut DockingPort)>,
+    hardpoints: Query<'_, '_, (&'_ ParentGuid, &'_ Hardpoint)>,
+    mut bodies: ParamSet<'_, '_, (DockingBodyPoseQuery<'_, '_>, DockingShipQuery<'_, '_>)>,
+) {
+    let any_sequence_active = bodies
+        .p1()
+        .iter()
+        .any(|(_, _, docking, ..)| docking.phase != DockingPhase::Undocked);
+    let any_port_occupied = ports
+        .iter()
+        .any(|(_, _, port)| port.occupant_guid.is_some());
+    if !any_sequence_active && !any_port_occupied {
+        return;
+    }
+
+    let hardpoint_by_key = hardpoints
+        .iter()
+        .map(|(parent, hardpoint)| ((parent.0, hardpoint.hardpoint_id.as_str()), hardpoint))
+        .collect::<HashMap<_, _>>();
+    let pose_by_guid = bodies
+        .p0()
+        .iter()
+        .map(|(guid, position, rotation, velocity)| {
+            (
+                guid.0,
+                (
+                    position.0,
+                    rotation.as_radians(),
+                    velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO),
+                ),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut port_poses = HashMap::<Uuid, PortPose>::new();
+    for (guid, mounted_on, port) in &ports {
+        let host_guid = mounted_on.parent_entity_id;
+        let Some(&(host_position, host_heading_rad, host_velocity)) = pose_by_guid.get(&host_guid)
+        else {
+            continue;
+        };
+        let (local_offset, local_forward) = hardpoint_by_key
+            .get(&(host_guid, mounted_on.hardpoint_id.as_str()))
+            .map(|hardpoint| {
+                (
+                    hardpoint.offset_m.truncate().as_dvec2(),
+                    (hardpoint.local_rotation * Vec3::Y).truncate().as_dvec2(),
+                )
+            })
+            .unwrap_or((DVec2::ZERO, DVec2::Y));
+        let host_rotation = DVec2::from_angle(host_heading_rad);
+        let forward = host_rotation
+            .rotate(local_forward)
+            .try_normalize()
+            .unwrap_or(DVec2::Y);
+        port_poses.insert(
+            guid.0,
+            PortPose {
+                host_guid,
+                position: host_position + host_rotation.rotate(local_offset),
+                forward,
+                heading_rad: f64::atan2(-forward.x, forward.y),
+                host_velocity,
+                request_range_m: f64::from(port.request_range_m.max(0.0)),
+                capture_range_m: f64::from(port.capture_range_m.max(0.0)),
+                corridor_cos: f64::from(port.corridor_half_angle_deg.clamp(0.0, 180.0))
+                    .to_radians()
+                    .cos(),
+                max_capture_speed_mps: f64::from(port.max_capture_speed_mps.max(0.0)),
+                undock_offset_m: f64::from(port.undock_offset_m.max(0.0)),
+                occupant_guid: port.occupant_guid,
+            },
+        );
+    }
+
+    let mut occupant_by_port = HashMap::<Uuid, Uuid>::new();
+    for (
+        entity,
+        guid,
+        mut docking,
+        mut position,
+        mut rotation,
+        mut velocity,
+        angular_velocity,
+        body_disabled,
+        has_parent,
+    ) in &mut bodies.p1()
+    {
+        let assigned_port = docking
+            .port_guid
+            .and_then(|port_guid| port_poses.get(&port_guid).map(|pose| (port_guid, *pose)));
+        match docking.phase {
+            DockingPhase::Undocked => {}
+            DockingPhase::Requested => {
+                let candidate = port_poses
+                    .iter()
+                    .filter(|(port_guid, pose)| {
+                        pose.host_guid != guid.0
+                            && pose.occupant_guid.is_none()
+                            && !occupant_by_port.contains_key(*port_guid)
+                            && pose.position.distance(position.0) <= pose.request_range_m
+                    })
+                    .min_by(|(_, a), (_, b)| {
+                        a.position
+                            .distance_squared(position.0)
+                            .total_cmp(&b.position.distance_squared(position.0))
+                    });
+                match candidate {
+                    Some((port_guid, pose)) => {
+                        docking.phase = DockingPhase::Approaching;
+                        docking.port_guid = Some(*port_guid);
+                        docking.host_guid = Some(pose.host_guid);
+                    }
+                    None => docking.reset(),
+                }
+            }
+            DockingPhase::Approaching => {
+                let Some((port_guid, pose)) = assigned_port else {
+                    docking.reset();
+                    continue;
+                };
+                let taken = pose
+                    .occupant_guid
+                    .is_some_and(|occupant| occupant != guid.0)
+                    || occupant_by_port.contains_key(&port_guid);
+                let offset = position.0 - pose.position;
+                let distance_m = offset.length();
+                if taken || distance_m > pose.request_range_m {
+                    docking.reset();
+                    continue;
+                }
+                let in_corridor = offset
+                    .try_normalize()
+                    .is_none_or(|direction| direction.dot(pose.forward) >= pose.corridor_cos);
+                let relative_speed_mps = (velocity.0 - pose.host_velocity).length();
+                if distance_m > pose.capture_range_m
+                    || !in_corridor
+                    || relative_speed_mps > pose.max_capture_speed_mps
+                {
+                    continue;
+                }
+                docking.phase = DockingPhase::Docked;
+                docking.host_guid = Some(pose.host_guid);
+                occupant_by_port.insert(port_guid, guid.0);
+                pin_to_port(
+                    &mut position,
+                    &mut rotation,
+                    &mut velocity,
+                    angular_velocity,
+                    &pose,
+                );
+                commands.entity(entity).insert((
+                    ParentGuid(pose.host_guid),
+                    RigidBodyDisabled,
+                    ColliderDisabled,
+                ));
+            }
+            DockingPhase::Docked => {
+                let Some((port_guid, pose)) = assigned_port else {
+                    // Port or host is gone: release the ship where it is.
+                    release_from_port(&mut commands, entity);
+                    docking.reset();
+                    continue;
+                };
+                occupant_by_port.insert(port_guid, guid.0);
+                pin_to_port(
+                    &mut position,
+                    &mut rotation,
+                    &mut velocity,
+                    angular_velocity,
+                    &pose,
+                );
+                if !body_disabled || !has_parent {
+                    commands.entity(entity).insert((
+                        ParentGuid(pose.host_guid),
+                        RigidBodyDisabled,
+                        ColliderDisabled,
+                    ));
+                }
+            }
+            DockingPhase::Undocking => {
+                if let Some((_, pose)) = assigned_port {
+                    position.0 = pose.position + pose.forward * pose.undock_offset_m;
+                    *rotation = Rotation::radians(pose.heading_rad);
+                    velocity.0 = pose.host_velocity;
+                }
+                release_from_port(&mut commands, entity);
+                docking.reset();
+            }
+        }
+    }
+
+    for (guid, _, mut port) in &mut ports {
+        let occupant = occupant_by_port.get(&guid.0).copied();
+        if port.occupant_guid != occupant {
+            port.occupant_guid = occupant;
+        }
+    }
+}
+
+fn pin_to_port(
+    position: &mut Position,
+    rotation: &mut Rotation,
+    velocity: &mut LinearVelocity,
+    angular_velocity: Option<Mut<'_, AngularVelocity>>,
+    pose: &PortPose,
+) {
+    if position.0 != pose.position {
+        position.0 = pose.position;
+    }
+    let docked_rotation = Rotation::radians(pose.heading_rad);
+    if *rotation != docked_rotation {
+        *rotation = docked_rotation;
+    }
+    if velocity.0 != pose.host_velocity {
+        velocity.0 = pose.host_velocity;
+    }
+    if let Some(mut angular_velocity) = angular_velocity
+        && angular_velocity.0 != 0.0
+    {
+        angular_velocity.0 = 0.0;
+    }
+}
+
+fn release_from_port(commands: &mut Commands<'_, '_>, entity: Entity) {
+    commands
+        .entity(entity)
+        .remove::<(ParentGuid, RigidBodyDisabled, ColliderDisabled)>();
+}
+
+fn drain_docking_action(queue: &mut ActionQueue) -> bool {
+    let mut requested = false;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        match action {
+            EntityAction::InitiateDocking => requested = true,
+            _ => queue.pending.push(action),
+        }
+    }
+    requested
+}
@@ -0,0 +1,402 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{Inventory, InventoryEntry, stackable_item_uuid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemCategory {
+    #[default]
+    RawMineral,
+    RawVolatile,
+    RawBiological,
+    RefinedMaterial,
+    ChemicalFeedstock,
+    Alloy,
+    Composite,
+    Synthetic,
+    ExoticMaterial,
+    Subassembly,
+    Ammo,
+    EquipmentKit,
+    Blueprint,
+    EndProduct,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct ItemDefinition {
+    pub item_id: String,
+    pub display_name: String,
+    pub category: ItemCategory,
+    #[serde(default)]
+    pub tier: u8,
+    pub stack_size: u32,
+    pub unit_mass_kg: f32,
+    #[serde(default)]
+    pub base_value: u32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct ItemRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub items: Vec<ItemDefinition>,
+}
+
+impl ItemRegistry {
+    pub fn item(&self, item_id: &str) -> Option<&ItemDefinition> {
+        self.items.iter().find(|item| item.item_id == item_id)
+    }
+
+    /// Resolves a stackable inventory entry UUID back to its catalog item.
+    pub fn item_for_entity_id(&self, item_entity_id: Uuid) -> Option<&ItemDefinition> {
+        self.items
+            .iter()
+            .find(|item| stackable_item_uuid(&item.item_id) == item_entity_id)
+    }
+
+    /// Resolves an inventory row by its `item_id`, falling back to the entry
+    /// UUID for rows written before entries carried catalog ids.
+    pub fn resolve_inventory_entry(&self, entry: &InventoryEntry) -> Option<&ItemDefinition> {
+        if !entry.item_id.is_empty() {
+            return self.item(&entry.item_id);
+        }
+        self.item_for_entity_id(entry.item_entity_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct RecipeItemAmount {
+    pub item_id: String,
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct RecipeDefinition {
+    pub recipe_id: String,
+    pub facility_id: String,
+    pub inputs: Vec<RecipeItemAmount>,
+    pub output: RecipeItemAmount,
+    pub craft_time_s: f32,
+    #[serde(default)]
+    pub power_cost: f32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct RecipeRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub recipes: Vec<RecipeDefinition>,
+}
+
+impl RecipeRegistry {
+    pub fn recipe(&self, recipe_id: &str) -> Option<&RecipeDefinition> {
+        self.recipes
+            .iter()
+            .find(|recipe| recipe.recipe_id == recipe_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct FacilityDefinition {
+    pub facility_id: String,
+    pub display_name: String,
+    /// Output categories this facility produces; informational for UI.
+    #[serde(default)]
+    pub categories: Vec<ItemCategory>,
+    pub queue_slots: u32,
+    /// Recipes must carry at least one of these tags; empty allows any recipe
+    /// that names this facility.
+    #[serde(default)]
+    pub allowed_recipe_tags: Vec<String>,
+    #[serde(default)]
+    pub power_buffer_mw: f32,
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct FacilityRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub facilities: Vec<FacilityDefinition>,
+}
+
+impl FacilityRegistry {
+    pub fn facility(&self, facility_id: &str) -> Option<&FacilityDefinition> {
+        self.facilities
+            .iter()
+            .find(|facility| facility.facility_id == facility_id)
+    }
+}
+
+fn default_loot_drop_chance() -> f32 {
+    1.0
+}
+
+fn default_loot_drop_quantity() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct LootDropDefinition {
+    pub item_id: String,
+    /// Probability in `(0, 1]` that this drop is rolled at all.
+    #[serde(default = "default_loot_drop_chance")]
+    pub chance: f32,
+    #[serde(default = "default_loot_drop_quantity")]
+    pub min_quantity: u32,
+    #[serde(default = "default_loot_drop_quantity")]
+    pub max_quantity: u32,
+}
+
+impl Default for LootDropDefinition {
+    fn default() -> Self {
+        Self {
+            item_id: String::new(),
+            chance: default_loot_drop_chance(),
+            min_quantity: default_loot_drop_quantity(),
+            max_quantity: default_loot_drop_quantity(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct LootTableDefinition {
+    pub loot_table_id: String,
+    /// Destruction profiles that fall back to this table when the destroyed
+    /// entity's `Destructible` names no `loot_table_id`.
+    #[serde(default)]
+    pub destruction_profile_ids: Vec<String>,
+    pub drops: Vec<LootDropDefinition>,
+    /// Display name for the container spawned from this table.
+    #[serde(default)]
+    pub wreck_display_name: Option<String>,
+}
+
+impl LootTableDefinition {
+    /// Rolls every drop with a hash of `seed_key`, so the same destroyed
+    /// entity always yields the same loot.
+    pub fn roll(&self, seed_key: &str, items: Option<&ItemRegistry>) -> Inventory {
+        let mut inventory = Inventory::default();
+        for (index, drop) in self.drops.iter().enumerate() {
+            let salt = (index as u64) << 1;
+            if loot_roll01(seed_key, &self.loot_table_id, salt) >= drop.chance {
+                continue;
+            }
+            let min_quantity = drop.min_quantity.min(drop.max_quantity);
+            let span = u64::from(drop.max_quantity - min_quantity) + 1;
+            let quantity = min_quantity
+                + (loot_hash_u64(seed_key, &self.loot_table_id, salt | 1) % span) as u32;
+            if quantity == 0 {
+                continue;
+            }
+            let unit_mass_kg = items
+                .and_then(|items| items.item(&drop.item_id))
+                .map(|item| item.unit_mass_kg)
+                .unwrap_or(1.0);
+            inventory.add_stack(&drop.item_id, quantity, unit_mass_kg);
+        }
+        inventory
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct LootTableRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub tables: Vec<LootTableDefinition>,
+}
+
+impl LootTableRegistry {
+    pub fn table(&self, loot_table_id: &str) -> Option<&LootTableDefinition> {
+        self.tables
+            .iter()
+            .find(|table| table.loot_table_id == loot_table_id)
+    }
+
+    pub fn table_for_destruction_profile(
+        &self,
+        destruction_profile_id: &str,
+    ) -> Option<&LootTableDefinition> {
+        self.tables.iter().find(|table| {
+            table
+                .destruction_profile_ids
+                .iter()
+                .any(|profile_id| profile_id == destruction_profile_id)
+        })
+    }
+
+    /// Picks the explicit `loot_table_id` when set, otherwise the table keyed
+    /// by the destruction profile.
+    pub fn resolve(
+        &self,
+        loot_table_id: Option<&str>,
+        destruction_profile_id: &str,
+    ) -> Option<&LootTableDefinition> {
+        match loot_table_id {
+            Some(loot_table_id) => self.table(loot_table_id),
+            None => self.table_for_destruction_profile(destruction_profile_id),
+        }
+    }
+}
+
+fn loot_hash_u64(seed_key: &str, loot_table_id: &str, salt: u64) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64 ^ salt.wrapping_mul(0x9e3779b97f4a7c15);
+    for byte in seed_key.bytes().chain([0]).chain(loot_table_id.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    // Final avalanche so neighbouring salts decorrelate.
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^ (hash >> 33)
+}
+
+fn loot_roll01(seed_key: &str, loot_table_id: &str, salt: u64) -> f32 {
+    let value = loot_hash_u64(seed_key, loot_table_id, salt);
+    ((value >> 11) as f64 / ((1u64 << 53) as f64)) as f32
+}
+
+fn default_market_spread() -> f32 {
+    0.1
+}
+
+fn default_market_price_elasticity() -> f32 {
+    0.5
+}
+
+fn default_market_min_price_ratio() -> f32 {
+    0.5
+}
+
+fn default_market_max_price_ratio() -> f32 {
+    2.0
+}
+
+fn default_market_price_drift_per_s() -> f32 {
+    0.02
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct MarketListingDefinition {
+    pub item_id: String,
+    /// Mid price in credits when stock sits at `target_stock`.
+    pub base_price: u32,
+    /// The market sells this item to players.
+    #[serde(default = "default_true")]
+    pub sells: bool,
+    /// The market buys this item from players.
+    #[serde(default = "default_true")]
+    pub buys: bool,
+    pub initial_stock: u32,
+    pub target_stock: u32,
+    /// Stock ceiling; the market stops buying once it is reached.
+    pub max_stock: u32,
+    /// Units per second the NPC economy adds or removes to pull stock back
+    /// toward `target_stock`.
+    #[serde(default)]
+    pub restock_per_s: f32,
+}
+
+impl Default for MarketListingDefinition {
+    fn default() -> Self {
+        Self {
+            item_id: String::new(),
+            base_price: 0,
+            sells: true,
+            buys: true,
+            initial_stock: 0,
+            target_stock: 0,
+            max_stock: 0,
+            restock_per_s: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct MarketDefinition {
+    pub market_id: String,
+    pub display_name: String,
+    /// Fraction added to the mid price when the market sells and taken off
+    /// when it buys.
+    #[serde(default = "default_market_spread")]
+    pub spread: f32,
+    /// How strongly the stock-to-target ratio moves the equilibrium price.
+    #[serde(default = "default_market_price_elasticity")]
+    pub price_elasticity: f32,
+    #[serde(default = "default_market_min_price_ratio")]
+    pub min_price_ratio: f32,
+    #[serde(default = "default_market_max_price_ratio")]
+    pub max_price_ratio: f32,
+    /// Fraction of the gap to the equilibrium price closed per second.
+    #[serde(default = "default_market_price_drift_per_s")]
+    pub price_drift_per_s: f32,
+    pub listings: Vec<MarketListingDefinition>,
+}
+
+impl Default for MarketDefinition {
+    fn default() -> Self {
+        Self {
+            market_id: String::new(),
+            display_name: String::new(),
+            spread: default_market_spread(),
+            price_elasticity: default_market_price_elasticity(),
+            min_price_ratio: default_market_min_price_ratio(),
+            max_price_ratio: default_market_max_price_ratio(),
+            price_drift_per_s: default_market_price_drift_per_s(),
+            listings: Vec::new(),
+        }
+    }
+}
+
+impl MarketDefinition {
+    pub fn listing(&self, item_id: &str) -> Option<&MarketListingDefinition> {
+        self.listings
+            .iter()
+            .find(|listing| listing.item_id == item_id)
+    }
+
+    /// Mid price the market drifts toward at the given stock level: scarce
+    /// goods get dearer, gluts get cheaper, within the configured ratios.
+    pub fn equilibrium_price(&self, listing: &MarketListingDefinition, stock: u32) -> f64 {
+        let base_price = f64::from(listing.base_price);
+        let supply_ratio = f64::from(listing.target_stock.max(1)) / f64::from(stock.max(1));
+        let ratio = supply_ratio
+            .powf(f64::from(self.price_elasticity.max(0.0)))
+            .clamp(
+                f64::from(self.min_price_ratio.max(0.0)),
+                f64::from(self.max_price_ratio.max(self.min_price_ratio)),
+            );
+        base_price * ratio
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct MarketRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub markets: Vec<MarketDefinition>,
+}
+
+impl MarketRegistry {
+    pub fn market(&self, market_id: &str) -> Option<&MarketDefinition> {
+        self.markets
+            .iter()
+            .find(|market| market.market_id == market_id)
+    }
+}
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FactionStanding {
+    Hostile,
+    #[default]
+    Neutral,
+    Allied,
+}
+
+impl FactionStanding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hostile => "hostile",
+            Self::Neutral => "neutral",
+            Self::Allied => "allied",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "hostile" => Some(Self::Hostile),
+            "neutral" => Some(Self::Neutral),
+            "allied" => Some(Self::Allied),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct FactionDefinition {
+    pub faction_id: String,
+    pub display_name: String,
+    /// Reputation change applied to a player's standing with this faction when
+    /// they destroy one of its members. Usually negative.
+    #[serde(default = "default_member_kill_reputation_delta")]
+    pub member_kill_reputation_delta: f32,
+}
+
+fn default_member_kill_reputation_delta() -> f32 {
+    -10.0
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct FactionRelationDefinition {
+    pub faction_a: String,
+    pub faction_b: String,
+    pub standing: FactionStanding,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct FriendlyFirePolicy {
+    /// Members of the same faction may damage each other.
+    #[serde(default)]
+    pub allow_same_faction_damage: bool,
+    /// Members of allied factions may damage each other.
+    #[serde(default)]
+    pub allow_allied_damage: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct FactionReputationRules {
+    /// Fraction of a member-kill delta also applied to the victim faction's
+    /// allies.
+    #[serde(default = "default_ally_share")]
+    pub ally_share: f32,
+    /// Fraction of a member-kill delta applied, sign-flipped, to factions
+    /// hostile to the victim.
+    #[serde(default = "default_enemy_share")]
+    pub enemy_share: f32,
+    /// A player at or below this reputation is treated as hostile by the
+    /// faction regardless of the faction matrix.
+    #[serde(default = "default_hostile_threshold")]
+    pub hostile_threshold: f32,
+    #[serde(default = "default_reputation_min")]
+    pub min: f32,
+    #[serde(default = "default_reputation_max")]
+    pub max: f32,
+}
+
+fn default_ally_share() -> f32 {
+    0.5
+}
+
+fn default_enemy_share() -> f32 {
+    0.25
+}
+
+fn default_hostile_threshold() -> f32 {
+    -100.0
+}
+
+fn default_reputation_min() -> f32 {
+    -1000.0
+}
+
+fn default_reputation_max() -> f32 {
+    1000.0
+}
+
+impl Default for FactionReputationRules {
+    fn default() -> Self {
+        Self {
+            ally_share: default_ally_share(),
+            enemy_share: default_enemy_share(),
+            hostile_threshold: default_hostile_threshold(),
+            min: default_reputation_min(),
+            max: default_reputation_max(),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct FactionRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub factions: Vec<FactionDefinition>,
+    /// Symmetric pairwise standings. Pairs not listed use `default_standing`;
+    /// a faction is always allied with itself.
+    #[serde(default)]
+    pub relations: Vec<FactionRelationDefinition>,
+    #[serde(default)]
+    pub default_standing: FactionStanding,
+    #[serde(default)]
+    pub friendly_fire: FriendlyFirePolicy,
+    #[serde(default)]
+    pub reputation: FactionReputationRules,
+}
+
+impl FactionRegistry {
+    pub fn faction(&self, faction_id: &str) -> Option<&FactionDefinition> {
+        self.factions
+            .iter()
+            .find(|faction| faction.faction_id == faction_id)
+    }
+
+    pub fn standing(&self, faction_a: &str, faction_b: &str) -> FactionStanding {
+        if faction_a == faction_b {
+            return FactionStanding::Allied;
+        }
+        self.relations
+            .iter()
+            .find(|relation| {
+                (relation.faction_a == faction_a && relation.faction_b == faction_b)
+                    || (relation.faction_a == faction_b && relation.faction_b == faction_a)
+            })
+            .map_or(self.default_standing, |relation| relation.standing)
+    }
+
+    /// Standing of `target_faction` toward a player in `player_faction`,
+    /// dropping to hostile once the player's reputation with the target
+    /// faction falls to the hostile threshold.
+    pub fn standing_toward_player(
+        &self,
+        player_faction: Option<&str>,
+        player_reputation: Option<f32>,
+        target_faction: &str,
+    ) -> FactionStanding {
+        if player_reputation.is_some_and(|value| value <= self.reputation.hostile_threshold) {
+            return FactionStanding::Hostile;
+        }
+        player_faction.map_or(self.default_standing, |player_faction| {
+            self.standing(player_faction, target_faction)
+        })
+    }
+
+    /// Friendly-fire policy: damage between unaffiliated entities or
+    /// non-allied factions always applies.
+    pub fn allows_damage(
+        &self,
+        shooter_faction: Option<&str>,
+        target_faction: Option<&str>,
+    ) -> bool {
+        let (Some(shooter_faction), Some(target_faction)) = (shooter_faction, target_faction)
+        else {
+            return true;
+        };
+        if shooter_faction == target_faction {
+            return self.friendly_fire.allow_same_faction_damage;
+        }
+        self.standing(shooter_faction, target_faction) != FactionStanding::Allied
+            || self.friendly_fire.allow_allied_damage
+    }
+
+    /// Reputation deltas a player earns for destroying a member of
+    /// `victim_faction`: the victim faction itself, its allies, and (with the
+    /// sign flipped) factions hostile to it.
+    pub fn kill_reputation_deltas(&self, victim_faction: &str) -> Vec<(String, f32)> {
+        let Some(victim) = self.faction(victim_faction) else {
+            return Vec::new();
+        };
+        let base_delta = victim.member_kill_reputation_delta;
+        let mut deltas = vec![(victim.faction_id.clone(), base_delta)];
+        for faction in &self.factions {
+            if faction.faction_id == victim.faction_id {
+                continue;
+            }
+            let delta = match self.standing(&victim.faction_id, &faction.faction_id) {
+                FactionStanding::Allied => base_delta * self.reputation.ally_share,
+                FactionStanding::Hostile => -base_delta * self.reputation.enemy_share,
+                FactionStanding::Neutral => 0.0,
+            };
+            if delta != 0.0 {
+                deltas.push((faction.faction_id.clone(), delta));
+            }
+        }
+        deltas
+    }
+}
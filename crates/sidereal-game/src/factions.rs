@@ -0,0 +1,245 @@
+//! Faction standings, friendly fire and kill reputation
+//!
+//! An entity's faction is its own `FactionId`, or failing that the `FactionId`
+//! of the player named by its `OwnerId`. Server damage paths ask
+//! `FactionDamagePolicy` before applying damage, so the `FactionRegistry`
+//! friendly-fire policy decides whether same-faction and allied hits count.
+//! `credit_entity_kills` credits an entity that starts destruction to the
+//! player behind the last damaging hit on it within `KILL_CREDIT_WINDOW_S`,
+//! emitting one `EntityKillCreditedEvent`. `apply_kill_reputation` reads it
+//! to adjust that player's `FactionReputation` with the victim's faction and
+//! its allies and enemies; mission objectives read the same event.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    CombatAuthorityEnabled, EntityDestructionStartedEvent, EntityGuid, EntityKillCreditedEvent,
+    FactionId, FactionRegistry, FactionReputation, OwnerId, PlayerTag, ShotHitEvent,
+};
+
+/// A kill is only credited when the last damaging hit on the victim landed
+/// within this window.
+pub const KILL_CREDIT_WINDOW_S: f64 = 30.0;
+
+pub type FactionAffiliationQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static EntityGuid>,
+        Option<&'static FactionId>,
+        Option<&'static OwnerId>,
+        Has<PlayerTag>,
+    ),
+>;
+
+/// Faction and ownership lookups over a `FactionAffiliationQuery`. Players are
+/// indexed by GUID once per system run so per-hit owner lookups stay O(1).
+pub struct FactionAffiliations<'a, 'w, 's> {
+    query: &'a FactionAffiliationQuery<'w, 's>,
+    player_by_guid: HashMap<Uuid, Entity>,
+}
+
+impl<'a, 'w, 's> FactionAffiliations<'a, 'w, 's> {
+    pub fn new(query: &'a FactionAffiliationQuery<'w, 's>) -> Self {
+        let player_by_guid = query
+            .iter()
+            .filter(|(_, _, _, _, is_player)| *is_player)
+            .filter_map(|(entity, guid, ..)| guid.map(|guid| (guid.0, entity)))
+            .collect();
+        Self {
+            query,
+            player_by_guid,
+        }
+    }
+
+    pub fn entity_guid(&self, entity: Entity) -> Option<Uuid> {
+        let (_, guid, ..) = self.query.get(entity).ok()?;
+        guid.map(|guid| guid.0)
+    }
+
+    fn player_entity_for_owner(&self, owner_id: &str) -> Option<Entity> {
+        let owner_guid = Uuid::parse_str(owner_id).ok()?;
+        self.player_by_guid.get(&owner_guid).copied()
+    }
+}
+
+/// The faction `entity` fights for: its own `FactionId`, else its owning
+/// player's.
+pub fn affiliated_faction(
+    entity: Entity,
+    affiliations: &FactionAffiliations<'_, '_, '_>,
+) -> Option<String> {
+    let (_, _, faction_id, owner_id, _) = affiliations.query.get(entity).ok()?;
+    if let Some(faction_id) = faction_id {
+        return Some(faction_id.0.clone());
+    }
+    let player_entity = affiliations.player_entity_for_owner(&owner_id?.0)?;
+    let (_, _, player_faction, _, _) = affiliations.query.get(player_entity).ok()?;
+    player_faction.map(|faction_id| faction_id.0.clone())
+}
+
+/// The player credited for actions taken by `entity`: the entity itself when
+/// it is a player, else the player named by its `OwnerId`.
+pub fn responsible_player(
+    entity: Entity,
+    affiliations: &FactionAffiliations<'_, '_, '_>,
+) -> Option<Entity> {
+    let (_, _, _, owner_id, is_player) = affiliations.query.get(entity).ok()?;
+    if is_player {
+        return Some(entity);
+    }
+    affiliations.player_entity_for_owner(&owner_id?.0)
+}
+
+/// Friendly-fire gate for the server damage paths. Without a registry every
+/// hit applies.
+pub fn faction_damage_allowed(
+    registry: Option<&FactionRegistry>,
+    shooter: Option<Entity>,
+    target: Entity,
+    affiliations: &FactionAffiliations<'_, '_, '_>,
+) -> bool {
+    let Some(registry) = registry else {
+        return true;
+    };
+    let Some(target_faction) = affiliated_faction(target, affiliations) else {
+        return true;
+    };
+    let shooter_faction = shooter.and_then(|shooter| affiliated_faction(shooter, affiliations));
+    registry.allows_damage(shooter_faction.as_deref(), Some(target_faction.as_str()))
+}
+
+/// `faction_damage_allowed` as a single system parameter for the damage
+/// systems. Call `gate` once per run, then `allows` per hit.
+#[derive(SystemParam)]
+pub struct FactionDamagePolicy<'w, 's> {
+    registry: Option<Res<'w, FactionRegistry>>,
+    affiliations: FactionAffiliationQuery<'w, 's>,
+}
+
+impl<'w, 's> FactionDamagePolicy<'w, 's> {
+    pub fn gate(&self) -> FactionDamageGate<'_, 'w, 's> {
+        FactionDamageGate {
+            registry: self.registry.as_deref(),
+            affiliations: FactionAffiliations::new(&self.affiliations),
+        }
+    }
+}
+
+/// A `FactionDamagePolicy` with its player index built for this run.
+pub struct FactionDamageGate<'a, 'w, 's> {
+    registry: Option<&'a FactionRegistry>,
+    affiliations: FactionAffiliations<'a, 'w, 's>,
+}
+
+impl FactionDamageGate<'_, '_, '_> {
+    pub fn allows(&self, shooter: Option<Entity>, target: Entity) -> bool {
+        faction_damage_allowed(self.registry, shooter, target, &self.affiliations)
+    }
+}
+
+/// Last damaging hit per target, kept across ticks by `credit_entity_kills`.
+#[derive(Debug, Default)]
+pub struct KillCreditTracker {
+    /// Target -> (shooter guid, elapsed seconds of the hit).
+    last_hit_by_target: HashMap<Entity, (Uuid, f64)>,
+}
+
+pub fn credit_entity_kills(
+    mut tracker: Local<'_, KillCreditTracker>,
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut hit_events: MessageReader<'_, '_, ShotHitEvent>,
+    mut started_events: MessageReader<'_, '_, EntityDestructionStartedEvent>,
+    affiliation_query: FactionAffiliationQuery<'_, '_>,
+    mut credited_events: MessageWriter<'_, EntityKillCreditedEvent>,
+) {
+    let now_s = time.elapsed().as_secs_f64();
+    for hit in hit_events.read() {
+        if hit.damage > 0.0 {
+            tracker
+                .last_hit_by_target
+                .insert(hit.target_entity, (hit.shooter_guid, now_s));
+        }
+    }
+    let started = started_events.read().cloned().collect::<Vec<_>>();
+    if !authority_enabled.is_none_or(|value| value.0) {
+        tracker.last_hit_by_target.clear();
+        return;
+    }
+
+    let affiliations = FactionAffiliations::new(&affiliation_query);
+    let entity_by_guid = affiliation_query
+        .iter()
+        .filter_map(|(entity, guid, ..)| guid.map(|guid| (guid.0, entity)))
+        .collect::<HashMap<_, _>>();
+    for event in started {
+        let Some((shooter_guid, hit_at_s)) = tracker.last_hit_by_target.remove(&event.entity)
+        else {
+            continue;
+        };
+        if now_s - hit_at_s > KILL_CREDIT_WINDOW_S {
+            continue;
+        }
+        let Some(player) = entity_by_guid
+            .get(&shooter_guid)
+            .and_then(|shooter| responsible_player(*shooter, &affiliations))
+        else {
+            continue;
+        };
+        if responsible_player(event.entity, &affiliations) == Some(player) {
+            continue;
+        }
+        credited_events.write(EntityKillCreditedEvent {
+            victim_entity: event.entity,
+            victim_guid: event.entity_guid,
+            victim_faction: affiliated_faction(event.entity, &affiliations),
+            shooter_guid,
+            player_entity: player,
+        });
+    }
+    tracker
+        .last_hit_by_target
+        .retain(|_, (_, hit_at_s)| now_s - *hit_at_s <= KILL_CREDIT_WINDOW_S);
+}
+
+pub fn apply_kill_reputation(
+    mut commands: Commands<'_, '_>,
+    registry: Option<Res<'_, FactionRegistry>>,
+    mut credited_events: MessageReader<'_, '_, EntityKillCreditedEvent>,
+    mut reputations: Query<'_, '_, &'_ mut FactionReputation, With<PlayerTag>>,
+) {
+    let credited = credited_events.read().cloned().collect::<Vec<_>>();
+    let Some(registry) = registry else {
+        return;
+    };
+
+    let mut new_reputations = HashMap::<Entity, FactionReputation>::new();
+    for event in credited {
+        let Some(victim_faction) = event.victim_faction else {
+            continue;
+        };
+        let player = event.player_entity;
+        for (faction_id, delta) in registry.kill_reputation_deltas(&victim_faction) {
+            let rules = registry.reputation;
+            if let Ok(mut reputation) = reputations.get_mut(player) {
+                reputation.adjust(&faction_id, delta, rules.min, rules.max);
+            } else {
+                new_reputations.entry(player).or_default().adjust(
+                    &faction_id,
+                    delta,
+                    rules.min,
+                    rules.max,
+                );
+            }
+        }
+    }
+
+    for (player, reputation) in new_reputations {
+        commands.entity(player).insert(reputation);
+    }
+}
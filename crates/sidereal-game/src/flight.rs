@@ -8,19 +8,26 @@
 //! 2. Engine modules mounted on parent read control state
 //! 3. Engines check fuel availability via FuelTank
 //! 4. If fuel available: compute force vector, apply via Avian's Forces query helper, drain fuel
-//! 5. Avian's physics integrator handles the rest
+//! 5. Engines with a `PowerConsumer` draw from the hull's `ShipPowerState` for the share of
+//!    thrust their fuel covers (dry engines draw nothing); thrust scales with the power
+//!    delivered and drops further (with no afterburner) while the hull is overheated
+//! 6. Engines with a `ModuleHealth` scale their thrust caps by `module_performance`; destroyed
+//!    engines contribute nothing
+//! 7. Avian's physics integrator handles the rest
 
 use avian2d::prelude::*;
 use bevy::math::DVec2;
 use bevy::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+use crate::subsystems::module_performance;
 use crate::{
     ActionQueue, AfterburnerCapability, AfterburnerState, Engine, EntityAction, EntityGuid,
     FlightComputer, FlightControlAuthority, FlightFuelConsumptionEnabled, FlightTuning, FuelTank,
-    MaxVelocityMps, MountedOn, PlayerTag, ScriptNavigationTarget, SimulationMotionWriter, SizeM,
-    TotalMassKg, WorldPosition, resolve_world_position,
+    MaxVelocityMps, ModuleHealth, MountedOn, PlayerTag, PowerConsumer, ScriptNavigationTarget,
+    ShipPowerSettings, ShipPowerState, SimulationMotionWriter, SizeM, TotalMassKg, WorldPosition,
+    resolve_world_position,
 };
 
 const PASSIVE_ANGULAR_DAMP_RATE: f32 = 4.0;
@@ -234,10 +241,11 @@ fn resolve_navigation_forward(rotation: Option<&Rotation>, transform: Option<&Tr
 
 /// System that applies engine thrust based on FlightComputer state
 /// Uses Avian's Forces query helper for proper force integration
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn apply_engine_thrust(
     time: Res<Time<Fixed>>,
     fuel_consumption_enabled: Option<Res<FlightFuelConsumptionEnabled>>,
+    power_settings: Option<Res<ShipPowerSettings>>,
     // Hull entities with flight computers (by GUID)
     computers: Query<
         (&EntityGuid, &FlightComputer, Option<&AfterburnerState>),
@@ -246,14 +254,28 @@ pub fn apply_engine_thrust(
     // Parent entities that can receive forces (Avian Forces query helper)
     mut body_queries: ParamSet<(BodyForceQuery<'_, '_>, BodyKinematicsQuery<'_, '_>)>,
     // Engine and fuel modules (mounted under a shared parent GUID)
-    engines: Query<(&MountedOn, &Engine, Option<&AfterburnerCapability>)>,
+    engines: Query<(
+        &MountedOn,
+        &Engine,
+        Option<&AfterburnerCapability>,
+        Option<&PowerConsumer>,
+        Option<&ModuleHealth>,
+    )>,
     mut fuel_tanks: Query<(&MountedOn, &mut FuelTank)>,
+    // Hull power budgets (hulls without one are unconstrained)
+    mut power_states: Query<(&EntityGuid, &mut ShipPowerState)>,
 ) {
     let dt = time.delta_secs();
     let consume_fuel = fuel_consumption_enabled
         .as_deref()
         .map(|flag| flag.0)
         .unwrap_or(true);
+    let power_settings = power_settings.as_deref().copied().unwrap_or_default();
+    let overheated_parents = power_states
+        .iter()
+        .filter(|(_, power)| power.overheated)
+        .map(|(guid, _)| guid.0)
+        .collect::<HashSet<_>>();
 
     // Build control state by root parent GUID from hull flight-computer only.
     let mut control_by_parent = HashMap::<Uuid, (f32, f32, f32, bool, bool)>::new();
@@ -281,13 +303,19 @@ pub fn apply_engine_thrust(
     let mut fuel_available_kg_by_parent = HashMap::<Uuid, f32>::new();
     let mut fuel_tank_count_by_parent = HashMap::<Uuid, usize>::new();
     let mut fuel_exhausted_count = HashMap::<Uuid, usize>::new();
+    let mut requested_power_by_parent = HashMap::<Uuid, (f32, f32)>::new();
 
-    for (mounted_on, engine, afterburner_capability) in &engines {
+    for (mounted_on, engine, afterburner_capability, power_consumer, module_health) in &engines {
         let Some((throttle, yaw_input, _, brake_active, afterburner_active)) =
             control_by_parent.get(&mounted_on.parent_entity_id)
         else {
             continue;
         };
+        // Damaged engines deliver a proportional share of rated thrust; destroyed ones none.
+        let performance = module_performance(module_health);
+        if performance <= 0.0 {
+            continue;
+        }
 
         let throttle_demand = throttle.abs().clamp(0.0, 1.0);
         let brake_demand = if *brake_active { 1.0 } else { 0.0 };
@@ -295,7 +323,8 @@ pub fn apply_engine_thrust(
         let demand = throttle_demand.max(brake_demand).max(yaw_demand);
         let can_afterburn = *throttle > 0.0
             && *afterburner_active
-            && afterburner_capability.is_some_and(|cap| cap.enabled);
+            && afterburner_capability.is_some_and(|cap| cap.enabled)
+            && !overheated_parents.contains(&mounted_on.parent_entity_id);
         let burn_multiplier = if can_afterburn {
             afterburner_capability
                 .map(|cap| cap.fuel_burn_multiplier.max(1.0))
@@ -313,22 +342,30 @@ pub fn apply_engine_thrust(
         let requested_burn_kg = engine.burn_rate_kg_s * demand * burn_multiplier * dt;
 
         if requested_burn_kg > 0.0 {
+            if let Some(consumer) = power_consumer {
+                let power_demand = demand * burn_multiplier * dt;
+                let entry = requested_power_by_parent
+                    .entry(mounted_on.parent_entity_id)
+                    .or_default();
+                entry.0 += consumer.draw_mw.max(0.0) * power_demand;
+                entry.1 += consumer.heat_per_s.max(0.0) * power_demand;
+            }
             requested_burn_kg_by_parent
                 .entry(mounted_on.parent_entity_id)
                 .and_modify(|v| *v += requested_burn_kg)
                 .or_insert(requested_burn_kg);
             forward_thrust_cap_by_parent
                 .entry(mounted_on.parent_entity_id)
-                .and_modify(|v| *v += engine.thrust.abs() * thrust_multiplier)
-                .or_insert(engine.thrust.abs() * thrust_multiplier);
+                .and_modify(|v| *v += engine.thrust.abs() * thrust_multiplier * performance)
+                .or_insert(engine.thrust.abs() * thrust_multiplier * performance);
             reverse_thrust_cap_by_parent
                 .entry(mounted_on.parent_entity_id)
-                .and_modify(|v| *v += engine.reverse_thrust.abs())
-                .or_insert(engine.reverse_thrust.abs());
+                .and_modify(|v| *v += engine.reverse_thrust.abs() * performance)
+                .or_insert(engine.reverse_thrust.abs() * performance);
             torque_thrust_cap_by_parent
                 .entry(mounted_on.parent_entity_id)
-                .and_modify(|v| *v += engine.torque_thrust.abs())
-                .or_insert(engine.torque_thrust.abs());
+                .and_modify(|v| *v += engine.torque_thrust.abs() * performance)
+                .or_insert(engine.torque_thrust.abs() * performance);
             if can_afterburn
                 && let Some(cap) = afterburner_capability
                     .and_then(|capability| capability.max_afterburner_velocity_mps)
@@ -355,36 +392,63 @@ pub fn apply_engine_thrust(
             .or_insert(1);
     }
 
-    // Compute fuel-limited thrust budgets by parent.
-    let mut forward_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
-    let mut reverse_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
-    let mut torque_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
+    // Resolve how much of each parent's requested burn the tanks can cover.
+    let mut fuel_scale_by_parent = HashMap::<Uuid, f32>::new();
     let mut fuel_burn_kg_by_parent = HashMap::<Uuid, f32>::new();
     for (parent, requested_burn_kg) in &requested_burn_kg_by_parent {
         let available = fuel_available_kg_by_parent
             .get(parent)
             .copied()
             .unwrap_or(0.0);
-        let (actual_burn_kg, thrust_scale) = if consume_fuel {
+        let (actual_burn_kg, fuel_scale) = if consume_fuel {
             let actual_burn_kg = requested_burn_kg.min(available).max(0.0);
-            let thrust_scale = if *requested_burn_kg > 0.0 {
+            let fuel_scale = if *requested_burn_kg > 0.0 {
                 actual_burn_kg / *requested_burn_kg
             } else {
                 0.0
             };
-            (actual_burn_kg, thrust_scale)
+            (actual_burn_kg, fuel_scale)
         } else if *requested_burn_kg > 0.0 {
             (0.0, 1.0)
         } else {
             (0.0, 0.0)
         };
-        if consume_fuel && thrust_scale <= 0.0 {
+        if consume_fuel && fuel_scale <= 0.0 {
             let empty_count = fuel_tank_count_by_parent.get(parent).copied().unwrap_or(0);
             if empty_count > 0 {
                 fuel_exhausted_count.insert(*parent, empty_count);
             }
         }
         fuel_burn_kg_by_parent.insert(*parent, actual_burn_kg);
+        fuel_scale_by_parent.insert(*parent, fuel_scale);
+    }
+
+    // Draw engine power for the share of thrust the fuel can actually deliver
+    // (dry engines request none) and apply overheat penalties by parent.
+    let mut power_scale_by_parent = HashMap::<Uuid, f32>::new();
+    for (guid, mut power) in &mut power_states {
+        let fuel_scale = fuel_scale_by_parent.get(&guid.0).copied().unwrap_or(0.0);
+        let delivered = requested_power_by_parent
+            .get(&guid.0)
+            .filter(|_| fuel_scale > 0.0)
+            .map(|(energy_mj, heat)| {
+                power.draw_continuous(*energy_mj * fuel_scale, *heat * fuel_scale)
+            })
+            .unwrap_or(1.0);
+        let overheat_scale = if power.overheated {
+            power_settings.overheat_thrust_scale.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        power_scale_by_parent.insert(guid.0, delivered * overheat_scale);
+    }
+
+    // Compute fuel- and power-limited thrust budgets by parent.
+    let mut forward_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
+    let mut reverse_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
+    let mut torque_thrust_budget_by_parent = HashMap::<Uuid, f32>::new();
+    for (parent, fuel_scale) in &fuel_scale_by_parent {
+        let thrust_scale = fuel_scale * power_scale_by_parent.get(parent).copied().unwrap_or(1.0);
         forward_thrust_budget_by_parent.insert(
             *parent,
             forward_thrust_cap_by_parent
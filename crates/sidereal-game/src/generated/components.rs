@@ -62,6 +62,28 @@ pub struct GeneratedComponentRegistry {
     pub shader_entries: Vec<ShaderEditorRegistryEntry>,
 }
 
+impl GeneratedComponentRegistry {
+    /// The entries for components the server replicates to clients. Avian
+    /// entries carry no component metadata and always replicate.
+    pub fn replicated_subset(&self) -> Self {
+        let server_only_kinds =
+            ::inventory::iter::<crate::component_meta::SiderealComponentRegistration>
+                .into_iter()
+                .filter(|registration| !registration.meta.replicate)
+                .map(|registration| registration.meta.kind)
+                .collect::<std::collections::HashSet<_>>();
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .filter(|entry| !server_only_kinds.contains(entry.component_kind))
+                .cloned()
+                .collect(),
+            shader_entries: Vec::new(),
+        }
+    }
+}
+
 pub fn register_generated_components(app: &mut App) {
     for registration in ::inventory::iter::<crate::component_meta::SiderealComponentRegistration> {
         (registration.register_reflect)(app);
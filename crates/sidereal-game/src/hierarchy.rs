@@ -50,9 +50,13 @@ fn would_create_parent_cycle(
 /// Entities whose parents have not yet spawned are silently skipped and
 /// retried on subsequent frames (the `Without<ChildOf>` filter re-includes
 /// them automatically).
+///
+/// Entities that lose `ParentGuid` (e.g. a ship undocking) are detached again.
 #[allow(clippy::type_complexity)]
 pub fn sync_mounted_hierarchy(
     mut commands: Commands<'_, '_>,
+    mut removed_parent_guids: RemovedComponents<'_, '_, ParentGuid>,
+    detached: Query<'_, '_, (), (With<ChildOf>, Without<ParentGuid>)>,
     updated_hardpoints: Query<'_, '_, (Entity, &'_ Hardpoint), (With<ChildOf>, Changed<Hardpoint>)>,
     unmounted: Query<
         '_,
@@ -73,6 +77,12 @@ pub fn sync_mounted_hierarchy(
         ),
     >,
 ) {
+    for entity in removed_parent_guids.read() {
+        if detached.contains(entity) {
+            commands.entity(entity).remove::<ChildOf>();
+        }
+    }
+
     for (entity, hardpoint) in &updated_hardpoints {
         commands.entity(entity).insert(
             Transform::from_translation(hardpoint.offset_m).with_rotation(hardpoint.local_rotation),
@@ -214,6 +224,26 @@ mod tests {
         assert!(app.world().entities().contains(parent));
     }
 
+    #[test]
+    fn detaches_child_when_parent_guid_is_removed() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_mounted_hierarchy);
+
+        let parent_guid = Uuid::new_v4();
+        app.world_mut().spawn(EntityGuid(parent_guid));
+        let child = app
+            .world_mut()
+            .spawn((EntityGuid(Uuid::new_v4()), ParentGuid(parent_guid)))
+            .id();
+
+        app.update();
+        assert!(app.world().entity(child).contains::<ChildOf>());
+
+        app.world_mut().entity_mut(child).remove::<ParentGuid>();
+        app.update();
+        assert!(!app.world().entity(child).contains::<ChildOf>());
+    }
+
     #[test]
     fn inserts_missing_spatial_components_on_parent_before_linking_child() {
         let mut app = App::new();
@@ -1,33 +1,68 @@
 use bevy::prelude::*;
 
 pub mod actions;
+pub mod ammo;
 pub mod asteroid_field;
 pub mod asteroid_registry;
+pub mod autopilot;
+pub mod beams;
+pub mod cargo;
 pub mod character_movement;
 pub mod collision_outline_generation;
 pub mod combat;
 pub mod component_meta;
 pub mod components;
+pub mod crafting;
+pub mod credits;
+pub mod docking;
+pub mod economy_registry;
 pub mod editor_schema;
+pub mod faction_registry;
+pub mod factions;
 pub mod flight;
 pub mod generated;
 pub mod hierarchy;
+pub mod markets;
 pub mod mass;
+pub mod mining;
+pub mod missiles;
+pub mod mission_registry;
+pub mod missions;
 pub mod planet_registry;
+pub mod power;
 pub mod procedural_sprite_generation;
 pub mod render_layers;
+pub mod shields;
 pub mod ship_registry;
+pub mod station_services;
+pub mod subsystems;
+pub mod tractor;
 pub mod visibility_range;
 pub mod world_spatial;
 
 // Re-export commonly used items
 pub use actions::*;
+pub use ammo::process_weapon_reloads;
 pub use asteroid_field::{
     AsteroidChildPlan, AsteroidFractureParent, asteroid_child_member_key, asteroid_member_key,
-    build_fracture_child_plans, fracture_child_count, fracture_child_sprite,
-    fracture_depleted_asteroid_members, next_smaller_tier,
+    asteroid_member_resource_units_consumed, build_fracture_child_plans, fracture_child_count,
+    fracture_child_sprite, fracture_depleted_asteroid_members, next_smaller_tier,
+    record_asteroid_member_extraction, roll_asteroid_extraction_yield,
 };
 pub use asteroid_registry::*;
+pub use autopilot::{
+    AutopilotControl, apply_autopilot_to_flight_computers, compute_autopilot_controls,
+    process_autopilot_actions,
+};
+pub use beams::{
+    BeamStartedEvent, BeamStopReason, BeamStoppedEvent, bootstrap_beam_weapon_state,
+    update_beam_weapons,
+};
+pub use cargo::{
+    CargoCollectedEvent, CargoContainerSettings, CargoContainerSpawnedEvent,
+    collect_cargo_containers, drop_cargo_from_destroyed_entities, process_cargo_jettison_actions,
+    spawn_cargo_container,
+};
 pub use character_movement::{
     process_character_movement_actions, sync_player_to_controlled_entity,
 };
@@ -37,22 +72,56 @@ pub use collision_outline_generation::{
     generate_rdp_collision_outline_from_sprite_png,
 };
 pub use combat::{
-    BallisticProjectileSpawnedEvent, EntityDestroyedEvent, EntityDestructionStartedEvent,
-    ShotFiredEvent, ShotHitEvent, ShotImpactResolvedEvent, advance_pending_destructions,
-    apply_damage_from_shot_impacts, begin_pending_destructions, bootstrap_weapon_cooldown_state,
-    process_weapon_fire_actions, resolve_shot_impacts, tick_weapon_cooldowns,
-    update_ballistic_projectiles,
+    BallisticProjectileSpawnedEvent, DirectDamageRequest, EntityDestroyedEvent,
+    EntityDestructionStartedEvent, EntityKillCreditedEvent, RAM_DAMAGE_PER_DELTA_V_MPS,
+    RAM_DAMAGE_SAFE_DELTA_V_MPS, ShotFiredEvent, ShotHitEvent, ShotImpactResolvedEvent,
+    advance_pending_destructions, apply_damage_from_shot_impacts, apply_direct_damage_requests,
+    begin_pending_destructions, bootstrap_weapon_cooldown_state, enable_ram_collision_events,
+    process_weapon_fire_actions, ram_damage_from_impulse, request_ram_collision_damage,
+    resolve_shot_impacts, tick_weapon_cooldowns, update_ballistic_projectiles,
 };
 pub use component_meta::*;
 pub use components::*;
+pub use crafting::{
+    CraftingFailureReason, CraftingJobCompletedEvent, CraftingJobFailedEvent, CraftingJobRequest,
+    advance_crafting_jobs, process_crafting_job_requests,
+};
+pub use credits::{CreditTransactionError, debit_credits, grant_credits, ledger_tick};
+pub use docking::{advance_docking_sequences, process_docking_actions};
+pub use economy_registry::*;
 pub use editor_schema::*;
+pub use faction_registry::*;
+pub use factions::{
+    FactionAffiliationQuery, FactionAffiliations, FactionDamageGate, FactionDamagePolicy,
+    KILL_CREDIT_WINDOW_S, affiliated_faction, apply_kill_reputation, credit_entity_kills,
+    faction_damage_allowed, responsible_player,
+};
 pub use generated::components::*;
 pub use hierarchy::sync_mounted_hierarchy;
+pub use markets::{
+    MarketQuote, MarketTradeCompletedEvent, MarketTradeFailedEvent, MarketTradeFailureReason,
+    MarketTradeRequest, MarketTradeSide, market_quotes, process_market_trades, ship_in_trade_range,
+    update_market_prices,
+};
 pub use mass::{
     bootstrap_collision_profiles_from_aabb, bootstrap_root_dynamic_entity_colliders,
     bootstrap_root_dynamic_mass_components, collider_from_collision_shape, recompute_total_mass,
 };
+pub use mining::{
+    MiningStatus, MiningStatusChangedEvent, ResourceExtractedEvent, extract_asteroid_resources,
+    process_mining_actions,
+};
+pub use missiles::{MISSILE_DETONATION_PROFILE_ID, update_guided_missiles};
+pub use mission_registry::*;
+pub use missions::{
+    MAX_ACTIVE_MISSIONS, MissionCompletedEvent, MissionStartError, advance_mission_objective,
+    pay_mission_reward, start_mission, sync_active_mission, take_completed_missions,
+};
 pub use planet_registry::*;
+pub use power::{
+    ShipPowerSettings, bootstrap_ship_power_state, draw_activation_power, draw_sustained_power,
+    update_ship_power_budget,
+};
 pub use procedural_sprite_generation::{
     ProceduralSpriteImageSet, compute_collision_half_extents_from_procedural_sprite,
     generate_procedural_sprite_image_set, generate_rdp_collision_outline_from_procedural_sprite,
@@ -63,7 +132,17 @@ pub use render_layers::{
     validate_runtime_post_process_stack, validate_runtime_render_layer_definition,
     validate_runtime_render_layer_rule, validate_runtime_world_visual_stack,
 };
+pub use shields::{absorb_damage_with_mounted_shields, process_shield_actions, regenerate_shields};
 pub use ship_registry::*;
+pub use station_services::{
+    StationServiceFailedEvent, StationServiceFailureReason, StationServicePurchasedEvent,
+    process_station_service_actions,
+};
+pub use subsystems::{
+    SubsystemDamageSettings, apply_scanner_module_damage, apply_subsystem_damage,
+    module_performance, process_module_repairs,
+};
+pub use tractor::{apply_tractor_beam_forces, compute_tractor_force, process_tractor_actions};
 pub use visibility_range::{apply_visibility_range_buff, total_visibility_range_for_parent};
 pub use world_spatial::{resolve_world_position, resolve_world_rotation_rad};
 
@@ -147,11 +226,19 @@ impl Plugin for SiderealGameCorePlugin {
             .register_type::<FlightControlAuthority>()
             .register_type::<SimulationMotionWriter>()
             .register_type::<ScriptNavigationTarget>()
+            .register_type::<ScriptWeaponTarget>()
             .register_type::<PlanetRegistryEntry>()
             .register_type::<PlanetSpawnDefinition>()
             .register_type::<PlanetDefinition>()
             .register_type::<PlanetRegistry>()
-            .register_type::<AsteroidRegistry>();
+            .register_type::<AsteroidRegistry>()
+            .register_type::<ItemRegistry>()
+            .register_type::<RecipeRegistry>()
+            .register_type::<FacilityRegistry>()
+            .register_type::<LootTableRegistry>()
+            .register_type::<MarketRegistry>()
+            .register_type::<MissionRegistry>()
+            .register_type::<FactionRegistry>();
     }
 }
 
@@ -195,21 +282,62 @@ impl Plugin for SiderealSharedSimulationPlugin {
                 app.add_systems(
                     FixedUpdate,
                     (
-                        validate_action_capabilities,
-                        process_character_movement_actions,
-                        apply_navigation_targets_to_flight_computers,
-                        process_flight_actions,
-                        bootstrap_weapon_cooldown_state,
-                        tick_weapon_cooldowns,
-                        process_weapon_fire_actions,
-                        update_ballistic_projectiles,
-                        resolve_shot_impacts,
-                        apply_damage_from_shot_impacts,
-                        fracture_depleted_asteroid_members,
-                        begin_pending_destructions,
-                        advance_pending_destructions,
-                        recompute_total_mass,
-                        apply_engine_thrust,
+                        (
+                            validate_action_capabilities,
+                            process_character_movement_actions,
+                            apply_navigation_targets_to_flight_computers,
+                            process_autopilot_actions,
+                            process_flight_actions,
+                            apply_autopilot_to_flight_computers,
+                            process_shield_actions,
+                            process_tractor_actions,
+                            process_mining_actions,
+                            process_docking_actions,
+                            process_cargo_jettison_actions,
+                            process_station_service_actions,
+                            process_market_trades,
+                        )
+                            .chain(),
+                        (
+                            bootstrap_weapon_cooldown_state,
+                            bootstrap_beam_weapon_state,
+                            tick_weapon_cooldowns,
+                            process_weapon_reloads,
+                            process_module_repairs,
+                            bootstrap_ship_power_state,
+                            update_ship_power_budget,
+                            process_weapon_fire_actions,
+                            update_beam_weapons,
+                            update_ballistic_projectiles,
+                            update_guided_missiles,
+                            resolve_shot_impacts,
+                            apply_damage_from_shot_impacts,
+                            enable_ram_collision_events,
+                            request_ram_collision_damage,
+                            apply_direct_damage_requests,
+                            apply_subsystem_damage,
+                            apply_scanner_module_damage,
+                            regenerate_shields,
+                        )
+                            .chain(),
+                        (
+                            extract_asteroid_resources,
+                            process_crafting_job_requests,
+                            advance_crafting_jobs,
+                            update_market_prices,
+                            fracture_depleted_asteroid_members,
+                            begin_pending_destructions,
+                            credit_entity_kills,
+                            apply_kill_reputation,
+                            advance_pending_destructions,
+                            drop_cargo_from_destroyed_entities,
+                            collect_cargo_containers,
+                            recompute_total_mass,
+                            apply_engine_thrust,
+                            apply_tractor_beam_forces,
+                            advance_docking_sequences,
+                        )
+                            .chain(),
                     )
                         .chain()
                         .in_set(SiderealSimulationSet::SimulateGameplay),
@@ -231,9 +359,12 @@ impl Plugin for SiderealSharedSimulationPlugin {
                     (
                         validate_action_capabilities,
                         process_character_movement_actions,
+                        process_autopilot_actions,
                         process_flight_actions,
+                        apply_autopilot_to_flight_computers,
                         bootstrap_weapon_cooldown_state,
                         tick_weapon_cooldowns,
+                        update_ship_power_budget,
                         process_weapon_fire_actions,
                         update_ballistic_projectiles,
                         apply_engine_thrust,
@@ -280,12 +411,46 @@ impl Plugin for SiderealGamePlugin {
         {
             app.insert_resource(FlightFuelConsumptionEnabled::default());
         }
+        if app
+            .world()
+            .get_resource::<CargoContainerSettings>()
+            .is_none()
+        {
+            app.insert_resource(CargoContainerSettings::default());
+        }
+        if app.world().get_resource::<ShipPowerSettings>().is_none() {
+            app.insert_resource(ShipPowerSettings::default());
+        }
+        if app
+            .world()
+            .get_resource::<SubsystemDamageSettings>()
+            .is_none()
+        {
+            app.insert_resource(SubsystemDamageSettings::default());
+        }
         app.add_message::<ShotFiredEvent>();
         app.add_message::<ShotImpactResolvedEvent>();
         app.add_message::<ShotHitEvent>();
+        app.add_message::<DirectDamageRequest>();
         app.add_message::<BallisticProjectileSpawnedEvent>();
+        app.add_message::<BeamStartedEvent>();
+        app.add_message::<BeamStoppedEvent>();
         app.add_message::<EntityDestructionStartedEvent>();
         app.add_message::<EntityDestroyedEvent>();
+        app.add_message::<EntityKillCreditedEvent>();
+        app.add_message::<ResourceExtractedEvent>();
+        app.add_message::<MiningStatusChangedEvent>();
+        app.add_message::<CraftingJobRequest>();
+        app.add_message::<CraftingJobFailedEvent>();
+        app.add_message::<CraftingJobCompletedEvent>();
+        app.add_message::<CargoContainerSpawnedEvent>();
+        app.add_message::<CargoCollectedEvent>();
+        app.add_message::<StationServicePurchasedEvent>();
+        app.add_message::<StationServiceFailedEvent>();
+        app.add_message::<MarketTradeRequest>();
+        app.add_message::<MarketTradeCompletedEvent>();
+        app.add_message::<MarketTradeFailedEvent>();
+        app.add_message::<MissionCompletedEvent>();
 
         let add_hierarchy_rebuild = app
             .world()
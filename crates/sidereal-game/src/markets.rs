@@ -0,0 +1,505 @@
+//! NPC markets
+//!
+//! Station modules carrying a `Market` trade the goods listed in their
+//! `MarketRegistry` definition. Each listing keeps live stock and a mid price:
+//! every fixed tick the NPC economy pulls stock back toward `target_stock` at
+//! `restock_per_s`, and the price drifts toward the equilibrium for the
+//! current stock, so goods players buy out get dearer and goods they dump get
+//! cheaper. Players pay the mid price plus the definition's `spread` and are
+//! paid the mid price minus it.
+//!
+//! `MarketTradeRequest` messages buy or sell for a ship docked at the
+//! market's station, or within its `trade_range_m`. Trades run on the server
+//! only and are checked against the market's stock and stock ceiling, the
+//! ship's cargo and `CargoCapacity`, and the responsible player's
+//! `CreditWallet`. Payment moves through the player's `CreditLedger` with the
+//! station as counterparty. Each trade is reported as a
+//! `MarketTradeCompletedEvent`; refused trades as `MarketTradeFailedEvent`.
+
+use avian2d::prelude::Position;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::credits::{debit_credits, grant_credits, ledger_tick};
+use crate::factions::{FactionAffiliationQuery, FactionAffiliations, responsible_player};
+use crate::{
+    CargoCapacity, CombatAuthorityEnabled, CreditLedger, CreditWallet, DockingState, EntityGuid,
+    Inventory, ItemRegistry, Market, MarketDefinition, MarketListing, MarketRegistry, MassDirty,
+    MountedOn, PlayerTag,
+};
+
+/// Direction of a trade from the player's side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketTradeSide {
+    Buy,
+    Sell,
+}
+
+impl MarketTradeSide {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+/// Asks the server to trade `quantity` units of `item_id` between a ship's
+/// cargo and a market.
+#[derive(Debug, Clone, Message)]
+pub struct MarketTradeRequest {
+    pub player_guid: Uuid,
+    pub ship_guid: Uuid,
+    pub market_guid: Uuid,
+    pub item_id: String,
+    pub side: MarketTradeSide,
+    pub quantity: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketTradeFailureReason {
+    InvalidQuantity,
+    /// The ship does not exist or its responsible player is not the requester.
+    NotShipOwner,
+    /// No market with that guid, or its `market_id` has no definition.
+    UnknownMarket,
+    OutOfRange,
+    /// The market does not trade the item in that direction.
+    NotTraded,
+    InsufficientStock,
+    /// Selling would push the market past `max_stock`.
+    MarketFull,
+    /// The ship does not carry enough of the item to sell.
+    InsufficientCargo,
+    CargoFull,
+    NoWallet,
+    InsufficientCredits,
+}
+
+impl MarketTradeFailureReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::InvalidQuantity => "invalid_quantity",
+            Self::NotShipOwner => "not_ship_owner",
+            Self::UnknownMarket => "unknown_market",
+            Self::OutOfRange => "out_of_range",
+            Self::NotTraded => "not_traded",
+            Self::InsufficientStock => "insufficient_stock",
+            Self::MarketFull => "market_full",
+            Self::InsufficientCargo => "insufficient_cargo",
+            Self::CargoFull => "cargo_full",
+            Self::NoWallet => "no_wallet",
+            Self::InsufficientCredits => "insufficient_credits",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct MarketTradeCompletedEvent {
+    /// The `CreditLedgerEntry` that settled the trade.
+    pub transaction_id: Uuid,
+    pub player_guid: Uuid,
+    pub ship_guid: Uuid,
+    pub station_guid: Uuid,
+    pub market_guid: Uuid,
+    pub item_id: String,
+    pub side: MarketTradeSide,
+    pub quantity: u32,
+    pub unit_price: u64,
+    pub credits: u64,
+    pub balance_after: u64,
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct MarketTradeFailedEvent {
+    pub player_guid: Uuid,
+    pub ship_guid: Uuid,
+    pub market_guid: Uuid,
+    pub item_id: String,
+    pub side: MarketTradeSide,
+    pub quantity: u32,
+    pub reason: MarketTradeFailureReason,
+}
+
+/// Player-facing prices for one listing. `buy_price` is what a player pays
+/// per unit and `sell_price` what they receive; `None` when the market does
+/// not trade that way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketQuote {
+    pub item_id: String,
+    pub stock: u32,
+    pub max_stock: u32,
+    pub buy_price: Option<u64>,
+    pub sell_price: Option<u64>,
+}
+
+// Quotes round to whole credits; a spread that rounds away leaves buy and
+// sell equal, which still gives no profit on a round trip.
+fn buy_unit_price(price: f64, spread: f32) -> u64 {
+    ((price * (1.0 + f64::from(spread.max(0.0)))).round() as u64).max(1)
+}
+
+fn sell_unit_price(price: f64, spread: f32) -> u64 {
+    (price * (1.0 - f64::from(spread.clamp(0.0, 1.0)))).round() as u64
+}
+
+/// Current quotes for every listing the market's definition trades.
+pub fn market_quotes(market: &Market, definition: &MarketDefinition) -> Vec<MarketQuote> {
+    definition
+        .listings
+        .iter()
+        .filter_map(|listing_definition| {
+            let listing = market.listing(&listing_definition.item_id)?;
+            Some(MarketQuote {
+                item_id: listing.item_id.clone(),
+                stock: listing.stock,
+                max_stock: listing_definition.max_stock,
+                buy_price: listing_definition
+                    .sells
+                    .then(|| buy_unit_price(listing.price, definition.spread)),
+                sell_price: listing_definition
+                    .buys
+                    .then(|| sell_unit_price(listing.price, definition.spread)),
+            })
+        })
+        .collect()
+}
+
+/// Whether a ship can trade with a market mounted on `station_guid`: docked
+/// at that station, or within the market's `trade_range_m` of it.
+pub fn ship_in_trade_range(
+    market: &Market,
+    station_guid: Uuid,
+    station_position: Option<DVec2>,
+    ship_position: DVec2,
+    docking: Option<&DockingState>,
+) -> bool {
+    if docking.is_some_and(|docking| docking.is_docked() && docking.host_guid == Some(station_guid))
+    {
+        return true;
+    }
+    station_position.is_some_and(|station_position| {
+        station_position.distance(ship_position) <= f64::from(market.trade_range_m.max(0.0))
+    })
+}
+
+/// Seeds listings from the market definition, then restocks and moves prices
+/// toward their equilibrium for the elapsed fixed step.
+pub fn update_market_prices(
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    registry: Option<Res<'_, MarketRegistry>>,
+    mut markets: Query<'_, '_, &'_ mut Market>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let Some(registry) = registry else {
+        return;
+    };
+    let dt_s = time.delta_secs().max(0.0);
+    for mut market in &mut markets {
+        let Some(definition) = registry.market(&market.market_id) else {
+            continue;
+        };
+        sync_market_listings(&mut market, definition);
+        let drift = 1.0 - (-f64::from(definition.price_drift_per_s.max(0.0) * dt_s)).exp();
+        for listing in &mut market.listings {
+            let Some(listing_definition) = definition.listing(&listing.item_id) else {
+                continue;
+            };
+            if listing.stock == listing_definition.target_stock {
+                listing.restock_progress = 0.0;
+            } else {
+                listing.restock_progress += listing_definition.restock_per_s.max(0.0) * dt_s;
+                let step = listing.restock_progress.floor() as u32;
+                if step > 0 {
+                    listing.restock_progress -= step as f32;
+                    listing.stock = if listing.stock < listing_definition.target_stock {
+                        listing
+                            .stock
+                            .saturating_add(step)
+                            .min(listing_definition.target_stock)
+                    } else {
+                        listing
+                            .stock
+                            .saturating_sub(step)
+                            .max(listing_definition.target_stock)
+                    };
+                }
+            }
+            let equilibrium = definition.equilibrium_price(listing_definition, listing.stock);
+            listing.price += (equilibrium - listing.price) * drift;
+        }
+    }
+}
+
+/// Keeps one listing per defined item, in definition order. New items start
+/// at their initial stock and base price; items dropped from the definition
+/// are removed.
+fn sync_market_listings(market: &mut Market, definition: &MarketDefinition) {
+    let in_sync = market.listings.len() == definition.listings.len()
+        && market
+            .listings
+            .iter()
+            .zip(&definition.listings)
+            .all(|(listing, listing_definition)| listing.item_id == listing_definition.item_id);
+    if in_sync {
+        return;
+    }
+    let mut existing = std::mem::take(&mut market.listings)
+        .into_iter()
+        .map(|listing| (listing.item_id.clone(), listing))
+        .collect::<HashMap<_, _>>();
+    market.listings = definition
+        .listings
+        .iter()
+        .map(|listing_definition| {
+            existing
+                .remove(&listing_definition.item_id)
+                .unwrap_or_else(|| MarketListing {
+                    item_id: listing_definition.item_id.clone(),
+                    stock: listing_definition.initial_stock,
+                    price: f64::from(listing_definition.base_price),
+                    restock_progress: 0.0,
+                })
+        })
+        .collect();
+}
+
+type TradeShipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static Position,
+        Option<&'static mut Inventory>,
+        Option<&'static CargoCapacity>,
+        Option<&'static DockingState>,
+    ),
+>;
+
+/// Applies `MarketTradeRequest`s against market stock, ship cargo and the
+/// requesting player's wallet. Ships without an `Inventory` collect this
+/// tick's purchases in one new inventory, inserted after every request is
+/// applied.
+#[allow(clippy::too_many_arguments)]
+pub fn process_market_trades(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    registry: Option<Res<'_, MarketRegistry>>,
+    items: Option<Res<'_, ItemRegistry>>,
+    mut requests: MessageReader<'_, '_, MarketTradeRequest>,
+    mut ships: TradeShipQuery<'_, '_>,
+    stations: Query<'_, '_, (&'_ EntityGuid, &'_ Position), Without<MountedOn>>,
+    mut markets: Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ mut Market)>,
+    affiliation_query: FactionAffiliationQuery<'_, '_>,
+    mut wallets: Query<'_, '_, (&'_ mut CreditWallet, &'_ mut CreditLedger), With<PlayerTag>>,
+    mut completed_events: MessageWriter<'_, MarketTradeCompletedEvent>,
+    mut failed_events: MessageWriter<'_, MarketTradeFailedEvent>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        requests.clear();
+        return;
+    }
+    let tick = ledger_tick(&time);
+    let affiliations = FactionAffiliations::new(&affiliation_query);
+    let mut new_inventories = HashMap::<Entity, Inventory>::new();
+    for request in requests.read() {
+        let fail = |reason| MarketTradeFailedEvent {
+            player_guid: request.player_guid,
+            ship_guid: request.ship_guid,
+            market_guid: request.market_guid,
+            item_id: request.item_id.clone(),
+            side: request.side,
+            quantity: request.quantity,
+            reason,
+        };
+        if request.quantity == 0 {
+            failed_events.write(fail(MarketTradeFailureReason::InvalidQuantity));
+            continue;
+        }
+
+        let Some((ship_entity, _, ship_position, mut inventory, capacity, docking)) = ships
+            .iter_mut()
+            .find(|(_, guid, ..)| guid.0 == request.ship_guid)
+        else {
+            failed_events.write(fail(MarketTradeFailureReason::NotShipOwner));
+            continue;
+        };
+        let player = responsible_player(ship_entity, &affiliations)
+            .filter(|player| affiliations.entity_guid(*player) == Some(request.player_guid));
+        let Some(player) = player else {
+            failed_events.write(fail(MarketTradeFailureReason::NotShipOwner));
+            continue;
+        };
+
+        let Some((_, mounted_on, mut market)) = markets
+            .iter_mut()
+            .find(|(guid, ..)| guid.0 == request.market_guid)
+        else {
+            failed_events.write(fail(MarketTradeFailureReason::UnknownMarket));
+            continue;
+        };
+        let Some(definition) = registry
+            .as_deref()
+            .and_then(|registry| registry.market(&market.market_id))
+        else {
+            failed_events.write(fail(MarketTradeFailureReason::UnknownMarket));
+            continue;
+        };
+        let station_guid = mounted_on.parent_entity_id;
+        let station_position = stations
+            .iter()
+            .find(|(guid, _)| guid.0 == station_guid)
+            .map(|(_, position)| position.0);
+        if !ship_in_trade_range(
+            &market,
+            station_guid,
+            station_position,
+            ship_position.0,
+            docking,
+        ) {
+            failed_events.write(fail(MarketTradeFailureReason::OutOfRange));
+            continue;
+        }
+
+        sync_market_listings(&mut market, definition);
+        let (Some(listing_definition), Some(item)) = (
+            definition.listing(&request.item_id),
+            items
+                .as_deref()
+                .and_then(|items| items.item(&request.item_id)),
+        ) else {
+            failed_events.write(fail(MarketTradeFailureReason::NotTraded));
+            continue;
+        };
+        let Some(listing) = market.listing_mut(&request.item_id) else {
+            failed_events.write(fail(MarketTradeFailureReason::NotTraded));
+            continue;
+        };
+        let unit_price = match request.side {
+            MarketTradeSide::Buy if listing_definition.sells => {
+                buy_unit_price(listing.price, definition.spread)
+            }
+            MarketTradeSide::Sell if listing_definition.buys => {
+                sell_unit_price(listing.price, definition.spread)
+            }
+            _ => 0,
+        };
+        if unit_price == 0 {
+            failed_events.write(fail(MarketTradeFailureReason::NotTraded));
+            continue;
+        }
+
+        match request.side {
+            MarketTradeSide::Buy => {
+                if listing.stock < request.quantity {
+                    failed_events.write(fail(MarketTradeFailureReason::InsufficientStock));
+                    continue;
+                }
+                let current_mass_kg = inventory
+                    .as_deref()
+                    .or(new_inventories.get(&ship_entity))
+                    .map_or(0.0, |inventory| inventory.total_mass_kg());
+                let added_mass_kg = item.unit_mass_kg * request.quantity as f32;
+                if capacity
+                    .is_some_and(|capacity| !capacity.has_room_for(current_mass_kg, added_mass_kg))
+                {
+                    failed_events.write(fail(MarketTradeFailureReason::CargoFull));
+                    continue;
+                }
+            }
+            MarketTradeSide::Sell => {
+                let carried = inventory
+                    .as_deref()
+                    .or(new_inventories.get(&ship_entity))
+                    .map_or(0, |inventory| inventory.quantity_of(&request.item_id));
+                if carried < request.quantity {
+                    failed_events.write(fail(MarketTradeFailureReason::InsufficientCargo));
+                    continue;
+                }
+                if listing.stock.saturating_add(request.quantity) > listing_definition.max_stock {
+                    failed_events.write(fail(MarketTradeFailureReason::MarketFull));
+                    continue;
+                }
+            }
+        }
+
+        let Ok((mut wallet, mut ledger)) = wallets.get_mut(player) else {
+            failed_events.write(fail(MarketTradeFailureReason::NoWallet));
+            continue;
+        };
+        let Some(credits) = unit_price.checked_mul(u64::from(request.quantity)) else {
+            failed_events.write(fail(MarketTradeFailureReason::InsufficientCredits));
+            continue;
+        };
+        let reason = format!("market.{}", request.side.as_str());
+        let settled = match request.side {
+            MarketTradeSide::Buy => debit_credits(
+                &mut wallet,
+                &mut ledger,
+                credits,
+                &reason,
+                Some(station_guid),
+                tick,
+            ),
+            MarketTradeSide::Sell => grant_credits(
+                &mut wallet,
+                &mut ledger,
+                credits,
+                &reason,
+                Some(station_guid),
+                tick,
+            ),
+        };
+        let Ok(entry) = settled else {
+            failed_events.write(fail(MarketTradeFailureReason::InsufficientCredits));
+            continue;
+        };
+
+        match request.side {
+            MarketTradeSide::Buy => {
+                listing.stock -= request.quantity;
+                let inventory = match inventory.as_deref_mut() {
+                    Some(inventory) => inventory,
+                    None => new_inventories.entry(ship_entity).or_default(),
+                };
+                inventory.add_stack(&request.item_id, request.quantity, item.unit_mass_kg);
+            }
+            MarketTradeSide::Sell => {
+                listing.stock += request.quantity;
+                let inventory = match inventory.as_deref_mut() {
+                    Some(inventory) => Some(inventory),
+                    None => new_inventories.get_mut(&ship_entity),
+                };
+                if let Some(inventory) = inventory {
+                    inventory.remove_stack(&request.item_id, request.quantity);
+                }
+            }
+        }
+        commands.entity(ship_entity).insert(MassDirty);
+
+        completed_events.write(MarketTradeCompletedEvent {
+            transaction_id: entry.transaction_id,
+            player_guid: request.player_guid,
+            ship_guid: request.ship_guid,
+            station_guid,
+            market_guid: request.market_guid,
+            item_id: request.item_id.clone(),
+            side: request.side,
+            quantity: request.quantity,
+            unit_price,
+            credits,
+            balance_after: wallet.balance,
+        });
+    }
+    for (ship_entity, inventory) in new_inventories {
+        commands.entity(ship_entity).insert(inventory);
+    }
+}
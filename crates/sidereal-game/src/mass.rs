@@ -10,14 +10,7 @@ use crate::generated::components::{
 };
 
 fn inventory_mass_kg(inventory: Option<&Inventory>) -> f32 {
-    inventory
-        .map(|inv| {
-            inv.entries
-                .iter()
-                .map(|entry| entry.unit_mass_kg.max(0.0) * entry.quantity as f32)
-                .sum::<f32>()
-        })
-        .unwrap_or(0.0)
+    inventory.map(Inventory::total_mass_kg).unwrap_or(0.0)
 }
 
 fn module_tree_mass(
@@ -0,0 +1,379 @@
+//! Mining subsystem
+//!
+//! Mining lasers are modules mounted on a hull via `MountedOn` and toggled by
+//! the hull's `ActivateMiningLaser` / `DeactivateMiningLaser` actions. An active
+//! laser locks the nearest asteroid field member in range whose resource pool
+//! is not exhausted. Each completed cycle draws one deterministic yield roll
+//! from the field's `AsteroidResourceProfile`, charges the units against the
+//! member's entry in `AsteroidFieldDamageState`, stacks the output into the
+//! hull's `Inventory`, and marks the hull `MassDirty` so `recompute_total_mass`
+//! folds the cargo into `CargoMassKg`. A hull whose `CargoCapacity` cannot take
+//! another unit shuts its lasers off and reports `MiningStatus::HoldFull`.
+
+use avian2d::prelude::Position;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, AsteroidFieldDamageState, AsteroidFieldMember, AsteroidResourceProfile,
+    CargoCapacity, EntityAction, EntityGuid, Inventory, MassDirty, MiningLaser, MountedOn, OwnerId,
+    PendingDestruction, SimulationMotionWriter, WorldPosition,
+    asteroid_member_resource_units_consumed, record_asteroid_member_extraction,
+    resolve_world_position, roll_asteroid_extraction_yield,
+};
+
+/// Emitted once per completed extraction cycle that minted inventory.
+#[derive(Debug, Clone, Message)]
+pub struct ResourceExtractedEvent {
+    pub miner_entity: Entity,
+    pub miner_guid: Uuid,
+    pub miner_owner_id: Option<String>,
+    pub laser_guid: Uuid,
+    pub source_guid: Uuid,
+    pub item_id: String,
+    pub quantity: u32,
+    pub unit_mass_kg: f32,
+    /// True when this cycle exhausted the source member's resource pool.
+    pub source_exhausted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiningStatus {
+    Started,
+    Stopped,
+    /// The hull's `CargoCapacity` is full; its lasers were switched off.
+    HoldFull,
+}
+
+/// Emitted when a hull's mining lasers switch on or off. Unlike
+/// `ResourceExtractedEvent` this fires on transitions, not every cycle.
+#[derive(Debug, Clone, Message)]
+pub struct MiningStatusChangedEvent {
+    pub miner_entity: Entity,
+    pub miner_guid: Uuid,
+    pub miner_owner_id: Option<String>,
+    pub status: MiningStatus,
+}
+
+type MiningHullQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static OwnerId>,
+        Option<&'static mut Inventory>,
+        Option<&'static CargoCapacity>,
+    ),
+    (Without<MountedOn>, Without<AsteroidFieldMember>),
+>;
+
+type MiningMemberQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static EntityGuid,
+        &'static AsteroidFieldMember,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+    ),
+    Without<PendingDestruction>,
+>;
+
+#[derive(Debug, Clone)]
+struct MiningCandidate {
+    guid: Uuid,
+    position: DVec2,
+    member: AsteroidFieldMember,
+    field_guid: Uuid,
+    remaining_units: f32,
+}
+
+pub fn process_mining_actions(
+    mut hulls: Query<
+        '_,
+        '_,
+        (
+            Entity,
+            &'_ EntityGuid,
+            Option<&'_ OwnerId>,
+            &'_ mut ActionQueue,
+        ),
+        With<SimulationMotionWriter>,
+    >,
+    mut lasers: Query<'_, '_, (&'_ MountedOn, &'_ mut MiningLaser)>,
+    mut status_events: MessageWriter<'_, MiningStatusChangedEvent>,
+) {
+    let mut requested_by_parent = HashMap::<Uuid, (bool, Entity, Option<String>)>::new();
+    for (entity, guid, owner_id, mut queue) in &mut hulls {
+        if let Some(active) = drain_mining_toggle_action(&mut queue) {
+            requested_by_parent.insert(
+                guid.0,
+                (active, entity, owner_id.map(|owner| owner.0.clone())),
+            );
+        }
+    }
+    if requested_by_parent.is_empty() {
+        return;
+    }
+
+    let mut changed_hulls = HashSet::<Uuid>::new();
+    for (mounted_on, mut laser) in &mut lasers {
+        let Some((active, _, _)) = requested_by_parent.get(&mounted_on.parent_entity_id) else {
+            continue;
+        };
+        if laser.active == *active {
+            continue;
+        }
+        laser.active = *active;
+        if !*active {
+            laser.target_guid = None;
+            laser.cycle_progress_s = 0.0;
+        }
+        changed_hulls.insert(mounted_on.parent_entity_id);
+    }
+
+    for hull_guid in changed_hulls {
+        let Some((active, entity, owner_id)) = requested_by_parent.remove(&hull_guid) else {
+            continue;
+        };
+        status_events.write(MiningStatusChangedEvent {
+            miner_entity: entity,
+            miner_guid: hull_guid,
+            miner_owner_id: owner_id,
+            status: if active {
+                MiningStatus::Started
+            } else {
+                MiningStatus::Stopped
+            },
+        });
+    }
+}
+
+pub fn extract_asteroid_resources(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    mut lasers: Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ mut MiningLaser)>,
+    mut hulls: MiningHullQuery<'_, '_>,
+    members: MiningMemberQuery<'_, '_>,
+    mut fields: Query<
+        '_,
+        '_,
+        (
+            &'_ EntityGuid,
+            &'_ AsteroidResourceProfile,
+            &'_ mut AsteroidFieldDamageState,
+        ),
+    >,
+    mut extracted_events: MessageWriter<'_, ResourceExtractedEvent>,
+    mut status_events: MessageWriter<'_, MiningStatusChangedEvent>,
+) {
+    let dt_s = time.delta_secs();
+    if dt_s <= 0.0 || !lasers.iter().any(|(_, _, laser)| laser.active) {
+        return;
+    }
+
+    let field_guid_by_id = fields
+        .iter()
+        .map(|(guid, _, _)| (guid.0.to_string(), guid.0))
+        .collect::<HashMap<_, _>>();
+    let mut candidates = Vec::<MiningCandidate>::new();
+    for (guid, member, position, world_position) in &members {
+        let Some(position) = resolve_world_position(position, world_position) else {
+            continue;
+        };
+        let Some(field_guid) = field_guid_by_id.get(&member.field_entity_id).copied() else {
+            continue;
+        };
+        let Some((_, profile, damage_state)) =
+            fields.iter().find(|(field, _, _)| field.0 == field_guid)
+        else {
+            continue;
+        };
+        let consumed = asteroid_member_resource_units_consumed(damage_state, &member.member_key);
+        candidates.push(MiningCandidate {
+            guid: guid.0,
+            position,
+            member: member.clone(),
+            field_guid,
+            remaining_units: (profile.depletion_pool_units - consumed).max(0.0),
+        });
+    }
+
+    let hull_by_guid = hulls
+        .iter()
+        .filter_map(|(entity, guid, position, world_position, _, _, _)| {
+            resolve_world_position(position, world_position).map(|pos| (guid.0, (entity, pos)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut new_inventories = HashMap::<Entity, Inventory>::new();
+    let mut hold_full_hulls = HashSet::<Entity>::new();
+    for (laser_guid, mounted_on, mut laser) in &mut lasers {
+        if !laser.active {
+            continue;
+        }
+        let Some((hull_entity, hull_position)) =
+            hull_by_guid.get(&mounted_on.parent_entity_id).copied()
+        else {
+            continue;
+        };
+
+        let range_m = f64::from(laser.range_m.max(0.0));
+        let can_mine = |candidate: &MiningCandidate| {
+            candidate.remaining_units > 0.0 && candidate.position.distance(hull_position) <= range_m
+        };
+        let target = laser
+            .target_guid
+            .and_then(|guid| candidates.iter().find(|candidate| candidate.guid == guid))
+            .filter(|candidate| can_mine(candidate))
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .filter(|candidate| can_mine(candidate))
+                    .min_by(|a, b| {
+                        a.position
+                            .distance_squared(hull_position)
+                            .total_cmp(&b.position.distance_squared(hull_position))
+                    })
+            })
+            .cloned();
+        let Some(target) = target else {
+            if laser.target_guid.is_some() || laser.cycle_progress_s > 0.0 {
+                laser.target_guid = None;
+                laser.cycle_progress_s = 0.0;
+            }
+            continue;
+        };
+        if laser.target_guid != Some(target.guid) {
+            laser.target_guid = Some(target.guid);
+            laser.cycle_progress_s = 0.0;
+        }
+
+        let cycle_time_s = laser.cycle_time_s.max(0.05);
+        laser.cycle_progress_s += dt_s;
+        if laser.cycle_progress_s < cycle_time_s {
+            continue;
+        }
+        laser.cycle_progress_s -= cycle_time_s;
+
+        let Some((_, profile, mut damage_state)) = fields
+            .iter_mut()
+            .find(|(field, _, _)| field.0 == target.field_guid)
+        else {
+            continue;
+        };
+        let consumed =
+            asteroid_member_resource_units_consumed(&damage_state, &target.member.member_key);
+        let remaining_units = (profile.depletion_pool_units - consumed).max(0.0);
+        let Some((entry, rolled_units)) =
+            roll_asteroid_extraction_yield(profile, &target.member.member_key, consumed as u64)
+        else {
+            continue;
+        };
+        let units = (rolled_units * laser.yield_multiplier.max(0.0))
+            .min(remaining_units)
+            .floor();
+        if units < 1.0 {
+            // Leftover fractions can never mint a whole unit; close the pool out.
+            record_asteroid_member_extraction(
+                &mut damage_state,
+                &target.member,
+                remaining_units,
+                profile.depletion_pool_units,
+            );
+            continue;
+        }
+        let Ok((_, hull_guid, _, _, owner_id, inventory, capacity)) = hulls.get_mut(hull_entity)
+        else {
+            continue;
+        };
+        let mut quantity = units as u32;
+        if let Some(capacity) = capacity
+            && entry.unit_mass_kg > 0.0
+        {
+            let current_mass_kg = inventory
+                .as_deref()
+                .or_else(|| new_inventories.get(&hull_entity))
+                .map(Inventory::total_mass_kg)
+                .unwrap_or(0.0);
+            let free_kg = (capacity.max_mass_kg - current_mass_kg).max(0.0);
+            quantity = quantity.min((free_kg / entry.unit_mass_kg).floor() as u32);
+        }
+        if quantity == 0 {
+            laser.active = false;
+            laser.target_guid = None;
+            laser.cycle_progress_s = 0.0;
+            if hold_full_hulls.insert(hull_entity) {
+                status_events.write(MiningStatusChangedEvent {
+                    miner_entity: hull_entity,
+                    miner_guid: hull_guid.0,
+                    miner_owner_id: owner_id.map(|owner| owner.0.clone()),
+                    status: MiningStatus::HoldFull,
+                });
+            }
+            continue;
+        }
+        let units = quantity as f32;
+        let exhausted = record_asteroid_member_extraction(
+            &mut damage_state,
+            &target.member,
+            units,
+            profile.depletion_pool_units,
+        );
+        if let Some(candidate) = candidates
+            .iter_mut()
+            .find(|candidate| candidate.guid == target.guid)
+        {
+            candidate.remaining_units = (remaining_units - units).max(0.0);
+        }
+
+        if let Some(mut inventory) = inventory {
+            inventory.add_stack(&entry.item_id, quantity, entry.unit_mass_kg);
+        } else {
+            // Hulls without cargo get an inventory inserted once all lasers ran.
+            new_inventories.entry(hull_entity).or_default().add_stack(
+                &entry.item_id,
+                quantity,
+                entry.unit_mass_kg,
+            );
+        }
+        commands.entity(hull_entity).insert(MassDirty);
+        extracted_events.write(ResourceExtractedEvent {
+            miner_entity: hull_entity,
+            miner_guid: hull_guid.0,
+            miner_owner_id: owner_id.map(|owner| owner.0.clone()),
+            laser_guid: laser_guid.0,
+            source_guid: target.guid,
+            item_id: entry.item_id.clone(),
+            quantity,
+            unit_mass_kg: entry.unit_mass_kg,
+            source_exhausted: exhausted,
+        });
+        if exhausted {
+            laser.target_guid = None;
+            laser.cycle_progress_s = 0.0;
+        }
+    }
+
+    for (hull_entity, inventory) in new_inventories {
+        commands.entity(hull_entity).insert(inventory);
+    }
+}
+
+fn drain_mining_toggle_action(queue: &mut ActionQueue) -> Option<bool> {
+    let mut requested = None;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        match action {
+            EntityAction::ActivateMiningLaser => requested = Some(true),
+            EntityAction::DeactivateMiningLaser => requested = Some(false),
+            _ => queue.pending.push(action),
+        }
+    }
+    requested
+}
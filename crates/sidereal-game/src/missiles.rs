@@ -0,0 +1,313 @@
+//! Guided missile subsystem
+//!
+//! `MissileLauncher` modules answer the same weapon-group fire actions as
+//! ballistic weapons (see `process_weapon_fire_actions`), but launch only on the
+//! authoritative server and only while the shooter holds a lock: its selected
+//! target must be within `lock_range_m` and carry a strong enough
+//! `SignalSignature`. Each launch spawns a `GuidedMissile` entity, a kinematic
+//! sensor body, that turns toward the locked target at `turn_rate_rad_s` and
+//! accelerates along its heading up to `max_speed_mps`; the physics step moves
+//! it along the velocity set here. A missile detonates when it passes within
+//! `proximity_fuse_m` of the target, strikes another body, or burns out its
+//! fuel. Detonation deals linear-falloff area damage, nearest target first,
+//! through shields and resistances (subject to the faction friendly-fire
+//! policy), then hands the missile to the shared destruction pipeline so
+//! observers see a `missile_detonation` effect.
+
+use avian2d::prelude::{
+    Collider, LinearVelocity, Position, RigidBody, Rotation, Sensor, SpatialQuery,
+    SpatialQueryFilter,
+};
+use bevy::{math::DVec2, prelude::*};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::combat::{ResistanceProfileQuery, mitigate_hull_damage};
+use crate::factions::FactionDamagePolicy;
+use crate::{
+    CombatAuthorityEnabled, EntityDestructionStartedEvent, EntityGuid, GuidedMissile, HealthPool,
+    MissileLauncher, MountedOn, OwnerId, PendingDestruction, PendingDestructionPhase,
+    PublicVisibility, ShieldGenerator, ShotHitEvent, SignalSignature,
+    absorb_damage_with_mounted_shields,
+};
+
+pub const MISSILE_DETONATION_PROFILE_ID: &str = "missile_detonation";
+
+/// Missile body footprint; the sensor collider keeps missiles visible to
+/// spatial queries without shoving the bodies they fly past.
+const MISSILE_COLLIDER_LENGTH_M: f64 = 2.0;
+const MISSILE_COLLIDER_WIDTH_M: f64 = 0.6;
+
+pub(crate) type MissileLockTargetQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static EntityGuid,
+        &'static Position,
+        &'static SignalSignature,
+    ),
+>;
+
+/// True when `target_guid` is inside the launcher's lock range and bright
+/// enough to hold a lock.
+pub(crate) fn has_missile_lock(
+    launcher: &MissileLauncher,
+    shooter_position: DVec2,
+    target_guid: Uuid,
+    targets: &MissileLockTargetQuery<'_, '_>,
+) -> bool {
+    targets.iter().any(|(guid, position, signature)| {
+        guid.0 == target_guid
+            && signature.strength >= launcher.min_lock_signal_strength
+            && position.0.distance(shooter_position) <= f64::from(launcher.lock_range_m.max(0.0))
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn spawn_guided_missile(
+    commands: &mut Commands<'_, '_>,
+    launcher: &MissileLauncher,
+    shooter_guid: Uuid,
+    weapon_guid: Uuid,
+    target_guid: Uuid,
+    origin: DVec2,
+    heading_rad: f32,
+    launch_velocity: DVec2,
+    owner_id: Option<OwnerId>,
+) -> Entity {
+    let missile_entity = commands
+        .spawn((
+            Name::new("GuidedMissile"),
+            EntityGuid(Uuid::new_v4()),
+            Position(origin),
+            Rotation::radians(f64::from(heading_rad)),
+            LinearVelocity(launch_velocity),
+            RigidBody::Kinematic,
+            Collider::rectangle(MISSILE_COLLIDER_WIDTH_M, MISSILE_COLLIDER_LENGTH_M),
+            Sensor,
+            GuidedMissile::from_launcher(launcher, shooter_guid, weapon_guid, target_guid),
+            PublicVisibility,
+        ))
+        .id();
+    if let Some(owner_id) = owner_id {
+        commands.entity(missile_entity).insert(owner_id);
+    }
+    missile_entity
+}
+
+/// Heading (radians, +Y forward) that points along `direction`.
+fn heading_toward(direction: DVec2) -> f64 {
+    (-direction.x).atan2(direction.y)
+}
+
+fn wrap_angle(angle: f64) -> f64 {
+    (angle + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI
+}
+
+/// Point on the segment `start..end` closest to `point`.
+fn closest_point_on_segment(start: DVec2, end: DVec2, point: DVec2) -> DVec2 {
+    let segment = end - start;
+    let length_sq = segment.length_squared();
+    if length_sq <= f64::EPSILON {
+        return start;
+    }
+    let t = ((point - start).dot(segment) / length_sq).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_guided_missiles(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    bodies: Query<'_, '_, (Entity, &'_ EntityGuid, &'_ Position), Without<GuidedMissile>>,
+    mut missile_params: ParamSet<
+        '_,
+        '_,
+        (
+            SpatialQuery<'_, '_>,
+            Query<
+                '_,
+                '_,
+                (
+                    Entity,
+                    &'_ EntityGuid,
+                    &'_ Position,
+                    &'_ Rotation,
+                    &'_ LinearVelocity,
+                    &'_ GuidedMissile,
+                ),
+                Without<PendingDestruction>,
+            >,
+            Query<
+                '_,
+                '_,
+                (
+                    &'_ mut Position,
+                    &'_ mut Rotation,
+                    &'_ mut LinearVelocity,
+                    &'_ mut GuidedMissile,
+                ),
+            >,
+        ),
+    >,
+    mut health_pools: Query<'_, '_, &'_ mut HealthPool>,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+    resistance_profiles: ResistanceProfileQuery<'_, '_>,
+    faction_policy: FactionDamagePolicy<'_, '_>,
+    mut shot_hit_events: MessageWriter<'_, ShotHitEvent>,
+    mut started_events: MessageWriter<'_, EntityDestructionStartedEvent>,
+) {
+    let dt_s = time.delta_secs_f64();
+    if dt_s <= 0.0 || !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+
+    let mut body_by_guid = HashMap::<Uuid, (Entity, DVec2)>::new();
+    for (entity, guid, position) in &bodies {
+        body_by_guid.insert(guid.0, (entity, position.0));
+    }
+    let faction_gate = faction_policy.gate();
+
+    let missile_snapshots = missile_params
+        .p1()
+        .iter()
+        .map(|(entity, guid, position, rotation, velocity, missile)| {
+            (
+                entity,
+                guid.0,
+                position.0,
+                rotation.as_radians(),
+                velocity.0,
+                missile.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let missile_entities = missile_snapshots
+        .iter()
+        .map(|(entity, ..)| *entity)
+        .collect::<Vec<_>>();
+
+    for (entity, missile_guid, start, heading_rad, velocity, missile) in missile_snapshots {
+        let target_position = body_by_guid
+            .get(&missile.target_guid)
+            .map(|(_, position)| *position);
+        let mut heading_rad = heading_rad;
+        if let Some(target_position) = target_position {
+            let to_target = target_position - start;
+            if to_target.length_squared() > f64::EPSILON {
+                let max_turn = f64::from(missile.turn_rate_rad_s.max(0.0)) * dt_s;
+                let turn = wrap_angle(heading_toward(to_target) - heading_rad);
+                heading_rad += turn.clamp(-max_turn, max_turn);
+            }
+        }
+        let forward = DVec2::new(-heading_rad.sin(), heading_rad.cos());
+        let speed = (velocity.length() + f64::from(missile.thrust_mps2.max(0.0)) * dt_s)
+            .min(f64::from(missile.max_speed_mps.max(0.0)));
+        let step = forward * speed * dt_s;
+        let end = start + step;
+        let remaining_fuel_s = missile.remaining_fuel_s - dt_s as f32;
+
+        let fuse_point = target_position.and_then(|target_position| {
+            let closest = closest_point_on_segment(start, end, target_position);
+            (closest.distance(target_position) <= f64::from(missile.proximity_fuse_m.max(0.0)))
+                .then_some(closest)
+        });
+        let impact_point = fuse_point.or_else(|| {
+            let travel_distance = step.length();
+            let ray_direction = Dir2::new(forward.as_vec2()).ok()?;
+            let mut excluded = missile_entities.clone();
+            if let Some((shooter_entity, _)) = body_by_guid.get(&missile.shooter_guid) {
+                excluded.push(*shooter_entity);
+            }
+            let filter = SpatialQueryFilter::from_excluded_entities(excluded);
+            missile_params
+                .p0()
+                .cast_ray(start, ray_direction, travel_distance, true, &filter)
+                .map(|hit| start + forward * hit.distance)
+        });
+        let detonation_point = impact_point.or_else(|| (remaining_fuel_s <= 0.0).then_some(end));
+
+        // The physics step carries the missile along `step`; a detonation pins
+        // it at the blast point instead.
+        if let Ok((mut position, mut rotation, mut linear_velocity, mut state)) =
+            missile_params.p2().get_mut(entity)
+        {
+            *rotation = Rotation::radians(heading_rad);
+            if let Some(detonation_point) = detonation_point {
+                position.0 = detonation_point;
+                linear_velocity.0 = DVec2::ZERO;
+            } else {
+                linear_velocity.0 = forward * speed;
+            }
+            state.remaining_fuel_s = remaining_fuel_s.max(0.0);
+        }
+
+        let Some(detonation_point) = detonation_point else {
+            continue;
+        };
+        let shooter_entity = body_by_guid
+            .get(&missile.shooter_guid)
+            .map(|(shooter_entity, _)| *shooter_entity);
+        let mut blast_targets = body_by_guid
+            .iter()
+            .map(|(guid, (target_entity, target_position))| {
+                (
+                    target_position.distance(detonation_point),
+                    *guid,
+                    *target_entity,
+                )
+            })
+            .filter(|(distance_m, ..)| *distance_m <= f64::from(missile.blast_radius_m))
+            .collect::<Vec<_>>();
+        blast_targets.sort_by(|(a_distance, a_guid, _), (b_distance, b_guid, _)| {
+            a_distance.total_cmp(b_distance).then(a_guid.cmp(b_guid))
+        });
+        for (distance_m, target_guid, target_entity) in blast_targets {
+            let damage = missile.blast_damage_at(distance_m as f32);
+            if damage <= 0.0 || !faction_gate.allows(shooter_entity, target_entity) {
+                continue;
+            }
+            let Ok(mut health_pool) = health_pools.get_mut(target_entity) else {
+                continue;
+            };
+            let after_shields = absorb_damage_with_mounted_shields(
+                Some(target_guid),
+                damage,
+                missile.damage_type,
+                &mut shields,
+            );
+            let hull_damage = mitigate_hull_damage(
+                target_entity,
+                Some(target_guid),
+                after_shields,
+                missile.damage_type,
+                &resistance_profiles,
+            );
+            health_pool.current = (health_pool.current - hull_damage).max(0.0);
+            shot_hit_events.write(ShotHitEvent {
+                shooter_guid: missile.shooter_guid,
+                target_entity,
+                target_guid: Some(target_guid),
+                weapon_entity: entity,
+                weapon_guid: missile.weapon_guid,
+                impact_pos: detonation_point,
+                damage: hull_damage,
+                shield_absorbed: damage - after_shields,
+                damage_type: missile.damage_type,
+            });
+        }
+        commands.entity(entity).insert(PendingDestruction {
+            destruction_profile_id: MISSILE_DETONATION_PROFILE_ID.to_string(),
+            remaining_delay_s: 0.0,
+            phase: PendingDestructionPhase::EffectDelay,
+        });
+        started_events.write(EntityDestructionStartedEvent {
+            entity,
+            entity_guid: missile_guid,
+            destruction_profile_id: MISSILE_DETONATION_PROFILE_ID.to_string(),
+            effect_origin: detonation_point,
+            destroy_delay_s: 0.0,
+        });
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MissionObjectiveKind {
+    /// Bring the player's controlled ship within `radius_m` of a world point.
+    ReachLocation { x: f64, y: f64, radius_m: f64 },
+    /// Destroy a specific entity, or `count` members of a faction. Kills are
+    /// credited to the player responsible for the last damaging hit.
+    DestroyEntity {
+        #[serde(default)]
+        target_entity_id: Option<Uuid>,
+        #[serde(default)]
+        faction_id: Option<String>,
+        #[serde(default = "default_destroy_count")]
+        count: u32,
+    },
+    /// Hand over cargo from the controlled ship while it is within `range_m`
+    /// of the destination. Partial deliveries count toward `quantity`.
+    DeliverItem {
+        item_id: String,
+        quantity: u32,
+        destination_entity_id: Uuid,
+        #[serde(default = "default_delivery_range_m")]
+        range_m: f64,
+    },
+    /// Discover a static landmark with the player's scanners.
+    ScanLandmark { landmark_entity_id: Uuid },
+}
+
+impl MissionObjectiveKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReachLocation { .. } => "reach_location",
+            Self::DestroyEntity { .. } => "destroy_entity",
+            Self::DeliverItem { .. } => "deliver_item",
+            Self::ScanLandmark { .. } => "scan_landmark",
+        }
+    }
+
+    /// Progress count at which the objective is complete.
+    pub fn required(&self) -> u32 {
+        match self {
+            Self::DestroyEntity { count, .. } => (*count).max(1),
+            Self::DeliverItem { quantity, .. } => (*quantity).max(1),
+            Self::ReachLocation { .. } | Self::ScanLandmark { .. } => 1,
+        }
+    }
+}
+
+fn default_destroy_count() -> u32 {
+    1
+}
+
+fn default_delivery_range_m() -> f64 {
+    500.0
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct MissionObjectiveDefinition {
+    pub objective_id: String,
+    pub description: String,
+    #[serde(flatten)]
+    pub kind: MissionObjectiveKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct MissionReputationReward {
+    pub faction_id: String,
+    pub delta: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct MissionReward {
+    #[serde(default)]
+    pub credits: u64,
+    #[serde(default)]
+    pub reputation: Vec<MissionReputationReward>,
+}
+
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct MissionDefinition {
+    pub mission_id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    /// All objectives must be complete before the mission completes; they may
+    /// progress in any order.
+    pub objectives: Vec<MissionObjectiveDefinition>,
+    #[serde(default)]
+    pub reward: MissionReward,
+    /// Completed missions can only be started again when repeatable.
+    #[serde(default)]
+    pub repeatable: bool,
+}
+
+impl MissionDefinition {
+    pub fn objective(&self, objective_id: &str) -> Option<&MissionObjectiveDefinition> {
+        self.objectives
+            .iter()
+            .find(|objective| objective.objective_id == objective_id)
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Resource)]
+pub struct MissionRegistry {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub missions: Vec<MissionDefinition>,
+}
+
+impl MissionRegistry {
+    pub fn mission(&self, mission_id: &str) -> Option<&MissionDefinition> {
+        self.missions
+            .iter()
+            .find(|mission| mission.mission_id == mission_id)
+    }
+}
@@ -0,0 +1,164 @@
+//! Player missions
+//!
+//! Missions are authored in the Lua `MissionRegistry`; a player's progress
+//! lives in their `MissionLog`, which persists with the player and replicates
+//! to its owner. The replication server's mission evaluator decides when an
+//! objective advances (from script events and world state) and uses these
+//! helpers for the bookkeeping: starting a mission, keeping its objective
+//! list in step with the registry, capping progress, closing out finished
+//! missions and paying their rewards. Completions are reported as
+//! `MissionCompletedEvent`s.
+
+use bevy::prelude::*;
+use uuid::Uuid;
+
+use crate::credits::grant_credits;
+use crate::{
+    ActiveMission, CreditLedger, CreditTransactionError, CreditWallet, FactionReputation,
+    FactionReputationRules, MissionDefinition, MissionLog, MissionObjectiveProgress, MissionReward,
+};
+
+/// Missions a player may have in progress at once.
+pub const MAX_ACTIVE_MISSIONS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissionStartError {
+    AlreadyActive,
+    /// The mission is not repeatable and the player has finished it.
+    AlreadyCompleted,
+    TooManyActive,
+    NoObjectives,
+}
+
+impl MissionStartError {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AlreadyActive => "already_active",
+            Self::AlreadyCompleted => "already_completed",
+            Self::TooManyActive => "too_many_active",
+            Self::NoObjectives => "no_objectives",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct MissionCompletedEvent {
+    pub player_guid: Uuid,
+    pub mission_id: String,
+    pub display_name: String,
+    /// Credits actually paid; zero when the reward has none or the grant was
+    /// refused.
+    pub credits: u64,
+}
+
+pub fn start_mission(
+    log: &mut MissionLog,
+    definition: &MissionDefinition,
+    tick: u64,
+) -> Result<(), MissionStartError> {
+    if log.active_mission(&definition.mission_id).is_some() {
+        return Err(MissionStartError::AlreadyActive);
+    }
+    if !definition.repeatable && log.has_completed(&definition.mission_id) {
+        return Err(MissionStartError::AlreadyCompleted);
+    }
+    if log.active.len() >= MAX_ACTIVE_MISSIONS {
+        return Err(MissionStartError::TooManyActive);
+    }
+    if definition.objectives.is_empty() {
+        return Err(MissionStartError::NoObjectives);
+    }
+    let mut mission = ActiveMission {
+        mission_id: definition.mission_id.clone(),
+        display_name: String::new(),
+        objectives: Vec::new(),
+        accepted_tick: tick,
+    };
+    sync_active_mission(&mut mission, definition);
+    log.active.push(mission);
+    Ok(())
+}
+
+/// Brings an active mission's objectives in line with its definition,
+/// keeping progress by `objective_id`. Fills in authored records that list
+/// only a `mission_id` and follows registry reloads that add, drop or retune
+/// objectives. Returns true when anything changed.
+pub fn sync_active_mission(mission: &mut ActiveMission, definition: &MissionDefinition) -> bool {
+    let objectives = definition
+        .objectives
+        .iter()
+        .map(|objective| {
+            let required = objective.kind.required();
+            let current = mission
+                .objectives
+                .iter()
+                .find(|progress| progress.objective_id == objective.objective_id)
+                .map_or(0, |progress| progress.current.min(required));
+            MissionObjectiveProgress {
+                objective_id: objective.objective_id.clone(),
+                description: objective.description.clone(),
+                current,
+                required,
+            }
+        })
+        .collect::<Vec<_>>();
+    if mission.objectives == objectives && mission.display_name == definition.display_name {
+        return false;
+    }
+    mission.objectives = objectives;
+    mission.display_name = definition.display_name.clone();
+    true
+}
+
+/// Adds up to `amount` to an objective's progress without passing its
+/// requirement. Returns the amount applied.
+pub fn advance_mission_objective(
+    mission: &mut ActiveMission,
+    objective_id: &str,
+    amount: u32,
+) -> u32 {
+    let Some(objective) = mission.objective_mut(objective_id) else {
+        return 0;
+    };
+    let applied = amount.min(objective.required.saturating_sub(objective.current));
+    objective.current += applied;
+    applied
+}
+
+/// Moves every finished mission out of the active list and records it as
+/// completed, returning them in log order.
+pub fn take_completed_missions(log: &mut MissionLog) -> Vec<ActiveMission> {
+    let (completed, active): (Vec<_>, Vec<_>) = std::mem::take(&mut log.active)
+        .into_iter()
+        .partition(ActiveMission::is_complete);
+    log.active = active;
+    for mission in &completed {
+        if !log.has_completed(&mission.mission_id) {
+            log.completed_mission_ids.push(mission.mission_id.clone());
+        }
+    }
+    completed
+}
+
+/// Pays a mission reward. Reputation changes are applied within the faction
+/// registry's bounds when `reputation_rules` is given; credits go through
+/// `grant_credits` with reason `mission.reward`, and a player without a
+/// wallet forfeits them. Returns the credits paid.
+pub fn pay_mission_reward(
+    reward: &MissionReward,
+    wallet: Option<(&mut CreditWallet, &mut CreditLedger)>,
+    reputation: &mut FactionReputation,
+    reputation_rules: Option<&FactionReputationRules>,
+    tick: u64,
+) -> Result<u64, CreditTransactionError> {
+    if let Some(rules) = reputation_rules {
+        for change in &reward.reputation {
+            reputation.adjust(&change.faction_id, change.delta, rules.min, rules.max);
+        }
+    }
+    let Some((wallet, ledger)) = wallet.filter(|_| reward.credits > 0) else {
+        return Ok(0);
+    };
+    grant_credits(wallet, ledger, reward.credits, "mission.reward", None, tick)?;
+    Ok(reward.credits)
+}
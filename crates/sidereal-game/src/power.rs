@@ -0,0 +1,170 @@
+//! Ship power and heat budget
+//!
+//! Hulls with mounted `Reactor`, `Capacitor` or `Heatsink` modules get a
+//! `ShipPowerState` that pools those modules into one budget: reactors refill
+//! stored energy every fixed tick, capacitors bound it, and heatsinks set how
+//! much heat the hull absorbs and how fast it bleeds off. Modules carrying a
+//! `PowerConsumer` draw from that budget — `apply_engine_thrust` scales thrust
+//! by the share of engine power delivered, ballistic weapons and launchers pay
+//! per shot, beams pay per tick while held, and active shields pay their upkeep
+//! per tick in `regenerate_shields`. Engines only request power for the thrust
+//! their fuel can deliver. Scanners are meant to hook in the same way through
+//! `draw_sustained_power`.
+//!
+//! Reaching `heat_capacity` overheats the hull: weapons refuse to fire, the
+//! afterburner cuts out and engine thrust drops to
+//! `ShipPowerSettings::overheat_thrust_scale` until heat falls back below the
+//! recovery fraction. Hulls without a `ShipPowerState` are unconstrained.
+//!
+//! The budget update runs under client prediction as well, so the owning
+//! client's HUD tracks its own draws between server corrections.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    Capacitor, CombatAuthorityEnabled, EntityGuid, Heatsink, MountedOn, PowerConsumer, Reactor,
+    ShipPowerState,
+};
+
+/// Tuning for overheat penalties.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ShipPowerSettings {
+    /// An overheated hull recovers once heat falls to this fraction of capacity.
+    pub overheat_recovery_fraction: f32,
+    /// Engine thrust multiplier while overheated.
+    pub overheat_thrust_scale: f32,
+}
+
+impl Default for ShipPowerSettings {
+    fn default() -> Self {
+        Self {
+            overheat_recovery_fraction: 0.5,
+            overheat_thrust_scale: 0.4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct PowerModuleTotals {
+    generation_mw: f32,
+    capacity_mj: f32,
+    heat_capacity: f32,
+    dissipation_per_s: f32,
+}
+
+fn collect_power_module_totals(
+    reactors: &Query<'_, '_, (&'_ MountedOn, &'_ Reactor)>,
+    capacitors: &Query<'_, '_, (&'_ MountedOn, &'_ Capacitor)>,
+    heatsinks: &Query<'_, '_, (&'_ MountedOn, &'_ Heatsink)>,
+) -> HashMap<Uuid, PowerModuleTotals> {
+    let mut totals = HashMap::<Uuid, PowerModuleTotals>::new();
+    for (mounted_on, reactor) in reactors {
+        totals
+            .entry(mounted_on.parent_entity_id)
+            .or_default()
+            .generation_mw += reactor.output_mw.max(0.0);
+    }
+    for (mounted_on, capacitor) in capacitors {
+        totals
+            .entry(mounted_on.parent_entity_id)
+            .or_default()
+            .capacity_mj += capacitor.capacity_mj.max(0.0);
+    }
+    for (mounted_on, heatsink) in heatsinks {
+        let entry = totals.entry(mounted_on.parent_entity_id).or_default();
+        entry.heat_capacity += heatsink.heat_capacity.max(0.0);
+        entry.dissipation_per_s += heatsink.dissipation_per_s.max(0.0);
+    }
+    totals
+}
+
+/// Gives hulls with power or thermal modules a fully charged, cold budget.
+pub fn bootstrap_ship_power_state(
+    mut commands: Commands<'_, '_>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    hulls: Query<'_, '_, (Entity, &'_ EntityGuid), (Without<ShipPowerState>, Without<MountedOn>)>,
+    reactors: Query<'_, '_, (&'_ MountedOn, &'_ Reactor)>,
+    capacitors: Query<'_, '_, (&'_ MountedOn, &'_ Capacitor)>,
+    heatsinks: Query<'_, '_, (&'_ MountedOn, &'_ Heatsink)>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let totals = collect_power_module_totals(&reactors, &capacitors, &heatsinks);
+    for (entity, guid) in &hulls {
+        let Some(totals) = totals.get(&guid.0) else {
+            continue;
+        };
+        commands.entity(entity).insert(ShipPowerState {
+            stored_mj: totals.capacity_mj,
+            capacity_mj: totals.capacity_mj,
+            generation_mw: totals.generation_mw,
+            heat: 0.0,
+            heat_capacity: totals.heat_capacity,
+            dissipation_per_s: totals.dissipation_per_s,
+            overheated: false,
+        });
+    }
+}
+
+/// Refreshes module totals, regenerates stored energy, dissipates heat and
+/// clears overheat once the hull has cooled down.
+pub fn update_ship_power_budget(
+    time: Res<'_, Time<Fixed>>,
+    settings: Option<Res<'_, ShipPowerSettings>>,
+    mut states: Query<'_, '_, (&'_ EntityGuid, &'_ mut ShipPowerState)>,
+    reactors: Query<'_, '_, (&'_ MountedOn, &'_ Reactor)>,
+    capacitors: Query<'_, '_, (&'_ MountedOn, &'_ Capacitor)>,
+    heatsinks: Query<'_, '_, (&'_ MountedOn, &'_ Heatsink)>,
+) {
+    let settings = settings.as_deref().copied().unwrap_or_default();
+    let dt_s = time.delta_secs().max(0.0);
+    let totals = collect_power_module_totals(&reactors, &capacitors, &heatsinks);
+    for (guid, mut state) in &mut states {
+        let totals = totals.get(&guid.0).copied().unwrap_or_default();
+        let mut next = state.clone();
+        next.generation_mw = totals.generation_mw;
+        next.capacity_mj = totals.capacity_mj;
+        next.heat_capacity = totals.heat_capacity;
+        next.dissipation_per_s = totals.dissipation_per_s;
+
+        // A hull without capacitors still gets this tick's generation to spend.
+        let tick_generation_mj = next.generation_mw * dt_s;
+        next.stored_mj = (next.stored_mj.max(0.0) + tick_generation_mj)
+            .min(next.capacity_mj.max(tick_generation_mj));
+        next.heat =
+            (next.heat - next.dissipation_per_s * dt_s).clamp(0.0, next.heat_capacity.max(0.0));
+        if next.overheated
+            && next.heat <= next.heat_capacity * settings.overheat_recovery_fraction.clamp(0.0, 1.0)
+        {
+            next.overheated = false;
+        }
+        state.set_if_neq(next);
+    }
+}
+
+/// Pays for one discrete activation (a shot or a launch). Hulls without a
+/// budget and modules without a `PowerConsumer` always succeed.
+pub fn draw_activation_power(
+    power: Option<&mut ShipPowerState>,
+    consumer: Option<&PowerConsumer>,
+) -> bool {
+    let (Some(power), Some(consumer)) = (power, consumer) else {
+        return true;
+    };
+    power.try_draw(consumer.energy_per_use_mj, consumer.heat_per_use)
+}
+
+/// Pays for `dt_s` of a sustained all-or-nothing draw such as a held beam.
+pub fn draw_sustained_power(
+    power: Option<&mut ShipPowerState>,
+    consumer: Option<&PowerConsumer>,
+    dt_s: f32,
+) -> bool {
+    let (Some(power), Some(consumer)) = (power, consumer) else {
+        return true;
+    };
+    power.try_draw(consumer.draw_mw * dt_s, consumer.heat_per_s * dt_s)
+}
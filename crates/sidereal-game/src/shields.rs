@@ -0,0 +1,123 @@
+//! Shield subsystem
+//!
+//! Shield generators are modules mounted on a hull via `MountedOn`. The hull's
+//! `ActionQueue` toggles them with `ActivateShield` / `DeactivateShield`; combat
+//! damage resolution routes through `absorb_damage_with_mounted_shields` so
+//! active shields soak damage before the hull `HealthPool`. Active shields with
+//! a `PowerConsumer` pay their upkeep from the hull's `ShipPowerState` each
+//! tick and stop regenerating while the hull cannot cover it.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::power::draw_sustained_power;
+use crate::{
+    ActionQueue, DamageType, EntityAction, EntityGuid, MountedOn, PowerConsumer, ShieldGenerator,
+    ShipPowerState, SimulationMotionWriter,
+};
+
+pub fn process_shield_actions(
+    mut hulls: Query<'_, '_, (&'_ EntityGuid, &'_ mut ActionQueue), With<SimulationMotionWriter>>,
+    mut shields: Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+) {
+    let mut requested_by_parent = HashMap::<Uuid, bool>::new();
+    for (guid, mut queue) in &mut hulls {
+        if let Some(active) = drain_shield_toggle_action(&mut queue) {
+            requested_by_parent.insert(guid.0, active);
+        }
+    }
+    if requested_by_parent.is_empty() {
+        return;
+    }
+
+    for (mounted_on, mut shield) in &mut shields {
+        let Some(active) = requested_by_parent.get(&mounted_on.parent_entity_id) else {
+            continue;
+        };
+        if shield.active != *active {
+            shield.active = *active;
+        }
+    }
+}
+
+pub fn regenerate_shields(
+    time: Res<'_, Time<Fixed>>,
+    mut shields: Query<
+        '_,
+        '_,
+        (
+            &'_ MountedOn,
+            &'_ mut ShieldGenerator,
+            Option<&'_ PowerConsumer>,
+        ),
+    >,
+    mut hulls: Query<'_, '_, (Entity, &'_ EntityGuid, &'_ mut ShipPowerState)>,
+) {
+    let dt_s = time.delta_secs();
+    if dt_s <= 0.0 {
+        return;
+    }
+    let hull_by_guid = hulls
+        .iter()
+        .map(|(entity, guid, _)| (guid.0, entity))
+        .collect::<HashMap<_, _>>();
+    for (mounted_on, mut shield, power_consumer) in &mut shields {
+        if !shield.active {
+            continue;
+        }
+        let mut power = hull_by_guid
+            .get(&mounted_on.parent_entity_id)
+            .and_then(|hull| hulls.get_mut(*hull).ok())
+            .map(|(_, _, power)| power);
+        if !draw_sustained_power(power.as_deref_mut(), power_consumer, dt_s) {
+            continue;
+        }
+        if shield.regen_delay_remaining_s > 0.0 {
+            shield.regen_delay_remaining_s = (shield.regen_delay_remaining_s - dt_s).max(0.0);
+            continue;
+        }
+        let capacity = shield.capacity.max(0.0);
+        if shield.current >= capacity {
+            continue;
+        }
+        shield.current = (shield.current + shield.regen_rate_per_s.max(0.0) * dt_s).min(capacity);
+    }
+}
+
+/// Routes `damage` through every active shield mounted on `target_guid` and
+/// returns the amount that should still be applied to the hull.
+pub fn absorb_damage_with_mounted_shields(
+    target_guid: Option<Uuid>,
+    damage: f32,
+    damage_type: DamageType,
+    shields: &mut Query<'_, '_, (&'_ MountedOn, &'_ mut ShieldGenerator)>,
+) -> f32 {
+    let Some(target_guid) = target_guid else {
+        return damage;
+    };
+    let mut remaining = damage.max(0.0);
+    for (mounted_on, mut shield) in shields.iter_mut() {
+        if remaining <= 0.0 {
+            break;
+        }
+        if mounted_on.parent_entity_id != target_guid || !shield.active {
+            continue;
+        }
+        remaining = shield.absorb(remaining, damage_type);
+    }
+    remaining
+}
+
+fn drain_shield_toggle_action(queue: &mut ActionQueue) -> Option<bool> {
+    let mut requested = None;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        match action {
+            EntityAction::ActivateShield => requested = Some(true),
+            EntityAction::DeactivateShield => requested = Some(false),
+            _ => queue.pending.push(action),
+        }
+    }
+    requested
+}
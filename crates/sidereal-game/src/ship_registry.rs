@@ -68,6 +68,10 @@ pub struct ShipRootDefinition {
     #[serde(default)]
     pub scanner_component: Option<JsonValue>,
     #[serde(default)]
+    pub damage_resistance_profile: Option<JsonValue>,
+    #[serde(default)]
+    pub crafting_facility: Option<JsonValue>,
+    #[serde(default)]
     pub avian_linear_damping: Option<f32>,
     #[serde(default)]
     pub avian_angular_damping: Option<f32>,
@@ -88,6 +92,8 @@ impl Default for ShipRootDefinition {
             flight_tuning: JsonValue::Null,
             visibility_range_buff_m: JsonValue::Null,
             scanner_component: None,
+            damage_resistance_profile: None,
+            crafting_facility: None,
             avian_linear_damping: None,
             avian_angular_damping: None,
         }
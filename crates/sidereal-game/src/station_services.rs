@@ -0,0 +1,300 @@
+//! Station repair and refuel services
+//!
+//! Station modules carrying a `StationService` sell hull repairs or fuel at
+//! the unit price in their `Cost` (credits per hit point or per kilogram).
+//! `PurchaseHullRepair` and `PurchaseRefuel` buy from a station the ship is
+//! docked at, or failing that the nearest one within the service's
+//! `service_range_m`. The ship gets as much as it needs and its responsible
+//! player's `CreditWallet` can cover, so a short balance buys a partial
+//! repair or refuel.
+//!
+//! Purchases run on the server only. Payment is a `debit_credits` against the
+//! player's wallet, so it lands in their `CreditLedger` (and from there the
+//! replication server's economy audit log) with the station as counterparty.
+//! The wallet, `HealthPool` and `FuelTank` changes persist with their
+//! entities. Each purchase is reported as a `StationServicePurchasedEvent`;
+//! refused purchases as `StationServiceFailedEvent`.
+
+use avian2d::prelude::Position;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::credits::{debit_credits, ledger_tick};
+use crate::factions::{FactionAffiliationQuery, FactionAffiliations, responsible_player};
+use crate::{
+    ActionQueue, CombatAuthorityEnabled, Cost, CreditLedger, CreditWallet, DockingState,
+    EntityAction, EntityGuid, FuelTank, HealthPool, MountedOn, PlayerTag, SimulationMotionWriter,
+    StationService, StationServiceKind,
+};
+
+#[derive(Debug, Clone, Message)]
+pub struct StationServicePurchasedEvent {
+    /// The `CreditLedgerEntry` that paid for the service.
+    pub transaction_id: Uuid,
+    pub player_guid: Uuid,
+    pub ship_guid: Uuid,
+    pub station_guid: Uuid,
+    pub service_guid: Uuid,
+    pub kind: StationServiceKind,
+    /// Hit points restored or kilograms of fuel delivered; fractional when
+    /// the deficit is.
+    pub units: f32,
+    pub unit_price: u64,
+    pub credits_charged: u64,
+    pub balance_after: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StationServiceFailureReason {
+    /// No station offering the service is docked or in range.
+    NoServiceInRange,
+    /// The hull is already fully repaired or fuelled.
+    NothingToService,
+    /// The ship has no responsible player with a `CreditWallet`.
+    NoWallet,
+    InsufficientCredits,
+}
+
+impl StationServiceFailureReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NoServiceInRange => "no_service_in_range",
+            Self::NothingToService => "nothing_to_service",
+            Self::NoWallet => "no_wallet",
+            Self::InsufficientCredits => "insufficient_credits",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Message)]
+pub struct StationServiceFailedEvent {
+    pub ship_guid: Uuid,
+    pub player_guid: Option<Uuid>,
+    pub kind: StationServiceKind,
+    pub reason: StationServiceFailureReason,
+}
+
+type ServiceShipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        &'static Position,
+        &'static mut ActionQueue,
+        Option<&'static DockingState>,
+        Option<&'static mut HealthPool>,
+    ),
+    With<SimulationMotionWriter>,
+>;
+
+#[derive(Debug, Clone, Copy)]
+struct ServiceOffer {
+    station_guid: Uuid,
+    service_guid: Uuid,
+    unit_price: u64,
+}
+
+/// Turns station service actions into purchases.
+///
+/// Input is a latest-intent snapshot, so the action repeats while held; only
+/// the first tick of a press buys anything.
+#[allow(clippy::too_many_arguments)]
+pub fn process_station_service_actions(
+    mut held_last_tick: Local<'_, HashSet<(Uuid, StationServiceKind)>>,
+    time: Res<'_, Time<Fixed>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut ships: ServiceShipQuery<'_, '_>,
+    stations: Query<'_, '_, (&'_ EntityGuid, &'_ Position), Without<MountedOn>>,
+    services: Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ StationService, &'_ Cost)>,
+    mut fuel_tanks: Query<'_, '_, (&'_ MountedOn, &'_ mut FuelTank)>,
+    affiliation_query: FactionAffiliationQuery<'_, '_>,
+    mut wallets: Query<'_, '_, (&'_ mut CreditWallet, &'_ mut CreditLedger), With<PlayerTag>>,
+    mut purchased_events: MessageWriter<'_, StationServicePurchasedEvent>,
+    mut failed_events: MessageWriter<'_, StationServiceFailedEvent>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let tick = ledger_tick(&time);
+    let station_positions = stations
+        .iter()
+        .map(|(guid, position)| (guid.0, position.0))
+        .collect::<HashMap<_, _>>();
+    let affiliations = FactionAffiliations::new(&affiliation_query);
+
+    let mut held_now = HashSet::<(Uuid, StationServiceKind)>::new();
+    for (ship_entity, ship_guid, position, mut queue, docking, mut health) in &mut ships {
+        for kind in drain_station_service_actions(&mut queue) {
+            held_now.insert((ship_guid.0, kind));
+            if held_last_tick.contains(&(ship_guid.0, kind)) {
+                continue;
+            }
+            let player = responsible_player(ship_entity, &affiliations);
+            let player_guid = player.and_then(|player| affiliations.entity_guid(player));
+            let fail = |reason| StationServiceFailedEvent {
+                ship_guid: ship_guid.0,
+                player_guid,
+                kind,
+                reason,
+            };
+
+            let docked_host = docking
+                .filter(|docking| docking.is_docked())
+                .and_then(|docking| docking.host_guid);
+            let Some(offer) =
+                nearest_service_offer(kind, position.0, docked_host, &station_positions, &services)
+            else {
+                failed_events.write(fail(StationServiceFailureReason::NoServiceInRange));
+                continue;
+            };
+
+            let units_needed: f32 = match kind {
+                StationServiceKind::HullRepair => health
+                    .as_deref()
+                    .map_or(0.0, |health| (health.maximum - health.current).max(0.0)),
+                StationServiceKind::Refuel => fuel_tanks
+                    .iter()
+                    .filter(|(mounted_on, _)| mounted_on.parent_entity_id == ship_guid.0)
+                    .map(|(_, tank)| (tank.capacity_kg - tank.fuel_kg).max(0.0))
+                    .sum(),
+            };
+            if units_needed <= 0.0 {
+                failed_events.write(fail(StationServiceFailureReason::NothingToService));
+                continue;
+            }
+
+            let (Some(player_guid), Some((mut wallet, mut ledger))) = (
+                player_guid,
+                player.and_then(|player| wallets.get_mut(player).ok()),
+            ) else {
+                failed_events.write(fail(StationServiceFailureReason::NoWallet));
+                continue;
+            };
+            // Billed pro rata for what is actually missing, rounded up to
+            // the next whole credit and never past what the wallet holds.
+            let units = if offer.unit_price == 0 {
+                units_needed
+            } else {
+                units_needed.min((wallet.balance as f64 / offer.unit_price as f64) as f32)
+            };
+            if units <= 0.0 {
+                failed_events.write(fail(StationServiceFailureReason::InsufficientCredits));
+                continue;
+            }
+            let credits_charged =
+                ((f64::from(units) * offer.unit_price as f64).ceil() as u64).min(wallet.balance);
+            // Free services have nothing to record in the ledger.
+            let transaction_id = if credits_charged == 0 {
+                Uuid::new_v4()
+            } else {
+                let Ok(entry) = debit_credits(
+                    &mut wallet,
+                    &mut ledger,
+                    credits_charged,
+                    &format!("station_service.{}", kind.as_str()),
+                    Some(offer.station_guid),
+                    tick,
+                ) else {
+                    failed_events.write(fail(StationServiceFailureReason::InsufficientCredits));
+                    continue;
+                };
+                entry.transaction_id
+            };
+
+            match kind {
+                StationServiceKind::HullRepair => {
+                    if let Some(health) = health.as_deref_mut() {
+                        health.current = (health.current + units).min(health.maximum);
+                    }
+                }
+                StationServiceKind::Refuel => {
+                    let mut remaining_kg = units;
+                    for (mounted_on, mut tank) in &mut fuel_tanks {
+                        if mounted_on.parent_entity_id != ship_guid.0 || remaining_kg <= 0.0 {
+                            continue;
+                        }
+                        let added_kg = (tank.capacity_kg - tank.fuel_kg).max(0.0).min(remaining_kg);
+                        if added_kg > 0.0 {
+                            tank.fuel_kg += added_kg;
+                            remaining_kg -= added_kg;
+                        }
+                    }
+                }
+            }
+
+            purchased_events.write(StationServicePurchasedEvent {
+                transaction_id,
+                player_guid,
+                ship_guid: ship_guid.0,
+                station_guid: offer.station_guid,
+                service_guid: offer.service_guid,
+                kind,
+                units,
+                unit_price: offer.unit_price,
+                credits_charged,
+                balance_after: wallet.balance,
+            });
+        }
+    }
+    *held_last_tick = held_now;
+}
+
+/// The station the ship is docked at if it offers `kind`, else the nearest
+/// station offering it within its service range.
+fn nearest_service_offer(
+    kind: StationServiceKind,
+    ship_position: DVec2,
+    docked_host: Option<Uuid>,
+    station_positions: &HashMap<Uuid, DVec2>,
+    services: &Query<'_, '_, (&'_ EntityGuid, &'_ MountedOn, &'_ StationService, &'_ Cost)>,
+) -> Option<ServiceOffer> {
+    services
+        .iter()
+        .filter(|(_, _, service, _)| service.kind == kind)
+        .filter_map(|(service_guid, mounted_on, service, cost)| {
+            let station_guid = mounted_on.parent_entity_id;
+            let distance_m = if docked_host == Some(station_guid) {
+                -1.0
+            } else {
+                let distance_m = station_positions
+                    .get(&station_guid)?
+                    .distance(ship_position);
+                if distance_m > f64::from(service.service_range_m.max(0.0)) {
+                    return None;
+                }
+                distance_m
+            };
+            Some((
+                distance_m,
+                ServiceOffer {
+                    station_guid,
+                    service_guid: service_guid.0,
+                    unit_price: cost.credits,
+                },
+            ))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, offer)| offer)
+}
+
+fn drain_station_service_actions(queue: &mut ActionQueue) -> Vec<StationServiceKind> {
+    let mut requested = Vec::new();
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        let kind = match action {
+            EntityAction::PurchaseHullRepair => StationServiceKind::HullRepair,
+            EntityAction::PurchaseRefuel => StationServiceKind::Refuel,
+            _ => {
+                queue.pending.push(action);
+                continue;
+            }
+        };
+        if !requested.contains(&kind) {
+            requested.push(kind);
+        }
+    }
+    requested
+}
@@ -0,0 +1,235 @@
+//! Subsystem damage and field repair
+//!
+//! Mounted modules carrying a `ModuleHealth` take a share of every hull hit
+//! that lands within `SubsystemDamageSettings::module_hit_radius_m` of their
+//! hardpoint; the nearest module absorbs it. Damage degrades the module in
+//! proportion to `ModuleHealth::performance`: engines scale their thrust caps
+//! (`apply_engine_thrust`), scanners shrink `ScannerComponent::base_range_m`
+//! from their rated range, and destroyed weapons, launchers and beam emitters
+//! stop answering fire actions. The hull's own `HealthPool` is unaffected —
+//! module damage is applied on top of the regular hit.
+//!
+//! `RepairModules` spends one repair kit from the hull's `Inventory` to start
+//! a timed field repair on its most damaged module, one module at a time.
+
+use avian2d::prelude::{Position, Rotation};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    ActionQueue, CombatAuthorityEnabled, EntityAction, EntityGuid, Hardpoint, Inventory, MassDirty,
+    ModuleHealth, MountedOn, ParentGuid, ScannerComponent, ShotHitEvent,
+};
+
+/// Tuning for module hits and field repairs.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SubsystemDamageSettings {
+    /// Share of hull damage a hit also deals to the nearest module.
+    pub module_damage_fraction: f32,
+    /// Hits further than this from every hardpoint spare the modules.
+    pub module_hit_radius_m: f32,
+    /// Catalog `item_id` consumed per field repair.
+    pub repair_kit_item_id: String,
+    /// Module health restored per kit.
+    pub repair_per_kit: f32,
+    pub repair_time_s: f32,
+}
+
+impl Default for SubsystemDamageSettings {
+    fn default() -> Self {
+        Self {
+            module_damage_fraction: 0.5,
+            module_hit_radius_m: 6.0,
+            repair_kit_item_id: "repair.module_kit".to_string(),
+            repair_per_kit: 100.0,
+            repair_time_s: 4.0,
+        }
+    }
+}
+
+/// Fraction of rated output a module delivers. Modules without a
+/// `ModuleHealth` always run at full output.
+pub fn module_performance(health: Option<&ModuleHealth>) -> f32 {
+    health.map_or(1.0, ModuleHealth::performance)
+}
+
+/// Routes a share of each hull hit to the module mounted nearest the impact.
+pub fn apply_subsystem_damage(
+    settings: Option<Res<'_, SubsystemDamageSettings>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut hit_events: MessageReader<'_, '_, ShotHitEvent>,
+    hulls: Query<'_, '_, (&'_ Position, &'_ Rotation)>,
+    hardpoints: Query<'_, '_, (&'_ ParentGuid, &'_ Hardpoint)>,
+    mut modules: Query<'_, '_, (&'_ MountedOn, &'_ mut ModuleHealth)>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let settings = settings.as_deref().cloned().unwrap_or_default();
+    let hits = hit_events
+        .read()
+        .filter(|hit| hit.damage > 0.0)
+        .filter_map(|hit| Some((hit.target_guid?, hit.target_entity, hit)))
+        .collect::<Vec<_>>();
+    if hits.is_empty() {
+        return;
+    }
+    let hardpoint_offsets = hardpoints
+        .iter()
+        .map(|(parent_guid, hardpoint)| {
+            (
+                (parent_guid.0, hardpoint.hardpoint_id.as_str()),
+                hardpoint.offset_m.truncate(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    for (target_guid, target_entity, hit) in hits {
+        let Ok((hull_position, hull_rotation)) = hulls.get(target_entity) else {
+            continue;
+        };
+        let hull_quat = Quat::from_rotation_z(hull_rotation.as_radians() as f32);
+        let radius_m = f64::from(settings.module_hit_radius_m.max(0.0));
+        let mut nearest: Option<(f64, Mut<'_, ModuleHealth>)> = None;
+        for (mounted_on, health) in &mut modules {
+            if mounted_on.parent_entity_id != target_guid || health.is_destroyed() {
+                continue;
+            }
+            let Some(offset) =
+                hardpoint_offsets.get(&(target_guid, mounted_on.hardpoint_id.as_str()))
+            else {
+                continue;
+            };
+            let module_pos =
+                hull_position.0 + (hull_quat * offset.extend(0.0)).truncate().as_dvec2();
+            let distance_m = module_pos.distance(hit.impact_pos);
+            if distance_m <= radius_m
+                && nearest
+                    .as_ref()
+                    .is_none_or(|(best_m, _)| distance_m < *best_m)
+            {
+                nearest = Some((distance_m, health));
+            }
+        }
+        if let Some((_, mut health)) = nearest {
+            health.apply_damage(hit.damage * settings.module_damage_fraction.max(0.0));
+        }
+    }
+}
+
+/// Keeps scanner range in step with scanner module health, remembering the
+/// undamaged range in `ScannerComponent::rated_range_m`.
+pub fn apply_scanner_module_damage(
+    mut scanners: Query<
+        '_,
+        '_,
+        (&'_ ModuleHealth, &'_ mut ScannerComponent),
+        Or<(Changed<ModuleHealth>, Added<ScannerComponent>)>,
+    >,
+) {
+    for (health, mut scanner) in &mut scanners {
+        let rated_range_m = scanner.rated_range_m.unwrap_or(scanner.base_range_m);
+        let range_m = rated_range_m * health.performance();
+        if scanner.rated_range_m != Some(rated_range_m) || scanner.base_range_m != range_m {
+            scanner.rated_range_m = Some(rated_range_m);
+            scanner.base_range_m = range_m;
+        }
+    }
+}
+
+type RepairHullQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static mut ActionQueue>,
+        Option<&'static mut Inventory>,
+    ),
+    Without<ModuleHealth>,
+>;
+
+pub fn process_module_repairs(
+    mut commands: Commands<'_, '_>,
+    time: Res<'_, Time<Fixed>>,
+    settings: Option<Res<'_, SubsystemDamageSettings>>,
+    authority_enabled: Option<Res<'_, CombatAuthorityEnabled>>,
+    mut hulls: RepairHullQuery<'_, '_>,
+    mut modules: Query<'_, '_, (Entity, &'_ MountedOn, &'_ mut ModuleHealth)>,
+) {
+    if !authority_enabled.is_none_or(|value| value.0) {
+        return;
+    }
+    let settings = settings.as_deref().cloned().unwrap_or_default();
+    let dt_s = time.delta_secs().max(0.0);
+    let mut repair_requested = HashSet::<Uuid>::new();
+    for (_, guid, queue, _) in &mut hulls {
+        if let Some(mut queue) = queue
+            && drain_repair_modules_action(&mut queue)
+        {
+            repair_requested.insert(guid.0);
+        }
+    }
+
+    let mut busy_hulls = HashSet::<Uuid>::new();
+    let mut most_damaged = HashMap::<Uuid, (f32, Entity)>::new();
+    for (entity, mounted_on, mut health) in &mut modules {
+        let hull_guid = mounted_on.parent_entity_id;
+        if health.is_repairing() {
+            health.repair_remaining_s = (health.repair_remaining_s - dt_s).max(0.0);
+            if health.is_repairing() {
+                busy_hulls.insert(hull_guid);
+            } else {
+                health.restore(settings.repair_per_kit);
+            }
+            continue;
+        }
+        if !repair_requested.contains(&hull_guid) || !health.is_damaged() {
+            continue;
+        }
+        let performance = health.performance();
+        if most_damaged
+            .get(&hull_guid)
+            .is_none_or(|(best, _)| performance < *best)
+        {
+            most_damaged.insert(hull_guid, (performance, entity));
+        }
+    }
+
+    for (hull_entity, guid, _, inventory) in &mut hulls {
+        if busy_hulls.contains(&guid.0) {
+            continue;
+        }
+        let (Some((_, module_entity)), Some(mut inventory)) =
+            (most_damaged.get(&guid.0), inventory)
+        else {
+            continue;
+        };
+        if !inventory.remove_stack(&settings.repair_kit_item_id, 1) {
+            continue;
+        }
+        commands.entity(hull_entity).insert(MassDirty);
+        let Ok((_, _, mut health)) = modules.get_mut(*module_entity) else {
+            continue;
+        };
+        if settings.repair_time_s > 0.0 {
+            health.repair_remaining_s = settings.repair_time_s;
+        } else {
+            health.restore(settings.repair_per_kit);
+        }
+    }
+}
+
+fn drain_repair_modules_action(queue: &mut ActionQueue) -> bool {
+    let mut wants_repair = false;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        if action == EntityAction::RepairModules {
+            wants_repair = true;
+        } else {
+            queue.pending.push(action);
+        }
+    }
+    wants_repair
+}
@@ -0,0 +1,247 @@
+//! Tractor beam subsystem
+//!
+//! Tractor modules are mounted on a hull via `MountedOn` and toggled by the
+//! hull's `ActivateTractor` / `DeactivateTractor` actions. An active beam locks
+//! a towable body (loose dynamic bodies such as cargo or asteroid fragments, or
+//! ships whose `HealthPool` is depleted), preferring the controlling player's
+//! selected entity over the nearest one, and applies a damped spring force
+//! through Avian's `Forces` helper. The force is scaled by the target's
+//! `TotalMassKg` and capped by the module, with the reaction applied to the hull.
+
+use avian2d::prelude::*;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::autopilot::selected_target_for_hull;
+use crate::{
+    ActionQueue, ControlledEntityGuid, EntityAction, EntityGuid, FocusedEntityGuid, HealthPool,
+    MountedOn, PlayerTag, SelectedEntityGuid, ShipTag, SimulationMotionWriter, TotalMassKg,
+    TractorBeam, WorldPosition, resolve_world_position,
+};
+
+/// Spring stiffness (1/s^2) pulling the target toward the hold distance.
+const TRACTOR_STIFFNESS_PER_S2: f64 = 1.0;
+/// Relative-velocity damping (1/s); 2*sqrt(stiffness) keeps the tow critically damped.
+const TRACTOR_DAMPING_PER_S: f64 = 2.0;
+
+type TractorBodyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static EntityGuid,
+        Option<&'static Position>,
+        Option<&'static WorldPosition>,
+        Option<&'static LinearVelocity>,
+        Option<&'static TotalMassKg>,
+        Option<&'static RigidBody>,
+        Option<&'static HealthPool>,
+        Has<ShipTag>,
+    ),
+    Without<MountedOn>,
+>;
+
+#[derive(Debug, Clone, Copy)]
+struct TractorBody {
+    entity: Entity,
+    position: DVec2,
+    velocity: DVec2,
+    mass_kg: Option<f32>,
+    towable: bool,
+}
+
+pub fn process_tractor_actions(
+    mut hulls: Query<'_, '_, (&'_ EntityGuid, &'_ mut ActionQueue), With<SimulationMotionWriter>>,
+    mut tractors: Query<'_, '_, (&'_ MountedOn, &'_ mut TractorBeam)>,
+) {
+    let mut requested_by_parent = HashMap::<Uuid, bool>::new();
+    for (guid, mut queue) in &mut hulls {
+        if let Some(active) = drain_tractor_toggle_action(&mut queue) {
+            requested_by_parent.insert(guid.0, active);
+        }
+    }
+    if requested_by_parent.is_empty() {
+        return;
+    }
+
+    for (mounted_on, mut tractor) in &mut tractors {
+        let Some(active) = requested_by_parent.get(&mounted_on.parent_entity_id) else {
+            continue;
+        };
+        if tractor.active == *active {
+            continue;
+        }
+        tractor.active = *active;
+        if !*active {
+            tractor.target_guid = None;
+        }
+    }
+}
+
+pub fn apply_tractor_beam_forces(
+    mut tractors: Query<'_, '_, (&'_ MountedOn, &'_ mut TractorBeam)>,
+    mut body_queries: ParamSet<'_, '_, (TractorBodyQuery<'_, '_>, Query<'_, '_, Forces>)>,
+    players: Query<
+        '_,
+        '_,
+        (
+            &'_ ControlledEntityGuid,
+            Option<&'_ SelectedEntityGuid>,
+            Option<&'_ FocusedEntityGuid>,
+        ),
+        With<PlayerTag>,
+    >,
+) {
+    if !tractors.iter().any(|(_, tractor)| tractor.active) {
+        return;
+    }
+
+    let mut bodies_by_guid = HashMap::<Uuid, TractorBody>::new();
+    for (
+        entity,
+        guid,
+        position,
+        world_position,
+        velocity,
+        total_mass,
+        rigid_body,
+        health,
+        is_ship,
+    ) in &body_queries.p0()
+    {
+        let Some(position) = resolve_world_position(position, world_position) else {
+            continue;
+        };
+        let disabled_ship = is_ship && health.is_some_and(|health| health.current <= 0.0);
+        bodies_by_guid.insert(
+            guid.0,
+            TractorBody {
+                entity,
+                position,
+                velocity: velocity.map(|velocity| velocity.0).unwrap_or(DVec2::ZERO),
+                mass_kg: total_mass.map(|mass| mass.0),
+                towable: matches!(rigid_body, Some(RigidBody::Dynamic))
+                    && (!is_ship || disabled_ship),
+            },
+        );
+    }
+
+    let mut force_by_entity = HashMap::<Entity, DVec2>::new();
+    for (mounted_on, mut tractor) in &mut tractors {
+        if !tractor.active {
+            continue;
+        }
+        let hull_guid = mounted_on.parent_entity_id;
+        let Some(hull) = bodies_by_guid.get(&hull_guid).copied() else {
+            continue;
+        };
+
+        let holdable = |guid: Uuid| {
+            if guid == hull_guid {
+                return None;
+            }
+            bodies_by_guid
+                .get(&guid)
+                .filter(|body| tractor_can_hold(&tractor, hull.position, body))
+                .map(|body| (guid, *body))
+        };
+        // A held lock wins, then the player's selection, then the nearest
+        // body with GUID order breaking distance ties.
+        let target = tractor
+            .target_guid
+            .and_then(holdable)
+            .or_else(|| selected_target_for_hull(hull_guid, &players).and_then(holdable))
+            .or_else(|| {
+                bodies_by_guid
+                    .iter()
+                    .filter(|(guid, body)| {
+                        **guid != hull_guid && tractor_can_hold(&tractor, hull.position, body)
+                    })
+                    .min_by(|(guid_a, a), (guid_b, b)| {
+                        a.position
+                            .distance_squared(hull.position)
+                            .total_cmp(&b.position.distance_squared(hull.position))
+                            .then_with(|| guid_a.cmp(guid_b))
+                    })
+                    .map(|(guid, body)| (*guid, *body))
+            });
+        let Some((target_guid, target)) = target else {
+            if tractor.target_guid.is_some() {
+                tractor.target_guid = None;
+            }
+            continue;
+        };
+        if tractor.target_guid != Some(target_guid) {
+            tractor.target_guid = Some(target_guid);
+        }
+
+        let force = compute_tractor_force(
+            hull.position,
+            hull.velocity,
+            target.position,
+            target.velocity,
+            target.mass_kg.unwrap_or(1.0),
+            &tractor,
+        );
+        *force_by_entity.entry(target.entity).or_default() += force;
+        *force_by_entity.entry(hull.entity).or_default() -= force;
+    }
+
+    let mut forces = body_queries.p1();
+    for (entity, force) in force_by_entity {
+        if force == DVec2::ZERO {
+            continue;
+        }
+        if let Ok(mut body_forces) = forces.get_mut(entity) {
+            body_forces.apply_force(force);
+        }
+    }
+}
+
+/// Damped spring force applied to the tractored body, pulling it toward
+/// `hold_distance_m` from the hull and matching the hull's velocity.
+#[doc(hidden)]
+pub fn compute_tractor_force(
+    hull_position: DVec2,
+    hull_velocity: DVec2,
+    target_position: DVec2,
+    target_velocity: DVec2,
+    target_mass_kg: f32,
+    tractor: &TractorBeam,
+) -> DVec2 {
+    let offset = hull_position - target_position;
+    let distance_m = offset.length();
+    if !distance_m.is_finite() || distance_m <= f64::EPSILON {
+        return DVec2::ZERO;
+    }
+    let direction = offset / distance_m;
+    let stretch_m = distance_m - f64::from(tractor.hold_distance_m.max(0.0));
+    let relative_velocity = target_velocity - hull_velocity;
+    let desired_accel = direction * stretch_m * TRACTOR_STIFFNESS_PER_S2
+        - relative_velocity * TRACTOR_DAMPING_PER_S;
+    let force = desired_accel * f64::from(target_mass_kg.max(1.0));
+    force.clamp_length_max(f64::from(tractor.max_force_n.max(0.0)))
+}
+
+fn tractor_can_hold(tractor: &TractorBeam, hull_position: DVec2, body: &TractorBody) -> bool {
+    body.towable
+        && body
+            .mass_kg
+            .is_some_and(|mass_kg| mass_kg <= tractor.max_target_mass_kg)
+        && body.position.distance(hull_position) <= f64::from(tractor.range_m.max(0.0))
+}
+
+fn drain_tractor_toggle_action(queue: &mut ActionQueue) -> Option<bool> {
+    let mut requested = None;
+    let pending = std::mem::take(&mut queue.pending);
+    for action in pending {
+        match action {
+            EntityAction::ActivateTractor => requested = Some(true),
+            EntityAction::DeactivateTractor => requested = Some(false),
+            _ => queue.pending.push(action),
+        }
+    }
+    requested
+}
@@ -29,4 +29,9 @@ fn default_flight_capabilities_match_allowlist() {
     assert!(caps.can_handle(EntityAction::Brake));
     assert!(caps.can_handle(EntityAction::FirePrimary));
     assert!(caps.can_handle(EntityAction::FireSecondary));
+    assert!(caps.can_handle(EntityAction::ActivateShield));
+    assert!(caps.can_handle(EntityAction::DeactivateShield));
+    assert!(caps.can_handle(EntityAction::ActivateTractor));
+    assert!(caps.can_handle(EntityAction::ActivateMiningLaser));
+    assert!(caps.can_handle(EntityAction::DeployCargo));
 }
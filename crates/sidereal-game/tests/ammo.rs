@@ -0,0 +1,178 @@
+use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, AmmoCount, AmmoFeed, BallisticWeapon, EntityAction, EntityGuid, Hardpoint,
+    Inventory, MassDirty, MountedOn, ParentGuid, ShotFiredEvent, SimulationMotionWriter,
+    WeaponCooldownState, process_weapon_fire_actions, process_weapon_reloads,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+const BELT_ITEM_ID: &str = "ammo.gatling_belt";
+
+fn reload_app(step_s: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(step_s));
+    app
+}
+
+fn spawn_fed_weapon(app: &mut App, loaded: u32, belts: u32) -> (Entity, Entity) {
+    let hull_guid = Uuid::new_v4();
+    let mut inventory = Inventory::default();
+    inventory.add_stack(BELT_ITEM_ID, belts, 7.5);
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            Position(Vec2::ZERO.into()),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(Vec2::ZERO.into()),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+            inventory,
+        ))
+        .id();
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(hull_guid),
+        Hardpoint {
+            hardpoint_id: "weapon_fore_center".to_string(),
+            offset_m: Vec3::new(0.0, 4.0, 0.0),
+            local_rotation: Quat::IDENTITY,
+        },
+    ));
+    let weapon = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: hull_guid,
+                hardpoint_id: "weapon_fore_center".to_string(),
+            },
+            BallisticWeapon::corvette_ballistic_gatling(),
+            WeaponCooldownState::default(),
+            AmmoCount::new(loaded, 500),
+            AmmoFeed {
+                ammo_item_ids: vec![BELT_ITEM_ID.to_string()],
+                rounds_per_item: 50,
+                reload_time_s: 1.0,
+                auto_reload: true,
+                reload_remaining_s: 0.0,
+            },
+        ))
+        .id();
+    (hull, weapon)
+}
+
+fn run_reloads(app: &mut App, ticks: usize) {
+    for _ in 0..ticks {
+        let _ = app.world_mut().run_system_once(process_weapon_reloads);
+    }
+}
+
+#[test]
+fn empty_magazine_auto_reloads_partially_from_cargo_after_reload_time() {
+    let mut app = reload_app(0.5);
+    let (hull, weapon) = spawn_fed_weapon(&mut app, 0, 3);
+
+    run_reloads(&mut app, 2);
+    let feed = app.world().entity(weapon).get::<AmmoFeed>().unwrap();
+    assert!(feed.is_reloading());
+    assert_eq!(
+        app.world()
+            .entity(weapon)
+            .get::<AmmoCount>()
+            .unwrap()
+            .current,
+        0
+    );
+
+    run_reloads(&mut app, 1);
+    let ammo = app.world().entity(weapon).get::<AmmoCount>().unwrap();
+    assert_eq!(ammo.current, 150);
+    let hull_ref = app.world().entity(hull);
+    assert_eq!(
+        hull_ref
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of(BELT_ITEM_ID),
+        0
+    );
+    assert!(hull_ref.contains::<MassDirty>());
+}
+
+#[test]
+fn reload_action_tops_up_with_whole_items_only() {
+    let mut app = reload_app(0.5);
+    let (hull, weapon) = spawn_fed_weapon(&mut app, 420, 5);
+    app.world_mut()
+        .entity_mut(hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .push(EntityAction::ReloadWeapons);
+
+    run_reloads(&mut app, 3);
+
+    assert_eq!(
+        app.world()
+            .entity(weapon)
+            .get::<AmmoCount>()
+            .unwrap()
+            .current,
+        470
+    );
+    assert_eq!(
+        app.world()
+            .entity(hull)
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of(BELT_ITEM_ID),
+        4
+    );
+    assert!(
+        app.world()
+            .entity(hull)
+            .get::<ActionQueue>()
+            .unwrap()
+            .pending
+            .is_empty()
+    );
+}
+
+#[test]
+fn weapons_hold_fire_while_reloading() {
+    let mut app = reload_app(0.5);
+    let (hull, weapon) = spawn_fed_weapon(&mut app, 420, 5);
+    app.world_mut()
+        .entity_mut(weapon)
+        .get_mut::<AmmoFeed>()
+        .unwrap()
+        .reload_remaining_s = 0.5;
+    app.world_mut()
+        .entity_mut(hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .push(EntityAction::FirePrimary);
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    assert_eq!(
+        app.world()
+            .entity(weapon)
+            .get::<AmmoCount>()
+            .unwrap()
+            .current,
+        420
+    );
+    assert!(
+        app.world()
+            .resource::<Messages<ShotFiredEvent>>()
+            .is_empty()
+    );
+}
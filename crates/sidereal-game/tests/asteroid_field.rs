@@ -3,14 +3,20 @@ use bevy::ecs::system::RunSystemOnce;
 use bevy::math::DVec2;
 use bevy::prelude::*;
 use sidereal_game::{
-    AsteroidField, AsteroidFieldCluster, AsteroidFieldDamageState, AsteroidFieldLayout,
-    AsteroidFieldMember, AsteroidFieldPopulation, AsteroidFieldShape, AsteroidFractureParent,
-    AsteroidFractureProfile, AsteroidMemberStateEntry, AsteroidMemberStateKind,
-    AsteroidResourceProfile, AsteroidSizeRangeM, AsteroidSizeTier, AsteroidYieldEntry,
-    Destructible, EntityGuid, HealthPool, MassKg, ProceduralSprite, ProceduralSpriteSurfaceStyle,
-    SizeM, asteroid_child_member_key, asteroid_member_key, build_fracture_child_plans,
-    fracture_child_count, fracture_depleted_asteroid_members, next_smaller_tier,
+    ActionQueue, AsteroidField, AsteroidFieldCluster, AsteroidFieldDamageState,
+    AsteroidFieldLayout, AsteroidFieldMember, AsteroidFieldPopulation, AsteroidFieldShape,
+    AsteroidFractureParent, AsteroidFractureProfile, AsteroidMemberStateEntry,
+    AsteroidMemberStateKind, AsteroidResourceProfile, AsteroidSizeRangeM, AsteroidSizeTier,
+    AsteroidYieldEntry, BaseMassKg, CargoCapacity, CargoMassKg, Destructible, EntityAction,
+    EntityGuid, HealthPool, Inventory, MassDirty, MassKg, MiningLaser, MiningStatus,
+    MiningStatusChangedEvent, ModuleMassKg, MountedOn, OwnerId, ProceduralSprite,
+    ProceduralSpriteSurfaceStyle, ResourceExtractedEvent, SimulationMotionWriter, SizeM,
+    TotalMassKg, asteroid_child_member_key, asteroid_member_key, build_fracture_child_plans,
+    extract_asteroid_resources, fracture_child_count, fracture_depleted_asteroid_members,
+    next_smaller_tier, process_mining_actions, recompute_total_mass,
+    roll_asteroid_extraction_yield,
 };
+use std::time::Duration;
 use uuid::Uuid;
 
 fn fracture_profile() -> AsteroidFractureProfile {
@@ -194,6 +200,7 @@ fn asteroid_field_payloads_roundtrip_json() {
             weight: 1.0,
             min_units: 4.0,
             max_units: 18.0,
+            unit_mass_kg: 2.5,
         }],
         depletion_pool_units: 100.0,
     };
@@ -250,6 +257,7 @@ fn zero_health_field_member_fractures_into_linked_children() {
             Destructible {
                 destruction_profile_id: "destruction.asteroid.default".to_string(),
                 destroy_delay_s: 0.18,
+                ..Default::default()
             },
             MassKg(9_000.0),
             SizeM {
@@ -301,3 +309,328 @@ fn zero_health_field_member_fractures_into_linked_children() {
     assert_eq!(parent_entry.state, AsteroidMemberStateKind::Fractured);
     assert_eq!(parent_entry.spawned_children.len(), child_count);
 }
+
+fn single_ore_resource_profile(units_per_roll: f32, pool_units: f32) -> AsteroidResourceProfile {
+    AsteroidResourceProfile {
+        profile_id: "asteroid.resource.test_ore".to_string(),
+        extraction_profile_id: Some("extraction.mining_laser.basic".to_string()),
+        yield_table: vec![AsteroidYieldEntry {
+            item_id: "resource.iron_ore".to_string(),
+            weight: 1.0,
+            min_units: units_per_roll,
+            max_units: units_per_roll,
+            unit_mass_kg: 2.5,
+        }],
+        depletion_pool_units: pool_units,
+    }
+}
+
+fn mining_laser(active: bool, range_m: f32) -> MiningLaser {
+    MiningLaser {
+        range_m,
+        cycle_time_s: 1.0,
+        yield_multiplier: 1.0,
+        active,
+        target_guid: None,
+        cycle_progress_s: 0.0,
+    }
+}
+
+struct MiningScene {
+    hull: Entity,
+    laser: Entity,
+    member_guid: Uuid,
+    member_key: String,
+}
+
+fn spawn_mining_scene(
+    app: &mut App,
+    profile: AsteroidResourceProfile,
+    laser: MiningLaser,
+) -> MiningScene {
+    app.insert_resource(Time::<Fixed>::from_hz(1.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs(1));
+
+    let field_guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(field_guid),
+        AsteroidFieldDamageState::default(),
+        profile,
+    ));
+    let member_guid = Uuid::new_v4();
+    let member_key = "field:test:0001".to_string();
+    app.world_mut().spawn((
+        EntityGuid(member_guid),
+        AsteroidFieldMember {
+            field_entity_id: field_guid.to_string(),
+            cluster_key: "test".to_string(),
+            member_key: member_key.clone(),
+            parent_member_key: None,
+            size_tier: AsteroidSizeTier::Medium,
+            fracture_depth: 0,
+            resource_profile_id: "asteroid.resource.test_ore".to_string(),
+            fracture_profile_id: "asteroid.fracture.default".to_string(),
+        },
+        Position(DVec2::new(60.0, 0.0)),
+    ));
+
+    let hull_guid = Uuid::new_v4();
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            OwnerId("11111111-1111-1111-1111-111111111111".to_string()),
+            Position(DVec2::ZERO),
+            MassKg(1_000.0),
+            BaseMassKg(1_000.0),
+            CargoMassKg(0.0),
+            ModuleMassKg(0.0),
+            TotalMassKg(1_000.0),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+        ))
+        .id();
+    let laser = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: hull_guid,
+                hardpoint_id: "utility_fore".to_string(),
+            },
+            laser,
+        ))
+        .id();
+    MiningScene {
+        hull,
+        laser,
+        member_guid,
+        member_key,
+    }
+}
+
+fn collect_extracted_events(
+    mut reader: MessageReader<'_, '_, ResourceExtractedEvent>,
+) -> Vec<ResourceExtractedEvent> {
+    reader.read().cloned().collect()
+}
+
+fn drain_extracted_events(app: &mut App) -> Vec<ResourceExtractedEvent> {
+    app.world_mut()
+        .run_system_once(collect_extracted_events)
+        .expect("read extracted events")
+}
+
+fn collect_mining_statuses(
+    mut reader: MessageReader<'_, '_, MiningStatusChangedEvent>,
+) -> Vec<MiningStatus> {
+    reader.read().map(|event| event.status).collect()
+}
+
+fn drain_mining_statuses(app: &mut App) -> Vec<MiningStatus> {
+    app.world_mut()
+        .run_system_once(collect_mining_statuses)
+        .expect("read mining status events")
+}
+
+#[test]
+fn extraction_yield_rolls_are_deterministic_and_bounded() {
+    let mut profile = single_ore_resource_profile(1.0, 100.0);
+    profile.yield_table = vec![
+        AsteroidYieldEntry {
+            item_id: "resource.iron_ore".to_string(),
+            weight: 1.0,
+            min_units: 4.0,
+            max_units: 18.0,
+            unit_mass_kg: 2.5,
+        },
+        AsteroidYieldEntry {
+            item_id: "resource.nickel_ore".to_string(),
+            weight: 0.0,
+            min_units: 2.0,
+            max_units: 10.0,
+            unit_mass_kg: 2.8,
+        },
+    ];
+
+    for cycle_index in 0..32 {
+        let (entry, units) =
+            roll_asteroid_extraction_yield(&profile, "field:test:0001", cycle_index).expect("roll");
+        let (repeat_entry, repeat_units) =
+            roll_asteroid_extraction_yield(&profile, "field:test:0001", cycle_index)
+                .expect("repeat roll");
+        assert_eq!(entry.item_id, "resource.iron_ore");
+        assert_eq!(repeat_entry.item_id, entry.item_id);
+        assert_eq!(repeat_units, units);
+        assert!((4.0..=18.0).contains(&units));
+    }
+
+    profile.yield_table.clear();
+    assert!(roll_asteroid_extraction_yield(&profile, "field:test:0001", 0).is_none());
+}
+
+#[test]
+fn mining_laser_mints_member_resources_into_hull_inventory() {
+    let mut app = App::new();
+    app.add_message::<ResourceExtractedEvent>();
+    app.add_message::<MiningStatusChangedEvent>();
+    let scene = spawn_mining_scene(
+        &mut app,
+        single_ore_resource_profile(5.0, 12.0),
+        mining_laser(true, 100.0),
+    );
+
+    let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+
+    let inventory = app.world().entity(scene.hull).get::<Inventory>().unwrap();
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 5);
+    assert_eq!(inventory.entries[0].unit_mass_kg, 2.5);
+    assert!(app.world().entity(scene.hull).contains::<MassDirty>());
+    let laser = app
+        .world()
+        .entity(scene.laser)
+        .get::<MiningLaser>()
+        .unwrap();
+    assert_eq!(laser.target_guid, Some(scene.member_guid));
+    let events = drain_extracted_events(&mut app);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].quantity, 5);
+    assert_eq!(events[0].source_guid, scene.member_guid);
+    assert_eq!(
+        events[0].miner_owner_id.as_deref(),
+        Some("11111111-1111-1111-1111-111111111111")
+    );
+    assert!(!events[0].source_exhausted);
+
+    let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+    let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+
+    let inventory = app.world().entity(scene.hull).get::<Inventory>().unwrap();
+    assert_eq!(inventory.entries.len(), 1);
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 12);
+    let events = drain_extracted_events(&mut app);
+    assert_eq!(events.last().map(|event| event.quantity), Some(2));
+    assert!(events.last().is_some_and(|event| event.source_exhausted));
+
+    let mut damage_query = app.world_mut().query::<&AsteroidFieldDamageState>();
+    let damage_state = damage_query.single(app.world()).expect("damage state");
+    let entry = damage_state
+        .entries
+        .iter()
+        .find(|entry| entry.member_key == scene.member_key)
+        .expect("member damage entry");
+    assert_eq!(entry.resource_units_consumed, 12.0);
+    assert_eq!(entry.state, AsteroidMemberStateKind::Harvested);
+
+    // Exhausted members are never locked again.
+    let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+    let inventory = app.world().entity(scene.hull).get::<Inventory>().unwrap();
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 12);
+    let laser = app
+        .world()
+        .entity(scene.laser)
+        .get::<MiningLaser>()
+        .unwrap();
+    assert_eq!(laser.target_guid, None);
+
+    let _ = app.world_mut().run_system_once(recompute_total_mass);
+    let hull = app.world().entity(scene.hull);
+    assert_eq!(hull.get::<CargoMassKg>().unwrap().0, 30.0);
+    assert_eq!(hull.get::<TotalMassKg>().unwrap().0, 1_030.0);
+}
+
+#[test]
+fn mining_laser_ignores_members_outside_range() {
+    let mut app = App::new();
+    app.add_message::<ResourceExtractedEvent>();
+    app.add_message::<MiningStatusChangedEvent>();
+    let scene = spawn_mining_scene(
+        &mut app,
+        single_ore_resource_profile(5.0, 12.0),
+        mining_laser(true, 20.0),
+    );
+
+    let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+
+    assert!(app.world().entity(scene.hull).get::<Inventory>().is_none());
+    assert!(drain_extracted_events(&mut app).is_empty());
+}
+
+#[test]
+fn mining_stops_and_reports_once_the_hold_is_full() {
+    let mut app = App::new();
+    app.add_message::<ResourceExtractedEvent>();
+    app.add_message::<MiningStatusChangedEvent>();
+    let scene = spawn_mining_scene(
+        &mut app,
+        single_ore_resource_profile(5.0, 12.0),
+        mining_laser(true, 100.0),
+    );
+    app.world_mut()
+        .entity_mut(scene.hull)
+        .insert(CargoCapacity { max_mass_kg: 20.0 });
+
+    for _ in 0..3 {
+        let _ = app.world_mut().run_system_once(extract_asteroid_resources);
+    }
+
+    // 12.5 kg from the first cycle leaves room for three more 2.5 kg units.
+    let inventory = app.world().entity(scene.hull).get::<Inventory>().unwrap();
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 8);
+    let laser = app
+        .world()
+        .entity(scene.laser)
+        .get::<MiningLaser>()
+        .unwrap();
+    assert!(!laser.active);
+    assert_eq!(laser.target_guid, None);
+    assert_eq!(
+        drain_mining_statuses(&mut app),
+        vec![MiningStatus::HoldFull]
+    );
+    assert_eq!(drain_extracted_events(&mut app).len(), 2);
+
+    let mut damage_query = app.world_mut().query::<&AsteroidFieldDamageState>();
+    let damage_state = damage_query.single(app.world()).expect("damage state");
+    assert_eq!(damage_state.entries[0].resource_units_consumed, 8.0);
+}
+
+#[test]
+fn mining_actions_toggle_mounted_lasers() {
+    let mut app = App::new();
+    app.add_message::<MiningStatusChangedEvent>();
+    let scene = spawn_mining_scene(
+        &mut app,
+        single_ore_resource_profile(5.0, 12.0),
+        mining_laser(false, 100.0),
+    );
+    app.world_mut()
+        .entity_mut(scene.hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::ActivateMiningLaser, EntityAction::Forward];
+
+    let _ = app.world_mut().run_system_once(process_mining_actions);
+
+    assert!(
+        app.world()
+            .entity(scene.laser)
+            .get::<MiningLaser>()
+            .unwrap()
+            .active
+    );
+    let queue = app.world().entity(scene.hull).get::<ActionQueue>().unwrap();
+    assert_eq!(queue.pending, vec![EntityAction::Forward]);
+    assert_eq!(drain_mining_statuses(&mut app), vec![MiningStatus::Started]);
+
+    // Repeating the held action does not report again.
+    app.world_mut()
+        .entity_mut(scene.hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::ActivateMiningLaser];
+    let _ = app.world_mut().run_system_once(process_mining_actions);
+    assert!(drain_mining_statuses(&mut app).is_empty());
+}
@@ -0,0 +1,226 @@
+use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, AutopilotState, ControlledEntityGuid, EntityAction, EntityGuid, FlightComputer,
+    FlightControlAuthority, FocusedEntityGuid, PlayerTag, SelectedEntityGuid,
+    SimulationMotionWriter, apply_autopilot_to_flight_computers, compute_autopilot_controls,
+    process_autopilot_actions,
+};
+use uuid::Uuid;
+
+fn flight_computer() -> FlightComputer {
+    FlightComputer {
+        profile: "basic_fly_by_wire".to_string(),
+        throttle: 0.0,
+        yaw_input: 0.0,
+        brake_active: false,
+        turn_rate_deg_s: 90.0,
+    }
+}
+
+fn spawn_hull(app: &mut App, position: DVec2, velocity: DVec2) -> (Uuid, Entity) {
+    let guid = Uuid::new_v4();
+    let entity = app
+        .world_mut()
+        .spawn((
+            EntityGuid(guid),
+            Position(position),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(velocity),
+            flight_computer(),
+            AutopilotState::default(),
+            ActionQueue::default(),
+            FlightControlAuthority,
+            SimulationMotionWriter,
+        ))
+        .id();
+    (guid, entity)
+}
+
+fn spawn_target(app: &mut App, position: DVec2) -> Uuid {
+    let guid = Uuid::new_v4();
+    app.world_mut()
+        .spawn((EntityGuid(guid), Position(position)));
+    guid
+}
+
+fn spawn_player(app: &mut App, hull_guid: Uuid, selected: Option<Uuid>, focused: Option<Uuid>) {
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        PlayerTag,
+        ControlledEntityGuid(Some(hull_guid.to_string())),
+        SelectedEntityGuid(selected.map(|guid| guid.to_string())),
+        FocusedEntityGuid(focused.map(|guid| guid.to_string())),
+    ));
+}
+
+fn set_actions(app: &mut App, entity: Entity, actions: Vec<EntityAction>) {
+    app.world_mut()
+        .entity_mut(entity)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = actions;
+}
+
+fn autopilot(app: &App, entity: Entity) -> AutopilotState {
+    app.world()
+        .entity(entity)
+        .get::<AutopilotState>()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn engage_locks_selected_target_before_focused_target() {
+    let mut app = App::new();
+    let (hull_guid, hull) = spawn_hull(&mut app, DVec2::ZERO, DVec2::ZERO);
+    let selected = spawn_target(&mut app, DVec2::new(0.0, 1_000.0));
+    let focused = spawn_target(&mut app, DVec2::new(500.0, 0.0));
+    spawn_player(&mut app, hull_guid, Some(selected), Some(focused));
+    set_actions(
+        &mut app,
+        hull,
+        vec![
+            EntityAction::LongitudinalNeutral,
+            EntityAction::LateralNeutral,
+            EntityAction::EngageAutopilot,
+        ],
+    );
+
+    let _ = app.world_mut().run_system_once(process_autopilot_actions);
+
+    let state = autopilot(&app, hull);
+    assert!(state.engaged);
+    assert_eq!(state.target_guid, Some(selected));
+    let queue = app.world().entity(hull).get::<ActionQueue>().unwrap();
+    assert_eq!(
+        queue.pending,
+        vec![
+            EntityAction::LongitudinalNeutral,
+            EntityAction::LateralNeutral
+        ]
+    );
+}
+
+#[test]
+fn engage_falls_back_to_focused_target_and_ignores_missing_selection() {
+    let mut app = App::new();
+    let (hull_guid, hull) = spawn_hull(&mut app, DVec2::ZERO, DVec2::ZERO);
+    let focused = spawn_target(&mut app, DVec2::new(500.0, 0.0));
+    spawn_player(&mut app, hull_guid, None, Some(focused));
+    set_actions(&mut app, hull, vec![EntityAction::EngageAutopilot]);
+
+    let _ = app.world_mut().run_system_once(process_autopilot_actions);
+
+    assert_eq!(autopilot(&app, hull).target_guid, Some(focused));
+}
+
+#[test]
+fn manual_flight_input_disengages_autopilot() {
+    let mut app = App::new();
+    let (_hull_guid, hull) = spawn_hull(&mut app, DVec2::ZERO, DVec2::ZERO);
+    {
+        let mut entity = app.world_mut().entity_mut(hull);
+        let mut state = entity.get_mut::<AutopilotState>().unwrap();
+        state.engaged = true;
+        state.target_guid = Some(Uuid::new_v4());
+    }
+    set_actions(&mut app, hull, vec![EntityAction::Left]);
+
+    let _ = app.world_mut().run_system_once(process_autopilot_actions);
+
+    let state = autopilot(&app, hull);
+    assert!(!state.engaged);
+    assert_eq!(state.target_guid, None);
+    let queue = app.world().entity(hull).get::<ActionQueue>().unwrap();
+    assert_eq!(queue.pending, vec![EntityAction::Left]);
+}
+
+#[test]
+fn engaged_autopilot_thrusts_toward_target_ahead() {
+    let mut app = App::new();
+    let (hull_guid, hull) = spawn_hull(&mut app, DVec2::ZERO, DVec2::ZERO);
+    let target = spawn_target(&mut app, DVec2::new(0.0, 2_000.0));
+    spawn_player(&mut app, hull_guid, Some(target), None);
+    set_actions(&mut app, hull, vec![EntityAction::EngageAutopilot]);
+
+    let _ = app.world_mut().run_system_once(process_autopilot_actions);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_autopilot_to_flight_computers);
+
+    let computer = app.world().entity(hull).get::<FlightComputer>().unwrap();
+    assert_eq!(computer.throttle, 1.0);
+    assert_eq!(computer.yaw_input, 0.0);
+    assert!(!computer.brake_active);
+}
+
+#[test]
+fn autopilot_disengages_when_target_disappears() {
+    let mut app = App::new();
+    let (_hull_guid, hull) = spawn_hull(&mut app, DVec2::ZERO, DVec2::ZERO);
+    {
+        let mut entity = app.world_mut().entity_mut(hull);
+        let mut state = entity.get_mut::<AutopilotState>().unwrap();
+        state.engaged = true;
+        state.target_guid = Some(Uuid::new_v4());
+    }
+
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_autopilot_to_flight_computers);
+
+    assert!(!autopilot(&app, hull).engaged);
+}
+
+#[test]
+fn autopilot_brakes_inside_arrival_ring_for_stationary_target() {
+    let control = compute_autopilot_controls(
+        DVec2::ZERO,
+        DVec2::new(0.0, 20.0),
+        DVec2::Y,
+        DVec2::new(0.0, 100.0),
+        DVec2::ZERO,
+        150.0,
+        16.0,
+        100.0,
+    );
+    assert!(control.brake_active);
+    assert_eq!(control.throttle, 0.0);
+}
+
+#[test]
+fn autopilot_coasts_once_velocity_matches_moving_target() {
+    let control = compute_autopilot_controls(
+        DVec2::ZERO,
+        DVec2::new(30.0, 0.0),
+        DVec2::Y,
+        DVec2::new(0.0, 100.0),
+        DVec2::new(30.0, 0.0),
+        150.0,
+        16.0,
+        100.0,
+    );
+    assert!(control.arrived);
+    assert_eq!(control.throttle, 0.0);
+    assert_eq!(control.yaw_input, 0.0);
+    assert!(!control.brake_active);
+}
+
+#[test]
+fn autopilot_turns_toward_target_before_thrusting() {
+    let control = compute_autopilot_controls(
+        DVec2::ZERO,
+        DVec2::ZERO,
+        DVec2::Y,
+        DVec2::new(-2_000.0, 0.0),
+        DVec2::ZERO,
+        150.0,
+        16.0,
+        100.0,
+    );
+    assert_eq!(control.yaw_input, 1.0);
+    assert_eq!(control.throttle, 0.0);
+}
@@ -0,0 +1,146 @@
+use avian2d::prelude::{Gravity, PhysicsPlugins, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, BallisticProjectileSpawnedEvent, BeamStartedEvent, BeamStopReason,
+    BeamStoppedEvent, BeamWeapon, BeamWeaponState, EntityAction, EntityGuid, Hardpoint, MountedOn,
+    ParentGuid, ShotFiredEvent, ShotHitEvent, SimulationMotionWriter, process_weapon_fire_actions,
+    update_beam_weapons,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn beam_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PhysicsPlugins::default().with_length_unit(1.0));
+    app.insert_resource(Gravity(Vec2::ZERO.into()));
+    app.add_message::<ShotFiredEvent>();
+    app.add_message::<BallisticProjectileSpawnedEvent>();
+    app.add_message::<ShotHitEvent>();
+    app.add_message::<BeamStartedEvent>();
+    app.add_message::<BeamStoppedEvent>();
+    app.finish();
+    app.cleanup();
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(0.1));
+    app
+}
+
+fn spawn_beam_fixture(app: &mut App, beam: BeamWeapon) -> (Entity, Entity) {
+    let shooter_guid = Uuid::new_v4();
+    let shooter = app
+        .world_mut()
+        .spawn((
+            EntityGuid(shooter_guid),
+            Position(Vec2::ZERO.into()),
+            Rotation::from(Quat::IDENTITY),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+        ))
+        .id();
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(shooter_guid),
+        Hardpoint {
+            hardpoint_id: "weapon_fore_center".to_string(),
+            offset_m: Vec3::new(0.0, 6.0, 0.0),
+            local_rotation: Quat::IDENTITY,
+        },
+    ));
+    let weapon = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: shooter_guid,
+                hardpoint_id: "weapon_fore_center".to_string(),
+            },
+            beam,
+            BeamWeaponState::default(),
+        ))
+        .id();
+    (shooter, weapon)
+}
+
+fn run_beam_tick(app: &mut App, shooter: Entity, trigger_held: bool) -> BeamWeaponState {
+    if trigger_held {
+        app.world_mut()
+            .entity_mut(shooter)
+            .get_mut::<ActionQueue>()
+            .unwrap()
+            .pending = vec![EntityAction::FirePrimary];
+    }
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+    let _ = app.world_mut().run_system_once(update_beam_weapons);
+    let mut states = app.world_mut().query::<&BeamWeaponState>();
+    states.single(app.world()).unwrap().clone()
+}
+
+fn collect_started(mut events: MessageReader<'_, '_, BeamStartedEvent>) -> usize {
+    events.read().count()
+}
+
+fn collect_stopped(mut events: MessageReader<'_, '_, BeamStoppedEvent>) -> Vec<BeamStopReason> {
+    events.read().map(|event| event.reason).collect()
+}
+
+#[test]
+fn held_trigger_sustains_beam_until_released() {
+    let mut app = beam_app();
+    let (shooter, _weapon) = spawn_beam_fixture(&mut app, BeamWeapon::pulse_laser());
+
+    let first = run_beam_tick(&mut app, shooter, true);
+    assert!(first.firing);
+    assert!(!first.trigger_held);
+    assert_eq!(first.beam_length_m, 220.0);
+    assert!(first.target_guid.is_none());
+    assert!((first.heat - 2.5).abs() < 0.001);
+
+    let second = run_beam_tick(&mut app, shooter, true);
+    assert!(second.firing);
+    assert!((second.heat - 5.0).abs() < 0.001);
+
+    let released = run_beam_tick(&mut app, shooter, false);
+    assert!(!released.firing);
+    assert_eq!(released.beam_length_m, 0.0);
+    assert!(released.heat < second.heat);
+
+    let started = app.world_mut().run_system_once(collect_started).unwrap();
+    assert_eq!(started, 1);
+    let stopped = app.world_mut().run_system_once(collect_stopped).unwrap();
+    assert_eq!(stopped, vec![BeamStopReason::Released]);
+}
+
+#[test]
+fn overheated_beam_shuts_down_until_cooled_to_recovery_threshold() {
+    let mut app = beam_app();
+    let beam = BeamWeapon {
+        heat_per_second: 500.0,
+        cooling_per_second: 400.0,
+        ..BeamWeapon::pulse_laser()
+    };
+    let (shooter, _weapon) = spawn_beam_fixture(&mut app, beam);
+
+    assert!(run_beam_tick(&mut app, shooter, true).firing);
+    let overheating = run_beam_tick(&mut app, shooter, true);
+    assert!(overheating.firing);
+    assert!(overheating.overheated);
+    assert_eq!(overheating.heat, 100.0);
+
+    let shut_down = run_beam_tick(&mut app, shooter, true);
+    assert!(!shut_down.firing);
+    assert!(shut_down.overheated);
+
+    let recovered = run_beam_tick(&mut app, shooter, true);
+    assert!(!recovered.firing);
+    assert!(!recovered.overheated);
+
+    assert!(run_beam_tick(&mut app, shooter, true).firing);
+
+    let started = app.world_mut().run_system_once(collect_started).unwrap();
+    assert_eq!(started, 2);
+    let stopped = app.world_mut().run_system_once(collect_stopped).unwrap();
+    assert_eq!(stopped, vec![BeamStopReason::Overheated]);
+}
@@ -0,0 +1,261 @@
+use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, CargoCapacity, CargoCollectedEvent, CargoContainer, CargoContainerSettings,
+    CargoContainerSpawnedEvent, CargoJettisonSelection, Destructible, DisplayName, EntityAction,
+    EntityDestroyedEvent, EntityGuid, Inventory, LootDropDefinition, LootTableDefinition,
+    LootTableRegistry, ShipTag, SimulationMotionWriter, collect_cargo_containers,
+    drop_cargo_from_destroyed_entities, process_cargo_jettison_actions,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn cargo_app(step_s: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<CargoContainerSpawnedEvent>();
+    app.add_message::<CargoCollectedEvent>();
+    app.add_message::<EntityDestroyedEvent>();
+    app.insert_resource(CargoContainerSettings::default());
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(step_s));
+    app
+}
+
+fn ore_inventory(iron: u32, nickel: u32) -> Inventory {
+    let mut inventory = Inventory::default();
+    inventory.add_stack("resource.iron_ore", iron, 2.5);
+    inventory.add_stack("resource.nickel_ore", nickel, 2.8);
+    inventory
+}
+
+fn spawn_ship(app: &mut App, position: DVec2, inventory: Inventory) -> Entity {
+    app.world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            ShipTag,
+            Position(position),
+            Rotation::radians(0.0),
+            LinearVelocity(DVec2::ZERO),
+            ActionQueue::default(),
+            SimulationMotionWriter,
+            inventory,
+        ))
+        .id()
+}
+
+fn containers(app: &mut App) -> Vec<(Entity, Inventory, CargoContainer)> {
+    let mut query = app
+        .world_mut()
+        .query::<(Entity, &Inventory, &CargoContainer)>();
+    query
+        .iter(app.world())
+        .map(|(entity, inventory, container)| (entity, inventory.clone(), container.clone()))
+        .collect()
+}
+
+#[test]
+fn deploy_cargo_jettisons_selected_stack_behind_hull() {
+    let mut app = cargo_app(0.1);
+    let ship = spawn_ship(&mut app, DVec2::ZERO, ore_inventory(10, 4));
+    app.world_mut()
+        .entity_mut(ship)
+        .insert(CargoJettisonSelection(Some(
+            "resource.iron_ore".to_string(),
+        )));
+    app.world_mut()
+        .get_mut::<ActionQueue>(ship)
+        .unwrap()
+        .push(EntityAction::DeployCargo);
+
+    let _ = app
+        .world_mut()
+        .run_system_once(process_cargo_jettison_actions);
+
+    let hull_inventory = app.world().get::<Inventory>(ship).unwrap();
+    assert_eq!(hull_inventory.quantity_of("resource.iron_ore"), 0);
+    assert_eq!(hull_inventory.quantity_of("resource.nickel_ore"), 4);
+    let spawned = containers(&mut app);
+    assert_eq!(spawned.len(), 1);
+    let (container, inventory, _) = &spawned[0];
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 10);
+    assert_eq!(inventory.quantity_of("resource.nickel_ore"), 0);
+    let position = app.world().get::<Position>(*container).unwrap().0;
+    assert!(position.y < 0.0, "container should spawn aft of the hull");
+}
+
+#[test]
+fn held_deploy_cargo_only_jettisons_once() {
+    // The held-key gate lives in a system `Local`, so both ticks must run the
+    // same system instance rather than `run_system_once`.
+    let mut app = cargo_app(0.1);
+    app.add_systems(Update, process_cargo_jettison_actions);
+    let ship = spawn_ship(&mut app, DVec2::ZERO, ore_inventory(10, 0));
+
+    for _ in 0..2 {
+        app.world_mut()
+            .get_mut::<ActionQueue>(ship)
+            .unwrap()
+            .pending = vec![EntityAction::DeployCargo];
+        app.update();
+        // Cargo mined while the key is still held stays aboard.
+        app.world_mut()
+            .get_mut::<Inventory>(ship)
+            .unwrap()
+            .add_stack("resource.iron_ore", 3, 2.5);
+    }
+
+    assert_eq!(containers(&mut app).len(), 1);
+    let hull_inventory = app.world().get::<Inventory>(ship).unwrap();
+    assert_eq!(hull_inventory.quantity_of("resource.iron_ore"), 6);
+}
+
+#[test]
+fn destroyed_entity_drops_its_inventory_into_a_container() {
+    let mut app = cargo_app(0.1);
+    let ship = spawn_ship(&mut app, DVec2::new(50.0, 0.0), ore_inventory(6, 2));
+    let ship_guid = app.world().get::<EntityGuid>(ship).unwrap().0;
+    app.world_mut().write_message(EntityDestroyedEvent {
+        entity: ship,
+        entity_guid: ship_guid,
+        destruction_profile_id: "explosion_burst".to_string(),
+        effect_origin: DVec2::new(50.0, 0.0),
+    });
+
+    let _ = app
+        .world_mut()
+        .run_system_once(drop_cargo_from_destroyed_entities);
+
+    let spawned = containers(&mut app);
+    assert_eq!(spawned.len(), 1);
+    let (_, inventory, container) = &spawned[0];
+    assert_eq!(inventory.quantity_of("resource.iron_ore"), 6);
+    assert_eq!(inventory.quantity_of("resource.nickel_ore"), 2);
+    assert_eq!(container.dropped_by_guid, Some(ship_guid));
+}
+
+fn wreck_loot_table() -> LootTableDefinition {
+    LootTableDefinition {
+        loot_table_id: "loot.test_wreck".to_string(),
+        destruction_profile_ids: vec!["explosion_burst".to_string()],
+        drops: vec![
+            LootDropDefinition {
+                item_id: "alloy.steel_plate".to_string(),
+                min_quantity: 2,
+                max_quantity: 5,
+                ..Default::default()
+            },
+            LootDropDefinition {
+                item_id: "subassembly.hull_plating".to_string(),
+                chance: 0.5,
+                ..Default::default()
+            },
+        ],
+        wreck_display_name: Some("Wreckage".to_string()),
+    }
+}
+
+#[test]
+fn loot_rolls_are_deterministic_per_seed() {
+    let table = wreck_loot_table();
+    let seed = Uuid::new_v4().to_string();
+
+    let first = table.roll(&seed, None);
+    let second = table.roll(&seed, None);
+
+    assert_eq!(first, second);
+    let steel = first.quantity_of("alloy.steel_plate");
+    assert!(
+        (2..=5).contains(&steel),
+        "steel quantity {steel} out of range"
+    );
+}
+
+#[test]
+fn destroyed_entity_drops_loot_from_its_destruction_profile_table() {
+    let mut app = cargo_app(0.1);
+    app.insert_resource(LootTableRegistry {
+        schema_version: 1,
+        tables: vec![wreck_loot_table()],
+    });
+    let guid = Uuid::new_v4();
+    let wreck = app
+        .world_mut()
+        .spawn((EntityGuid(guid), Destructible::default()))
+        .id();
+    app.world_mut().write_message(EntityDestroyedEvent {
+        entity: wreck,
+        entity_guid: guid,
+        destruction_profile_id: "explosion_burst".to_string(),
+        effect_origin: DVec2::ZERO,
+    });
+
+    let _ = app
+        .world_mut()
+        .run_system_once(drop_cargo_from_destroyed_entities);
+
+    let spawned = containers(&mut app);
+    assert_eq!(spawned.len(), 1);
+    let (container, inventory, _) = &spawned[0];
+    assert_eq!(
+        inventory,
+        &wreck_loot_table().roll(&guid.to_string(), None),
+        "container holds exactly the seeded roll"
+    );
+    assert_eq!(
+        app.world().get::<DisplayName>(*container).unwrap().0,
+        "Wreckage"
+    );
+}
+
+#[test]
+fn nearby_ship_collects_what_fits_after_pickup_delay() {
+    let mut app = cargo_app(0.5);
+    app.world_mut()
+        .resource_mut::<CargoContainerSettings>()
+        .pickup_delay_s = 0.4;
+    let collector = spawn_ship(&mut app, DVec2::new(10.0, 0.0), Inventory::default());
+    app.world_mut()
+        .entity_mut(collector)
+        .insert(CargoCapacity { max_mass_kg: 10.0 });
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        CargoContainer {
+            lifetime_remaining_s: 60.0,
+            pickup_delay_remaining_s: 0.4,
+            dropped_by_guid: None,
+        },
+        ore_inventory(6, 0),
+        Position(DVec2::ZERO),
+    ));
+
+    let _ = app.world_mut().run_system_once(collect_cargo_containers);
+
+    let collector_inventory = app.world().get::<Inventory>(collector).unwrap();
+    assert_eq!(collector_inventory.quantity_of("resource.iron_ore"), 4);
+    let remaining = containers(&mut app);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].1.quantity_of("resource.iron_ore"), 2);
+}
+
+#[test]
+fn expired_container_despawns() {
+    let mut app = cargo_app(0.5);
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        CargoContainer {
+            lifetime_remaining_s: 0.25,
+            pickup_delay_remaining_s: 0.0,
+            dropped_by_guid: None,
+        },
+        ore_inventory(1, 1),
+        Position(DVec2::ZERO),
+    ));
+
+    let _ = app.world_mut().run_system_once(collect_cargo_containers);
+
+    assert!(containers(&mut app).is_empty());
+}
@@ -1,10 +1,16 @@
 use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::message::Messages;
 use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
 use bevy::prelude::*;
 use sidereal_game::{
-    ActionQueue, AmmoCount, BallisticProjectile, BallisticWeapon, EntityAction, EntityGuid,
-    Hardpoint, MountedOn, OwnerId, ParentGuid, SimulationMotionWriter, WeaponCooldownState,
-    bootstrap_weapon_cooldown_state, process_weapon_fire_actions,
+    ActionQueue, AmmoCount, BallisticProjectile, BallisticWeapon, DamageResistanceEntry,
+    DamageResistanceProfile, DamageType, DirectDamageRequest, EntityAction, EntityGuid, Hardpoint,
+    HealthPool, MountedOn, OwnerId, ParentGuid, RAM_DAMAGE_PER_DELTA_V_MPS,
+    RAM_DAMAGE_SAFE_DELTA_V_MPS, ScriptWeaponTarget, ShotFiredEvent, ShotHitEvent,
+    ShotImpactResolvedEvent, SimulationMotionWriter, WeaponCooldownState, WeaponGroup,
+    apply_damage_from_shot_impacts, apply_direct_damage_requests, bootstrap_weapon_cooldown_state,
+    process_weapon_fire_actions, ram_damage_from_impulse,
 };
 use uuid::Uuid;
 
@@ -112,9 +118,258 @@ fn zero_speed_weapon_remains_hitscan_and_spawns_no_projectile_entity() {
     assert!(cooldown.remaining_s > 0.0);
 }
 
+#[test]
+fn script_weapon_target_aims_shots_at_the_named_target() {
+    let mut app = App::new();
+    app.add_message::<ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    let (shooter, _weapon) = spawn_weapon_fixture(&mut app, 0.0);
+    let target_guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(target_guid),
+        Position(Vec2::new(110.0, -12.0).into()),
+    ));
+    app.world_mut()
+        .entity_mut(shooter)
+        .insert(ScriptWeaponTarget { target_guid });
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    let directions = app
+        .world()
+        .resource::<Messages<ShotFiredEvent>>()
+        .iter_current_update_messages()
+        .map(|shot| shot.direction)
+        .collect::<Vec<_>>();
+    assert_eq!(directions.len(), 1);
+    assert!(directions[0].distance(Vec2::X) < 0.001);
+}
+
+#[test]
+fn fire_actions_only_trigger_weapons_in_the_matching_group() {
+    let mut app = App::new();
+    app.add_message::<sidereal_game::ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    let (shooter, weapon) = spawn_weapon_fixture(&mut app, 0.0);
+    app.world_mut().entity_mut(weapon).insert(WeaponGroup {
+        group: WeaponGroup::SECONDARY,
+    });
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+    let ammo = app.world().entity(weapon).get::<AmmoCount>().unwrap();
+    assert_eq!(ammo.current, 5);
+
+    app.world_mut()
+        .entity_mut(shooter)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::FireSecondary];
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+    let ammo = app.world().entity(weapon).get::<AmmoCount>().unwrap();
+    assert_eq!(ammo.current, 4);
+}
+
 #[test]
 fn corvette_gatling_defaults_to_tracer_hitscan_mode() {
     let weapon = BallisticWeapon::corvette_ballistic_gatling();
     assert!(!weapon.uses_projectile_entities());
     assert_eq!(weapon.projectile_speed_mps, 0.0);
 }
+
+fn spawn_armored_target(app: &mut App, profile: Option<DamageResistanceProfile>) -> (Uuid, Entity) {
+    let target_guid = Uuid::new_v4();
+    let mut target = app.world_mut().spawn((
+        EntityGuid(target_guid),
+        HealthPool {
+            current: 1000.0,
+            maximum: 1000.0,
+        },
+    ));
+    if let Some(profile) = profile {
+        target.insert(profile);
+    }
+    (target_guid, target.id())
+}
+
+fn write_typed_impact(
+    app: &mut App,
+    target: Entity,
+    target_guid: Uuid,
+    damage: f32,
+    damage_type: DamageType,
+) {
+    app.world_mut().write_message(ShotImpactResolvedEvent {
+        shooter_guid: Uuid::new_v4(),
+        weapon_entity: Entity::PLACEHOLDER,
+        weapon_guid: Uuid::new_v4(),
+        origin: DVec2::ZERO,
+        impact_pos: DVec2::ZERO,
+        max_range_m: 100.0,
+        damage_per_shot: damage,
+        damage_type,
+        target_entity: Some(target),
+        target_guid: Some(target_guid),
+    });
+}
+
+fn hull_health(app: &App, target: Entity) -> f32 {
+    app.world()
+        .entity(target)
+        .get::<HealthPool>()
+        .unwrap()
+        .current
+}
+
+#[test]
+fn resistance_profile_mitigates_only_matching_damage_type() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (target_guid, target) = spawn_armored_target(
+        &mut app,
+        Some(DamageResistanceProfile {
+            armor_rating: 0.0,
+            resistances: vec![DamageResistanceEntry {
+                damage_type: DamageType::Energy,
+                fraction: 0.5,
+            }],
+        }),
+    );
+
+    write_typed_impact(&mut app, target, target_guid, 100.0, DamageType::Energy);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+    assert_eq!(hull_health(&app, target), 950.0);
+
+    write_typed_impact(&mut app, target, target_guid, 100.0, DamageType::Explosive);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+    assert_eq!(hull_health(&app, target), 850.0);
+}
+
+#[test]
+fn mounted_armor_profiles_stack_with_hull_profile() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (target_guid, target) = spawn_armored_target(
+        &mut app,
+        Some(DamageResistanceProfile {
+            armor_rating: 5.0,
+            resistances: vec![DamageResistanceEntry {
+                damage_type: DamageType::Ballistic,
+                fraction: 0.5,
+            }],
+        }),
+    );
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        MountedOn {
+            parent_entity_id: target_guid,
+            hardpoint_id: "armor_hull".to_string(),
+        },
+        DamageResistanceProfile {
+            armor_rating: 5.0,
+            resistances: vec![DamageResistanceEntry {
+                damage_type: DamageType::Ballistic,
+                fraction: 0.5,
+            }],
+        },
+    ));
+
+    write_typed_impact(&mut app, target, target_guid, 100.0, DamageType::Ballistic);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+
+    // 100 * 0.5 * 0.5 - (5 + 5)
+    assert_eq!(hull_health(&app, target), 985.0);
+}
+
+#[test]
+fn armor_rating_never_heals_target() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (target_guid, target) = spawn_armored_target(
+        &mut app,
+        Some(DamageResistanceProfile {
+            armor_rating: 50.0,
+            resistances: Vec::new(),
+        }),
+    );
+
+    write_typed_impact(&mut app, target, target_guid, 10.0, DamageType::Thermal);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+
+    assert_eq!(hull_health(&app, target), 1000.0);
+}
+
+#[test]
+fn unarmored_target_takes_full_damage_of_every_type() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (target_guid, target) = spawn_armored_target(&mut app, None);
+
+    for damage_type in DamageType::ALL {
+        write_typed_impact(&mut app, target, target_guid, 10.0, damage_type);
+    }
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+
+    assert_eq!(hull_health(&app, target), 950.0);
+}
+
+#[test]
+fn ram_damage_scales_with_delta_v_above_the_safe_threshold() {
+    let mass_kg = 1_000.0;
+    let safe_impulse = RAM_DAMAGE_SAFE_DELTA_V_MPS * mass_kg;
+    assert_eq!(ram_damage_from_impulse(safe_impulse, mass_kg), 0.0);
+    assert_eq!(
+        ram_damage_from_impulse(safe_impulse + 5.0 * mass_kg, mass_kg),
+        5.0 * RAM_DAMAGE_PER_DELTA_V_MPS
+    );
+    // The same impulse barely moves a heavier hull.
+    assert_eq!(
+        ram_damage_from_impulse(safe_impulse + 5.0 * mass_kg, mass_kg * 10.0),
+        0.0
+    );
+    assert_eq!(ram_damage_from_impulse(f32::INFINITY, mass_kg), 0.0);
+    assert_eq!(ram_damage_from_impulse(1_000.0, 0.0), 0.0);
+}
+
+#[test]
+fn kinetic_ram_damage_is_mitigated_by_ram_resistance() {
+    let mut app = App::new();
+    app.add_message::<DirectDamageRequest>();
+    app.add_message::<ShotHitEvent>();
+    let (_target_guid, target) = spawn_armored_target(
+        &mut app,
+        Some(DamageResistanceProfile {
+            armor_rating: 0.0,
+            resistances: vec![DamageResistanceEntry {
+                damage_type: DamageType::KineticRam,
+                fraction: 0.3,
+            }],
+        }),
+    );
+
+    app.world_mut().write_message(DirectDamageRequest {
+        source_entity: Entity::PLACEHOLDER,
+        source_guid: Uuid::new_v4(),
+        target_entity: target,
+        damage: 100.0,
+        damage_type: DamageType::KineticRam,
+    });
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_direct_damage_requests);
+
+    assert_eq!(hull_health(&app, target), 930.0);
+}
@@ -134,3 +134,21 @@ fn generated_registry_resource_infers_editor_schema() {
         ComponentEditorValueKind::ColorRgb
     );
 }
+
+#[test]
+fn replicated_subset_drops_server_only_components() {
+    let registry = GeneratedComponentRegistry {
+        entries: generated_component_registry(),
+        shader_entries: Vec::new(),
+    };
+    let replicated = registry.replicated_subset();
+    let kinds = replicated
+        .entries
+        .iter()
+        .map(|entry| entry.component_kind)
+        .collect::<HashSet<_>>();
+    assert!(kinds.contains("health_pool"));
+    assert!(kinds.contains("avian_linear_velocity"));
+    assert!(!kinds.contains("market"));
+    assert!(!kinds.contains("credit_ledger"));
+}
@@ -0,0 +1,244 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use sidereal_game::{
+    CargoCapacity, CraftingFacility, CraftingFailureReason, CraftingJob, CraftingJobCompletedEvent,
+    CraftingJobFailedEvent, CraftingJobRequest, CraftingJobState, CraftingQueue,
+    FacilityDefinition, FacilityRegistry, Inventory, ItemCategory, ItemDefinition, ItemRegistry,
+    RecipeDefinition, RecipeItemAmount, RecipeRegistry, advance_crafting_jobs,
+    process_crafting_job_requests,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn test_item(item_id: &str, unit_mass_kg: f32) -> ItemDefinition {
+    ItemDefinition {
+        item_id: item_id.to_string(),
+        display_name: item_id.to_string(),
+        category: ItemCategory::RawMineral,
+        stack_size: 500,
+        unit_mass_kg,
+        ..Default::default()
+    }
+}
+
+fn crafting_app(step_s: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<CraftingJobRequest>();
+    app.add_message::<CraftingJobFailedEvent>();
+    app.add_message::<CraftingJobCompletedEvent>();
+    app.insert_resource(ItemRegistry {
+        schema_version: 1,
+        items: vec![
+            test_item("resource.iron_ore", 2.5),
+            test_item("material.iron_ingot", 5.0),
+        ],
+    });
+    app.insert_resource(FacilityRegistry {
+        schema_version: 1,
+        facilities: vec![FacilityDefinition {
+            facility_id: "facility.refinery_basic".to_string(),
+            display_name: "Refinery".to_string(),
+            queue_slots: 1,
+            ..Default::default()
+        }],
+    });
+    app.insert_resource(RecipeRegistry {
+        schema_version: 1,
+        recipes: vec![RecipeDefinition {
+            recipe_id: "recipe.refine_iron_ingot".to_string(),
+            facility_id: "facility.refinery_basic".to_string(),
+            inputs: vec![RecipeItemAmount {
+                item_id: "resource.iron_ore".to_string(),
+                amount: 4,
+            }],
+            output: RecipeItemAmount {
+                item_id: "material.iron_ingot".to_string(),
+                amount: 1,
+            },
+            craft_time_s: 1.0,
+            ..Default::default()
+        }],
+    });
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(step_s));
+    app
+}
+
+fn spawn_refinery(app: &mut App, ore: u32, jobs: Vec<CraftingJob>) -> Entity {
+    let mut inventory = Inventory::default();
+    inventory.add_stack("resource.iron_ore", ore, 2.5);
+    app.world_mut()
+        .spawn((
+            CraftingFacility {
+                facility_id: "facility.refinery_basic".to_string(),
+            },
+            CraftingQueue { jobs },
+            inventory,
+        ))
+        .id()
+}
+
+fn queued_job() -> CraftingJob {
+    CraftingJob {
+        job_id: Uuid::new_v4(),
+        recipe_id: "recipe.refine_iron_ingot".to_string(),
+        state: CraftingJobState::Queued,
+        progress_s: 0.0,
+        requested_by_player_id: None,
+    }
+}
+
+fn collect_failures(
+    mut events: MessageReader<'_, '_, CraftingJobFailedEvent>,
+) -> Vec<CraftingFailureReason> {
+    events.read().map(|event| event.reason).collect()
+}
+
+#[test]
+fn job_consumes_inputs_and_deposits_output_after_craft_time() {
+    let mut app = crafting_app(0.6);
+    let facility = spawn_refinery(&mut app, 6, vec![queued_job()]);
+
+    let _ = app.world_mut().run_system_once(advance_crafting_jobs);
+    let entity = app.world().entity(facility);
+    assert_eq!(
+        entity
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of("resource.iron_ore"),
+        2
+    );
+    let queue = entity.get::<CraftingQueue>().unwrap();
+    assert_eq!(queue.jobs[0].state, CraftingJobState::InProgress);
+
+    let _ = app.world_mut().run_system_once(advance_crafting_jobs);
+    let entity = app.world().entity(facility);
+    let inventory = entity.get::<Inventory>().unwrap();
+    assert_eq!(inventory.quantity_of("material.iron_ingot"), 1);
+    assert!(entity.get::<CraftingQueue>().unwrap().jobs.is_empty());
+}
+
+#[test]
+fn job_with_missing_inputs_is_cancelled_without_consuming_cargo() {
+    let mut app = crafting_app(0.1);
+    let facility = spawn_refinery(&mut app, 3, vec![queued_job()]);
+
+    let _ = app.world_mut().run_system_once(advance_crafting_jobs);
+
+    let entity = app.world().entity(facility);
+    assert_eq!(
+        entity
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of("resource.iron_ore"),
+        3
+    );
+    assert!(entity.get::<CraftingQueue>().unwrap().jobs.is_empty());
+    let failures = app.world_mut().run_system_once(collect_failures).unwrap();
+    assert_eq!(failures, vec![CraftingFailureReason::MissingInputs]);
+}
+
+#[test]
+fn full_cargo_holds_finished_output() {
+    let mut app = crafting_app(1.0);
+    let mut job = queued_job();
+    job.state = CraftingJobState::InProgress;
+    let facility = spawn_refinery(&mut app, 2, vec![job]);
+    app.world_mut()
+        .entity_mut(facility)
+        .insert(CargoCapacity { max_mass_kg: 7.0 });
+
+    let _ = app.world_mut().run_system_once(advance_crafting_jobs);
+
+    let entity = app.world().entity(facility);
+    let queue = entity.get::<CraftingQueue>().unwrap();
+    assert_eq!(queue.jobs[0].state, CraftingJobState::OutputBlocked);
+    assert_eq!(
+        entity
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of("material.iron_ingot"),
+        0
+    );
+    let failures = app.world_mut().run_system_once(collect_failures).unwrap();
+    assert_eq!(failures, vec![CraftingFailureReason::CargoFull]);
+}
+
+#[test]
+fn request_for_recipe_of_other_facility_is_rejected() {
+    let mut app = crafting_app(0.1);
+    app.world_mut()
+        .resource_mut::<FacilityRegistry>()
+        .facilities
+        .push(FacilityDefinition {
+            facility_id: "facility.fabricator_basic".to_string(),
+            display_name: "Fabricator".to_string(),
+            queue_slots: 1,
+            ..Default::default()
+        });
+    let facility_guid = Uuid::new_v4();
+    let facility = app
+        .world_mut()
+        .spawn((
+            sidereal_game::EntityGuid(facility_guid),
+            CraftingFacility {
+                facility_id: "facility.fabricator_basic".to_string(),
+            },
+        ))
+        .id();
+    app.world_mut().write_message(CraftingJobRequest {
+        facility_guid,
+        recipe_id: "recipe.refine_iron_ingot".to_string(),
+        job_id: Uuid::new_v4(),
+        requested_by_player_id: None,
+    });
+
+    let _ = app
+        .world_mut()
+        .run_system_once(process_crafting_job_requests);
+
+    let queue = app.world().entity(facility).get::<CraftingQueue>().unwrap();
+    assert!(queue.jobs.is_empty());
+    let failures = app.world_mut().run_system_once(collect_failures).unwrap();
+    assert_eq!(failures, vec![CraftingFailureReason::FacilityMismatch]);
+}
+
+#[test]
+fn player_request_requires_facility_ownership() {
+    let mut app = crafting_app(0.1);
+    let owner_id = Uuid::new_v4();
+    let facility_guid = Uuid::new_v4();
+    let facility = app
+        .world_mut()
+        .spawn((
+            sidereal_game::EntityGuid(facility_guid),
+            sidereal_game::OwnerId(owner_id.to_string()),
+            CraftingFacility {
+                facility_id: "facility.refinery_basic".to_string(),
+            },
+        ))
+        .id();
+    for player_id in [Uuid::new_v4(), owner_id] {
+        app.world_mut().write_message(CraftingJobRequest {
+            facility_guid,
+            recipe_id: "recipe.refine_iron_ingot".to_string(),
+            job_id: Uuid::new_v4(),
+            requested_by_player_id: Some(player_id.to_string()),
+        });
+    }
+
+    let _ = app
+        .world_mut()
+        .run_system_once(process_crafting_job_requests);
+
+    let queue = app.world().entity(facility).get::<CraftingQueue>().unwrap();
+    assert_eq!(queue.jobs.len(), 1);
+    assert_eq!(
+        queue.jobs[0].requested_by_player_id,
+        Some(owner_id.to_string())
+    );
+    let failures = app.world_mut().run_system_once(collect_failures).unwrap();
+    assert_eq!(failures, vec![CraftingFailureReason::NotFacilityOwner]);
+}
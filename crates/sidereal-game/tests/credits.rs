@@ -0,0 +1,93 @@
+use sidereal_game::{
+    CREDIT_LEDGER_MAX_ENTRIES, CreditLedger, CreditTransactionError, CreditWallet, debit_credits,
+    grant_credits,
+};
+use uuid::Uuid;
+
+#[test]
+fn grants_and_debits_append_sequenced_ledger_entries() {
+    let mut wallet = CreditWallet { balance: 100 };
+    let mut ledger = CreditLedger::default();
+    let counterparty = Uuid::new_v4();
+
+    let granted = grant_credits(
+        &mut wallet,
+        &mut ledger,
+        50,
+        "mission.reward",
+        Some(counterparty),
+        7,
+    )
+    .expect("grant should apply");
+    let debited = debit_credits(&mut wallet, &mut ledger, 120, "market.buy", None, 9)
+        .expect("debit should apply");
+
+    assert_eq!(wallet.balance, 30);
+    assert_eq!(ledger.entries, vec![granted.clone(), debited.clone()]);
+    assert_eq!((granted.sequence, granted.delta), (1, 50));
+    assert_eq!(granted.balance_after, 150);
+    assert_eq!(granted.counterparty, Some(counterparty));
+    assert_eq!(
+        (debited.sequence, debited.delta, debited.tick),
+        (2, -120, 9)
+    );
+    assert_eq!(debited.balance_after, 30);
+}
+
+#[test]
+fn refused_transactions_leave_wallet_and_ledger_untouched() {
+    let mut wallet = CreditWallet { balance: 10 };
+    let mut ledger = CreditLedger::default();
+
+    assert_eq!(
+        debit_credits(&mut wallet, &mut ledger, 11, "market.buy", None, 1),
+        Err(CreditTransactionError::InsufficientCredits {
+            balance: 10,
+            requested: 11,
+        })
+    );
+    assert_eq!(
+        grant_credits(&mut wallet, &mut ledger, 0, "mission.reward", None, 1),
+        Err(CreditTransactionError::ZeroAmount)
+    );
+    assert_eq!(
+        grant_credits(
+            &mut wallet,
+            &mut ledger,
+            u64::MAX,
+            "mission.reward",
+            None,
+            1
+        ),
+        Err(CreditTransactionError::AmountTooLarge)
+    );
+
+    assert_eq!(wallet.balance, 10);
+    assert!(ledger.entries.is_empty());
+}
+
+#[test]
+fn ledger_window_only_sheds_audited_entries() {
+    let mut wallet = CreditWallet::default();
+    let mut ledger = CreditLedger::default();
+    let total = CREDIT_LEDGER_MAX_ENTRIES as u64 + 5;
+
+    for tick in 0..total {
+        grant_credits(&mut wallet, &mut ledger, 1, "mission.reward", None, tick)
+            .expect("grant should apply");
+    }
+
+    assert_eq!(wallet.balance, total);
+    assert_eq!(ledger.entries.len(), total as usize);
+    assert_eq!(ledger.entries[0].sequence, 1);
+
+    ledger.mark_audited(3);
+    assert_eq!(ledger.entries.len(), total as usize - 3);
+    assert_eq!(ledger.entries[0].sequence, 4);
+
+    ledger.mark_audited(total);
+    assert_eq!(ledger.entries.len(), CREDIT_LEDGER_MAX_ENTRIES);
+    assert_eq!(ledger.entries[0].sequence, 6);
+    assert_eq!(ledger.last_sequence(), total);
+    assert_eq!(ledger.audited_sequence, total);
+}
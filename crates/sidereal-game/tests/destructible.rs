@@ -27,6 +27,7 @@ fn depleted_destructible_enters_pending_then_despawns() {
             Destructible {
                 destruction_profile_id: "explosion_burst".to_string(),
                 destroy_delay_s: 0.18,
+                ..Default::default()
             },
         ))
         .id();
@@ -0,0 +1,237 @@
+use avian2d::prelude::{ColliderDisabled, LinearVelocity, Position, RigidBodyDisabled, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, DockingPhase, DockingPort, DockingState, EntityAction, EntityGuid, Hardpoint,
+    MountedOn, ParentGuid, SimulationMotionWriter, advance_docking_sequences,
+    process_docking_actions,
+};
+use uuid::Uuid;
+
+struct DockingFixture {
+    host_guid: Uuid,
+    port_guid: Uuid,
+    port: Entity,
+}
+
+/// Host at the origin with a port 10 m aft, facing aft (-Y).
+fn spawn_host_with_port(app: &mut App) -> DockingFixture {
+    let host_guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(host_guid),
+        Position(DVec2::ZERO),
+        Rotation::radians(0.0),
+        LinearVelocity(DVec2::ZERO),
+    ));
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(host_guid),
+        Hardpoint {
+            hardpoint_id: "docking_aft".to_string(),
+            offset_m: Vec3::new(0.0, -10.0, 0.0),
+            local_rotation: Quat::from_rotation_z(std::f32::consts::PI),
+        },
+    ));
+    let port_guid = Uuid::new_v4();
+    let port = app
+        .world_mut()
+        .spawn((
+            EntityGuid(port_guid),
+            MountedOn {
+                parent_entity_id: host_guid,
+                hardpoint_id: "docking_aft".to_string(),
+            },
+            DockingPort {
+                request_range_m: 500.0,
+                capture_range_m: 30.0,
+                corridor_half_angle_deg: 30.0,
+                max_capture_speed_mps: 5.0,
+                undock_offset_m: 50.0,
+                occupant_guid: None,
+            },
+        ))
+        .id();
+    DockingFixture {
+        host_guid,
+        port_guid,
+        port,
+    }
+}
+
+fn spawn_ship(app: &mut App, position: DVec2) -> (Uuid, Entity) {
+    let guid = Uuid::new_v4();
+    let entity = app
+        .world_mut()
+        .spawn((
+            EntityGuid(guid),
+            Position(position),
+            Rotation::radians(0.0),
+            LinearVelocity(DVec2::ZERO),
+            ActionQueue::default(),
+            DockingState::default(),
+            SimulationMotionWriter,
+        ))
+        .id();
+    (guid, entity)
+}
+
+fn press_dock(app: &mut App, ship: Entity) {
+    app.world_mut()
+        .entity_mut(ship)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::InitiateDocking];
+    let _ = app.world_mut().run_system_once(process_docking_actions);
+}
+
+fn docking_state(app: &App, ship: Entity) -> DockingState {
+    app.world()
+        .entity(ship)
+        .get::<DockingState>()
+        .unwrap()
+        .clone()
+}
+
+fn position(app: &App, entity: Entity) -> DVec2 {
+    app.world().entity(entity).get::<Position>().unwrap().0
+}
+
+#[test]
+fn ship_in_corridor_is_captured_reparented_and_frozen() {
+    let mut app = App::new();
+    let fixture = spawn_host_with_port(&mut app);
+    let (ship_guid, ship) = spawn_ship(&mut app, DVec2::new(0.0, -35.0));
+
+    press_dock(&mut app, ship);
+    assert_eq!(docking_state(&app, ship).phase, DockingPhase::Requested);
+
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+    let state = docking_state(&app, ship);
+    assert_eq!(state.phase, DockingPhase::Approaching);
+    assert_eq!(state.port_guid, Some(fixture.port_guid));
+
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+    let state = docking_state(&app, ship);
+    assert_eq!(state.phase, DockingPhase::Docked);
+    assert_eq!(state.host_guid, Some(fixture.host_guid));
+
+    let ship_ref = app.world().entity(ship);
+    assert_eq!(
+        ship_ref.get::<ParentGuid>(),
+        Some(&ParentGuid(fixture.host_guid))
+    );
+    assert!(ship_ref.contains::<RigidBodyDisabled>());
+    assert!(ship_ref.contains::<ColliderDisabled>());
+    assert!(position(&app, ship).distance(DVec2::new(0.0, -10.0)) < 1e-6);
+    assert_eq!(
+        app.world()
+            .entity(fixture.port)
+            .get::<DockingPort>()
+            .unwrap()
+            .occupant_guid,
+        Some(ship_guid)
+    );
+}
+
+#[test]
+fn ship_outside_approach_corridor_is_not_captured() {
+    let mut app = App::new();
+    let _fixture = spawn_host_with_port(&mut app);
+    // Beside the port rather than in front of it.
+    let (_ship_guid, ship) = spawn_ship(&mut app, DVec2::new(25.0, -10.0));
+
+    press_dock(&mut app, ship);
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+
+    let ship_ref = app.world().entity(ship);
+    assert_eq!(
+        ship_ref.get::<DockingState>().unwrap().phase,
+        DockingPhase::Approaching
+    );
+    assert!(!ship_ref.contains::<ParentGuid>());
+    assert!(!ship_ref.contains::<RigidBodyDisabled>());
+}
+
+#[test]
+fn held_docking_action_only_toggles_once() {
+    // The held-key gate lives in a system `Local`, so both ticks must run the
+    // same system instance rather than `run_system_once`.
+    let mut app = App::new();
+    app.add_systems(Update, process_docking_actions);
+    let (_ship_guid, ship) = spawn_ship(&mut app, DVec2::new(0.0, -35.0));
+
+    for _ in 0..2 {
+        app.world_mut()
+            .entity_mut(ship)
+            .get_mut::<ActionQueue>()
+            .unwrap()
+            .pending = vec![EntityAction::InitiateDocking];
+        app.update();
+    }
+
+    assert_eq!(docking_state(&app, ship).phase, DockingPhase::Requested);
+}
+
+#[test]
+fn undocking_releases_ship_in_front_of_port_and_frees_it() {
+    let mut app = App::new();
+    let fixture = spawn_host_with_port(&mut app);
+    let (_ship_guid, ship) = spawn_ship(&mut app, DVec2::new(0.0, -35.0));
+    press_dock(&mut app, ship);
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+    assert!(docking_state(&app, ship).is_docked());
+
+    // Release the key, then press again to undock.
+    app.world_mut()
+        .entity_mut(ship)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending
+        .clear();
+    let _ = app.world_mut().run_system_once(process_docking_actions);
+    press_dock(&mut app, ship);
+    assert_eq!(docking_state(&app, ship).phase, DockingPhase::Undocking);
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+
+    let ship_ref = app.world().entity(ship);
+    assert_eq!(
+        ship_ref.get::<DockingState>().unwrap().phase,
+        DockingPhase::Undocked
+    );
+    assert!(!ship_ref.contains::<ParentGuid>());
+    assert!(!ship_ref.contains::<RigidBodyDisabled>());
+    assert!(position(&app, ship).distance(DVec2::new(0.0, -60.0)) < 1e-3);
+    assert_eq!(
+        app.world()
+            .entity(fixture.port)
+            .get::<DockingPort>()
+            .unwrap()
+            .occupant_guid,
+        None
+    );
+}
+
+#[test]
+fn persisted_docked_ship_is_refrozen_on_reload() {
+    let mut app = App::new();
+    let fixture = spawn_host_with_port(&mut app);
+    let (_ship_guid, ship) = spawn_ship(&mut app, DVec2::new(400.0, 400.0));
+    *app.world_mut()
+        .entity_mut(ship)
+        .get_mut::<DockingState>()
+        .unwrap() = DockingState {
+        phase: DockingPhase::Docked,
+        port_guid: Some(fixture.port_guid),
+        host_guid: Some(fixture.host_guid),
+    };
+
+    let _ = app.world_mut().run_system_once(advance_docking_sequences);
+
+    let ship_ref = app.world().entity(ship);
+    assert!(ship_ref.contains::<RigidBodyDisabled>());
+    assert!(ship_ref.contains::<ParentGuid>());
+    assert!(position(&app, ship).distance(DVec2::new(0.0, -10.0)) < 1e-6);
+}
@@ -0,0 +1,257 @@
+use avian2d::prelude::{Gravity, LinearVelocity, PhysicsPlugins, Position, Rotation};
+use bevy::ecs::message::Messages;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    DamageType, EntityDestructionStartedEvent, EntityGuid, EntityKillCreditedEvent,
+    FactionDefinition, FactionId, FactionRegistry, FactionRelationDefinition, FactionReputation,
+    FactionStanding, GuidedMissile, HealthPool, KILL_CREDIT_WINDOW_S, MissileLauncher, OwnerId,
+    PlayerTag, ShotHitEvent, apply_kill_reputation, credit_entity_kills, update_guided_missiles,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn faction(faction_id: &str, member_kill_reputation_delta: f32) -> FactionDefinition {
+    FactionDefinition {
+        faction_id: faction_id.to_string(),
+        display_name: faction_id.to_string(),
+        member_kill_reputation_delta,
+    }
+}
+
+fn relation(
+    faction_a: &str,
+    faction_b: &str,
+    standing: FactionStanding,
+) -> FactionRelationDefinition {
+    FactionRelationDefinition {
+        faction_a: faction_a.to_string(),
+        faction_b: faction_b.to_string(),
+        standing,
+    }
+}
+
+fn test_registry() -> FactionRegistry {
+    FactionRegistry {
+        schema_version: 1,
+        factions: vec![
+            faction("faction.union", -20.0),
+            faction("faction.traders", -10.0),
+            faction("faction.raiders", -4.0),
+        ],
+        relations: vec![
+            relation("faction.union", "faction.traders", FactionStanding::Allied),
+            relation("faction.raiders", "faction.union", FactionStanding::Hostile),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn standings_are_symmetric_and_drive_friendly_fire() {
+    let registry = test_registry();
+    assert_eq!(
+        registry.standing("faction.traders", "faction.union"),
+        FactionStanding::Allied
+    );
+    assert_eq!(
+        registry.standing("faction.union", "faction.raiders"),
+        FactionStanding::Hostile
+    );
+    assert_eq!(
+        registry.standing("faction.traders", "faction.raiders"),
+        FactionStanding::Neutral
+    );
+
+    assert!(!registry.allows_damage(Some("faction.union"), Some("faction.union")));
+    assert!(!registry.allows_damage(Some("faction.union"), Some("faction.traders")));
+    assert!(registry.allows_damage(Some("faction.union"), Some("faction.raiders")));
+    assert!(registry.allows_damage(None, Some("faction.union")));
+
+    let mut permissive = test_registry();
+    permissive.friendly_fire.allow_allied_damage = true;
+    assert!(permissive.allows_damage(Some("faction.union"), Some("faction.traders")));
+    assert!(!permissive.allows_damage(Some("faction.union"), Some("faction.union")));
+}
+
+#[test]
+fn low_reputation_turns_faction_hostile_toward_player() {
+    let registry = test_registry();
+    assert_eq!(
+        registry.standing_toward_player(Some("faction.union"), Some(-20.0), "faction.traders"),
+        FactionStanding::Allied
+    );
+    assert_eq!(
+        registry.standing_toward_player(Some("faction.union"), Some(-150.0), "faction.traders"),
+        FactionStanding::Hostile
+    );
+}
+
+#[test]
+fn missile_blast_spares_allied_targets() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PhysicsPlugins::default().with_length_unit(1.0));
+    app.insert_resource(Gravity(Vec2::ZERO.into()));
+    app.insert_resource(test_registry());
+    app.add_message::<ShotHitEvent>();
+    app.add_message::<EntityDestructionStartedEvent>();
+    app.finish();
+    app.cleanup();
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(0.1));
+
+    let shooter_guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(shooter_guid),
+        Position(Vec2::new(0.0, -500.0).into()),
+        FactionId("faction.union".to_string()),
+    ));
+    let mut spawn_target = |faction_id: &str, x: f32| {
+        app.world_mut()
+            .spawn((
+                EntityGuid(Uuid::new_v4()),
+                Position(Vec2::new(x, 10.0).into()),
+                FactionId(faction_id.to_string()),
+                HealthPool {
+                    current: 200.0,
+                    maximum: 200.0,
+                },
+            ))
+            .id()
+    };
+    let allied = spawn_target("faction.traders", -5.0);
+    let hostile = spawn_target("faction.raiders", 5.0);
+    let hostile_guid = app.world().entity(hostile).get::<EntityGuid>().unwrap().0;
+
+    let launcher = MissileLauncher::light_seeker_launcher();
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        Position(Vec2::ZERO.into()),
+        Rotation::from(Quat::IDENTITY),
+        LinearVelocity(Vec2::new(0.0, 100.0).into()),
+        GuidedMissile::from_launcher(&launcher, shooter_guid, Uuid::new_v4(), hostile_guid),
+    ));
+
+    let _ = app.world_mut().run_system_once(update_guided_missiles);
+
+    let health = |entity: Entity| {
+        app.world()
+            .entity(entity)
+            .get::<HealthPool>()
+            .unwrap()
+            .current
+    };
+    assert_eq!(health(allied), 200.0);
+    assert!(health(hostile) < 200.0);
+}
+
+fn write_hit(app: &mut App, shooter_guid: Uuid, target: Entity) {
+    let target_guid = app
+        .world()
+        .entity(target)
+        .get::<EntityGuid>()
+        .map(|guid| guid.0);
+    app.world_mut().write_message(ShotHitEvent {
+        shooter_guid,
+        target_entity: target,
+        target_guid,
+        weapon_entity: Entity::PLACEHOLDER,
+        weapon_guid: Uuid::new_v4(),
+        impact_pos: DVec2::ZERO,
+        damage: 50.0,
+        shield_absorbed: 0.0,
+        damage_type: DamageType::Ballistic,
+    });
+}
+
+fn advance_fixed_time(app: &mut App, seconds: f64) {
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f64(seconds));
+}
+
+#[test]
+fn kills_credit_the_last_recent_hitter_and_adjust_reputation_with_victim_neighbours() {
+    let mut app = App::new();
+    app.add_message::<ShotHitEvent>();
+    app.add_message::<EntityDestructionStartedEvent>();
+    app.add_message::<EntityKillCreditedEvent>();
+    app.insert_resource(test_registry());
+    app.insert_resource(Time::<Fixed>::default());
+    app.add_systems(Update, (credit_entity_kills, apply_kill_reputation).chain());
+
+    let player_guid = Uuid::new_v4();
+    let player = app
+        .world_mut()
+        .spawn((
+            EntityGuid(player_guid),
+            PlayerTag,
+            FactionId("faction.union".to_string()),
+        ))
+        .id();
+    let ship_guid = Uuid::new_v4();
+    app.world_mut()
+        .spawn((EntityGuid(ship_guid), OwnerId(player_guid.to_string())));
+    let mut spawn_victim = |faction_id: &str, owner: Option<Uuid>| {
+        let mut victim = app.world_mut().spawn((
+            EntityGuid(Uuid::new_v4()),
+            FactionId(faction_id.to_string()),
+        ));
+        if let Some(owner) = owner {
+            victim.insert(OwnerId(owner.to_string()));
+        }
+        victim.id()
+    };
+    let victim = spawn_victim("faction.traders", None);
+    let own_drone = spawn_victim("faction.raiders", Some(player_guid));
+    let stale = spawn_victim("faction.raiders", None);
+
+    // Hits landed on earlier ticks still count while inside the window.
+    write_hit(&mut app, ship_guid, stale);
+    app.update();
+    advance_fixed_time(&mut app, KILL_CREDIT_WINDOW_S - 5.0);
+    write_hit(&mut app, ship_guid, victim);
+    write_hit(&mut app, ship_guid, own_drone);
+    app.update();
+    advance_fixed_time(&mut app, 10.0);
+
+    for entity in [victim, own_drone, stale] {
+        let entity_guid = app.world().entity(entity).get::<EntityGuid>().unwrap().0;
+        app.world_mut()
+            .write_message(EntityDestructionStartedEvent {
+                entity,
+                entity_guid,
+                destruction_profile_id: "explosion_burst".to_string(),
+                effect_origin: DVec2::ZERO,
+                destroy_delay_s: 0.0,
+            });
+    }
+    app.update();
+
+    let credited = app
+        .world()
+        .resource::<Messages<EntityKillCreditedEvent>>()
+        .iter_current_update_messages()
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(credited.len(), 1);
+    assert_eq!(credited[0].victim_entity, victim);
+    assert_eq!(credited[0].player_entity, player);
+    assert_eq!(credited[0].shooter_guid, ship_guid);
+    assert_eq!(
+        credited[0].victim_faction.as_deref(),
+        Some("faction.traders")
+    );
+
+    let reputation = app
+        .world()
+        .entity(player)
+        .get::<FactionReputation>()
+        .expect("kill should create reputation");
+    assert_eq!(reputation.value("faction.traders"), Some(-10.0));
+    assert_eq!(reputation.value("faction.union"), Some(-5.0));
+    assert_eq!(reputation.value("faction.raiders"), None);
+}
@@ -226,7 +226,10 @@ fn apply_engine_thrust_does_not_consume_fuel_when_disabled_for_prediction() {
                 parent_entity_id: parent_guid,
                 hardpoint_id: "fuel".to_string(),
             },
-            FuelTank { fuel_kg: 10.0 },
+            FuelTank {
+                fuel_kg: 10.0,
+                capacity_kg: 10.0,
+            },
         ))
         .id();
     app.world_mut().spawn((
@@ -277,7 +280,10 @@ fn apply_engine_thrust_consumes_fuel_by_default_for_authority() {
                 parent_entity_id: parent_guid,
                 hardpoint_id: "fuel".to_string(),
             },
-            FuelTank { fuel_kg: 10.0 },
+            FuelTank {
+                fuel_kg: 10.0,
+                capacity_kg: 10.0,
+            },
         ))
         .id();
     app.world_mut().spawn((
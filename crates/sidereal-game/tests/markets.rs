@@ -0,0 +1,332 @@
+use avian2d::prelude::Position;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    CargoCapacity, CreditLedger, CreditWallet, EntityGuid, Inventory, ItemCategory, ItemDefinition,
+    ItemRegistry, Market, MarketDefinition, MarketListingDefinition, MarketRegistry,
+    MarketTradeCompletedEvent, MarketTradeFailedEvent, MarketTradeFailureReason,
+    MarketTradeRequest, MarketTradeSide, MountedOn, OwnerId, PlayerTag, market_quotes,
+    process_market_trades, update_market_prices,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+const ORE: &str = "resource.iron_ore";
+const MARKET_ID: &str = "market.test_exchange";
+
+fn test_market_definition() -> MarketDefinition {
+    MarketDefinition {
+        market_id: MARKET_ID.to_string(),
+        display_name: "Test Exchange".to_string(),
+        spread: 0.1,
+        price_elasticity: 1.0,
+        min_price_ratio: 0.5,
+        max_price_ratio: 2.0,
+        price_drift_per_s: 0.5,
+        listings: vec![MarketListingDefinition {
+            item_id: ORE.to_string(),
+            base_price: 10,
+            initial_stock: 100,
+            target_stock: 100,
+            max_stock: 120,
+            restock_per_s: 2.0,
+            ..Default::default()
+        }],
+    }
+}
+
+fn market_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.add_message::<MarketTradeRequest>();
+    app.add_message::<MarketTradeCompletedEvent>();
+    app.add_message::<MarketTradeFailedEvent>();
+    app.insert_resource(ItemRegistry {
+        schema_version: 1,
+        items: vec![ItemDefinition {
+            item_id: ORE.to_string(),
+            display_name: "Iron Ore".to_string(),
+            category: ItemCategory::RawMineral,
+            stack_size: 500,
+            unit_mass_kg: 2.5,
+            ..Default::default()
+        }],
+    });
+    app.insert_resource(MarketRegistry {
+        schema_version: 1,
+        markets: vec![test_market_definition()],
+    });
+    app.add_systems(Update, process_market_trades);
+    app
+}
+
+struct TradeFixture {
+    player: Entity,
+    player_guid: Uuid,
+    ship: Entity,
+    ship_guid: Uuid,
+    market: Entity,
+    market_guid: Uuid,
+}
+
+fn spawn_fixture(app: &mut App, balance: u64, ship_position: DVec2) -> TradeFixture {
+    let player_guid = Uuid::new_v4();
+    let player = app
+        .world_mut()
+        .spawn((EntityGuid(player_guid), PlayerTag, CreditWallet { balance }))
+        .id();
+    let ship_guid = Uuid::new_v4();
+    let ship = app
+        .world_mut()
+        .spawn((
+            EntityGuid(ship_guid),
+            OwnerId(player_guid.to_string()),
+            Position(ship_position),
+            Inventory::default(),
+            CargoCapacity { max_mass_kg: 100.0 },
+        ))
+        .id();
+    let station_guid = Uuid::new_v4();
+    app.world_mut()
+        .spawn((EntityGuid(station_guid), Position(DVec2::ZERO)));
+    let market_guid = Uuid::new_v4();
+    let market = app
+        .world_mut()
+        .spawn((
+            EntityGuid(market_guid),
+            MountedOn {
+                parent_entity_id: station_guid,
+                hardpoint_id: "trade".to_string(),
+            },
+            Market {
+                market_id: MARKET_ID.to_string(),
+                trade_range_m: 300.0,
+                listings: Vec::new(),
+            },
+        ))
+        .id();
+    TradeFixture {
+        player,
+        player_guid,
+        ship,
+        ship_guid,
+        market,
+        market_guid,
+    }
+}
+
+fn trade(app: &mut App, fixture: &TradeFixture, side: MarketTradeSide, quantity: u32) {
+    app.world_mut().write_message(MarketTradeRequest {
+        player_guid: fixture.player_guid,
+        ship_guid: fixture.ship_guid,
+        market_guid: fixture.market_guid,
+        item_id: ORE.to_string(),
+        side,
+        quantity,
+    });
+    app.update();
+}
+
+fn completed(app: &App) -> Vec<MarketTradeCompletedEvent> {
+    app.world()
+        .resource::<Messages<MarketTradeCompletedEvent>>()
+        .iter_current_update_messages()
+        .cloned()
+        .collect()
+}
+
+fn failures(app: &App) -> Vec<MarketTradeFailureReason> {
+    app.world()
+        .resource::<Messages<MarketTradeFailedEvent>>()
+        .iter_current_update_messages()
+        .map(|event| event.reason)
+        .collect()
+}
+
+#[test]
+fn buying_and_selling_move_cargo_stock_and_credits_at_quoted_prices() {
+    let mut app = market_app();
+    let fixture = spawn_fixture(&mut app, 1_000, DVec2::new(200.0, 0.0));
+
+    trade(&mut app, &fixture, MarketTradeSide::Buy, 20);
+
+    let bought = completed(&app);
+    assert_eq!(bought.len(), 1);
+    assert_eq!(bought[0].unit_price, 11);
+    assert_eq!(bought[0].credits, 220);
+    assert_eq!(
+        app.world()
+            .get::<Inventory>(fixture.ship)
+            .unwrap()
+            .quantity_of(ORE),
+        20
+    );
+    assert_eq!(
+        app.world()
+            .get::<CreditWallet>(fixture.player)
+            .unwrap()
+            .balance,
+        780
+    );
+    let market = app.world().get::<Market>(fixture.market).unwrap();
+    assert_eq!(market.listing(ORE).unwrap().stock, 80);
+
+    trade(&mut app, &fixture, MarketTradeSide::Sell, 5);
+
+    let sold = completed(&app);
+    assert_eq!(sold.len(), 1);
+    assert_eq!(sold[0].unit_price, 9);
+    assert_eq!(
+        app.world()
+            .get::<CreditWallet>(fixture.player)
+            .unwrap()
+            .balance,
+        825
+    );
+    let ledger = app.world().get::<CreditLedger>(fixture.player).unwrap();
+    let reasons = ledger
+        .entries
+        .iter()
+        .map(|entry| (entry.reason.as_str(), entry.delta))
+        .collect::<Vec<_>>();
+    assert_eq!(reasons, vec![("market.buy", -220), ("market.sell", 45)]);
+    assert_eq!(
+        app.world()
+            .get::<Market>(fixture.market)
+            .unwrap()
+            .listing(ORE)
+            .unwrap()
+            .stock,
+        85
+    );
+}
+
+#[test]
+fn same_tick_buys_into_a_ship_without_inventory_all_land_in_its_cargo() {
+    let mut app = market_app();
+    let fixture = spawn_fixture(&mut app, 1_000, DVec2::new(200.0, 0.0));
+    app.world_mut()
+        .entity_mut(fixture.ship)
+        .remove::<Inventory>();
+
+    for _ in 0..2 {
+        app.world_mut().write_message(MarketTradeRequest {
+            player_guid: fixture.player_guid,
+            ship_guid: fixture.ship_guid,
+            market_guid: fixture.market_guid,
+            item_id: ORE.to_string(),
+            side: MarketTradeSide::Buy,
+            quantity: 10,
+        });
+    }
+    app.update();
+
+    assert_eq!(completed(&app).len(), 2);
+    assert_eq!(
+        app.world()
+            .get::<Inventory>(fixture.ship)
+            .unwrap()
+            .quantity_of(ORE),
+        20
+    );
+    assert_eq!(
+        app.world()
+            .get::<CreditWallet>(fixture.player)
+            .unwrap()
+            .balance,
+        780
+    );
+}
+
+#[test]
+fn trades_are_refused_by_range_cargo_credits_and_stock_ceiling() {
+    let mut app = market_app();
+    let fixture = spawn_fixture(&mut app, 50, DVec2::new(200.0, 0.0));
+
+    // 41 units at 2.5 kg exceed the 100 kg hold before credits are checked.
+    trade(&mut app, &fixture, MarketTradeSide::Buy, 41);
+    assert_eq!(failures(&app), vec![MarketTradeFailureReason::CargoFull]);
+
+    trade(&mut app, &fixture, MarketTradeSide::Buy, 10);
+    assert_eq!(
+        failures(&app),
+        vec![MarketTradeFailureReason::InsufficientCredits]
+    );
+
+    trade(&mut app, &fixture, MarketTradeSide::Sell, 1);
+    assert_eq!(
+        failures(&app),
+        vec![MarketTradeFailureReason::InsufficientCargo]
+    );
+
+    app.world_mut()
+        .get_mut::<Inventory>(fixture.ship)
+        .unwrap()
+        .add_stack(ORE, 30, 2.5);
+    trade(&mut app, &fixture, MarketTradeSide::Sell, 30);
+    assert_eq!(failures(&app), vec![MarketTradeFailureReason::MarketFull]);
+
+    app.world_mut().get_mut::<Position>(fixture.ship).unwrap().0 = DVec2::new(400.0, 0.0);
+    trade(&mut app, &fixture, MarketTradeSide::Sell, 1);
+    assert_eq!(failures(&app), vec![MarketTradeFailureReason::OutOfRange]);
+
+    assert!(
+        app.world()
+            .get::<CreditLedger>(fixture.player)
+            .unwrap()
+            .entries
+            .is_empty()
+    );
+    assert_eq!(
+        app.world()
+            .get::<Market>(fixture.market)
+            .unwrap()
+            .listing(ORE)
+            .unwrap()
+            .stock,
+        100
+    );
+}
+
+#[test]
+fn prices_rise_when_stock_runs_short_and_ease_as_it_restocks() {
+    let mut app = market_app();
+    let fixture = spawn_fixture(&mut app, 0, DVec2::ZERO);
+    let step = |app: &mut App| {
+        app.world_mut()
+            .resource_mut::<Time<Fixed>>()
+            .advance_by(Duration::from_secs(1));
+        let _ = app.world_mut().run_system_once(update_market_prices);
+    };
+
+    step(&mut app);
+    app.world_mut()
+        .get_mut::<Market>(fixture.market)
+        .unwrap()
+        .listing_mut(ORE)
+        .unwrap()
+        .stock = 50;
+    step(&mut app);
+    let market = app.world().get::<Market>(fixture.market).unwrap();
+    let listing = market.listing(ORE).unwrap();
+    assert_eq!(listing.stock, 52);
+    let risen_price = listing.price;
+    assert!(risen_price > 10.0 && risen_price < 20.0);
+    let quotes = market_quotes(market, &test_market_definition());
+    assert!(quotes[0].buy_price.unwrap() > 11);
+
+    for _ in 0..60 {
+        step(&mut app);
+    }
+    let listing = app
+        .world()
+        .get::<Market>(fixture.market)
+        .unwrap()
+        .listing(ORE)
+        .unwrap()
+        .clone();
+    assert_eq!(listing.stock, 100);
+    assert!(listing.price < risen_price);
+    assert!((listing.price - 10.0).abs() < 0.5);
+}
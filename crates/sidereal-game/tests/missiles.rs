@@ -0,0 +1,243 @@
+use avian2d::prelude::{
+    Gravity, LinearVelocity, PhysicsPlugins, Position, RigidBody, Rotation, Sensor,
+};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, AmmoCount, ControlledEntityGuid, EntityAction, EntityDestructionStartedEvent,
+    EntityGuid, GuidedMissile, Hardpoint, HealthPool, MISSILE_DETONATION_PROFILE_ID,
+    MissileLauncher, MountedOn, ParentGuid, PendingDestruction, PlayerTag, ScriptWeaponTarget,
+    SelectedEntityGuid, ShotHitEvent, SignalSignature, SimulationMotionWriter, WeaponCooldownState,
+    process_weapon_fire_actions, update_guided_missiles,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn spawn_launcher_fixture(app: &mut App, target_offset: Vec2) -> (Entity, Entity, Uuid) {
+    let shooter_guid = Uuid::new_v4();
+    let target_guid = Uuid::new_v4();
+
+    app.world_mut().spawn((
+        EntityGuid(shooter_guid),
+        Position(Vec2::ZERO.into()),
+        Rotation::from(Quat::IDENTITY),
+        SimulationMotionWriter,
+        ActionQueue {
+            pending: vec![EntityAction::FirePrimary],
+        },
+    ));
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(shooter_guid),
+        Hardpoint {
+            hardpoint_id: "weapon_fore_center".to_string(),
+            offset_m: Vec3::new(0.0, 4.0, 0.0),
+            local_rotation: Quat::IDENTITY,
+        },
+    ));
+    let launcher = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: shooter_guid,
+                hardpoint_id: "weapon_fore_center".to_string(),
+            },
+            MissileLauncher::light_seeker_launcher(),
+            WeaponCooldownState::default(),
+            AmmoCount::new(2, 2),
+        ))
+        .id();
+    app.world_mut().spawn((
+        EntityGuid(target_guid),
+        Position(target_offset.into()),
+        SignalSignature {
+            strength: 1.0,
+            detection_radius_m: 0.0,
+            use_extent_for_detection: false,
+        },
+    ));
+    let player = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            PlayerTag,
+            ControlledEntityGuid(Some(shooter_guid.to_string())),
+            SelectedEntityGuid(None),
+        ))
+        .id();
+
+    (launcher, player, target_guid)
+}
+
+fn launch_app() -> App {
+    let mut app = App::new();
+    app.add_message::<sidereal_game::ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    app
+}
+
+#[test]
+fn launcher_fires_only_with_lock_on_selected_target() {
+    let mut app = launch_app();
+    let (launcher, player, target_guid) = spawn_launcher_fixture(&mut app, Vec2::new(0.0, 400.0));
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+    let missile_count = app
+        .world_mut()
+        .query::<&GuidedMissile>()
+        .iter(app.world())
+        .count();
+    assert_eq!(missile_count, 0);
+
+    app.world_mut()
+        .entity_mut(player)
+        .insert(SelectedEntityGuid(Some(target_guid.to_string())));
+    let mut shooter_queue = app
+        .world_mut()
+        .query_filtered::<&mut ActionQueue, With<SimulationMotionWriter>>();
+    shooter_queue.single_mut(app.world_mut()).unwrap().pending = vec![EntityAction::FirePrimary];
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    let missiles = app
+        .world_mut()
+        .query::<(&GuidedMissile, &Position, &RigidBody, Has<Sensor>)>()
+        .iter(app.world())
+        .map(|(missile, position, body, is_sensor)| (missile.clone(), position.0, *body, is_sensor))
+        .collect::<Vec<_>>();
+    assert_eq!(missiles.len(), 1);
+    let (missile, position, body, is_sensor) = &missiles[0];
+    assert_eq!(missile.target_guid, target_guid);
+    assert!((position.y - 4.0).abs() < 0.001);
+    assert_eq!(*body, RigidBody::Kinematic);
+    assert!(is_sensor);
+    let ammo = app.world().entity(launcher).get::<AmmoCount>().unwrap();
+    assert_eq!(ammo.current, 1);
+}
+
+#[test]
+fn script_weapon_target_locks_without_a_player_selection() {
+    let mut app = launch_app();
+    let (_launcher, _player, target_guid) = spawn_launcher_fixture(&mut app, Vec2::new(0.0, 400.0));
+    let shooter = app
+        .world_mut()
+        .query_filtered::<Entity, With<SimulationMotionWriter>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .entity_mut(shooter)
+        .insert(ScriptWeaponTarget { target_guid });
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    let targets = app
+        .world_mut()
+        .query::<&GuidedMissile>()
+        .iter(app.world())
+        .map(|missile| missile.target_guid)
+        .collect::<Vec<_>>();
+    assert_eq!(targets, vec![target_guid]);
+}
+
+#[test]
+fn out_of_range_target_blocks_lock() {
+    let mut app = launch_app();
+    let (_launcher, player, target_guid) = spawn_launcher_fixture(&mut app, Vec2::new(0.0, 5000.0));
+    app.world_mut()
+        .entity_mut(player)
+        .insert(SelectedEntityGuid(Some(target_guid.to_string())));
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    let missile_count = app
+        .world_mut()
+        .query::<&GuidedMissile>()
+        .iter(app.world())
+        .count();
+    assert_eq!(missile_count, 0);
+}
+
+#[test]
+fn missile_detonates_inside_proximity_fuse_with_falloff_area_damage() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(PhysicsPlugins::default().with_length_unit(1.0));
+    app.insert_resource(Gravity(Vec2::ZERO.into()));
+    app.add_message::<ShotHitEvent>();
+    app.add_message::<EntityDestructionStartedEvent>();
+    app.finish();
+    app.cleanup();
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(0.1));
+
+    let target_guid = Uuid::new_v4();
+    let target = app
+        .world_mut()
+        .spawn((
+            EntityGuid(target_guid),
+            Position(Vec2::new(0.0, 10.0).into()),
+            HealthPool {
+                current: 200.0,
+                maximum: 200.0,
+            },
+        ))
+        .id();
+    let bystander = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            Position(Vec2::new(60.0, 10.0).into()),
+            HealthPool {
+                current: 200.0,
+                maximum: 200.0,
+            },
+        ))
+        .id();
+    let flanker = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            Position(Vec2::new(0.0, 22.0).into()),
+            HealthPool {
+                current: 200.0,
+                maximum: 200.0,
+            },
+        ))
+        .id();
+    let launcher = MissileLauncher::light_seeker_launcher();
+    let missile = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            Position(Vec2::ZERO.into()),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(Vec2::new(0.0, 100.0).into()),
+            GuidedMissile::from_launcher(&launcher, Uuid::new_v4(), Uuid::new_v4(), target_guid),
+        ))
+        .id();
+
+    let _ = app.world_mut().run_system_once(update_guided_missiles);
+
+    let pending = app
+        .world()
+        .entity(missile)
+        .get::<PendingDestruction>()
+        .expect("missile should detonate");
+    assert_eq!(
+        pending.destruction_profile_id,
+        MISSILE_DETONATION_PROFILE_ID
+    );
+    let target_health = app.world().entity(target).get::<HealthPool>().unwrap();
+    assert!(target_health.current < 200.0);
+    assert!(target_health.current > 200.0 - launcher.damage - 0.001);
+    let bystander_health = app.world().entity(bystander).get::<HealthPool>().unwrap();
+    assert_eq!(bystander_health.current, 200.0);
+    let hit_order = app
+        .world_mut()
+        .run_system_once(|mut hits: MessageReader<'_, '_, ShotHitEvent>| {
+            hits.read().map(|hit| hit.target_entity).collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(hit_order, vec![target, flanker]);
+}
@@ -0,0 +1,133 @@
+use sidereal_game::{
+    ActiveMission, CreditLedger, CreditWallet, FactionReputation, FactionReputationRules,
+    MAX_ACTIVE_MISSIONS, MissionDefinition, MissionLog, MissionObjectiveDefinition,
+    MissionObjectiveKind, MissionReputationReward, MissionReward, MissionStartError,
+    advance_mission_objective, pay_mission_reward, start_mission, sync_active_mission,
+    take_completed_missions,
+};
+use uuid::Uuid;
+
+const MISSION_ID: &str = "mission.test_patrol";
+
+fn patrol_definition() -> MissionDefinition {
+    MissionDefinition {
+        mission_id: MISSION_ID.to_string(),
+        display_name: "Test Patrol".to_string(),
+        objectives: vec![
+            MissionObjectiveDefinition {
+                objective_id: "reach_beacon".to_string(),
+                description: "Reach the beacon".to_string(),
+                kind: MissionObjectiveKind::ReachLocation {
+                    x: 100.0,
+                    y: 0.0,
+                    radius_m: 50.0,
+                },
+            },
+            MissionObjectiveDefinition {
+                objective_id: "destroy_raiders".to_string(),
+                description: "Destroy raiders".to_string(),
+                kind: MissionObjectiveKind::DestroyEntity {
+                    target_entity_id: None,
+                    faction_id: Some("faction.void_raiders".to_string()),
+                    count: 3,
+                },
+            },
+        ],
+        reward: MissionReward {
+            credits: 250,
+            reputation: vec![MissionReputationReward {
+                faction_id: "faction.frontier_union".to_string(),
+                delta: 5.0,
+            }],
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn objectives_progress_to_completion_and_pay_the_reward_once() {
+    let definition = patrol_definition();
+    let mut log = MissionLog::default();
+    start_mission(&mut log, &definition, 7).expect("mission should start");
+    assert_eq!(
+        start_mission(&mut log, &definition, 8),
+        Err(MissionStartError::AlreadyActive)
+    );
+
+    let mission = &mut log.active[0];
+    assert_eq!(mission.display_name, "Test Patrol");
+    assert_eq!(mission.accepted_tick, 7);
+    assert_eq!(mission.objectives[1].required, 3);
+    assert_eq!(advance_mission_objective(mission, "reach_beacon", 1), 1);
+    assert_eq!(advance_mission_objective(mission, "destroy_raiders", 2), 2);
+    assert!(take_completed_missions(&mut log).is_empty());
+
+    // Extra kills are capped at the requirement.
+    assert_eq!(
+        advance_mission_objective(&mut log.active[0], "destroy_raiders", 4),
+        1
+    );
+    let completed = take_completed_missions(&mut log);
+    assert_eq!(completed.len(), 1);
+    assert!(log.active.is_empty());
+    assert!(log.has_completed(MISSION_ID));
+    assert_eq!(
+        start_mission(&mut log, &definition, 9),
+        Err(MissionStartError::AlreadyCompleted)
+    );
+
+    let mut wallet = CreditWallet { balance: 10 };
+    let mut ledger = CreditLedger::default();
+    let mut reputation = FactionReputation::default();
+    let rules = FactionReputationRules::default();
+    let paid = pay_mission_reward(
+        &definition.reward,
+        Some((&mut wallet, &mut ledger)),
+        &mut reputation,
+        Some(&rules),
+        12,
+    )
+    .expect("reward should pay");
+    assert_eq!(paid, 250);
+    assert_eq!(wallet.balance, 260);
+    assert_eq!(ledger.entries[0].reason, "mission.reward");
+    assert_eq!(reputation.value("faction.frontier_union"), Some(5.0));
+}
+
+#[test]
+fn active_missions_follow_registry_changes_and_respect_limits() {
+    let mut definition = patrol_definition();
+    // Authored records may name only the mission.
+    let mut mission = ActiveMission {
+        mission_id: MISSION_ID.to_string(),
+        display_name: String::new(),
+        objectives: Vec::new(),
+        accepted_tick: 0,
+    };
+    assert!(sync_active_mission(&mut mission, &definition));
+    assert_eq!(mission.objectives.len(), 2);
+    advance_mission_objective(&mut mission, "destroy_raiders", 3);
+    assert!(!sync_active_mission(&mut mission, &definition));
+
+    definition.objectives.remove(0);
+    definition.objectives[0].kind = MissionObjectiveKind::DestroyEntity {
+        target_entity_id: Some(Uuid::new_v4()),
+        faction_id: None,
+        count: 1,
+    };
+    assert!(sync_active_mission(&mut mission, &definition));
+    assert_eq!(mission.objectives.len(), 1);
+    assert_eq!(mission.objectives[0].current, 1);
+    assert!(mission.is_complete());
+
+    let mut log = MissionLog::default();
+    for index in 0..MAX_ACTIVE_MISSIONS {
+        let mut other = patrol_definition();
+        other.mission_id = format!("mission.test_{index}");
+        start_mission(&mut log, &other, 0).expect("mission should start");
+    }
+    assert_eq!(
+        start_mission(&mut log, &patrol_definition(), 0),
+        Err(MissionStartError::TooManyActive)
+    );
+}
@@ -0,0 +1,323 @@
+use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, AmmoCount, BallisticWeapon, Capacitor, Engine, EntityAction, EntityGuid,
+    FlightComputer, FlightControlAuthority, FlightFuelConsumptionEnabled, FuelTank, Hardpoint,
+    Heatsink, MountedOn, ParentGuid, PowerConsumer, Reactor, ShieldGenerator, ShipPowerState,
+    ShotFiredEvent, SimulationMotionWriter, WeaponCooldownState, apply_engine_thrust,
+    bootstrap_ship_power_state, process_weapon_fire_actions, regenerate_shields,
+    update_ship_power_budget,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn power_app(step_s: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(step_s));
+    app
+}
+
+fn mount(parent_guid: Uuid, hardpoint_id: &str) -> (EntityGuid, MountedOn) {
+    (
+        EntityGuid(Uuid::new_v4()),
+        MountedOn {
+            parent_entity_id: parent_guid,
+            hardpoint_id: hardpoint_id.to_string(),
+        },
+    )
+}
+
+fn spawn_powered_hull(app: &mut App) -> (Entity, Uuid) {
+    let hull_guid = Uuid::new_v4();
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            Position(Vec2::ZERO.into()),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(Vec2::ZERO.into()),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+        ))
+        .id();
+    app.world_mut().spawn((
+        mount(hull_guid, "power_core"),
+        Reactor { output_mw: 10.0 },
+        Capacitor { capacity_mj: 50.0 },
+    ));
+    app.world_mut().spawn((
+        mount(hull_guid, "thermal_core"),
+        Heatsink {
+            heat_capacity: 100.0,
+            dissipation_per_s: 20.0,
+        },
+    ));
+    (hull, hull_guid)
+}
+
+fn power_state(app: &App, hull: Entity) -> ShipPowerState {
+    app.world()
+        .entity(hull)
+        .get::<ShipPowerState>()
+        .cloned()
+        .expect("hull should have a power budget")
+}
+
+#[test]
+fn power_state_draws_are_all_or_nothing_and_overheat_at_capacity() {
+    let mut power = ShipPowerState {
+        stored_mj: 5.0,
+        capacity_mj: 10.0,
+        heat_capacity: 10.0,
+        ..Default::default()
+    };
+    assert!(!power.try_draw(6.0, 1.0));
+    assert_eq!(power.stored_mj, 5.0);
+    assert_eq!(power.heat, 0.0);
+
+    assert_eq!(power.draw_continuous(10.0, 8.0), 0.5);
+    assert_eq!(power.stored_mj, 0.0);
+    assert_eq!(power.heat, 4.0);
+
+    power.add_heat(20.0);
+    assert_eq!(power.heat, 10.0);
+    assert!(power.overheated);
+    power.stored_mj = 5.0;
+    assert!(!power.try_draw(1.0, 0.0));
+}
+
+#[test]
+fn budget_bootstraps_full_then_regenerates_dissipates_and_recovers() {
+    let mut app = power_app(0.5);
+    let (hull, _) = spawn_powered_hull(&mut app);
+
+    let _ = app.world_mut().run_system_once(bootstrap_ship_power_state);
+    let power = power_state(&app, hull);
+    assert_eq!(power.stored_mj, 50.0);
+    assert_eq!(power.generation_mw, 10.0);
+    assert_eq!(power.heat_capacity, 100.0);
+
+    {
+        let mut power = app.world_mut().get_mut::<ShipPowerState>(hull).unwrap();
+        power.stored_mj = 0.0;
+        power.heat = 100.0;
+        power.overheated = true;
+    }
+    let _ = app.world_mut().run_system_once(update_ship_power_budget);
+    let power = power_state(&app, hull);
+    assert_eq!(power.stored_mj, 5.0);
+    assert_eq!(power.heat, 90.0);
+    assert!(power.overheated);
+
+    for _ in 0..4 {
+        let _ = app.world_mut().run_system_once(update_ship_power_budget);
+    }
+    let power = power_state(&app, hull);
+    assert_eq!(power.heat, 50.0);
+    assert!(!power.overheated);
+}
+
+#[test]
+fn weapons_pay_per_shot_and_hold_fire_when_drained_or_overheated() {
+    let mut app = power_app(0.1);
+    let (hull, hull_guid) = spawn_powered_hull(&mut app);
+    let _ = app.world_mut().run_system_once(bootstrap_ship_power_state);
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(hull_guid),
+        Hardpoint {
+            hardpoint_id: "weapon_fore_center".to_string(),
+            offset_m: Vec3::new(0.0, 4.0, 0.0),
+            local_rotation: Quat::IDENTITY,
+        },
+    ));
+    let weapon = app
+        .world_mut()
+        .spawn((
+            mount(hull_guid, "weapon_fore_center"),
+            BallisticWeapon::corvette_ballistic_gatling(),
+            WeaponCooldownState::default(),
+            AmmoCount::new(100, 100),
+            PowerConsumer {
+                energy_per_use_mj: 2.0,
+                heat_per_use: 5.0,
+                ..Default::default()
+            },
+        ))
+        .id();
+    let fire = |app: &mut App| {
+        app.world_mut()
+            .get_mut::<WeaponCooldownState>(weapon)
+            .unwrap()
+            .remaining_s = 0.0;
+        app.world_mut()
+            .get_mut::<ActionQueue>(hull)
+            .unwrap()
+            .push(EntityAction::FirePrimary);
+        let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+        app.world().get::<AmmoCount>(weapon).unwrap().current
+    };
+
+    assert_eq!(fire(&mut app), 99);
+    let power = power_state(&app, hull);
+    assert_eq!(power.stored_mj, 48.0);
+    assert_eq!(power.heat, 5.0);
+
+    app.world_mut()
+        .get_mut::<ShipPowerState>(hull)
+        .unwrap()
+        .stored_mj = 1.0;
+    assert_eq!(fire(&mut app), 99);
+
+    {
+        let mut power = app.world_mut().get_mut::<ShipPowerState>(hull).unwrap();
+        power.stored_mj = 50.0;
+        power.overheated = true;
+    }
+    assert_eq!(fire(&mut app), 99);
+    assert_eq!(app.world().resource::<Messages<ShotFiredEvent>>().len(), 1);
+}
+
+#[test]
+fn engines_draw_power_and_heat_in_proportion_to_delivered_energy() {
+    let mut app = power_app(0.1);
+    app.insert_resource(FlightFuelConsumptionEnabled(false));
+    let (hull, hull_guid) = spawn_powered_hull(&mut app);
+    let _ = app.world_mut().run_system_once(bootstrap_ship_power_state);
+    app.world_mut().entity_mut(hull).insert((
+        FlightComputer {
+            profile: "basic_fly_by_wire".to_string(),
+            throttle: 1.0,
+            yaw_input: 0.0,
+            brake_active: false,
+            turn_rate_deg_s: 45.0,
+        },
+        FlightControlAuthority,
+    ));
+    app.world_mut().spawn((
+        mount(hull_guid, "engine"),
+        Engine {
+            thrust: 100.0,
+            reverse_thrust: 50.0,
+            torque_thrust: 25.0,
+            burn_rate_kg_s: 0.0001,
+        },
+        PowerConsumer {
+            draw_mw: 20.0,
+            heat_per_s: 30.0,
+            ..Default::default()
+        },
+    ));
+
+    let _ = app.world_mut().run_system_once(apply_engine_thrust);
+    let power = power_state(&app, hull);
+    assert!((power.stored_mj - 48.0).abs() < 1e-4);
+    assert!((power.heat - 3.0).abs() < 1e-4);
+
+    app.world_mut()
+        .get_mut::<ShipPowerState>(hull)
+        .unwrap()
+        .stored_mj = 1.0;
+    let _ = app.world_mut().run_system_once(apply_engine_thrust);
+    let power = power_state(&app, hull);
+    assert_eq!(power.stored_mj, 0.0);
+    assert!((power.heat - 4.5).abs() < 1e-4);
+}
+
+#[test]
+fn dry_engines_request_no_power() {
+    let mut app = power_app(0.1);
+    app.insert_resource(FlightFuelConsumptionEnabled(true));
+    let (hull, hull_guid) = spawn_powered_hull(&mut app);
+    let _ = app.world_mut().run_system_once(bootstrap_ship_power_state);
+    app.world_mut().entity_mut(hull).insert((
+        FlightComputer {
+            profile: "basic_fly_by_wire".to_string(),
+            throttle: 1.0,
+            yaw_input: 0.0,
+            brake_active: false,
+            turn_rate_deg_s: 45.0,
+        },
+        FlightControlAuthority,
+    ));
+    app.world_mut().spawn((
+        mount(hull_guid, "engine"),
+        Engine {
+            thrust: 100.0,
+            reverse_thrust: 50.0,
+            torque_thrust: 25.0,
+            burn_rate_kg_s: 1.0,
+        },
+        PowerConsumer {
+            draw_mw: 20.0,
+            heat_per_s: 30.0,
+            ..Default::default()
+        },
+    ));
+    app.world_mut().spawn((
+        mount(hull_guid, "fuel"),
+        FuelTank {
+            fuel_kg: 0.0,
+            capacity_kg: 10.0,
+        },
+    ));
+
+    let _ = app.world_mut().run_system_once(apply_engine_thrust);
+    let power = power_state(&app, hull);
+    assert_eq!(power.stored_mj, 50.0);
+    assert_eq!(power.heat, 0.0);
+}
+
+#[test]
+fn active_shields_pay_upkeep_and_stop_regenerating_when_unpowered() {
+    let mut app = power_app(0.1);
+    let (hull, hull_guid) = spawn_powered_hull(&mut app);
+    let _ = app.world_mut().run_system_once(bootstrap_ship_power_state);
+    let shield = app
+        .world_mut()
+        .spawn((
+            mount(hull_guid, "shield_core"),
+            ShieldGenerator {
+                capacity: 100.0,
+                current: 50.0,
+                regen_rate_per_s: 10.0,
+                regen_delay_s: 2.0,
+                regen_delay_remaining_s: 0.0,
+                active: true,
+                absorption: Vec::new(),
+            },
+            PowerConsumer {
+                draw_mw: 20.0,
+                heat_per_s: 10.0,
+                ..Default::default()
+            },
+        ))
+        .id();
+    let shield_current = |app: &App| {
+        app.world()
+            .entity(shield)
+            .get::<ShieldGenerator>()
+            .unwrap()
+            .current
+    };
+
+    let _ = app.world_mut().run_system_once(regenerate_shields);
+    let power = power_state(&app, hull);
+    assert!((power.stored_mj - 48.0).abs() < 1e-4);
+    assert!((power.heat - 1.0).abs() < 1e-4);
+    assert!((shield_current(&app) - 51.0).abs() < 1e-4);
+
+    app.world_mut()
+        .get_mut::<ShipPowerState>(hull)
+        .unwrap()
+        .stored_mj = 1.0;
+    let _ = app.world_mut().run_system_once(regenerate_shields);
+    assert_eq!(power_state(&app, hull).stored_mj, 1.0);
+    assert!((shield_current(&app) - 51.0).abs() < 1e-4);
+}
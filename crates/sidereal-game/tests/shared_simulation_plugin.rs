@@ -2,13 +2,19 @@ use avian2d::prelude::{
     AngularInertia, AngularVelocity, Collider, Gravity, LinearVelocity, Mass, PhysicsPlugins,
     Position, RigidBody, Rotation,
 };
+use bevy::math::DVec2;
 use bevy::prelude::*;
 use sidereal_game::{
-    ActionQueue, BallisticProjectileSpawnedEvent, EntityAction, EntityDestroyedEvent,
-    EntityDestructionStartedEvent, EntityGuid, FlightComputer, FlightControlAuthority,
-    FlightFuelConsumptionEnabled, FlightTuning, MaxVelocityMps, MountedOn, ShotFiredEvent,
-    ShotHitEvent, ShotImpactResolvedEvent, SiderealGameCorePlugin, SiderealSharedSimulationPlugin,
-    SimulationMotionWriter, SimulationRuntimeRole, SizeM, TotalMassKg, angular_inertia_from_size,
+    ActionQueue, BallisticProjectileSpawnedEvent, BeamStartedEvent, BeamStoppedEvent,
+    CargoCollectedEvent, CargoContainerSpawnedEvent, CraftingJobCompletedEvent,
+    CraftingJobFailedEvent, CraftingJobRequest, DirectDamageRequest, EntityAction,
+    EntityDestroyedEvent, EntityDestructionStartedEvent, EntityGuid, EntityKillCreditedEvent,
+    FlightComputer, FlightControlAuthority, FlightFuelConsumptionEnabled, FlightTuning, HealthPool,
+    MarketTradeCompletedEvent, MarketTradeFailedEvent, MarketTradeRequest, MaxVelocityMps,
+    MiningStatusChangedEvent, MountedOn, ResourceExtractedEvent, ShotFiredEvent, ShotHitEvent,
+    ShotImpactResolvedEvent, SiderealGameCorePlugin, SiderealSharedSimulationPlugin,
+    SimulationMotionWriter, SimulationRuntimeRole, SizeM, StationServiceFailedEvent,
+    StationServicePurchasedEvent, TotalMassKg, angular_inertia_from_size,
 };
 use std::time::Duration;
 use uuid::Uuid;
@@ -17,9 +23,25 @@ fn init_game_messages(app: &mut App) {
     app.add_message::<ShotFiredEvent>();
     app.add_message::<ShotImpactResolvedEvent>();
     app.add_message::<ShotHitEvent>();
+    app.add_message::<DirectDamageRequest>();
     app.add_message::<BallisticProjectileSpawnedEvent>();
+    app.add_message::<BeamStartedEvent>();
+    app.add_message::<BeamStoppedEvent>();
     app.add_message::<EntityDestructionStartedEvent>();
     app.add_message::<EntityDestroyedEvent>();
+    app.add_message::<EntityKillCreditedEvent>();
+    app.add_message::<ResourceExtractedEvent>();
+    app.add_message::<MiningStatusChangedEvent>();
+    app.add_message::<CraftingJobRequest>();
+    app.add_message::<CraftingJobFailedEvent>();
+    app.add_message::<CraftingJobCompletedEvent>();
+    app.add_message::<CargoContainerSpawnedEvent>();
+    app.add_message::<CargoCollectedEvent>();
+    app.add_message::<StationServicePurchasedEvent>();
+    app.add_message::<StationServiceFailedEvent>();
+    app.add_message::<MarketTradeRequest>();
+    app.add_message::<MarketTradeCompletedEvent>();
+    app.add_message::<MarketTradeFailedEvent>();
 }
 
 fn init_physics(app: &mut App) {
@@ -177,7 +199,10 @@ fn spawn_predicted_flight_body_with_fuel(app: &mut App, spawn_fuel: bool) -> Ent
                 parent_entity_id: parent_guid,
                 hardpoint_id: "fuel".to_string(),
             },
-            sidereal_game::FuelTank { fuel_kg: 1_000.0 },
+            sidereal_game::FuelTank {
+                fuel_kg: 1_000.0,
+                capacity_kg: 1_000.0,
+            },
         ));
     }
     entity
@@ -325,3 +350,46 @@ fn client_prediction_and_server_authority_have_right_turn_parity() {
         },
     );
 }
+
+fn spawn_ramming_body(app: &mut App, x_m: f64, velocity_x_mps: f64) -> Entity {
+    let mass = 1_000.0;
+    app.world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            HealthPool {
+                current: 10_000.0,
+                maximum: 10_000.0,
+            },
+            TotalMassKg(mass),
+            RigidBody::Dynamic,
+            Collider::circle(4.0),
+            Mass(mass),
+            Position::from_xy(x_m, 0.0),
+            Rotation::default(),
+            Transform::default(),
+            LinearVelocity(DVec2::new(velocity_x_mps, 0.0)),
+            AngularVelocity::default(),
+        ))
+        .id()
+}
+
+fn hull_health_after_head_on_collision(closing_speed_mps: f64) -> (f32, f32) {
+    let mut app = configure_sim_app(SimulationRuntimeRole::ServerAuthority);
+    let left = spawn_ramming_body(&mut app, -10.0, closing_speed_mps / 2.0);
+    let right = spawn_ramming_body(&mut app, 10.0, -closing_speed_mps / 2.0);
+    for _ in 0..60 {
+        run_fixed_tick(&mut app);
+    }
+    let health = |entity| app.world().get::<HealthPool>(entity).unwrap().current;
+    (health(left), health(right))
+}
+
+#[test]
+fn hull_collisions_deal_kinetic_ram_damage_above_the_safe_delta_v() {
+    let (left, right) = hull_health_after_head_on_collision(60.0);
+    assert!(left < 10_000.0, "left hull took no ram damage");
+    assert!(right < 10_000.0, "right hull took no ram damage");
+
+    let (left, right) = hull_health_after_head_on_collision(2.0);
+    assert_eq!((left, right), (10_000.0, 10_000.0));
+}
@@ -0,0 +1,197 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, DamageType, DirectDamageRequest, EntityAction, EntityGuid, HealthPool, MountedOn,
+    ShieldDamageAbsorption, ShieldGenerator, ShotHitEvent, ShotImpactResolvedEvent,
+    SimulationMotionWriter, apply_damage_from_shot_impacts, apply_direct_damage_requests,
+    process_shield_actions,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+fn test_shield(active: bool) -> ShieldGenerator {
+    ShieldGenerator {
+        capacity: 100.0,
+        current: 100.0,
+        regen_rate_per_s: 10.0,
+        regen_delay_s: 2.0,
+        regen_delay_remaining_s: 0.0,
+        active,
+        absorption: vec![ShieldDamageAbsorption {
+            damage_type: DamageType::Ballistic,
+            fraction: 0.5,
+        }],
+    }
+}
+
+fn spawn_shielded_hull(app: &mut App, active: bool) -> (Uuid, Entity, Entity) {
+    let hull_guid = Uuid::new_v4();
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            HealthPool {
+                current: 500.0,
+                maximum: 500.0,
+            },
+            SimulationMotionWriter,
+            ActionQueue::default(),
+        ))
+        .id();
+    let shield = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: hull_guid,
+                hardpoint_id: "shield_core".to_string(),
+            },
+            test_shield(active),
+        ))
+        .id();
+    (hull_guid, hull, shield)
+}
+
+fn write_impact(app: &mut App, target: Entity, target_guid: Uuid, damage: f32) {
+    app.world_mut().write_message(ShotImpactResolvedEvent {
+        shooter_guid: Uuid::new_v4(),
+        weapon_entity: Entity::PLACEHOLDER,
+        weapon_guid: Uuid::new_v4(),
+        origin: DVec2::ZERO,
+        impact_pos: DVec2::ZERO,
+        max_range_m: 100.0,
+        damage_per_shot: damage,
+        damage_type: DamageType::Ballistic,
+        target_entity: Some(target),
+        target_guid: Some(target_guid),
+    });
+}
+
+#[test]
+fn active_shield_soaks_configured_fraction_before_hull() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (hull_guid, hull, shield) = spawn_shielded_hull(&mut app, true);
+
+    write_impact(&mut app, hull, hull_guid, 40.0);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+
+    let health = app.world().entity(hull).get::<HealthPool>().unwrap();
+    assert_eq!(health.current, 480.0);
+    let shield = app.world().entity(shield).get::<ShieldGenerator>().unwrap();
+    assert_eq!(shield.current, 80.0);
+    assert_eq!(shield.regen_delay_remaining_s, 2.0);
+}
+
+#[test]
+fn inactive_shield_lets_damage_through_to_hull() {
+    let mut app = App::new();
+    app.add_message::<ShotImpactResolvedEvent>();
+    app.add_message::<ShotHitEvent>();
+    let (hull_guid, hull, shield) = spawn_shielded_hull(&mut app, false);
+
+    write_impact(&mut app, hull, hull_guid, 40.0);
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_damage_from_shot_impacts);
+
+    let health = app.world().entity(hull).get::<HealthPool>().unwrap();
+    assert_eq!(health.current, 460.0);
+    let shield = app.world().entity(shield).get::<ShieldGenerator>().unwrap();
+    assert_eq!(shield.current, 100.0);
+}
+
+#[test]
+fn direct_damage_goes_through_shields_and_reports_a_hit() {
+    let mut app = App::new();
+    app.add_message::<DirectDamageRequest>();
+    app.add_message::<ShotHitEvent>();
+    let (hull_guid, hull, shield) = spawn_shielded_hull(&mut app, true);
+    let source_guid = Uuid::new_v4();
+
+    app.world_mut().write_message(DirectDamageRequest {
+        source_entity: Entity::PLACEHOLDER,
+        source_guid,
+        target_entity: hull,
+        damage: 40.0,
+        damage_type: DamageType::Ballistic,
+    });
+    let _ = app
+        .world_mut()
+        .run_system_once(apply_direct_damage_requests);
+
+    let health = app.world().entity(hull).get::<HealthPool>().unwrap();
+    assert_eq!(health.current, 480.0);
+    let shield = app.world().entity(shield).get::<ShieldGenerator>().unwrap();
+    assert_eq!(shield.current, 80.0);
+    let hits = app
+        .world()
+        .resource::<Messages<ShotHitEvent>>()
+        .iter_current_update_messages()
+        .map(|hit| {
+            (
+                hit.shooter_guid,
+                hit.target_guid,
+                hit.damage,
+                hit.shield_absorbed,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(hits, vec![(source_guid, Some(hull_guid), 20.0, 20.0)]);
+}
+
+#[test]
+fn shield_actions_toggle_mounted_generators() {
+    let mut app = App::new();
+    let (_hull_guid, hull, shield) = spawn_shielded_hull(&mut app, false);
+    app.world_mut()
+        .entity_mut(hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::Forward, EntityAction::ActivateShield];
+
+    let _ = app.world_mut().run_system_once(process_shield_actions);
+
+    assert!(
+        app.world()
+            .entity(shield)
+            .get::<ShieldGenerator>()
+            .unwrap()
+            .active
+    );
+    let queue = app.world().entity(hull).get::<ActionQueue>().unwrap();
+    assert_eq!(queue.pending, vec![EntityAction::Forward]);
+}
+
+#[test]
+fn shield_regenerates_only_after_delay_expires() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_millis(100));
+    let (_hull_guid, _hull, shield) = spawn_shielded_hull(&mut app, true);
+    {
+        let mut entity = app.world_mut().entity_mut(shield);
+        let mut generator = entity.get_mut::<ShieldGenerator>().unwrap();
+        generator.current = 50.0;
+        generator.regen_delay_remaining_s = 0.05;
+    }
+
+    let _ = app
+        .world_mut()
+        .run_system_once(sidereal_game::regenerate_shields);
+    let generator = app.world().entity(shield).get::<ShieldGenerator>().unwrap();
+    assert_eq!(generator.current, 50.0);
+    assert_eq!(generator.regen_delay_remaining_s, 0.0);
+
+    let _ = app
+        .world_mut()
+        .run_system_once(sidereal_game::regenerate_shields);
+    let generator = app.world().entity(shield).get::<ShieldGenerator>().unwrap();
+    assert!((generator.current - 51.0).abs() < 0.001);
+}
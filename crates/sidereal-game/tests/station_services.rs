@@ -0,0 +1,320 @@
+use avian2d::prelude::Position;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, Cost, CreditLedger, CreditWallet, DockingPhase, DockingState, EntityAction,
+    EntityGuid, FuelTank, HealthPool, MountedOn, OwnerId, PlayerTag, SimulationMotionWriter,
+    StationService, StationServiceFailedEvent, StationServiceFailureReason, StationServiceKind,
+    StationServicePurchasedEvent, process_station_service_actions,
+};
+use uuid::Uuid;
+
+/// Runs the system from a schedule so its held-key state carries across
+/// ticks.
+fn station_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.add_message::<StationServicePurchasedEvent>();
+    app.add_message::<StationServiceFailedEvent>();
+    app.add_systems(Update, process_station_service_actions);
+    app
+}
+
+fn spawn_player(app: &mut App, balance: u64) -> (Entity, Uuid) {
+    let player_guid = Uuid::new_v4();
+    let player = app
+        .world_mut()
+        .spawn((EntityGuid(player_guid), PlayerTag, CreditWallet { balance }))
+        .id();
+    (player, player_guid)
+}
+
+fn spawn_ship(app: &mut App, player_guid: Uuid, position: DVec2, health: HealthPool) -> Entity {
+    app.world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            OwnerId(player_guid.to_string()),
+            Position(position),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+            health,
+        ))
+        .id()
+}
+
+fn spawn_station(
+    app: &mut App,
+    position: DVec2,
+    kind: StationServiceKind,
+    service_range_m: f32,
+    unit_price: u64,
+) -> Uuid {
+    let station_guid = Uuid::new_v4();
+    app.world_mut()
+        .spawn((EntityGuid(station_guid), Position(position)));
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        MountedOn {
+            parent_entity_id: station_guid,
+            hardpoint_id: "service_bay".to_string(),
+        },
+        StationService {
+            kind,
+            service_range_m,
+        },
+        Cost {
+            credits: unit_price,
+        },
+    ));
+    station_guid
+}
+
+fn request(app: &mut App, ship: Entity, action: EntityAction) {
+    app.world_mut()
+        .get_mut::<ActionQueue>(ship)
+        .unwrap()
+        .push(action);
+    app.update();
+}
+
+fn purchases(app: &App) -> Vec<StationServicePurchasedEvent> {
+    let messages = app
+        .world()
+        .resource::<Messages<StationServicePurchasedEvent>>();
+    messages.iter_current_update_messages().cloned().collect()
+}
+
+fn failures(app: &App) -> Vec<StationServiceFailureReason> {
+    let messages = app
+        .world()
+        .resource::<Messages<StationServiceFailedEvent>>();
+    messages
+        .iter_current_update_messages()
+        .map(|event| event.reason)
+        .collect()
+}
+
+#[test]
+fn docked_repair_is_capped_by_the_wallet_and_charged_once_per_press() {
+    let mut app = station_app();
+    let (player, player_guid) = spawn_player(&mut app, 150);
+    let ship = spawn_ship(
+        &mut app,
+        player_guid,
+        DVec2::new(5_000.0, 0.0),
+        HealthPool {
+            current: 20.0,
+            maximum: 200.0,
+        },
+    );
+    // Docked ships are served regardless of distance.
+    let station_guid = spawn_station(
+        &mut app,
+        DVec2::ZERO,
+        StationServiceKind::HullRepair,
+        0.0,
+        2,
+    );
+    app.world_mut().entity_mut(ship).insert(DockingState {
+        phase: DockingPhase::Docked,
+        port_guid: None,
+        host_guid: Some(station_guid),
+    });
+
+    request(&mut app, ship, EntityAction::PurchaseHullRepair);
+
+    assert_eq!(app.world().get::<HealthPool>(ship).unwrap().current, 95.0);
+    assert_eq!(app.world().get::<CreditWallet>(player).unwrap().balance, 0);
+    let purchased = purchases(&app);
+    assert_eq!(purchased.len(), 1);
+    assert_eq!(purchased[0].units, 75.0);
+    assert_eq!(purchased[0].credits_charged, 150);
+    assert_eq!(purchased[0].station_guid, station_guid);
+    assert_eq!(purchased[0].player_guid, player_guid);
+    let ledger = app.world().get::<CreditLedger>(player).unwrap();
+    assert_eq!(ledger.entries.len(), 1);
+    assert_eq!(
+        ledger.entries[0].transaction_id,
+        purchased[0].transaction_id
+    );
+    assert_eq!(ledger.entries[0].reason, "station_service.hull_repair");
+    assert_eq!(ledger.entries[0].counterparty, Some(station_guid));
+    assert_eq!(ledger.entries[0].delta, -150);
+
+    // Holding the key does not buy again; a fresh press with an empty wallet
+    // is refused.
+    app.world_mut()
+        .get_mut::<CreditWallet>(player)
+        .unwrap()
+        .balance = 1_000;
+    request(&mut app, ship, EntityAction::PurchaseHullRepair);
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        1_000
+    );
+    app.world_mut()
+        .get_mut::<CreditWallet>(player)
+        .unwrap()
+        .balance = 0;
+    app.update();
+    request(&mut app, ship, EntityAction::PurchaseHullRepair);
+    assert_eq!(
+        failures(&app),
+        vec![StationServiceFailureReason::InsufficientCredits]
+    );
+}
+
+#[test]
+fn refuel_fills_tanks_from_the_nearest_depot_in_range() {
+    let mut app = station_app();
+    let (player, player_guid) = spawn_player(&mut app, 1_000);
+    let ship = spawn_ship(
+        &mut app,
+        player_guid,
+        DVec2::new(100.0, 0.0),
+        HealthPool {
+            current: 100.0,
+            maximum: 100.0,
+        },
+    );
+    let ship_guid = app.world().get::<EntityGuid>(ship).unwrap().0;
+    let tanks = [(250.0, 1_000.0), (900.0, 1_000.0)].map(|(fuel_kg, capacity_kg)| {
+        app.world_mut()
+            .spawn((
+                EntityGuid(Uuid::new_v4()),
+                MountedOn {
+                    parent_entity_id: ship_guid,
+                    hardpoint_id: "fuel".to_string(),
+                },
+                FuelTank {
+                    fuel_kg,
+                    capacity_kg,
+                },
+            ))
+            .id()
+    });
+    let near_guid = spawn_station(
+        &mut app,
+        DVec2::new(250.0, 0.0),
+        StationServiceKind::Refuel,
+        300.0,
+        1,
+    );
+    spawn_station(
+        &mut app,
+        DVec2::new(-300.0, 0.0),
+        StationServiceKind::Refuel,
+        500.0,
+        1,
+    );
+
+    request(&mut app, ship, EntityAction::PurchaseRefuel);
+
+    for tank in tanks {
+        assert_eq!(app.world().get::<FuelTank>(tank).unwrap().fuel_kg, 1_000.0);
+    }
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        150
+    );
+    let purchased = purchases(&app);
+    assert_eq!(purchased.len(), 1);
+    assert_eq!(purchased[0].station_guid, near_guid);
+    assert_eq!(purchased[0].units, 850.0);
+}
+
+#[test]
+fn fractional_deficits_are_billed_pro_rata() {
+    let mut app = station_app();
+    let (player, player_guid) = spawn_player(&mut app, 1_000);
+    let ship = spawn_ship(
+        &mut app,
+        player_guid,
+        DVec2::ZERO,
+        HealthPool {
+            current: 99.75,
+            maximum: 100.0,
+        },
+    );
+    spawn_station(
+        &mut app,
+        DVec2::ZERO,
+        StationServiceKind::HullRepair,
+        100.0,
+        20,
+    );
+
+    request(&mut app, ship, EntityAction::PurchaseHullRepair);
+
+    assert_eq!(app.world().get::<HealthPool>(ship).unwrap().current, 100.0);
+    let purchased = purchases(&app);
+    assert_eq!(purchased.len(), 1);
+    assert_eq!(purchased[0].units, 0.25);
+    assert_eq!(purchased[0].credits_charged, 5);
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        995
+    );
+}
+
+#[test]
+fn purchases_are_refused_out_of_range_or_when_nothing_needs_service() {
+    let mut app = station_app();
+    let (player, player_guid) = spawn_player(&mut app, 1_000);
+    let ship = spawn_ship(
+        &mut app,
+        player_guid,
+        DVec2::ZERO,
+        HealthPool {
+            current: 100.0,
+            maximum: 100.0,
+        },
+    );
+    spawn_station(
+        &mut app,
+        DVec2::new(400.0, 0.0),
+        StationServiceKind::HullRepair,
+        1_000.0,
+        2,
+    );
+    spawn_station(
+        &mut app,
+        DVec2::new(400.0, 0.0),
+        StationServiceKind::Refuel,
+        100.0,
+        1,
+    );
+
+    app.world_mut()
+        .get_mut::<ActionQueue>(ship)
+        .unwrap()
+        .push(EntityAction::PurchaseRefuel);
+    request(&mut app, ship, EntityAction::PurchaseHullRepair);
+
+    let mut reasons = failures(&app);
+    reasons.sort_by_key(|reason| reason.as_str());
+    assert_eq!(
+        reasons,
+        vec![
+            StationServiceFailureReason::NoServiceInRange,
+            StationServiceFailureReason::NothingToService,
+        ]
+    );
+    assert!(purchases(&app).is_empty());
+    assert_eq!(
+        app.world().get::<CreditWallet>(player).unwrap().balance,
+        1_000
+    );
+}
+
+#[test]
+fn tanks_persisted_without_capacity_fill_to_their_current_fuel() {
+    let tank: FuelTank = serde_json::from_value(serde_json::json!({ "fuel_kg": 640.0 }))
+        .expect("legacy tank should deserialize");
+    assert_eq!(tank.capacity_kg, 640.0);
+
+    let tank: FuelTank =
+        serde_json::from_value(serde_json::json!({ "fuel_kg": 10.0, "capacity_kg": 0.0 }))
+            .expect("tank should deserialize");
+    assert_eq!(tank.capacity_kg, 0.0);
+}
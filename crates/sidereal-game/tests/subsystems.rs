@@ -0,0 +1,288 @@
+use avian2d::prelude::{LinearVelocity, Position, Rotation};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, AmmoCount, BallisticWeapon, DamageType, EntityAction, EntityGuid, Hardpoint,
+    Inventory, MassDirty, ModuleHealth, MountedOn, ParentGuid, ScannerComponent,
+    ScannerContactDetailTier, ShotFiredEvent, ShotHitEvent, SimulationMotionWriter,
+    WeaponCooldownState, apply_scanner_module_damage, apply_subsystem_damage,
+    process_module_repairs, process_weapon_fire_actions,
+};
+use std::time::Duration;
+use uuid::Uuid;
+
+const KIT_ITEM_ID: &str = "repair.module_kit";
+
+fn subsystem_app(step_s: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<ShotHitEvent>();
+    app.add_message::<ShotFiredEvent>();
+    app.add_message::<sidereal_game::BallisticProjectileSpawnedEvent>();
+    app.insert_resource(Time::<Fixed>::from_hz(10.0));
+    app.world_mut()
+        .resource_mut::<Time<Fixed>>()
+        .advance_by(Duration::from_secs_f32(step_s));
+    app
+}
+
+fn spawn_hull(app: &mut App, kits: u32) -> (Entity, Uuid) {
+    let hull_guid = Uuid::new_v4();
+    let mut inventory = Inventory::default();
+    inventory.add_stack(KIT_ITEM_ID, kits, 15.0);
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            Position(Vec2::ZERO.into()),
+            Rotation::from(Quat::IDENTITY),
+            LinearVelocity(Vec2::ZERO.into()),
+            SimulationMotionWriter,
+            ActionQueue::default(),
+            inventory,
+        ))
+        .id();
+    (hull, hull_guid)
+}
+
+fn spawn_module(
+    app: &mut App,
+    hull_guid: Uuid,
+    hardpoint_id: &str,
+    offset_m: Vec3,
+    health: ModuleHealth,
+) -> Entity {
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        ParentGuid(hull_guid),
+        Hardpoint {
+            hardpoint_id: hardpoint_id.to_string(),
+            offset_m,
+            local_rotation: Quat::IDENTITY,
+        },
+    ));
+    app.world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: hull_guid,
+                hardpoint_id: hardpoint_id.to_string(),
+            },
+            health,
+        ))
+        .id()
+}
+
+fn write_hit(app: &mut App, hull: Entity, hull_guid: Uuid, impact_pos: DVec2, damage: f32) {
+    app.world_mut().write_message(ShotHitEvent {
+        shooter_guid: Uuid::new_v4(),
+        target_entity: hull,
+        target_guid: Some(hull_guid),
+        weapon_entity: Entity::PLACEHOLDER,
+        weapon_guid: Uuid::new_v4(),
+        impact_pos,
+        damage,
+        shield_absorbed: 0.0,
+        damage_type: DamageType::Ballistic,
+    });
+}
+
+fn module_health(app: &App, module: Entity) -> ModuleHealth {
+    app.world()
+        .entity(module)
+        .get::<ModuleHealth>()
+        .cloned()
+        .expect("module should keep its health")
+}
+
+#[test]
+fn hits_damage_only_the_nearest_module_within_radius() {
+    let mut app = subsystem_app(0.1);
+    let (hull, hull_guid) = spawn_hull(&mut app, 0);
+    let engine = spawn_module(
+        &mut app,
+        hull_guid,
+        "engine_main_aft",
+        Vec3::new(0.0, -8.0, 0.0),
+        ModuleHealth::new(300.0),
+    );
+    let weapon = spawn_module(
+        &mut app,
+        hull_guid,
+        "weapon_fore_center",
+        Vec3::new(0.0, 8.0, 0.0),
+        ModuleHealth::new(200.0),
+    );
+
+    write_hit(&mut app, hull, hull_guid, DVec2::new(1.0, -7.0), 100.0);
+    write_hit(&mut app, hull, hull_guid, DVec2::new(40.0, 0.0), 100.0);
+    let _ = app.world_mut().run_system_once(apply_subsystem_damage);
+
+    assert_eq!(module_health(&app, engine).current, 250.0);
+    assert_eq!(module_health(&app, weapon).current, 200.0);
+}
+
+#[test]
+fn destroyed_weapons_hold_fire() {
+    let mut app = subsystem_app(0.1);
+    let (hull, hull_guid) = spawn_hull(&mut app, 0);
+    let weapon = spawn_module(
+        &mut app,
+        hull_guid,
+        "weapon_fore_center",
+        Vec3::new(0.0, 4.0, 0.0),
+        ModuleHealth {
+            current: 0.0,
+            maximum: 200.0,
+            repair_remaining_s: 0.0,
+        },
+    );
+    app.world_mut().entity_mut(weapon).insert((
+        BallisticWeapon::corvette_ballistic_gatling(),
+        WeaponCooldownState::default(),
+        AmmoCount::new(100, 100),
+    ));
+    app.world_mut()
+        .get_mut::<ActionQueue>(hull)
+        .unwrap()
+        .push(EntityAction::FirePrimary);
+
+    let _ = app.world_mut().run_system_once(process_weapon_fire_actions);
+
+    assert_eq!(app.world().get::<AmmoCount>(weapon).unwrap().current, 100);
+    assert!(
+        app.world()
+            .resource::<Messages<ShotFiredEvent>>()
+            .is_empty()
+    );
+}
+
+#[test]
+fn scanner_range_tracks_module_health_from_its_rated_range() {
+    let mut app = subsystem_app(0.1);
+    let (_, hull_guid) = spawn_hull(&mut app, 0);
+    let scanner = spawn_module(
+        &mut app,
+        hull_guid,
+        "scanner_dorsal",
+        Vec3::ZERO,
+        ModuleHealth::new(150.0),
+    );
+    app.world_mut()
+        .entity_mut(scanner)
+        .insert(ScannerComponent {
+            base_range_m: 1000.0,
+            rated_range_m: None,
+            level: 1,
+            detail_tier: ScannerContactDetailTier::Iff,
+            supports_density: true,
+            supports_directional_awareness: true,
+            max_contacts: 64,
+        });
+    let _ = app.world_mut().run_system_once(apply_scanner_module_damage);
+    assert_eq!(
+        app.world()
+            .get::<ScannerComponent>(scanner)
+            .unwrap()
+            .rated_range_m,
+        Some(1000.0)
+    );
+
+    app.world_mut()
+        .get_mut::<ModuleHealth>(scanner)
+        .unwrap()
+        .apply_damage(75.0);
+    let _ = app.world_mut().run_system_once(apply_scanner_module_damage);
+    assert_eq!(
+        app.world()
+            .get::<ScannerComponent>(scanner)
+            .unwrap()
+            .base_range_m,
+        500.0
+    );
+
+    app.world_mut()
+        .get_mut::<ModuleHealth>(scanner)
+        .unwrap()
+        .restore(75.0);
+    let _ = app.world_mut().run_system_once(apply_scanner_module_damage);
+    assert_eq!(
+        app.world()
+            .get::<ScannerComponent>(scanner)
+            .unwrap()
+            .base_range_m,
+        1000.0
+    );
+}
+
+#[test]
+fn repair_action_spends_a_kit_on_the_most_damaged_module_after_repair_time() {
+    let mut app = subsystem_app(2.0);
+    let (hull, hull_guid) = spawn_hull(&mut app, 2);
+    let engine = spawn_module(
+        &mut app,
+        hull_guid,
+        "engine_main_aft",
+        Vec3::ZERO,
+        ModuleHealth {
+            current: 250.0,
+            maximum: 300.0,
+            repair_remaining_s: 0.0,
+        },
+    );
+    let weapon = spawn_module(
+        &mut app,
+        hull_guid,
+        "weapon_fore_center",
+        Vec3::ZERO,
+        ModuleHealth {
+            current: 0.0,
+            maximum: 200.0,
+            repair_remaining_s: 0.0,
+        },
+    );
+    let request_repair = |app: &mut App| {
+        app.world_mut()
+            .get_mut::<ActionQueue>(hull)
+            .unwrap()
+            .push(EntityAction::RepairModules);
+        let _ = app.world_mut().run_system_once(process_module_repairs);
+    };
+
+    request_repair(&mut app);
+    assert!(module_health(&app, weapon).is_repairing());
+    assert!(!module_health(&app, engine).is_repairing());
+    let hull_ref = app.world().entity(hull);
+    assert_eq!(
+        hull_ref
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of(KIT_ITEM_ID),
+        1
+    );
+    assert!(hull_ref.contains::<MassDirty>());
+
+    // A second request while a repair is underway is ignored.
+    request_repair(&mut app);
+    assert_eq!(
+        app.world()
+            .entity(hull)
+            .get::<Inventory>()
+            .unwrap()
+            .quantity_of(KIT_ITEM_ID),
+        1
+    );
+
+    let _ = app.world_mut().run_system_once(process_module_repairs);
+    assert_eq!(module_health(&app, weapon).current, 100.0);
+    assert!(!module_health(&app, weapon).is_repairing());
+    assert!(!module_health(&app, weapon).is_destroyed());
+    assert!(
+        app.world()
+            .entity(hull)
+            .get::<ActionQueue>()
+            .unwrap()
+            .pending
+            .is_empty()
+    );
+}
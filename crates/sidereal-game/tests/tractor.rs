@@ -0,0 +1,222 @@
+use avian2d::prelude::{LinearVelocity, Position, RigidBody};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use sidereal_game::{
+    ActionQueue, ControlledEntityGuid, EntityAction, EntityGuid, HealthPool, MountedOn, PlayerTag,
+    SelectedEntityGuid, ShipTag, SimulationMotionWriter, TotalMassKg, TractorBeam,
+    apply_tractor_beam_forces, compute_tractor_force, process_tractor_actions,
+};
+use uuid::Uuid;
+
+fn test_tractor(active: bool) -> TractorBeam {
+    TractorBeam {
+        range_m: 100.0,
+        hold_distance_m: 10.0,
+        max_force_n: 5_000.0,
+        max_target_mass_kg: 2_000.0,
+        active,
+        target_guid: None,
+    }
+}
+
+fn spawn_tractor_hull(app: &mut App, active: bool) -> (Uuid, Entity, Entity) {
+    let hull_guid = Uuid::new_v4();
+    let hull = app
+        .world_mut()
+        .spawn((
+            EntityGuid(hull_guid),
+            Position(DVec2::ZERO),
+            LinearVelocity(DVec2::ZERO),
+            TotalMassKg(10_000.0),
+            RigidBody::Dynamic,
+            ShipTag,
+            HealthPool {
+                current: 100.0,
+                maximum: 100.0,
+            },
+            SimulationMotionWriter,
+            ActionQueue::default(),
+        ))
+        .id();
+    let tractor = app
+        .world_mut()
+        .spawn((
+            EntityGuid(Uuid::new_v4()),
+            MountedOn {
+                parent_entity_id: hull_guid,
+                hardpoint_id: "utility_aft".to_string(),
+            },
+            test_tractor(active),
+        ))
+        .id();
+    (hull_guid, hull, tractor)
+}
+
+fn spawn_body(app: &mut App, position: DVec2, mass_kg: f32) -> Uuid {
+    let guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(guid),
+        Position(position),
+        LinearVelocity(DVec2::ZERO),
+        TotalMassKg(mass_kg),
+        RigidBody::Dynamic,
+    ));
+    guid
+}
+
+fn locked_target(app: &App, tractor: Entity) -> Option<Uuid> {
+    app.world()
+        .entity(tractor)
+        .get::<TractorBeam>()
+        .unwrap()
+        .target_guid
+}
+
+#[test]
+fn tractor_actions_toggle_mounted_beams_and_release_target() {
+    let mut app = App::new();
+    let (_hull_guid, hull, tractor) = spawn_tractor_hull(&mut app, false);
+    app.world_mut()
+        .entity_mut(hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::ActivateTractor, EntityAction::FirePrimary];
+
+    let _ = app.world_mut().run_system_once(process_tractor_actions);
+
+    assert!(
+        app.world()
+            .entity(tractor)
+            .get::<TractorBeam>()
+            .unwrap()
+            .active
+    );
+    let queue = app.world().entity(hull).get::<ActionQueue>().unwrap();
+    assert_eq!(queue.pending, vec![EntityAction::FirePrimary]);
+
+    app.world_mut()
+        .entity_mut(tractor)
+        .get_mut::<TractorBeam>()
+        .unwrap()
+        .target_guid = Some(Uuid::new_v4());
+    app.world_mut()
+        .entity_mut(hull)
+        .get_mut::<ActionQueue>()
+        .unwrap()
+        .pending = vec![EntityAction::DeactivateTractor];
+    let _ = app.world_mut().run_system_once(process_tractor_actions);
+
+    let beam = app.world().entity(tractor).get::<TractorBeam>().unwrap();
+    assert!(!beam.active);
+    assert_eq!(beam.target_guid, None);
+}
+
+#[test]
+fn active_tractor_locks_nearest_towable_body_in_range() {
+    let mut app = App::new();
+    let (_hull_guid, _hull, tractor) = spawn_tractor_hull(&mut app, true);
+    let near = spawn_body(&mut app, DVec2::new(30.0, 0.0), 500.0);
+    let _far = spawn_body(&mut app, DVec2::new(60.0, 0.0), 500.0);
+    let _out_of_range = spawn_body(&mut app, DVec2::new(150.0, 0.0), 500.0);
+
+    let _ = app.world_mut().run_system_once(apply_tractor_beam_forces);
+
+    assert_eq!(locked_target(&app, tractor), Some(near));
+}
+
+#[test]
+fn tractor_prefers_selected_body_and_breaks_distance_ties_by_guid() {
+    let mut app = App::new();
+    let (hull_guid, _hull, tractor) = spawn_tractor_hull(&mut app, true);
+    let first = spawn_body(&mut app, DVec2::new(30.0, 0.0), 500.0);
+    let second = spawn_body(&mut app, DVec2::new(-30.0, 0.0), 500.0);
+
+    let _ = app.world_mut().run_system_once(apply_tractor_beam_forces);
+    assert_eq!(locked_target(&app, tractor), Some(first.min(second)));
+
+    let selected = spawn_body(&mut app, DVec2::new(0.0, 80.0), 500.0);
+    app.world_mut().spawn((
+        PlayerTag,
+        ControlledEntityGuid(Some(hull_guid.to_string())),
+        SelectedEntityGuid(Some(selected.to_string())),
+    ));
+    app.world_mut()
+        .entity_mut(tractor)
+        .get_mut::<TractorBeam>()
+        .unwrap()
+        .target_guid = None;
+    let _ = app.world_mut().run_system_once(apply_tractor_beam_forces);
+    assert_eq!(locked_target(&app, tractor), Some(selected));
+}
+
+#[test]
+fn tractor_skips_bodies_heavier_than_module_limit_and_operational_ships() {
+    let mut app = App::new();
+    let (_hull_guid, _hull, tractor) = spawn_tractor_hull(&mut app, true);
+    let _heavy = spawn_body(&mut app, DVec2::new(20.0, 0.0), 50_000.0);
+    app.world_mut().spawn((
+        EntityGuid(Uuid::new_v4()),
+        Position(DVec2::new(25.0, 0.0)),
+        TotalMassKg(500.0),
+        RigidBody::Dynamic,
+        ShipTag,
+        HealthPool {
+            current: 50.0,
+            maximum: 100.0,
+        },
+    ));
+
+    let _ = app.world_mut().run_system_once(apply_tractor_beam_forces);
+    assert_eq!(locked_target(&app, tractor), None);
+
+    let disabled_guid = Uuid::new_v4();
+    app.world_mut().spawn((
+        EntityGuid(disabled_guid),
+        Position(DVec2::new(40.0, 0.0)),
+        TotalMassKg(500.0),
+        RigidBody::Dynamic,
+        ShipTag,
+        HealthPool {
+            current: 0.0,
+            maximum: 100.0,
+        },
+    ));
+    let _ = app.world_mut().run_system_once(apply_tractor_beam_forces);
+    assert_eq!(locked_target(&app, tractor), Some(disabled_guid));
+}
+
+#[test]
+fn tractor_force_pulls_toward_hold_distance_and_respects_cap() {
+    let beam = test_tractor(true);
+
+    let pull = compute_tractor_force(
+        DVec2::ZERO,
+        DVec2::ZERO,
+        DVec2::new(20.0, 0.0),
+        DVec2::ZERO,
+        100.0,
+        &beam,
+    );
+    assert!((pull - DVec2::new(-1_000.0, 0.0)).length() < 1e-6);
+
+    let at_rest = compute_tractor_force(
+        DVec2::ZERO,
+        DVec2::ZERO,
+        DVec2::new(10.0, 0.0),
+        DVec2::ZERO,
+        100.0,
+        &beam,
+    );
+    assert!(at_rest.length() < 1e-6);
+
+    let capped = compute_tractor_force(
+        DVec2::ZERO,
+        DVec2::ZERO,
+        DVec2::new(90.0, 0.0),
+        DVec2::ZERO,
+        1_000.0,
+        &beam,
+    );
+    assert!((capped.length() - 5_000.0).abs() < 1e-6);
+}
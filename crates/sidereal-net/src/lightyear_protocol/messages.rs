@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use sidereal_game::EntityAction;
+use sidereal_game::{EntityAction, MarketQuote, MarketTradeSide};
 
-pub const LIGHTYEAR_PROTOCOL_VERSION: u32 = 7;
+pub const LIGHTYEAR_PROTOCOL_VERSION: u32 = 13;
 
 /// Client authenticates replication session and binds transport identity.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -96,6 +96,50 @@ pub struct ClientTacticalResnapshotRequestMessage {
     pub request_contacts_snapshot: bool,
 }
 
+/// Client asks to move the weapon mounted on one of its ships' hardpoints to
+/// another fire group.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClientWeaponGroupAssignmentMessage {
+    pub player_entity_id: String,
+    pub ship_entity_id: String,
+    pub hardpoint_id: String,
+    pub group: u8,
+}
+
+/// Client asks to buy from or sell to a station market with one of its ships'
+/// cargo. The server re-validates ownership, range, stock, cargo and credits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClientMarketTradeMessage {
+    pub player_entity_id: String,
+    pub ship_entity_id: String,
+    pub market_entity_id: String,
+    pub item_id: String,
+    pub side: MarketTradeSide,
+    pub quantity: u32,
+}
+
+/// Client asks to queue a recipe job at a crafting facility it owns. The server
+/// re-validates ownership and the recipe against the facility type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClientCraftingJobMessage {
+    pub player_entity_id: String,
+    pub facility_entity_id: String,
+    pub recipe_id: String,
+}
+
+/// Server snapshot of a station market the player's controlled ship can trade
+/// with, for the client trade UI. Sent periodically while in range and after
+/// each of the player's trades there.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ServerMarketSnapshotMessage {
+    pub player_entity_id: String,
+    pub market_entity_id: String,
+    pub station_entity_id: String,
+    pub market_id: String,
+    pub display_name: String,
+    pub quotes: Vec<MarketQuote>,
+}
+
 /// Server authoritative weapon fire notification for client-side tracer visuals.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ServerWeaponFiredMessage {
@@ -109,6 +153,18 @@ pub struct ServerWeaponFiredMessage {
     pub ttl_s: f32,
 }
 
+/// Server authoritative beam start/stop notification, sent on the reliable
+/// manifest channel. Clients keep the beam's looping `fire` cue alive while
+/// `active` and release it on the stop message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ServerBeamWeaponMessage {
+    pub shooter_entity_id: String,
+    pub weapon_guid: String,
+    pub audio_profile_id: Option<String>,
+    pub origin_xy: [f64; 2],
+    pub active: bool,
+}
+
 /// Server authoritative destruction-effect notification for pre-despawn VFX.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ServerEntityDestructionMessage {
@@ -129,6 +185,10 @@ pub struct TacticalContact {
     pub kind: String,
     pub map_icon_asset_id: Option<String>,
     pub faction_id: Option<String>,
+    /// IFF standing of the contact toward the receiving player
+    /// (`hostile`/`neutral`/`allied`), resolved server-side from the faction
+    /// registry and the player's reputation.
+    pub standing: Option<String>,
     pub position_xy: [f64; 2],
     pub size_m: Option<[f32; 3]>,
     pub mass_kg: Option<f32>,
@@ -12,11 +12,13 @@ use sidereal_game::WeaponCooldownState;
 use sidereal_game::component_meta::SiderealComponentRegistration;
 
 use super::{
-    ClientAuthMessage, ClientControlRequestMessage, ClientDisconnectNotifyMessage,
-    ClientLocalViewModeMessage, ClientNotificationDismissedMessage, ClientRealtimeInputMessage,
-    ClientTacticalResnapshotRequestMessage, ControlChannel, InputChannel, ManifestChannel,
-    NotificationChannel, ServerAssetCatalogVersionMessage, ServerControlAckMessage,
-    ServerControlRejectMessage, ServerEntityDestructionMessage, ServerNotificationMessage,
+    ClientAuthMessage, ClientControlRequestMessage, ClientCraftingJobMessage,
+    ClientDisconnectNotifyMessage, ClientLocalViewModeMessage, ClientMarketTradeMessage,
+    ClientNotificationDismissedMessage, ClientRealtimeInputMessage,
+    ClientTacticalResnapshotRequestMessage, ClientWeaponGroupAssignmentMessage, ControlChannel,
+    InputChannel, ManifestChannel, NotificationChannel, ServerAssetCatalogVersionMessage,
+    ServerBeamWeaponMessage, ServerControlAckMessage, ServerControlRejectMessage,
+    ServerEntityDestructionMessage, ServerMarketSnapshotMessage, ServerNotificationMessage,
     ServerOwnerAssetManifestDeltaMessage, ServerOwnerAssetManifestSnapshotMessage,
     ServerSessionDeniedMessage, ServerSessionReadyMessage, ServerTacticalContactsDeltaMessage,
     ServerTacticalContactsSnapshotMessage, ServerTacticalFogDeltaMessage,
@@ -81,8 +83,18 @@ fn register_lightyear_common_protocol(app: &mut App) {
         .add_direction(NetworkDirection::ClientToServer);
     app.register_message::<ClientTacticalResnapshotRequestMessage>()
         .add_direction(NetworkDirection::ClientToServer);
+    app.register_message::<ClientWeaponGroupAssignmentMessage>()
+        .add_direction(NetworkDirection::ClientToServer);
+    app.register_message::<ClientMarketTradeMessage>()
+        .add_direction(NetworkDirection::ClientToServer);
+    app.register_message::<ServerMarketSnapshotMessage>()
+        .add_direction(NetworkDirection::ServerToClient);
+    app.register_message::<ClientCraftingJobMessage>()
+        .add_direction(NetworkDirection::ClientToServer);
     app.register_message::<ServerWeaponFiredMessage>()
         .add_direction(NetworkDirection::ServerToClient);
+    app.register_message::<ServerBeamWeaponMessage>()
+        .add_direction(NetworkDirection::ServerToClient);
     app.register_message::<ServerEntityDestructionMessage>()
         .add_direction(NetworkDirection::ServerToClient);
     app.register_message::<ServerTacticalFogSnapshotMessage>()
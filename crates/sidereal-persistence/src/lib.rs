@@ -10,6 +10,7 @@ const SCRIPT_CATALOG_DOCUMENTS_TABLE: &str = "script_catalog_documents";
 const SCRIPT_CATALOG_VERSIONS_TABLE: &str = "script_catalog_versions";
 const SCRIPT_CATALOG_DRAFTS_TABLE: &str = "script_catalog_drafts";
 const PLAYER_NOTIFICATIONS_TABLE: &str = "player_notifications";
+const ECONOMY_AUDIT_LOG_TABLE: &str = "economy_audit_log";
 
 static GRAPH_SCHEMA_WRITE_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 
@@ -79,6 +80,30 @@ pub fn ensure_player_notifications_schema(client: &mut Client) -> Result<()> {
     Ok(())
 }
 
+/// Append-only log of credit movements, written independently of the
+/// gateway's account tables so economy disputes can be traced per player.
+pub fn ensure_economy_audit_schema(client: &mut Client) -> Result<()> {
+    client
+        .batch_execute(&format!(
+            "
+                CREATE TABLE IF NOT EXISTS {ECONOMY_AUDIT_LOG_TABLE} (
+                    transaction_id UUID PRIMARY KEY,
+                    player_entity_id TEXT NOT NULL,
+                    transaction_kind TEXT NOT NULL,
+                    counterparty_entity_id TEXT NULL,
+                    credits_delta BIGINT NOT NULL,
+                    balance_after BIGINT NOT NULL,
+                    payload JSONB NOT NULL DEFAULT '{{}}'::jsonb,
+                    created_at_epoch_s BIGINT NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS economy_audit_log_player_created_idx
+                    ON {ECONOMY_AUDIT_LOG_TABLE} (player_entity_id, created_at_epoch_s DESC);
+                "
+        ))
+        .map_err(db_err("create economy audit log table"))?;
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GraphComponentRecord {
     pub component_id: String,
@@ -116,6 +141,19 @@ pub struct PlayerNotificationRecord {
     pub dismissed_at_epoch_s: Option<i64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EconomyAuditRecord {
+    pub transaction_id: String,
+    pub player_entity_id: String,
+    pub transaction_kind: String,
+    pub counterparty_entity_id: Option<String>,
+    /// Signed change to the player's balance; debits are negative.
+    pub credits_delta: i64,
+    pub balance_after: i64,
+    pub payload: JsonValue,
+    pub created_at_epoch_s: i64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct StaleComponentCleanupRow {
     entity_id: String,
@@ -257,6 +295,7 @@ impl GraphPersistence {
             .map_err(db_err("create script world init state table"))?;
         ensure_script_catalog_schema(&mut self.client)?;
         ensure_player_notifications_schema(&mut self.client)?;
+        ensure_economy_audit_schema(&mut self.client)?;
 
         Ok(())
     }
@@ -368,6 +407,87 @@ impl GraphPersistence {
             .map_err(db_err("mark player notification dismissed"))
     }
 
+    pub fn ensure_economy_audit_schema(&mut self) -> Result<()> {
+        ensure_economy_audit_schema(&mut self.client)
+    }
+
+    pub fn insert_economy_audit_record(&mut self, record: &EconomyAuditRecord) -> Result<()> {
+        let payload = serde_json::to_string(&record.payload)
+            .map_err(|err| PersistenceError::Serialization(err.to_string()))?;
+        self.client
+            .execute(
+                &format!(
+                    "
+                    INSERT INTO {ECONOMY_AUDIT_LOG_TABLE} (
+                        transaction_id,
+                        player_entity_id,
+                        transaction_kind,
+                        counterparty_entity_id,
+                        credits_delta,
+                        balance_after,
+                        payload,
+                        created_at_epoch_s
+                    )
+                    VALUES ($1::text::uuid, $2, $3, $4, $5, $6, $7::text::jsonb, $8)
+                    ON CONFLICT (transaction_id) DO NOTHING
+                    "
+                ),
+                &[
+                    &record.transaction_id,
+                    &record.player_entity_id,
+                    &record.transaction_kind,
+                    &record.counterparty_entity_id,
+                    &record.credits_delta,
+                    &record.balance_after,
+                    &payload,
+                    &record.created_at_epoch_s,
+                ],
+            )
+            .map_err(db_err("insert economy audit record"))?;
+        Ok(())
+    }
+
+    /// Most recent audit records for a player, newest first.
+    pub fn load_economy_audit_records(
+        &mut self,
+        player_entity_id: &str,
+        limit: i64,
+    ) -> Result<Vec<EconomyAuditRecord>> {
+        let rows = self
+            .client
+            .query(
+                &format!(
+                    "
+                    SELECT transaction_id::text, player_entity_id, transaction_kind,
+                           counterparty_entity_id, credits_delta, balance_after,
+                           payload::text, created_at_epoch_s
+                    FROM {ECONOMY_AUDIT_LOG_TABLE}
+                    WHERE player_entity_id = $1
+                    ORDER BY created_at_epoch_s DESC
+                    LIMIT $2
+                    "
+                ),
+                &[&player_entity_id, &limit],
+            )
+            .map_err(db_err("load economy audit records"))?;
+        let mut out = Vec::with_capacity(rows.len());
+        for row in rows {
+            let payload: String = row.get(6);
+            out.push(EconomyAuditRecord {
+                transaction_id: row.get(0),
+                player_entity_id: row.get(1),
+                transaction_kind: row.get(2),
+                counterparty_entity_id: row.get(3),
+                credits_delta: row.get(4),
+                balance_after: row.get(5),
+                payload: serde_json::from_str(&payload)
+                    .map_err(|err| PersistenceError::Serialization(err.to_string()))?,
+                created_at_epoch_s: row.get(7),
+            });
+        }
+        Ok(out)
+    }
+
     pub fn script_world_init_state_exists(&mut self, init_key: &str) -> Result<bool> {
         self.client
             .query_opt(
@@ -846,6 +966,15 @@ pub fn infer_script_family(script_path: &str) -> String {
     if script_path.starts_with("world/") {
         return "world".to_string();
     }
+    if script_path.starts_with("economy/") {
+        return "economy".to_string();
+    }
+    if script_path.starts_with("factions/") {
+        return "factions".to_string();
+    }
+    if script_path.starts_with("missions/") {
+        return "missions".to_string();
+    }
     "misc".to_string()
 }
 
@@ -1675,6 +1804,15 @@ mod tests {
         assert_eq!(infer_script_family("planets/aurelia.lua"), "planet");
         assert_eq!(infer_script_family("ai/pirate_patrol.lua"), "ai");
         assert_eq!(infer_script_family("world/something_else.lua"), "world");
+        assert_eq!(infer_script_family("economy/item_registry.lua"), "economy");
+        assert_eq!(
+            infer_script_family("factions/faction_registry.lua"),
+            "factions"
+        );
+        assert_eq!(
+            infer_script_family("missions/mission_registry.lua"),
+            "missions"
+        );
         assert_eq!(infer_script_family("misc/foo.lua"), "misc");
     }
 
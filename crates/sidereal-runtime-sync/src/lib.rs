@@ -2,6 +2,8 @@
 use bevy::ecs::reflect::{AppTypeRegistry, ReflectCommandExt, ReflectComponent};
 use bevy::prelude::*;
 #[cfg(feature = "persistence")]
+use bevy::reflect::TypeRegistry;
+#[cfg(feature = "persistence")]
 use bevy::reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 #[cfg(feature = "persistence")]
 use serde::Serialize as _;
@@ -205,35 +207,36 @@ pub fn serialize_entity_components_to_graph_records(
     app_type_registry: &AppTypeRegistry,
 ) -> Vec<GraphComponentRecord> {
     let type_registry = app_type_registry.read();
-    let mut records = Vec::new();
-
-    for entry in &registry.entries {
-        let Some(type_registration) = type_registry.get_with_type_path(entry.type_path) else {
-            continue;
-        };
-        let Some(reflect_component) = type_registration.data::<ReflectComponent>() else {
-            continue;
-        };
-        let Some(reflected) = reflect_component.reflect(entity_ref) else {
-            continue;
-        };
-
-        let serializer = TypedReflectSerializer::new(reflected, &type_registry);
-        let mut buf = Vec::new();
-        let mut json_serializer = serde_json::Serializer::new(&mut buf);
-        if serializer.serialize(&mut json_serializer).is_err() {
-            continue;
-        }
-        let Ok(json_value) = serde_json::from_slice::<serde_json::Value>(&buf) else {
-            continue;
-        };
-
-        records.push(GraphComponentRecord {
-            component_id: format_component_id(entity_id, entry.component_kind),
-            component_kind: entry.component_kind.to_string(),
-            properties: json_value,
-        });
-    }
+    registry
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let json_value =
+                serialize_component_to_json(entity_ref, entry.type_path, &type_registry)?;
+            Some(GraphComponentRecord {
+                component_id: format_component_id(entity_id, entry.component_kind),
+                component_kind: entry.component_kind.to_string(),
+                properties: json_value,
+            })
+        })
+        .collect()
+}
 
-    records
+/// Serializes the component registered under `type_path` on `entity_ref`,
+/// or `None` when the entity lacks it or it does not serialize.
+#[cfg(feature = "persistence")]
+pub fn serialize_component_to_json(
+    entity_ref: EntityRef<'_>,
+    type_path: &str,
+    type_registry: &TypeRegistry,
+) -> Option<serde_json::Value> {
+    let type_registration = type_registry.get_with_type_path(type_path)?;
+    let reflected = type_registration
+        .data::<ReflectComponent>()?
+        .reflect(entity_ref)?;
+    let serializer = TypedReflectSerializer::new(reflected, type_registry);
+    let mut buf = Vec::new();
+    let mut json_serializer = serde_json::Serializer::new(&mut buf);
+    serializer.serialize(&mut json_serializer).ok()?;
+    serde_json::from_slice::<serde_json::Value>(&buf).ok()
 }
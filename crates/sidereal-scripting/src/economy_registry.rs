@@ -0,0 +1,524 @@
+use crate::{
+    LuaSandboxPolicy, ScriptError, load_lua_module_from_source, lua_value_to_json,
+    resolve_script_path_from_root, validate_unique_ids,
+};
+use mlua::Value;
+use serde::de::DeserializeOwned;
+use sidereal_game::{
+    FacilityRegistry, ItemRegistry, LootTableRegistry, MarketRegistry, RecipeRegistry,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+pub const ITEM_REGISTRY_SCRIPT_REL_PATH: &str = "economy/item_registry.lua";
+pub const RECIPE_REGISTRY_SCRIPT_REL_PATH: &str = "economy/recipe_registry.lua";
+pub const FACILITY_REGISTRY_SCRIPT_REL_PATH: &str = "economy/facility_registry.lua";
+pub const LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH: &str = "economy/loot_table_registry.lua";
+pub const MARKET_REGISTRY_SCRIPT_REL_PATH: &str = "economy/market_registry.lua";
+
+pub fn load_item_registry_from_root(scripts_root: &Path) -> Result<ItemRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let registry_source = read_registry_source(scripts_root, ITEM_REGISTRY_SCRIPT_REL_PATH)?;
+    load_item_registry_from_source(
+        &registry_source,
+        Path::new(ITEM_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+}
+
+pub fn load_item_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+) -> Result<ItemRegistry, ScriptError> {
+    let registry =
+        decode_registry_module::<ItemRegistry>(registry_source, registry_path, policy, "item")?;
+    validate_item_registry(registry_path, &registry)?;
+    Ok(registry)
+}
+
+pub fn load_facility_registry_from_root(
+    scripts_root: &Path,
+) -> Result<FacilityRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let registry_source = read_registry_source(scripts_root, FACILITY_REGISTRY_SCRIPT_REL_PATH)?;
+    load_facility_registry_from_source(
+        &registry_source,
+        Path::new(FACILITY_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+}
+
+pub fn load_facility_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+) -> Result<FacilityRegistry, ScriptError> {
+    let registry = decode_registry_module::<FacilityRegistry>(
+        registry_source,
+        registry_path,
+        policy,
+        "facility",
+    )?;
+    validate_facility_registry(registry_path, &registry)?;
+    Ok(registry)
+}
+
+/// Loads the recipe catalog and validates it against the item and facility
+/// catalogs from the same scripts root.
+pub fn load_recipe_registry_from_root(scripts_root: &Path) -> Result<RecipeRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_registry = load_item_registry_from_root(scripts_root)?;
+    let facility_registry = load_facility_registry_from_root(scripts_root)?;
+    let registry_source = read_registry_source(scripts_root, RECIPE_REGISTRY_SCRIPT_REL_PATH)?;
+    load_recipe_registry_from_source(
+        &registry_source,
+        Path::new(RECIPE_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &item_registry,
+        &facility_registry,
+    )
+}
+
+pub fn load_recipe_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+    item_registry: &ItemRegistry,
+    facility_registry: &FacilityRegistry,
+) -> Result<RecipeRegistry, ScriptError> {
+    let registry =
+        decode_registry_module::<RecipeRegistry>(registry_source, registry_path, policy, "recipe")?;
+    validate_recipe_registry(registry_path, &registry, item_registry, facility_registry)?;
+    Ok(registry)
+}
+
+/// Loads the loot table catalog and validates its drops against the item
+/// catalog from the same scripts root.
+pub fn load_loot_table_registry_from_root(
+    scripts_root: &Path,
+) -> Result<LootTableRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_registry = load_item_registry_from_root(scripts_root)?;
+    let registry_source = read_registry_source(scripts_root, LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH)?;
+    load_loot_table_registry_from_source(
+        &registry_source,
+        Path::new(LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &item_registry,
+    )
+}
+
+pub fn load_loot_table_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+    item_registry: &ItemRegistry,
+) -> Result<LootTableRegistry, ScriptError> {
+    let registry = decode_registry_module::<LootTableRegistry>(
+        registry_source,
+        registry_path,
+        policy,
+        "loot table",
+    )?;
+    validate_loot_table_registry(registry_path, &registry, item_registry)?;
+    Ok(registry)
+}
+
+/// Loads the NPC market catalog and validates its listings against the item
+/// catalog from the same scripts root.
+pub fn load_market_registry_from_root(scripts_root: &Path) -> Result<MarketRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_registry = load_item_registry_from_root(scripts_root)?;
+    let registry_source = read_registry_source(scripts_root, MARKET_REGISTRY_SCRIPT_REL_PATH)?;
+    load_market_registry_from_source(
+        &registry_source,
+        Path::new(MARKET_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &item_registry,
+    )
+}
+
+pub fn load_market_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+    item_registry: &ItemRegistry,
+) -> Result<MarketRegistry, ScriptError> {
+    let registry =
+        decode_registry_module::<MarketRegistry>(registry_source, registry_path, policy, "market")?;
+    validate_market_registry(registry_path, &registry, item_registry)?;
+    Ok(registry)
+}
+
+pub(crate) fn read_registry_source(
+    scripts_root: &Path,
+    rel_path: &str,
+) -> Result<String, ScriptError> {
+    let registry_path = resolve_script_path_from_root(scripts_root, rel_path)?;
+    std::fs::read_to_string(&registry_path)
+        .map_err(|err| ScriptError::Io(format!("read {} failed: {err}", registry_path.display())))
+}
+
+pub(crate) fn decode_registry_module<T: DeserializeOwned>(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+    registry_label: &str,
+) -> Result<T, ScriptError> {
+    let registry_module = load_lua_module_from_source(registry_source, registry_path, policy)?;
+    let registry_json = lua_value_to_json(Value::Table(registry_module.root().clone()))?;
+    serde_json::from_value::<T>(registry_json).map_err(|err| {
+        ScriptError::Contract(format!(
+            "{}: {registry_label} registry decode failed: {err}",
+            registry_path.display()
+        ))
+    })
+}
+
+pub(crate) fn validate_schema_version(
+    registry_path: &Path,
+    schema_version: u32,
+) -> Result<(), ScriptError> {
+    if schema_version == 0 {
+        return Err(ScriptError::Contract(format!(
+            "{}: schema_version must be >= 1",
+            registry_path.display()
+        )));
+    }
+    Ok(())
+}
+
+fn validate_item_registry(
+    registry_path: &Path,
+    registry: &ItemRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "item_id",
+        registry.items.iter().map(|item| item.item_id.as_str()),
+    )?;
+    for item in &registry.items {
+        if item.display_name.trim().is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: item_id={} display_name must not be empty",
+                registry_path.display(),
+                item.item_id
+            )));
+        }
+        if item.stack_size == 0 {
+            return Err(ScriptError::Contract(format!(
+                "{}: item_id={} stack_size must be >= 1",
+                registry_path.display(),
+                item.item_id
+            )));
+        }
+        if !(item.unit_mass_kg.is_finite() && item.unit_mass_kg > 0.0) {
+            return Err(ScriptError::Contract(format!(
+                "{}: item_id={} unit_mass_kg must be > 0",
+                registry_path.display(),
+                item.item_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_facility_registry(
+    registry_path: &Path,
+    registry: &FacilityRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "facility_id",
+        registry
+            .facilities
+            .iter()
+            .map(|facility| facility.facility_id.as_str()),
+    )?;
+    for facility in &registry.facilities {
+        if facility.queue_slots == 0 {
+            return Err(ScriptError::Contract(format!(
+                "{}: facility_id={} queue_slots must be >= 1",
+                registry_path.display(),
+                facility.facility_id
+            )));
+        }
+        if !(facility.power_buffer_mw.is_finite() && facility.power_buffer_mw >= 0.0) {
+            return Err(ScriptError::Contract(format!(
+                "{}: facility_id={} power_buffer_mw must be >= 0",
+                registry_path.display(),
+                facility.facility_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_recipe_registry(
+    registry_path: &Path,
+    registry: &RecipeRegistry,
+    item_registry: &ItemRegistry,
+    facility_registry: &FacilityRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "recipe_id",
+        registry
+            .recipes
+            .iter()
+            .map(|recipe| recipe.recipe_id.as_str()),
+    )?;
+    let item_ids = item_registry
+        .items
+        .iter()
+        .map(|item| item.item_id.as_str())
+        .collect::<HashSet<_>>();
+
+    for recipe in &registry.recipes {
+        let Some(facility) = facility_registry.facility(&recipe.facility_id) else {
+            return Err(ScriptError::Contract(format!(
+                "{}: recipe_id={} references unknown facility_id={}",
+                registry_path.display(),
+                recipe.recipe_id,
+                recipe.facility_id
+            )));
+        };
+        if recipe.inputs.is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: recipe_id={} inputs must not be empty",
+                registry_path.display(),
+                recipe.recipe_id
+            )));
+        }
+        for amount in recipe.inputs.iter().chain(std::iter::once(&recipe.output)) {
+            if !item_ids.contains(amount.item_id.as_str()) {
+                return Err(ScriptError::Contract(format!(
+                    "{}: recipe_id={} references unknown item_id={}",
+                    registry_path.display(),
+                    recipe.recipe_id,
+                    amount.item_id
+                )));
+            }
+            if amount.amount == 0 {
+                return Err(ScriptError::Contract(format!(
+                    "{}: recipe_id={} item_id={} amount must be >= 1",
+                    registry_path.display(),
+                    recipe.recipe_id,
+                    amount.item_id
+                )));
+            }
+        }
+        if !(recipe.craft_time_s.is_finite() && recipe.craft_time_s > 0.0) {
+            return Err(ScriptError::Contract(format!(
+                "{}: recipe_id={} craft_time_s must be > 0",
+                registry_path.display(),
+                recipe.recipe_id
+            )));
+        }
+        if !(recipe.power_cost.is_finite() && recipe.power_cost >= 0.0) {
+            return Err(ScriptError::Contract(format!(
+                "{}: recipe_id={} power_cost must be >= 0",
+                registry_path.display(),
+                recipe.recipe_id
+            )));
+        }
+        if !facility.allowed_recipe_tags.is_empty()
+            && !recipe
+                .tags
+                .iter()
+                .any(|tag| facility.allowed_recipe_tags.contains(tag))
+        {
+            return Err(ScriptError::Contract(format!(
+                "{}: recipe_id={} has no tag allowed by facility_id={}",
+                registry_path.display(),
+                recipe.recipe_id,
+                recipe.facility_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_loot_table_registry(
+    registry_path: &Path,
+    registry: &LootTableRegistry,
+    item_registry: &ItemRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "loot_table_id",
+        registry
+            .tables
+            .iter()
+            .map(|table| table.loot_table_id.as_str()),
+    )?;
+    let mut profile_tables = HashMap::<&str, &str>::new();
+    for table in &registry.tables {
+        for profile_id in &table.destruction_profile_ids {
+            if let Some(other_table_id) =
+                profile_tables.insert(profile_id.as_str(), table.loot_table_id.as_str())
+            {
+                return Err(ScriptError::Contract(format!(
+                    "{}: destruction_profile_id={} is claimed by loot_table_id={} and loot_table_id={}",
+                    registry_path.display(),
+                    profile_id,
+                    other_table_id,
+                    table.loot_table_id
+                )));
+            }
+        }
+        if table.drops.is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: loot_table_id={} drops must not be empty",
+                registry_path.display(),
+                table.loot_table_id
+            )));
+        }
+        for drop in &table.drops {
+            if item_registry.item(&drop.item_id).is_none() {
+                return Err(ScriptError::Contract(format!(
+                    "{}: loot_table_id={} references unknown item_id={}",
+                    registry_path.display(),
+                    table.loot_table_id,
+                    drop.item_id
+                )));
+            }
+            if !(drop.chance.is_finite() && drop.chance > 0.0 && drop.chance <= 1.0) {
+                return Err(ScriptError::Contract(format!(
+                    "{}: loot_table_id={} item_id={} chance must be in (0, 1]",
+                    registry_path.display(),
+                    table.loot_table_id,
+                    drop.item_id
+                )));
+            }
+            if drop.min_quantity == 0 || drop.min_quantity > drop.max_quantity {
+                return Err(ScriptError::Contract(format!(
+                    "{}: loot_table_id={} item_id={} requires 1 <= min_quantity <= max_quantity",
+                    registry_path.display(),
+                    table.loot_table_id,
+                    drop.item_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_market_registry(
+    registry_path: &Path,
+    registry: &MarketRegistry,
+    item_registry: &ItemRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "market_id",
+        registry
+            .markets
+            .iter()
+            .map(|market| market.market_id.as_str()),
+    )?;
+    for market in &registry.markets {
+        if market.display_name.trim().is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: market_id={} display_name must not be empty",
+                registry_path.display(),
+                market.market_id
+            )));
+        }
+        if !(market.spread.is_finite() && (0.0..1.0).contains(&market.spread)) {
+            return Err(ScriptError::Contract(format!(
+                "{}: market_id={} spread must be in [0, 1)",
+                registry_path.display(),
+                market.market_id
+            )));
+        }
+        let ratios_valid = market.min_price_ratio.is_finite()
+            && market.max_price_ratio.is_finite()
+            && market.min_price_ratio > 0.0
+            && market.min_price_ratio <= 1.0
+            && market.max_price_ratio >= 1.0;
+        if !ratios_valid {
+            return Err(ScriptError::Contract(format!(
+                "{}: market_id={} requires 0 < min_price_ratio <= 1 <= max_price_ratio",
+                registry_path.display(),
+                market.market_id
+            )));
+        }
+        if !(market.price_elasticity.is_finite()
+            && market.price_elasticity >= 0.0
+            && market.price_drift_per_s.is_finite()
+            && market.price_drift_per_s >= 0.0)
+        {
+            return Err(ScriptError::Contract(format!(
+                "{}: market_id={} price_elasticity and price_drift_per_s must be >= 0",
+                registry_path.display(),
+                market.market_id
+            )));
+        }
+        validate_unique_ids(
+            registry_path,
+            "item_id",
+            market
+                .listings
+                .iter()
+                .map(|listing| listing.item_id.as_str()),
+        )?;
+        if market.listings.is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: market_id={} listings must not be empty",
+                registry_path.display(),
+                market.market_id
+            )));
+        }
+        for listing in &market.listings {
+            if item_registry.item(&listing.item_id).is_none() {
+                return Err(ScriptError::Contract(format!(
+                    "{}: market_id={} references unknown item_id={}",
+                    registry_path.display(),
+                    market.market_id,
+                    listing.item_id
+                )));
+            }
+            if listing.base_price == 0 {
+                return Err(ScriptError::Contract(format!(
+                    "{}: market_id={} item_id={} base_price must be >= 1",
+                    registry_path.display(),
+                    market.market_id,
+                    listing.item_id
+                )));
+            }
+            if !listing.buys && !listing.sells {
+                return Err(ScriptError::Contract(format!(
+                    "{}: market_id={} item_id={} must be bought or sold",
+                    registry_path.display(),
+                    market.market_id,
+                    listing.item_id
+                )));
+            }
+            if listing.initial_stock > listing.max_stock || listing.target_stock > listing.max_stock
+            {
+                return Err(ScriptError::Contract(format!(
+                    "{}: market_id={} item_id={} requires initial_stock and target_stock <= max_stock",
+                    registry_path.display(),
+                    market.market_id,
+                    listing.item_id
+                )));
+            }
+            if !(listing.restock_per_s.is_finite() && listing.restock_per_s >= 0.0) {
+                return Err(ScriptError::Contract(format!(
+                    "{}: market_id={} item_id={} restock_per_s must be >= 0",
+                    registry_path.display(),
+                    market.market_id,
+                    listing.item_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::economy_registry::{
+    decode_registry_module, read_registry_source, validate_schema_version,
+};
+use crate::{LuaSandboxPolicy, ScriptError, validate_unique_ids};
+use sidereal_game::FactionRegistry;
+use std::collections::HashSet;
+use std::path::Path;
+
+pub const FACTION_REGISTRY_SCRIPT_REL_PATH: &str = "factions/faction_registry.lua";
+
+pub fn load_faction_registry_from_root(
+    scripts_root: &Path,
+) -> Result<FactionRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let registry_source = read_registry_source(scripts_root, FACTION_REGISTRY_SCRIPT_REL_PATH)?;
+    load_faction_registry_from_source(
+        &registry_source,
+        Path::new(FACTION_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+    )
+}
+
+pub fn load_faction_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+) -> Result<FactionRegistry, ScriptError> {
+    let registry = decode_registry_module::<FactionRegistry>(
+        registry_source,
+        registry_path,
+        policy,
+        "faction",
+    )?;
+    validate_faction_registry(registry_path, &registry)?;
+    Ok(registry)
+}
+
+fn validate_faction_registry(
+    registry_path: &Path,
+    registry: &FactionRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "faction_id",
+        registry
+            .factions
+            .iter()
+            .map(|faction| faction.faction_id.as_str()),
+    )?;
+    for faction in &registry.factions {
+        if faction.display_name.trim().is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: faction_id={} display_name must not be empty",
+                registry_path.display(),
+                faction.faction_id
+            )));
+        }
+        if !faction.member_kill_reputation_delta.is_finite() {
+            return Err(ScriptError::Contract(format!(
+                "{}: faction_id={} member_kill_reputation_delta must be finite",
+                registry_path.display(),
+                faction.faction_id
+            )));
+        }
+    }
+
+    let mut seen_pairs = HashSet::<(&str, &str)>::new();
+    for relation in &registry.relations {
+        for faction_id in [&relation.faction_a, &relation.faction_b] {
+            if registry.faction(faction_id).is_none() {
+                return Err(ScriptError::Contract(format!(
+                    "{}: relation {}<->{} references unknown faction_id={}",
+                    registry_path.display(),
+                    relation.faction_a,
+                    relation.faction_b,
+                    faction_id
+                )));
+            }
+        }
+        if relation.faction_a == relation.faction_b {
+            return Err(ScriptError::Contract(format!(
+                "{}: relation {}<->{} must name two different factions",
+                registry_path.display(),
+                relation.faction_a,
+                relation.faction_b
+            )));
+        }
+        let pair = if relation.faction_a < relation.faction_b {
+            (relation.faction_a.as_str(), relation.faction_b.as_str())
+        } else {
+            (relation.faction_b.as_str(), relation.faction_a.as_str())
+        };
+        if !seen_pairs.insert(pair) {
+            return Err(ScriptError::Contract(format!(
+                "{}: relation {}<->{} is declared more than once",
+                registry_path.display(),
+                pair.0,
+                pair.1
+            )));
+        }
+    }
+
+    let rules = &registry.reputation;
+    if !(rules.min.is_finite() && rules.max.is_finite() && rules.min < rules.max) {
+        return Err(ScriptError::Contract(format!(
+            "{}: reputation min must be below max",
+            registry_path.display()
+        )));
+    }
+    if !(rules.min..=rules.max).contains(&rules.hostile_threshold) {
+        return Err(ScriptError::Contract(format!(
+            "{}: reputation hostile_threshold must lie within min..max",
+            registry_path.display()
+        )));
+    }
+    for (label, share) in [
+        ("ally_share", rules.ally_share),
+        ("enemy_share", rules.enemy_share),
+    ] {
+        if !(share.is_finite() && share >= 0.0) {
+            return Err(ScriptError::Contract(format!(
+                "{}: reputation {label} must be >= 0",
+                registry_path.display()
+            )));
+        }
+    }
+    Ok(())
+}
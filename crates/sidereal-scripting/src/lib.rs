@@ -1,4 +1,7 @@
 mod audio_registry;
+mod economy_registry;
+mod faction_registry;
+mod mission_registry;
 
 use mlua::{HookTriggers, Lua, LuaOptions, StdLib, Table, Value, VmState};
 use serde::{Deserialize, Serialize};
@@ -18,6 +21,24 @@ use tracing::{debug, error, info};
 use uuid::Uuid;
 
 pub use audio_registry::{load_audio_registry_from_root, load_audio_registry_from_source};
+pub use economy_registry::{
+    FACILITY_REGISTRY_SCRIPT_REL_PATH, ITEM_REGISTRY_SCRIPT_REL_PATH,
+    LOOT_TABLE_REGISTRY_SCRIPT_REL_PATH, MARKET_REGISTRY_SCRIPT_REL_PATH,
+    RECIPE_REGISTRY_SCRIPT_REL_PATH, load_facility_registry_from_root,
+    load_facility_registry_from_source, load_item_registry_from_root,
+    load_item_registry_from_source, load_loot_table_registry_from_root,
+    load_loot_table_registry_from_source, load_market_registry_from_root,
+    load_market_registry_from_source, load_recipe_registry_from_root,
+    load_recipe_registry_from_source,
+};
+pub use faction_registry::{
+    FACTION_REGISTRY_SCRIPT_REL_PATH, load_faction_registry_from_root,
+    load_faction_registry_from_source,
+};
+pub use mission_registry::{
+    MISSION_REGISTRY_SCRIPT_REL_PATH, load_mission_registry_from_root,
+    load_mission_registry_from_source,
+};
 
 #[derive(Debug, Error)]
 pub enum ScriptError {
@@ -36,6 +57,9 @@ pub struct LuaSandboxPolicy {
     pub memory_limit_bytes: usize,
     pub instruction_limit: u64,
     pub hook_instruction_interval: u32,
+    /// Consecutive failed runs (errors or budget violations) after which a
+    /// runtime handler is quarantined. 0 disables quarantine.
+    pub quarantine_after_failures: u32,
 }
 
 impl Default for LuaSandboxPolicy {
@@ -44,6 +68,7 @@ impl Default for LuaSandboxPolicy {
             memory_limit_bytes: 8 * 1024 * 1024,
             instruction_limit: 200_000,
             hook_instruction_interval: 1_000,
+            quarantine_after_failures: 5,
         }
     }
 }
@@ -69,6 +94,11 @@ impl LuaSandboxPolicy {
         {
             policy.hook_instruction_interval = parsed;
         }
+        if let Ok(raw) = std::env::var("SIDEREAL_SCRIPT_QUARANTINE_FAILURES")
+            && let Ok(parsed) = raw.parse::<u32>()
+        {
+            policy.quarantine_after_failures = parsed;
+        }
         policy
     }
 }
@@ -379,6 +409,16 @@ fn validate_asteroid_registry(
                 profile.resource_profile_id
             )));
         }
+        for entry in &profile.yield_table {
+            if !(entry.unit_mass_kg.is_finite() && entry.unit_mass_kg > 0.0) {
+                return Err(ScriptError::Contract(format!(
+                    "{}: resource_profile_id={} item_id={} unit_mass_kg must be > 0",
+                    registry_path.display(),
+                    profile.resource_profile_id,
+                    entry.item_id
+                )));
+            }
+        }
     }
     Ok(())
 }
@@ -761,6 +801,16 @@ fn validate_ship_definition(
         )));
     }
     validate_ship_visual_asset(registry_path, definition, asset_registry)?;
+    if let Some(profile) = &definition.root.damage_resistance_profile {
+        validate_damage_resistance_profile(
+            profile,
+            &format!(
+                "{}: ship_id={} root.damage_resistance_profile",
+                registry_path.display(),
+                definition.ship_id
+            ),
+        )?;
+    }
     validate_unique_ids(
         registry_path,
         "hardpoint_id",
@@ -930,6 +980,40 @@ fn validate_ship_module_definition(
                 component.kind
             )));
         }
+        if component.kind == "damage_resistance_profile" {
+            validate_damage_resistance_profile(
+                &component.properties,
+                &format!(
+                    "{}: module_id={} component kind=damage_resistance_profile",
+                    registry_path.display(),
+                    definition.module_id
+                ),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_damage_resistance_profile(
+    properties: &JsonValue,
+    context: &str,
+) -> Result<(), ScriptError> {
+    let profile =
+        serde_json::from_value::<sidereal_game::DamageResistanceProfile>(properties.clone())
+            .map_err(|err| ScriptError::Contract(format!("{context} is invalid: {err}")))?;
+    if !(profile.armor_rating.is_finite() && profile.armor_rating >= 0.0) {
+        return Err(ScriptError::Contract(format!(
+            "{context} armor_rating must be finite and non-negative"
+        )));
+    }
+    if profile
+        .resistances
+        .iter()
+        .any(|entry| !(0.0..=1.0).contains(&entry.fraction))
+    {
+        return Err(ScriptError::Contract(format!(
+            "{context} resistance fractions must be within 0..=1"
+        )));
     }
     Ok(())
 }
@@ -2130,6 +2214,8 @@ pub fn validate_component_kinds(
     Ok(())
 }
 
+const INSTRUCTION_BUDGET_EXCEEDED_MESSAGE: &str = "lua instruction budget exceeded; script aborted";
+
 fn create_sandboxed_lua(policy: &LuaSandboxPolicy) -> Result<Lua, ScriptError> {
     let libs = StdLib::ALL_SAFE ^ StdLib::IO ^ StdLib::OS ^ StdLib::PACKAGE;
     let lua = Lua::new_with(libs, LuaOptions::default())
@@ -2161,9 +2247,7 @@ fn create_sandboxed_lua(policy: &LuaSandboxPolicy) -> Result<Lua, ScriptError> {
         move |_lua, _debug| {
             let executed = counter.fetch_add(hook_interval, Ordering::Relaxed) + hook_interval;
             if executed > instruction_limit {
-                return Err(mlua::Error::runtime(
-                    "lua instruction budget exceeded; script aborted",
-                ));
+                return Err(mlua::Error::runtime(INSTRUCTION_BUDGET_EXCEEDED_MESSAGE));
             }
             Ok(VmState::Continue)
         },
@@ -2178,6 +2262,45 @@ pub fn reset_lua_instruction_budget(lua: &Lua) {
     }
 }
 
+/// Instructions executed since the last `reset_lua_instruction_budget`,
+/// counted in steps of the policy's hook interval.
+pub fn lua_instructions_used(lua: &Lua) -> u64 {
+    lua.app_data_ref::<Arc<AtomicU64>>()
+        .map_or(0, |counter| counter.load(Ordering::Relaxed))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaBudgetViolation {
+    Instructions,
+    Memory,
+}
+
+impl LuaBudgetViolation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Instructions => "instruction_limit",
+            Self::Memory => "memory_limit",
+        }
+    }
+}
+
+/// Which `LuaSandboxPolicy` limit aborted a call, if any. Errors raised
+/// inside Rust callbacks are unwrapped to their cause.
+pub fn lua_budget_violation(err: &mlua::Error) -> Option<LuaBudgetViolation> {
+    match err {
+        mlua::Error::MemoryError(_) => Some(LuaBudgetViolation::Memory),
+        mlua::Error::CallbackError { cause, .. } | mlua::Error::WithContext { cause, .. } => {
+            lua_budget_violation(cause)
+        }
+        mlua::Error::RuntimeError(message)
+            if message.contains(INSTRUCTION_BUDGET_EXCEEDED_MESSAGE) =>
+        {
+            Some(LuaBudgetViolation::Instructions)
+        }
+        _ => None,
+    }
+}
+
 pub fn create_sandboxed_lua_vm(policy: &LuaSandboxPolicy) -> Result<Lua, ScriptError> {
     create_sandboxed_lua(policy)
 }
@@ -0,0 +1,164 @@
+use crate::economy_registry::{
+    decode_registry_module, read_registry_source, validate_schema_version,
+};
+use crate::{
+    LuaSandboxPolicy, ScriptError, load_faction_registry_from_root, load_item_registry_from_root,
+    validate_unique_ids,
+};
+use sidereal_game::{FactionRegistry, ItemRegistry, MissionObjectiveKind, MissionRegistry};
+use std::path::Path;
+
+pub const MISSION_REGISTRY_SCRIPT_REL_PATH: &str = "missions/mission_registry.lua";
+
+/// Loads the mission catalog and validates it against the item and faction
+/// catalogs from the same scripts root.
+pub fn load_mission_registry_from_root(
+    scripts_root: &Path,
+) -> Result<MissionRegistry, ScriptError> {
+    let policy = LuaSandboxPolicy::from_env();
+    let item_registry = load_item_registry_from_root(scripts_root)?;
+    let faction_registry = load_faction_registry_from_root(scripts_root)?;
+    let registry_source = read_registry_source(scripts_root, MISSION_REGISTRY_SCRIPT_REL_PATH)?;
+    load_mission_registry_from_source(
+        &registry_source,
+        Path::new(MISSION_REGISTRY_SCRIPT_REL_PATH),
+        &policy,
+        &item_registry,
+        &faction_registry,
+    )
+}
+
+pub fn load_mission_registry_from_source(
+    registry_source: &str,
+    registry_path: &Path,
+    policy: &LuaSandboxPolicy,
+    item_registry: &ItemRegistry,
+    faction_registry: &FactionRegistry,
+) -> Result<MissionRegistry, ScriptError> {
+    let registry = decode_registry_module::<MissionRegistry>(
+        registry_source,
+        registry_path,
+        policy,
+        "mission",
+    )?;
+    validate_mission_registry(registry_path, &registry, item_registry, faction_registry)?;
+    Ok(registry)
+}
+
+fn validate_mission_registry(
+    registry_path: &Path,
+    registry: &MissionRegistry,
+    item_registry: &ItemRegistry,
+    faction_registry: &FactionRegistry,
+) -> Result<(), ScriptError> {
+    validate_schema_version(registry_path, registry.schema_version)?;
+    validate_unique_ids(
+        registry_path,
+        "mission_id",
+        registry
+            .missions
+            .iter()
+            .map(|mission| mission.mission_id.as_str()),
+    )?;
+    let unknown_faction = |mission_id: &str, faction_id: &str| {
+        ScriptError::Contract(format!(
+            "{}: mission_id={mission_id} references unknown faction_id={faction_id}",
+            registry_path.display()
+        ))
+    };
+    for mission in &registry.missions {
+        let mission_id = mission.mission_id.as_str();
+        if mission.display_name.trim().is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: mission_id={mission_id} display_name must not be empty",
+                registry_path.display()
+            )));
+        }
+        if mission.objectives.is_empty() {
+            return Err(ScriptError::Contract(format!(
+                "{}: mission_id={mission_id} requires at least one objective",
+                registry_path.display()
+            )));
+        }
+        validate_unique_ids(
+            registry_path,
+            "objective_id",
+            mission
+                .objectives
+                .iter()
+                .map(|objective| objective.objective_id.as_str()),
+        )?;
+        for objective in &mission.objectives {
+            let invalid = |message: &str| {
+                ScriptError::Contract(format!(
+                    "{}: mission_id={mission_id} objective_id={} {message}",
+                    registry_path.display(),
+                    objective.objective_id
+                ))
+            };
+            match &objective.kind {
+                MissionObjectiveKind::ReachLocation { x, y, radius_m } => {
+                    if !(x.is_finite() && y.is_finite()) {
+                        return Err(invalid("position must be finite"));
+                    }
+                    if !(radius_m.is_finite() && *radius_m > 0.0) {
+                        return Err(invalid("radius_m must be > 0"));
+                    }
+                }
+                MissionObjectiveKind::DestroyEntity {
+                    target_entity_id,
+                    faction_id,
+                    count,
+                } => match (target_entity_id, faction_id) {
+                    (Some(_), None) if *count != 1 => {
+                        return Err(invalid("count must be 1 for a target_entity_id"));
+                    }
+                    (Some(_), None) => {}
+                    (None, Some(faction_id)) => {
+                        if faction_registry.faction(faction_id).is_none() {
+                            return Err(unknown_faction(mission_id, faction_id));
+                        }
+                        if *count == 0 {
+                            return Err(invalid("count must be >= 1"));
+                        }
+                    }
+                    _ => {
+                        return Err(invalid(
+                            "requires exactly one of target_entity_id or faction_id",
+                        ));
+                    }
+                },
+                MissionObjectiveKind::DeliverItem {
+                    item_id,
+                    quantity,
+                    range_m,
+                    ..
+                } => {
+                    if item_registry.item(item_id).is_none() {
+                        return Err(invalid(&format!("references unknown item_id={item_id}")));
+                    }
+                    if *quantity == 0 {
+                        return Err(invalid("quantity must be >= 1"));
+                    }
+                    if !(range_m.is_finite() && *range_m > 0.0) {
+                        return Err(invalid("range_m must be > 0"));
+                    }
+                }
+                MissionObjectiveKind::ScanLandmark { .. } => {}
+            }
+        }
+        for change in &mission.reward.reputation {
+            if faction_registry.faction(&change.faction_id).is_none() {
+                return Err(unknown_faction(mission_id, &change.faction_id));
+            }
+            if !change.delta.is_finite() {
+                return Err(ScriptError::Contract(format!(
+                    "{}: mission_id={mission_id} reputation delta for faction_id={} must be finite",
+                    registry_path.display(),
+                    change.faction_id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
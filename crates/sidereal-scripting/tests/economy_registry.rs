@@ -0,0 +1,210 @@
+use sidereal_game::{InventoryEntry, ItemCategory, stackable_item_uuid};
+use sidereal_scripting::{
+    LuaSandboxPolicy, load_facility_registry_from_root, load_item_registry_from_root,
+    load_item_registry_from_source, load_loot_table_registry_from_root,
+    load_loot_table_registry_from_source, load_market_registry_from_root,
+    load_market_registry_from_source, load_recipe_registry_from_root,
+    load_recipe_registry_from_source, resolve_scripts_root,
+};
+use std::path::{Path, PathBuf};
+
+fn shared_scripts_root() -> PathBuf {
+    resolve_scripts_root(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn loads_shared_economy_registries_from_workspace_scripts() {
+    let root = shared_scripts_root();
+    let items = load_item_registry_from_root(&root).expect("item registry");
+    let facilities = load_facility_registry_from_root(&root).expect("facility registry");
+    let recipes = load_recipe_registry_from_root(&root).expect("recipe registry");
+
+    let iron_ore = items.item("resource.iron_ore").expect("iron ore item");
+    assert_eq!(iron_ore.category, ItemCategory::RawMineral);
+    assert_eq!(iron_ore.unit_mass_kg, 2.5);
+    assert!(facilities.facility("facility.refinery_basic").is_some());
+    let recipe = recipes
+        .recipe("recipe.refine_iron_ingot")
+        .expect("iron ingot recipe");
+    assert_eq!(recipe.output.item_id, "material.iron_ingot");
+}
+
+#[test]
+fn resolves_inventory_entries_to_item_definitions() {
+    let items = load_item_registry_from_root(&shared_scripts_root()).expect("item registry");
+    let legacy_entry = InventoryEntry {
+        item_entity_id: stackable_item_uuid("resource.nickel_ore"),
+        item_id: String::new(),
+        quantity: 3,
+        unit_mass_kg: 2.8,
+    };
+    let resolved = items
+        .resolve_inventory_entry(&legacy_entry)
+        .expect("entry resolves by uuid");
+    assert_eq!(resolved.display_name, "Nickel Ore");
+    assert_eq!(resolved.stack_size, 500);
+}
+
+#[test]
+fn rejects_zero_item_stack_size() {
+    let source = r#"
+return {
+  schema_version = 1,
+  items = {
+    {
+      item_id = "resource.bad",
+      display_name = "Bad",
+      category = "raw_mineral",
+      stack_size = 0,
+      unit_mass_kg = 1.0,
+      tags = { "ore" },
+    },
+  },
+}
+"#;
+    let err = load_item_registry_from_source(
+        source,
+        Path::new("economy/item_registry.lua"),
+        &LuaSandboxPolicy::default(),
+    )
+    .expect_err("zero stack size should fail");
+    assert!(
+        err.to_string()
+            .contains("item_id=resource.bad stack_size must be >= 1"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn rejects_recipe_with_unknown_item_reference() {
+    let root = shared_scripts_root();
+    let items = load_item_registry_from_root(&root).expect("item registry");
+    let facilities = load_facility_registry_from_root(&root).expect("facility registry");
+    let source = r#"
+return {
+  schema_version = 1,
+  recipes = {
+    {
+      recipe_id = "recipe.bad",
+      facility_id = "facility.refinery_basic",
+      inputs = {
+        { item_id = "resource.unobtainium", amount = 1 },
+      },
+      output = { item_id = "material.iron_ingot", amount = 1 },
+      craft_time_s = 1.0,
+      tags = { "refining" },
+    },
+  },
+}
+"#;
+    let err = load_recipe_registry_from_source(
+        source,
+        Path::new("economy/recipe_registry.lua"),
+        &LuaSandboxPolicy::default(),
+        &items,
+        &facilities,
+    )
+    .expect_err("unknown item should fail");
+    assert!(
+        err.to_string()
+            .contains("recipe_id=recipe.bad references unknown item_id=resource.unobtainium"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn resolves_loot_tables_by_ship_override_and_destruction_profile() {
+    let loot_tables =
+        load_loot_table_registry_from_root(&shared_scripts_root()).expect("loot table registry");
+
+    let by_profile = loot_tables
+        .resolve(None, "explosion_burst")
+        .expect("default wreck table");
+    assert_eq!(by_profile.loot_table_id, "loot.ship_wreck_default");
+    let by_ship = loot_tables
+        .resolve(Some("loot.ship.rocinante"), "explosion_burst")
+        .expect("rocinante table");
+    assert_eq!(by_ship.loot_table_id, "loot.ship.rocinante");
+}
+
+#[test]
+fn rejects_loot_drop_with_out_of_range_chance() {
+    let items = load_item_registry_from_root(&shared_scripts_root()).expect("item registry");
+    let source = r#"
+return {
+  schema_version = 1,
+  tables = {
+    {
+      loot_table_id = "loot.bad",
+      drops = {
+        { item_id = "alloy.steel_plate", chance = 1.5 },
+      },
+    },
+  },
+}
+"#;
+    let err = load_loot_table_registry_from_source(
+        source,
+        Path::new("economy/loot_table_registry.lua"),
+        &LuaSandboxPolicy::default(),
+        &items,
+    )
+    .expect_err("chance above one should fail");
+    assert!(
+        err.to_string()
+            .contains("loot_table_id=loot.bad item_id=alloy.steel_plate chance must be in (0, 1]"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn loads_market_listings_with_defaulted_pricing_fields() {
+    let markets = load_market_registry_from_root(&shared_scripts_root()).expect("market registry");
+
+    let exchange = markets
+        .market("market.frontier_exchange")
+        .expect("frontier exchange");
+    let ore = exchange
+        .listing("resource.iron_ore")
+        .expect("iron ore listing");
+    assert!(ore.buys);
+    assert!(!ore.sells);
+    let kits = exchange.listing("repair.module_kit").expect("kit listing");
+    assert!(kits.buys && kits.sells);
+    assert_eq!(
+        exchange.equilibrium_price(kits, kits.target_stock),
+        f64::from(kits.base_price)
+    );
+}
+
+#[test]
+fn rejects_market_listing_with_stock_above_ceiling() {
+    let items = load_item_registry_from_root(&shared_scripts_root()).expect("item registry");
+    let source = r#"
+return {
+  schema_version = 1,
+  markets = {
+    {
+      market_id = "market.bad",
+      display_name = "Bad Market",
+      listings = {
+        { item_id = "alloy.steel_plate", base_price = 50, initial_stock = 20, target_stock = 10, max_stock = 10 },
+      },
+    },
+  },
+}
+"#;
+    let err = load_market_registry_from_source(
+        source,
+        Path::new("economy/market_registry.lua"),
+        &LuaSandboxPolicy::default(),
+        &items,
+    )
+    .expect_err("initial stock above ceiling should fail");
+    assert!(
+        err.to_string().contains(
+            "market_id=market.bad item_id=alloy.steel_plate requires initial_stock and target_stock <= max_stock"
+        ),
+        "unexpected error: {err}"
+    );
+}
@@ -0,0 +1,72 @@
+use sidereal_game::FactionStanding;
+use sidereal_scripting::{
+    FACTION_REGISTRY_SCRIPT_REL_PATH, LuaSandboxPolicy, load_faction_registry_from_root,
+    load_faction_registry_from_source, resolve_scripts_root,
+};
+use std::path::Path;
+
+#[test]
+fn loads_shared_faction_registry_from_workspace_scripts() {
+    let root = resolve_scripts_root(env!("CARGO_MANIFEST_DIR"));
+    let registry = load_faction_registry_from_root(&root).expect("faction registry");
+
+    assert!(registry.faction("faction.frontier_union").is_some());
+    assert_eq!(
+        registry.standing("faction.void_raiders", "faction.frontier_union"),
+        FactionStanding::Hostile
+    );
+    assert!(!registry.allows_damage(Some("faction.frontier_union"), Some("faction.free_traders")));
+}
+
+#[test]
+fn rejects_relation_with_unknown_faction() {
+    let source = r#"
+return {
+  schema_version = 1,
+  factions = {
+    { faction_id = "faction.alpha", display_name = "Alpha" },
+  },
+  relations = {
+    { faction_a = "faction.alpha", faction_b = "faction.missing", standing = "hostile" },
+  },
+}
+"#;
+    let err = load_faction_registry_from_source(
+        source,
+        Path::new(FACTION_REGISTRY_SCRIPT_REL_PATH),
+        &LuaSandboxPolicy::default(),
+    )
+    .expect_err("unknown faction should fail");
+    assert!(
+        err.to_string()
+            .contains("references unknown faction_id=faction.missing"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn rejects_duplicate_relation_pairs_in_either_order() {
+    let source = r#"
+return {
+  schema_version = 1,
+  factions = {
+    { faction_id = "faction.alpha", display_name = "Alpha" },
+    { faction_id = "faction.beta", display_name = "Beta" },
+  },
+  relations = {
+    { faction_a = "faction.alpha", faction_b = "faction.beta", standing = "allied" },
+    { faction_a = "faction.beta", faction_b = "faction.alpha", standing = "hostile" },
+  },
+}
+"#;
+    let err = load_faction_registry_from_source(
+        source,
+        Path::new(FACTION_REGISTRY_SCRIPT_REL_PATH),
+        &LuaSandboxPolicy::default(),
+    )
+    .expect_err("duplicate pair should fail");
+    assert!(
+        err.to_string().contains("is declared more than once"),
+        "unexpected error: {err}"
+    );
+}
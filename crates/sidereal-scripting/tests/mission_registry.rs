@@ -0,0 +1,177 @@
+use sidereal_game::{
+    FactionDefinition, FactionRegistry, ItemDefinition, ItemRegistry, MissionObjectiveKind,
+};
+use sidereal_scripting::{
+    LuaSandboxPolicy, MISSION_REGISTRY_SCRIPT_REL_PATH, load_mission_registry_from_root,
+    load_mission_registry_from_source, resolve_scripts_root,
+};
+use std::path::Path;
+
+fn test_item_registry() -> ItemRegistry {
+    ItemRegistry {
+        schema_version: 1,
+        items: vec![ItemDefinition {
+            item_id: "resource.iron_ore".to_string(),
+            display_name: "Iron Ore".to_string(),
+            stack_size: 500,
+            unit_mass_kg: 2.5,
+            ..Default::default()
+        }],
+    }
+}
+
+fn test_faction_registry() -> FactionRegistry {
+    FactionRegistry {
+        schema_version: 1,
+        factions: vec![FactionDefinition {
+            faction_id: "faction.alpha".to_string(),
+            display_name: "Alpha".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn load(source: &str) -> Result<sidereal_game::MissionRegistry, String> {
+    load_mission_registry_from_source(
+        source,
+        Path::new(MISSION_REGISTRY_SCRIPT_REL_PATH),
+        &LuaSandboxPolicy::default(),
+        &test_item_registry(),
+        &test_faction_registry(),
+    )
+    .map_err(|err| err.to_string())
+}
+
+#[test]
+fn loads_shared_mission_registry_from_workspace_scripts() {
+    let root = resolve_scripts_root(env!("CARGO_MANIFEST_DIR"));
+    let registry = load_mission_registry_from_root(&root).expect("mission registry");
+
+    let survey = registry
+        .mission("mission.survey_aurelia")
+        .expect("survey mission");
+    assert!(matches!(
+        survey
+            .objective("scan_aurelia")
+            .map(|objective| &objective.kind),
+        Some(MissionObjectiveKind::ScanLandmark { .. })
+    ));
+    let bounty = registry
+        .mission("mission.raider_bounty")
+        .expect("bounty mission");
+    assert_eq!(bounty.objectives[0].kind.required(), 3);
+    assert!(bounty.repeatable);
+}
+
+#[test]
+fn decodes_each_objective_type() {
+    let registry = load(
+        r#"
+return {
+  schema_version = 1,
+  missions = {
+    {
+      mission_id = "mission.test",
+      display_name = "Test",
+      objectives = {
+        { objective_id = "reach", description = "Reach", type = "reach_location", x = 10, y = -5.5, radius_m = 100 },
+        { objective_id = "kill", description = "Kill", type = "destroy_entity", faction_id = "faction.alpha", count = 2 },
+        {
+          objective_id = "deliver",
+          description = "Deliver",
+          type = "deliver_item",
+          item_id = "resource.iron_ore",
+          quantity = 5,
+          destination_entity_id = "0012ebad-0000-0000-0000-000000000010",
+        },
+        {
+          objective_id = "scan",
+          description = "Scan",
+          type = "scan_landmark",
+          landmark_entity_id = "0012ebad-0000-0000-0000-000000000010",
+        },
+      },
+      reward = { credits = 100 },
+    },
+  },
+}
+"#,
+    )
+    .expect("mission registry should load");
+
+    let mission = registry.mission("mission.test").expect("mission");
+    let kinds = mission
+        .objectives
+        .iter()
+        .map(|objective| objective.kind.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            "reach_location",
+            "destroy_entity",
+            "deliver_item",
+            "scan_landmark"
+        ]
+    );
+    assert_eq!(
+        mission.objectives[2].kind,
+        MissionObjectiveKind::DeliverItem {
+            item_id: "resource.iron_ore".to_string(),
+            quantity: 5,
+            destination_entity_id: "0012ebad-0000-0000-0000-000000000010".parse().unwrap(),
+            range_m: 500.0,
+        }
+    );
+    assert_eq!(mission.reward.credits, 100);
+}
+
+#[test]
+fn rejects_unknown_references_and_ambiguous_destroy_targets() {
+    let mission_with = |objective: &str| {
+        format!(
+            r#"
+return {{
+  schema_version = 1,
+  missions = {{
+    {{ mission_id = "mission.test", display_name = "Test", objectives = {{ {objective} }} }},
+  }},
+}}
+"#
+        )
+    };
+
+    let err = load(&mission_with(
+        r#"{ objective_id = "o", description = "d", type = "deliver_item", item_id = "resource.missing", quantity = 1, destination_entity_id = "0012ebad-0000-0000-0000-000000000010" }"#,
+    ))
+    .expect_err("unknown item should fail");
+    assert!(
+        err.contains("unknown item_id=resource.missing"),
+        "unexpected error: {err}"
+    );
+
+    let err = load(&mission_with(
+        r#"{ objective_id = "o", description = "d", type = "destroy_entity", faction_id = "faction.missing" }"#,
+    ))
+    .expect_err("unknown faction should fail");
+    assert!(
+        err.contains("unknown faction_id=faction.missing"),
+        "unexpected error: {err}"
+    );
+
+    let err = load(&mission_with(
+        r#"{ objective_id = "o", description = "d", type = "destroy_entity", faction_id = "faction.alpha", target_entity_id = "0012ebad-0000-0000-0000-000000000010" }"#,
+    ))
+    .expect_err("two destroy targets should fail");
+    assert!(
+        err.contains("exactly one of target_entity_id or faction_id"),
+        "unexpected error: {err}"
+    );
+
+    let err = load(&mission_with(
+        r#"{ objective_id = "o", description = "d", type = "board_ship" }"#,
+    ))
+    .expect_err("unknown objective type should fail");
+    assert!(err.contains("decode failed"), "unexpected error: {err}");
+}
@@ -0,0 +1,52 @@
+use sidereal_scripting::{
+    LuaBudgetViolation, LuaSandboxPolicy, create_sandboxed_lua_vm, lua_budget_violation,
+    lua_instructions_used, reset_lua_instruction_budget,
+};
+
+fn policy() -> LuaSandboxPolicy {
+    LuaSandboxPolicy {
+        memory_limit_bytes: 512 * 1024,
+        instruction_limit: 10_000,
+        hook_instruction_interval: 100,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn instruction_usage_is_counted_per_call_and_runaway_loops_are_classified() {
+    let lua = create_sandboxed_lua_vm(&policy()).expect("sandboxed vm");
+
+    lua.load("local n = 0 for i = 1, 500 do n = n + i end")
+        .exec()
+        .expect("short loop fits the budget");
+    let used = lua_instructions_used(&lua);
+    assert!(used > 0 && used <= 10_000, "unexpected usage {used}");
+
+    reset_lua_instruction_budget(&lua);
+    assert_eq!(lua_instructions_used(&lua), 0);
+    let err = lua
+        .load("while true do end")
+        .exec()
+        .expect_err("runaway loop should abort");
+    assert_eq!(
+        lua_budget_violation(&err),
+        Some(LuaBudgetViolation::Instructions)
+    );
+    assert!(lua_instructions_used(&lua) > 10_000);
+
+    let err = lua
+        .load("error('plain failure')")
+        .exec()
+        .expect_err("script error");
+    assert_eq!(lua_budget_violation(&err), None);
+}
+
+#[test]
+fn allocations_past_the_memory_limit_are_classified() {
+    let lua = create_sandboxed_lua_vm(&policy()).expect("sandboxed vm");
+    let err = lua
+        .load("local parts = {} for i = 1, 100000 do parts[i] = string.rep('x', 64) .. i end")
+        .exec()
+        .expect_err("allocation should exceed the memory limit");
+    assert_eq!(lua_budget_violation(&err), Some(LuaBudgetViolation::Memory));
+}
@@ -325,3 +325,28 @@ fn rejects_missing_or_non_image_visual_assets() {
         "unexpected error: {err}"
     );
 }
+
+#[test]
+fn rejects_out_of_range_damage_resistance_fractions() {
+    let source = valid_ship_source().replace(
+        "visibility_range_buff_m = { additive_m = 10.0, multiplier = 1.0 },",
+        r#"visibility_range_buff_m = { additive_m = 10.0, multiplier = 1.0 },
+    damage_resistance_profile = {
+      armor_rating = 1.0,
+      resistances = { { damage_type = "Energy", fraction = 1.5 } },
+    },"#,
+    );
+    let err = load_ship_registry_from_sources(
+        valid_ship_registry_source(),
+        Path::new("ships/registry.lua"),
+        &ship_sources(&source),
+        &module_registry(),
+        &asset_registry("image/png"),
+    )
+    .expect_err("expected out-of-range resistance fraction to fail");
+    assert!(
+        err.to_string()
+            .contains("root.damage_resistance_profile resistance fractions must be within 0..=1"),
+        "unexpected error: {err}"
+    );
+}
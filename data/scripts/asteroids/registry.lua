@@ -46,10 +46,10 @@ AsteroidRegistry.resource_profiles = {
     extraction_profile_id = "extraction.mining_laser.basic",
     depletion_pool_units = 100.0,
     yield_table = {
-      { item_id = "resource.iron_ore", weight = 1.0, min_units = 4.0, max_units = 18.0 },
-      { item_id = "resource.nickel_ore", weight = 0.55, min_units = 2.0, max_units = 10.0 },
-      { item_id = "resource.silicate_rock", weight = 0.85, min_units = 3.0, max_units = 16.0 },
-      { item_id = "resource.rare_earth_oxides", weight = 0.12, min_units = 1.0, max_units = 4.0 },
+      { item_id = "resource.iron_ore", weight = 1.0, min_units = 4.0, max_units = 18.0, unit_mass_kg = 2.5 },
+      { item_id = "resource.nickel_ore", weight = 0.55, min_units = 2.0, max_units = 10.0, unit_mass_kg = 2.8 },
+      { item_id = "resource.silicate_rock", weight = 0.85, min_units = 3.0, max_units = 16.0, unit_mass_kg = 1.6 },
+      { item_id = "resource.rare_earth_oxides", weight = 0.12, min_units = 1.0, max_units = 4.0, unit_mass_kg = 0.8 },
     },
   },
 }
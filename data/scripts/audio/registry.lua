@@ -143,6 +143,43 @@ AudioRegistry.profiles = {
       },
     },
   },
+  {
+    profile_id = "weapon.beam_laser",
+    kind = "weapon",
+    cues = {
+      fire = {
+        playback = {
+          kind = "segmented_loop",
+          clip_asset_id = "audio.sfx.weapon.ballistic_fire",
+        },
+        route = {
+          bus = "sfx",
+          sends = {
+            {
+              send_id = "world_reverb",
+              level_db = -12.0,
+            },
+          },
+        },
+        spatial = {
+          mode = "world_2d",
+          min_distance_m = 5.0,
+          max_distance_m = 220.0,
+          rolloff = "logarithmic",
+          pan_strength = 1.0,
+          distance_lowpass = {
+            enabled = true,
+            near_hz = 14000.0,
+            far_hz = 4000.0,
+          },
+        },
+        concurrency = {
+          group_id = "weapon_loop_per_emitter",
+          steal = "restart",
+        },
+      },
+    },
+  },
   {
     profile_id = "destruction.asteroid.default",
     kind = "destruction",
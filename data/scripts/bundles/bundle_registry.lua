@@ -63,7 +63,30 @@ local C = {
   ThrusterPlumeShaderSettings = "thruster_plume_shader_settings",
   WeaponTag = "weapon_tag",
   BallisticWeapon = "ballistic_weapon",
+  MissileLauncher = "missile_launcher",
+  BeamWeapon = "beam_weapon",
+  WeaponGroup = "weapon_group",
   AmmoCount = "ammo_count",
+  AmmoFeed = "ammo_feed",
+  ShieldGenerator = "shield_generator",
+  Reactor = "reactor",
+  Capacitor = "capacitor",
+  Heatsink = "heatsink",
+  PowerConsumer = "power_consumer",
+  ModuleHealth = "module_health",
+  DamageResistanceProfile = "damage_resistance_profile",
+  TractorBeam = "tractor_beam",
+  MiningLaser = "mining_laser",
+  AutopilotState = "autopilot_state",
+  DockingState = "docking_state",
+  DockingPort = "docking_port",
+  StationService = "station_service",
+  CraftingFacility = "crafting_facility",
+  Market = "market",
+  Cost = "cost",
+  CreditWallet = "credit_wallet",
+  CreditLedger = "credit_ledger",
+  MissionLog = "mission_log",
   ScriptState = "script_state",
   AsteroidField = "asteroid_field",
   AsteroidFieldLayout = "asteroid_field_layout",
@@ -96,6 +119,7 @@ local SHIP_REQUIRED_COMPONENT_KINDS = {
   C.CollisionAabbM,
   C.ScannerComponent,
   C.VisibilityRangeBuffM,
+  C.SignalSignature,
   C.VisualAssetId,
   C.MapIcon,
   C.BaseMassKg,
@@ -122,7 +146,24 @@ local SHIP_REQUIRED_COMPONENT_KINDS = {
   C.ThrusterPlumeShaderSettings,
   C.WeaponTag,
   C.BallisticWeapon,
+  C.MissileLauncher,
+  C.BeamWeapon,
+  C.WeaponGroup,
   C.AmmoCount,
+  C.AmmoFeed,
+  C.ShieldGenerator,
+  C.Reactor,
+  C.Capacitor,
+  C.Heatsink,
+  C.PowerConsumer,
+  C.ModuleHealth,
+  C.DamageResistanceProfile,
+  C.TractorBeam,
+  C.MiningLaser,
+  C.AutopilotState,
+  C.DockingState,
+  C.DockingPort,
+  C.CraftingFacility,
 }
 
 local ASTEROID_REQUIRED_COMPONENT_KINDS = {
@@ -209,6 +250,31 @@ local ENVIRONMENT_LIGHTING_REQUIRED_COMPONENT_KINDS = {
   C.EnvironmentLightingState,
 }
 
+local STATION_REQUIRED_COMPONENT_KINDS = {
+  C.DisplayName,
+  C.EntityLabels,
+  C.OwnerId,
+  C.PublicVisibility,
+  C.MassKg,
+  C.SizeM,
+  C.CollisionProfile,
+  C.CollisionAabbM,
+  C.VisualAssetId,
+  C.MapIcon,
+  C.AvianPosition,
+  C.AvianRotation,
+  C.AvianLinearVelocity,
+  C.AvianAngularVelocity,
+  C.AvianRigidBody,
+  C.Hardpoint,
+  C.ParentGuid,
+  C.MountedOn,
+  C.DockingPort,
+  C.StationService,
+  C.Market,
+  C.Cost,
+}
+
 local PLAYER_REQUIRED_COMPONENT_KINDS = {
   C.DisplayName,
   C.PlayerTag,
@@ -219,6 +285,9 @@ local PLAYER_REQUIRED_COMPONENT_KINDS = {
   C.CharacterMovementController,
   C.ActionQueue,
   C.ContactResolutionM,
+  C.CreditWallet,
+  C.CreditLedger,
+  C.MissionLog,
   C.OwnerId,
   C.AvianPosition,
   C.AvianRotation,
@@ -234,7 +303,13 @@ local PLAYER_REQUIRED_COMPONENT_KINDS = {
 BundleRegistry.bundle_classes = {
   player = { "player.default" },
   controllable = { "ship.corvette", "ship.rocinante" },
-  world = { "asteroid.field", "asteroid.field_member", "planet.body", "environment.lighting" },
+  world = {
+    "asteroid.field",
+    "asteroid.field_member",
+    "planet.body",
+    "environment.lighting",
+    "station.outpost",
+  },
 }
 
 BundleRegistry.bundles = {
@@ -273,6 +348,11 @@ BundleRegistry.bundles = {
     graph_records_script = "bundles/starter/environment_lighting.lua",
     required_component_kinds = ENVIRONMENT_LIGHTING_REQUIRED_COMPONENT_KINDS,
   },
+  ["station.outpost"] = {
+    bundle_class = "world",
+    graph_records_script = "bundles/station/outpost.lua",
+    required_component_kinds = STATION_REQUIRED_COMPONENT_KINDS,
+  },
 }
 
 return BundleRegistry
@@ -36,6 +36,22 @@ local function flight_actions_supported()
     "AfterburnerOff",
     "FirePrimary",
     "FireSecondary",
+    "FireWeaponGroup3",
+    "FireWeaponGroup4",
+    "ReloadWeapons",
+    "ActivateShield",
+    "DeactivateShield",
+    "ActivateTractor",
+    "DeactivateTractor",
+    "ActivateMiningLaser",
+    "DeactivateMiningLaser",
+    "DeployCargo",
+    "RepairModules",
+    "EngageAutopilot",
+    "DisengageAutopilot",
+    "InitiateDocking",
+    "PurchaseHullRepair",
+    "PurchaseRefuel",
   }
 end
 
@@ -159,6 +175,8 @@ function ShipBodyBundle.build_graph_records(ctx)
       turn_rate_deg_s = 90.0,
     })),
     component(ship_id, "afterburner_state", { active = false }),
+    component(ship_id, "autopilot_state", { engaged = false, arrival_distance_m = 150.0 }),
+    component(ship_id, "docking_state", { phase = "Undocked" }),
     component(ship_id, "flight_tuning", root_or_default(root, "flight_tuning", {
       max_linear_accel_mps2 = 120.0,
       passive_brake_accel_mps2 = 16.611296,
@@ -190,6 +208,11 @@ function ShipBodyBundle.build_graph_records(ctx)
       additive_m = 300.0,
       multiplier = 1.0,
     })),
+    component(ship_id, "signal_signature", root_or_default(root, "signal_signature", {
+      strength = 1.0,
+      detection_radius_m = 0.0,
+      use_extent_for_detection = false,
+    })),
     component(ship_id, "avian_position", { spawn_position[1], spawn_position[2] }),
     component(ship_id, "avian_rotation", { cos = 1.0, sin = 0.0 }),
     component(ship_id, "avian_linear_velocity", { 0.0, 0.0 }),
@@ -201,6 +224,8 @@ function ShipBodyBundle.build_graph_records(ctx)
     component(ship_id, "avian_angular_damping", root_or_default(root, "avian_angular_damping", 0.0)),
   }
   add_if_present(ship_components, ship_id, "scanner_component", root.scanner_component)
+  add_if_present(ship_components, ship_id, "damage_resistance_profile", root.damage_resistance_profile)
+  add_if_present(ship_components, ship_id, "crafting_facility", root.crafting_facility)
 
   if ctx.script_state_data ~= nil then
     ship_components[#ship_components + 1] = component(ship_id, "script_state", {
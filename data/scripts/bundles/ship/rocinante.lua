@@ -67,6 +67,10 @@ function RocinanteBundle.build_graph_records(ctx)
   local module_fuel_tank_left_id = ctx.new_uuid()
   local module_fuel_tank_right_id = ctx.new_uuid()
   local module_weapon_gatling_fore_id = ctx.new_uuid()
+  local hardpoint_power_core_id = ctx.new_uuid()
+  local hardpoint_thermal_core_id = ctx.new_uuid()
+  local module_reactor_id = ctx.new_uuid()
+  local module_heatsink_id = ctx.new_uuid()
 
   local ship_components = {
     component(ship_id, "display_name", display_name),
@@ -271,6 +275,11 @@ function RocinanteBundle.build_graph_records(ctx)
           hot_color_rgb = { 1.0, 0.82, 0.38 },
           afterburner_color_rgb = { 1.0, 0.96, 0.78 },
         }),
+        component(module_engine_main_id, "power_consumer", {
+          draw_mw = 6.0,
+          heat_per_s = 4.0,
+        }),
+        component(module_engine_main_id, "module_health", { current = 300.0, maximum = 300.0 }),
         component(module_engine_main_id, "mass_kg", 500.0),
         component(module_engine_main_id, "parent_guid", hardpoint_engine_main_aft_id),
         component(module_engine_main_id, "mounted_on", {
@@ -287,7 +296,7 @@ function RocinanteBundle.build_graph_records(ctx)
       {
         component(module_fuel_tank_left_id, "display_name", "Fuel Tank Port"),
         component(module_fuel_tank_left_id, "entity_labels", { "Module", "FuelTank" }),
-        component(module_fuel_tank_left_id, "fuel_tank", { fuel_kg = 1000.0 }),
+        component(module_fuel_tank_left_id, "fuel_tank", { fuel_kg = 1000.0, capacity_kg = 1000.0 }),
         component(module_fuel_tank_left_id, "mass_kg", 1100.0),
         component(module_fuel_tank_left_id, "parent_guid", hardpoint_fuel_left_id),
         component(module_fuel_tank_left_id, "mounted_on", {
@@ -304,7 +313,7 @@ function RocinanteBundle.build_graph_records(ctx)
       {
         component(module_fuel_tank_right_id, "display_name", "Fuel Tank Starboard"),
         component(module_fuel_tank_right_id, "entity_labels", { "Module", "FuelTank" }),
-        component(module_fuel_tank_right_id, "fuel_tank", { fuel_kg = 1000.0 }),
+        component(module_fuel_tank_right_id, "fuel_tank", { fuel_kg = 1000.0, capacity_kg = 1000.0 }),
         component(module_fuel_tank_right_id, "mass_kg", 1100.0),
         component(module_fuel_tank_right_id, "parent_guid", hardpoint_fuel_right_id),
         component(module_fuel_tank_right_id, "mounted_on", {
@@ -336,6 +345,17 @@ function RocinanteBundle.build_graph_records(ctx)
           current = 500,
           capacity = 500,
         }),
+        component(module_weapon_gatling_fore_id, "ammo_feed", {
+          ammo_item_ids = { "ammo.gatling_belt" },
+          rounds_per_item = 50,
+          reload_time_s = 3.0,
+          auto_reload = true,
+        }),
+        component(module_weapon_gatling_fore_id, "power_consumer", {
+          energy_per_use_mj = 0.1,
+          heat_per_use = 0.3,
+        }),
+        component(module_weapon_gatling_fore_id, "module_health", { current = 200.0, maximum = 200.0 }),
         component(module_weapon_gatling_fore_id, "mass_kg", 120.0),
         component(module_weapon_gatling_fore_id, "parent_guid", hardpoint_weapon_fore_center_id),
         component(module_weapon_gatling_fore_id, "mounted_on", {
@@ -345,6 +365,74 @@ function RocinanteBundle.build_graph_records(ctx)
         component(module_weapon_gatling_fore_id, "owner_id", owner_id),
       }
     ),
+    new_entity(
+      hardpoint_power_core_id,
+      { "Entity", "Hardpoint" },
+      ship_id,
+      {
+        component(hardpoint_power_core_id, "display_name", "Power Core Hardpoint"),
+        component(hardpoint_power_core_id, "entity_labels", { "Hardpoint" }),
+        component(hardpoint_power_core_id, "hardpoint", {
+          hardpoint_id = "power_core",
+          offset_m = { 0.0, 0.0, -6.5 },
+        }),
+        component(hardpoint_power_core_id, "parent_guid", ship_id),
+        component(hardpoint_power_core_id, "owner_id", owner_id),
+      }
+    ),
+    new_entity(
+      hardpoint_thermal_core_id,
+      { "Entity", "Hardpoint" },
+      ship_id,
+      {
+        component(hardpoint_thermal_core_id, "display_name", "Thermal Core Hardpoint"),
+        component(hardpoint_thermal_core_id, "entity_labels", { "Hardpoint" }),
+        component(hardpoint_thermal_core_id, "hardpoint", {
+          hardpoint_id = "thermal_core",
+          offset_m = { 0.0, 0.0, -3.5 },
+        }),
+        component(hardpoint_thermal_core_id, "parent_guid", ship_id),
+        component(hardpoint_thermal_core_id, "owner_id", owner_id),
+      }
+    ),
+    new_entity(
+      module_reactor_id,
+      { "Entity", "Module", "Reactor" },
+      hardpoint_power_core_id,
+      {
+        component(module_reactor_id, "display_name", "Fusion Reactor"),
+        component(module_reactor_id, "entity_labels", { "Module", "Reactor" }),
+        component(module_reactor_id, "reactor", { output_mw = 12.0 }),
+        component(module_reactor_id, "capacitor", { capacity_mj = 60.0 }),
+        component(module_reactor_id, "mass_kg", 650.0),
+        component(module_reactor_id, "parent_guid", hardpoint_power_core_id),
+        component(module_reactor_id, "mounted_on", {
+          parent_entity_id = ship_id,
+          hardpoint_id = "power_core",
+        }),
+        component(module_reactor_id, "owner_id", owner_id),
+      }
+    ),
+    new_entity(
+      module_heatsink_id,
+      { "Entity", "Module", "Heatsink" },
+      hardpoint_thermal_core_id,
+      {
+        component(module_heatsink_id, "display_name", "Radiator Heatsink"),
+        component(module_heatsink_id, "entity_labels", { "Module", "Heatsink" }),
+        component(module_heatsink_id, "heatsink", {
+          heat_capacity = 100.0,
+          dissipation_per_s = 6.0,
+        }),
+        component(module_heatsink_id, "mass_kg", 220.0),
+        component(module_heatsink_id, "parent_guid", hardpoint_thermal_core_id),
+        component(module_heatsink_id, "mounted_on", {
+          parent_entity_id = ship_id,
+          hardpoint_id = "thermal_core",
+        }),
+        component(module_heatsink_id, "owner_id", owner_id),
+      }
+    ),
   }
 end
 
@@ -2,6 +2,8 @@ local PlayerBundle = {}
 
 PlayerBundle.context = {}
 
+local STARTING_CREDITS = 1000
+
 local function component(entity_id, kind, properties)
   return {
     component_id = entity_id .. ":" .. kind,
@@ -65,6 +67,23 @@ function PlayerBundle.build_graph_records(ctx)
       component(player_id, "action_queue", {
         pending = { "LongitudinalNeutral", "LateralNeutral" },
       }),
+      component(player_id, "credit_wallet", { balance = STARTING_CREDITS }),
+      component(player_id, "credit_ledger", {
+        entries = {
+          {
+            transaction_id = ctx.new_uuid(),
+            sequence = 1,
+            tick = 0,
+            reason = "account.opening_balance",
+            delta = STARTING_CREDITS,
+            balance_after = STARTING_CREDITS,
+          },
+        },
+      }),
+      component(player_id, "mission_log", {
+        active = { { mission_id = "mission.survey_aurelia" } },
+        completed_mission_ids = {},
+      }),
       component(player_id, "contact_resolution_m", ctx.contact_resolution_m or 100.0),
       component(player_id, "tactical_map_ui_settings", {
         shader_asset_id = "tactical_map_overlay_wgsl",
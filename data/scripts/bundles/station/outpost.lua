@@ -0,0 +1,156 @@
+local StationOutpostBundle = {}
+
+StationOutpostBundle.context = {}
+
+-- Service modules mounted on every outpost. The docking port sits just past the
+-- hull edge, facing out along +Y, so ships can reach its capture range.
+StationOutpostBundle.mounted_modules = {
+  {
+    hardpoint_id = "docking_port_north",
+    module_id = "module.docking.port_mk1",
+    offset_m = { 0.0, 70.0, 0.0 },
+    local_rotation_rad = 0.0,
+  },
+  {
+    hardpoint_id = "service_repair",
+    module_id = "module.station.repair_bay_mk1",
+    offset_m = { -30.0, 0.0, 0.0 },
+  },
+  {
+    hardpoint_id = "service_fuel",
+    module_id = "module.station.fuel_depot_mk1",
+    offset_m = { 30.0, 0.0, 0.0 },
+  },
+  {
+    hardpoint_id = "service_trade",
+    module_id = "module.station.trade_terminal_mk1",
+    offset_m = { 0.0, -30.0, 0.0 },
+  },
+}
+
+local function component(entity_id, kind, properties)
+  return {
+    component_id = entity_id .. ":" .. kind,
+    component_kind = kind,
+    properties = properties,
+  }
+end
+
+local function new_entity(entity_id, labels, parent_entity_id, components)
+  local properties = {}
+  if parent_entity_id ~= nil then
+    properties.parent_entity_id = parent_entity_id
+  end
+  return {
+    entity_id = entity_id,
+    labels = labels,
+    properties = properties,
+    components = components,
+  }
+end
+
+local function copy_array(values)
+  local out = {}
+  if values == nil then
+    return out
+  end
+  for index, value in ipairs(values) do
+    out[index] = value
+  end
+  return out
+end
+
+local function yaw_quat_z(rad)
+  local half = (rad or 0.0) * 0.5
+  return { 0.0, 0.0, math.sin(half), math.cos(half) }
+end
+
+local function normalized_spawn_position(ctx)
+  local spawn_position = ctx.spawn_position or { 0.0, 0.0 }
+  return { spawn_position[1] or spawn_position.x or 0.0, spawn_position[2] or spawn_position.y or 0.0 }
+end
+
+function StationOutpostBundle.build_graph_records(ctx)
+  if ctx.load_ship_module_definition == nil then
+    error("context.load_ship_module_definition must be set by host")
+  end
+
+  local station_id = ctx.entity_id or ctx.new_uuid()
+  local owner_id = ctx.owner_id or "world:system"
+  local display_name = ctx.display_name or "Outpost"
+  local entity_labels = ctx.entity_labels or { "Station", "Outpost" }
+  local size_m = ctx.size_m or 120.0
+  local half_extent_m = size_m * 0.5
+  local spawn_position = normalized_spawn_position(ctx)
+
+  local records = {
+    new_entity(station_id, { "Entity", "Station" }, nil, {
+      component(station_id, "display_name", display_name),
+      component(station_id, "entity_labels", entity_labels),
+      component(station_id, "owner_id", owner_id),
+      component(station_id, "public_visibility", {}),
+      component(station_id, "size_m", { length = size_m, width = size_m, height = size_m * 0.5 }),
+      component(station_id, "collision_profile", { mode = "Aabb" }),
+      component(station_id, "collision_aabb_m", {
+        half_extents = { half_extent_m, half_extent_m, size_m * 0.25 },
+      }),
+      -- No dedicated station art yet; reuse the largest hull sprite.
+      component(station_id, "visual_asset_id", ctx.visual_asset_id or "rocinante_01"),
+      component(station_id, "map_icon", { asset_id = ctx.map_icon_asset_id or "map_icon_planet_svg" }),
+      component(station_id, "avian_position", { spawn_position[1], spawn_position[2] }),
+      component(station_id, "avian_rotation", { cos = 1.0, sin = 0.0 }),
+      component(station_id, "avian_linear_velocity", { 0.0, 0.0 }),
+      component(station_id, "avian_angular_velocity", 0.0),
+      component(station_id, "avian_rigid_body", "Static"),
+    }),
+  }
+
+  for _, mount in ipairs(StationOutpostBundle.mounted_modules) do
+    local module_def = ctx.load_ship_module_definition(mount.module_id)
+    if module_def == nil then
+      error("station module definition not found for module_id=" .. tostring(mount.module_id))
+    end
+
+    local hardpoint_entity_id = ctx.new_uuid()
+    records[#records + 1] = new_entity(
+      hardpoint_entity_id,
+      { "Entity", "Hardpoint" },
+      station_id,
+      {
+        component(hardpoint_entity_id, "display_name", module_def.display_name .. " Hardpoint"),
+        component(hardpoint_entity_id, "entity_labels", { "Hardpoint" }),
+        component(hardpoint_entity_id, "hardpoint", {
+          hardpoint_id = mount.hardpoint_id,
+          offset_m = mount.offset_m,
+          local_rotation = yaw_quat_z(mount.local_rotation_rad or 0.0),
+        }),
+        component(hardpoint_entity_id, "parent_guid", station_id),
+        component(hardpoint_entity_id, "owner_id", owner_id),
+      }
+    )
+
+    local module_entity_id = ctx.new_uuid()
+    local module_components = {
+      component(module_entity_id, "display_name", module_def.display_name or module_def.module_id),
+      component(module_entity_id, "entity_labels", copy_array(module_def.entity_labels or { "Module" })),
+    }
+    for _, module_component in ipairs(module_def.components or {}) do
+      module_components[#module_components + 1] =
+        component(module_entity_id, module_component.kind, module_component.properties)
+    end
+    module_components[#module_components + 1] = component(module_entity_id, "parent_guid", hardpoint_entity_id)
+    module_components[#module_components + 1] = component(module_entity_id, "mounted_on", {
+      parent_entity_id = station_id,
+      hardpoint_id = mount.hardpoint_id,
+    })
+    module_components[#module_components + 1] = component(module_entity_id, "owner_id", owner_id)
+
+    local labels = copy_array(module_def.entity_labels or { "Module" })
+    table.insert(labels, 1, "Entity")
+    records[#records + 1] = new_entity(module_entity_id, labels, hardpoint_entity_id, module_components)
+  end
+
+  return records
+end
+
+return StationOutpostBundle
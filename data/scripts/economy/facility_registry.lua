@@ -0,0 +1,24 @@
+local FacilityRegistry = {}
+
+FacilityRegistry.schema_version = 1
+
+FacilityRegistry.facilities = {
+  {
+    facility_id = "facility.refinery_basic",
+    display_name = "Basic Refinery",
+    categories = { "refined_material" },
+    queue_slots = 2,
+    allowed_recipe_tags = { "refining" },
+    power_buffer_mw = 4.0,
+  },
+  {
+    facility_id = "facility.fabricator_basic",
+    display_name = "Basic Fabricator",
+    categories = { "alloy", "subassembly", "ammo", "equipment_kit" },
+    queue_slots = 3,
+    allowed_recipe_tags = { "fabrication", "alloying" },
+    power_buffer_mw = 6.0,
+  },
+}
+
+return FacilityRegistry
@@ -0,0 +1,132 @@
+local ItemRegistry = {}
+
+ItemRegistry.schema_version = 1
+
+ItemRegistry.items = {
+  {
+    item_id = "resource.iron_ore",
+    display_name = "Iron Ore",
+    category = "raw_mineral",
+    tier = 1,
+    stack_size = 500,
+    unit_mass_kg = 2.5,
+    base_value = 4,
+    tags = { "ore", "metal" },
+    description = "Ferrous ore broken loose from common rocky asteroids.",
+  },
+  {
+    item_id = "resource.nickel_ore",
+    display_name = "Nickel Ore",
+    category = "raw_mineral",
+    tier = 1,
+    stack_size = 500,
+    unit_mass_kg = 2.8,
+    base_value = 6,
+    tags = { "ore", "metal" },
+  },
+  {
+    item_id = "resource.silicate_rock",
+    display_name = "Silicate Rock",
+    category = "raw_mineral",
+    tier = 1,
+    stack_size = 500,
+    unit_mass_kg = 1.6,
+    base_value = 2,
+    tags = { "ore", "silicate" },
+  },
+  {
+    item_id = "resource.rare_earth_oxides",
+    display_name = "Rare Earth Oxides",
+    category = "raw_mineral",
+    tier = 2,
+    stack_size = 200,
+    unit_mass_kg = 0.8,
+    base_value = 28,
+    tags = { "ore", "rare_earth" },
+  },
+  {
+    item_id = "material.iron_ingot",
+    display_name = "Iron Ingot",
+    category = "refined_material",
+    tier = 1,
+    stack_size = 250,
+    unit_mass_kg = 5.0,
+    base_value = 14,
+    tags = { "metal", "refined" },
+  },
+  {
+    item_id = "material.nickel_ingot",
+    display_name = "Nickel Ingot",
+    category = "refined_material",
+    tier = 1,
+    stack_size = 250,
+    unit_mass_kg = 5.6,
+    base_value = 20,
+    tags = { "metal", "refined" },
+  },
+  {
+    item_id = "material.glass_pane",
+    display_name = "Glass Pane",
+    category = "refined_material",
+    tier = 1,
+    stack_size = 250,
+    unit_mass_kg = 3.0,
+    base_value = 8,
+    tags = { "silicate", "refined" },
+  },
+  {
+    item_id = "alloy.steel_plate",
+    display_name = "Steel Plate",
+    category = "alloy",
+    tier = 2,
+    stack_size = 100,
+    unit_mass_kg = 12.0,
+    base_value = 55,
+    tags = { "metal", "structural" },
+  },
+  {
+    item_id = "subassembly.hull_plating",
+    display_name = "Hull Plating",
+    category = "subassembly",
+    tier = 2,
+    stack_size = 50,
+    unit_mass_kg = 40.0,
+    base_value = 210,
+    tags = { "structural", "ship_part" },
+    description = "Armoured panel used to patch and extend ship hulls.",
+  },
+  {
+    item_id = "ammo.gatling_belt",
+    display_name = "Gatling Ammo Belt",
+    category = "ammo",
+    tier = 1,
+    stack_size = 200,
+    unit_mass_kg = 7.5,
+    base_value = 18,
+    tags = { "ammo", "ballistic" },
+    description = "Linked belt of fifty rounds for ballistic gatlings.",
+  },
+  {
+    item_id = "ammo.seeker_missile",
+    display_name = "Seeker Missile",
+    category = "ammo",
+    tier = 2,
+    stack_size = 24,
+    unit_mass_kg = 45.0,
+    base_value = 140,
+    tags = { "ammo", "missile" },
+  },
+  {
+    item_id = "repair.module_kit",
+    display_name = "Module Repair Kit",
+    category = "equipment_kit",
+    tier = 2,
+    stack_size = 20,
+    unit_mass_kg = 15.0,
+    base_value = 120,
+    tags = { "repair", "ship_part" },
+    description = "Field spares that restore a damaged ship module.",
+  },
+}
+
+return ItemRegistry
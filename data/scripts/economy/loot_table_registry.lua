@@ -0,0 +1,31 @@
+local LootTableRegistry = {}
+
+LootTableRegistry.schema_version = 1
+
+-- Destroyed entities use their Destructible loot_table_id when set, otherwise
+-- the table that lists their destruction_profile_id. Rolls are seeded by the
+-- destroyed entity's guid, so the same wreck always yields the same drops.
+LootTableRegistry.tables = {
+  {
+    loot_table_id = "loot.ship_wreck_default",
+    destruction_profile_ids = { "explosion_burst" },
+    wreck_display_name = "Ship Wreckage",
+    drops = {
+      { item_id = "alloy.steel_plate", chance = 0.6, min_quantity = 1, max_quantity = 4 },
+      { item_id = "material.iron_ingot", chance = 0.5, min_quantity = 2, max_quantity = 6 },
+      { item_id = "subassembly.hull_plating", chance = 0.2, min_quantity = 1, max_quantity = 1 },
+      { item_id = "ammo.gatling_belt", chance = 0.4, min_quantity = 1, max_quantity = 3 },
+    },
+  },
+  {
+    loot_table_id = "loot.ship.rocinante",
+    wreck_display_name = "Rocinante Wreckage",
+    drops = {
+      { item_id = "alloy.steel_plate", min_quantity = 4, max_quantity = 10 },
+      { item_id = "subassembly.hull_plating", chance = 0.5, min_quantity = 1, max_quantity = 3 },
+      { item_id = "resource.rare_earth_oxides", chance = 0.3, min_quantity = 2, max_quantity = 8 },
+    },
+  },
+}
+
+return LootTableRegistry
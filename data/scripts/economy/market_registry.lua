@@ -0,0 +1,33 @@
+local MarketRegistry = {}
+
+MarketRegistry.schema_version = 1
+
+-- Station modules with a `market` component name one of these markets. Live
+-- stock and prices are seeded from the listings and then persist per station.
+-- Prices drift toward base_price * (target_stock / stock) ^ price_elasticity,
+-- clamped by min/max_price_ratio; players buy at the mid price plus spread and
+-- sell at the mid price minus spread.
+MarketRegistry.markets = {
+  {
+    market_id = "market.frontier_exchange",
+    display_name = "Frontier Exchange",
+    spread = 0.12,
+    price_elasticity = 0.6,
+    min_price_ratio = 0.5,
+    max_price_ratio = 2.5,
+    price_drift_per_s = 0.02,
+    listings = {
+      -- Mining outposts buy raw ore but do not sell it back.
+      { item_id = "resource.iron_ore", base_price = 4, sells = false, initial_stock = 2000, target_stock = 2000, max_stock = 8000, restock_per_s = 1.0 },
+      { item_id = "resource.nickel_ore", base_price = 6, sells = false, initial_stock = 1500, target_stock = 1500, max_stock = 6000, restock_per_s = 0.8 },
+      { item_id = "resource.rare_earth_oxides", base_price = 28, initial_stock = 200, target_stock = 200, max_stock = 1200, restock_per_s = 0.1 },
+      { item_id = "material.iron_ingot", base_price = 14, initial_stock = 600, target_stock = 600, max_stock = 3000, restock_per_s = 0.3 },
+      { item_id = "alloy.steel_plate", base_price = 55, initial_stock = 150, target_stock = 150, max_stock = 800, restock_per_s = 0.05 },
+      { item_id = "ammo.gatling_belt", base_price = 18, buys = false, initial_stock = 400, target_stock = 400, max_stock = 400, restock_per_s = 0.2 },
+      { item_id = "ammo.seeker_missile", base_price = 140, buys = false, initial_stock = 40, target_stock = 40, max_stock = 40, restock_per_s = 0.01 },
+      { item_id = "repair.module_kit", base_price = 120, initial_stock = 30, target_stock = 30, max_stock = 120, restock_per_s = 0.01 },
+    },
+  },
+}
+
+return MarketRegistry
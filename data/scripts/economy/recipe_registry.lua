@@ -0,0 +1,88 @@
+local RecipeRegistry = {}
+
+RecipeRegistry.schema_version = 1
+
+RecipeRegistry.recipes = {
+  {
+    recipe_id = "recipe.refine_iron_ingot",
+    facility_id = "facility.refinery_basic",
+    inputs = {
+      { item_id = "resource.iron_ore", amount = 4 },
+    },
+    output = { item_id = "material.iron_ingot", amount = 1 },
+    craft_time_s = 6.0,
+    power_cost = 0.5,
+    tags = { "refining" },
+  },
+  {
+    recipe_id = "recipe.refine_nickel_ingot",
+    facility_id = "facility.refinery_basic",
+    inputs = {
+      { item_id = "resource.nickel_ore", amount = 4 },
+    },
+    output = { item_id = "material.nickel_ingot", amount = 1 },
+    craft_time_s = 7.0,
+    power_cost = 0.6,
+    tags = { "refining" },
+  },
+  {
+    recipe_id = "recipe.melt_glass_pane",
+    facility_id = "facility.refinery_basic",
+    inputs = {
+      { item_id = "resource.silicate_rock", amount = 5 },
+    },
+    output = { item_id = "material.glass_pane", amount = 2 },
+    craft_time_s = 5.0,
+    power_cost = 0.4,
+    tags = { "refining" },
+  },
+  {
+    recipe_id = "recipe.alloy_steel_plate",
+    facility_id = "facility.fabricator_basic",
+    inputs = {
+      { item_id = "material.iron_ingot", amount = 2 },
+      { item_id = "material.nickel_ingot", amount = 1 },
+    },
+    output = { item_id = "alloy.steel_plate", amount = 1 },
+    craft_time_s = 10.0,
+    power_cost = 1.2,
+    tags = { "alloying" },
+  },
+  {
+    recipe_id = "recipe.fabricate_hull_plating",
+    facility_id = "facility.fabricator_basic",
+    inputs = {
+      { item_id = "alloy.steel_plate", amount = 3 },
+      { item_id = "material.glass_pane", amount = 1 },
+    },
+    output = { item_id = "subassembly.hull_plating", amount = 1 },
+    craft_time_s = 18.0,
+    power_cost = 2.5,
+    tags = { "fabrication" },
+  },
+  {
+    recipe_id = "recipe.fabricate_gatling_belt",
+    facility_id = "facility.fabricator_basic",
+    inputs = {
+      { item_id = "material.iron_ingot", amount = 2 },
+    },
+    output = { item_id = "ammo.gatling_belt", amount = 1 },
+    craft_time_s = 8.0,
+    power_cost = 0.8,
+    tags = { "fabrication" },
+  },
+  {
+    recipe_id = "recipe.fabricate_module_repair_kit",
+    facility_id = "facility.fabricator_basic",
+    inputs = {
+      { item_id = "alloy.steel_plate", amount = 1 },
+      { item_id = "material.glass_pane", amount = 1 },
+    },
+    output = { item_id = "repair.module_kit", amount = 1 },
+    craft_time_s = 12.0,
+    power_cost = 1.5,
+    tags = { "fabrication" },
+  },
+}
+
+return RecipeRegistry
@@ -0,0 +1,58 @@
+local FactionRegistry = {}
+
+FactionRegistry.schema_version = 1
+
+-- Standings are symmetric; pairs not listed here use default_standing and a
+-- faction is always allied with itself.
+FactionRegistry.default_standing = "neutral"
+
+FactionRegistry.factions = {
+  {
+    faction_id = "faction.frontier_union",
+    display_name = "Frontier Union",
+    member_kill_reputation_delta = -15.0,
+  },
+  {
+    faction_id = "faction.corporate_combine",
+    display_name = "Corporate Combine",
+    member_kill_reputation_delta = -10.0,
+  },
+  {
+    faction_id = "faction.free_traders",
+    display_name = "Free Traders Guild",
+    member_kill_reputation_delta = -20.0,
+  },
+  {
+    faction_id = "faction.void_raiders",
+    display_name = "Void Raiders",
+    member_kill_reputation_delta = -5.0,
+  },
+}
+
+FactionRegistry.relations = {
+  { faction_a = "faction.frontier_union", faction_b = "faction.free_traders", standing = "allied" },
+  { faction_a = "faction.frontier_union", faction_b = "faction.void_raiders", standing = "hostile" },
+  { faction_a = "faction.corporate_combine", faction_b = "faction.void_raiders", standing = "hostile" },
+  { faction_a = "faction.free_traders", faction_b = "faction.void_raiders", standing = "hostile" },
+}
+
+-- Hits between members of the same or allied factions deal no damage unless
+-- enabled here.
+FactionRegistry.friendly_fire = {
+  allow_same_faction_damage = false,
+  allow_allied_damage = false,
+}
+
+-- Destroying a faction member applies its member_kill_reputation_delta to the
+-- killer's reputation with that faction, ally_share of it to the faction's
+-- allies and enemy_share of it, sign-flipped, to its enemies. Players at or
+-- below hostile_threshold are treated as hostile by that faction.
+FactionRegistry.reputation = {
+  ally_share = 0.5,
+  enemy_share = 0.25,
+  hostile_threshold = -100.0,
+  min = -1000.0,
+  max = 1000.0,
+}
+
+return FactionRegistry
@@ -0,0 +1,85 @@
+local MissionRegistry = {}
+
+MissionRegistry.schema_version = 1
+
+-- Missions are started for a player by the `start_mission` script intent or
+-- by listing them in the player's `mission_log` graph record. Objectives may
+-- complete in any order; the server's mission evaluator tracks them:
+--   reach_location  controlled ship within radius_m of { x, y }
+--   destroy_entity  target_entity_id, or `count` members of faction_id,
+--                   credited to the player behind the last damaging hit
+--   deliver_item    cargo handed over while within range_m of the destination
+--   scan_landmark   landmark discovered by the player's scanners
+-- Rewards are paid once, when the last objective completes.
+MissionRegistry.missions = {
+  {
+    mission_id = "mission.survey_aurelia",
+    display_name = "Survey Aurelia",
+    description = "Fly out to Aurelia and log it with your scanners.",
+    objectives = {
+      {
+        objective_id = "scan_aurelia",
+        description = "Scan Aurelia",
+        type = "scan_landmark",
+        landmark_entity_id = "0012ebad-0000-0000-0000-000000000010",
+      },
+      {
+        objective_id = "reach_aurelia_orbit",
+        description = "Reach high orbit over Aurelia",
+        type = "reach_location",
+        x = 8000.0,
+        y = 0.0,
+        radius_m = 1200.0,
+      },
+    },
+    reward = {
+      credits = 500,
+      reputation = {
+        { faction_id = "faction.frontier_union", delta = 2.0 },
+      },
+    },
+  },
+  {
+    mission_id = "mission.ore_run_aurelia",
+    display_name = "Ore Run: Aurelia",
+    description = "Aurelia's foundries are short of iron ore.",
+    repeatable = true,
+    objectives = {
+      {
+        objective_id = "deliver_iron_ore",
+        description = "Deliver iron ore to Aurelia",
+        type = "deliver_item",
+        item_id = "resource.iron_ore",
+        quantity = 40,
+        destination_entity_id = "0012ebad-0000-0000-0000-000000000010",
+        range_m = 1200.0,
+      },
+    },
+    reward = {
+      credits = 650,
+    },
+  },
+  {
+    mission_id = "mission.raider_bounty",
+    display_name = "Raider Bounty",
+    description = "The Frontier Union pays for every Void Raider taken out.",
+    repeatable = true,
+    objectives = {
+      {
+        objective_id = "destroy_raiders",
+        description = "Destroy Void Raider ships",
+        type = "destroy_entity",
+        faction_id = "faction.void_raiders",
+        count = 3,
+      },
+    },
+    reward = {
+      credits = 1200,
+      reputation = {
+        { faction_id = "faction.frontier_union", delta = 5.0 },
+      },
+    },
+  },
+}
+
+return MissionRegistry
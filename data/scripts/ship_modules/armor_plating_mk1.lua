@@ -0,0 +1,25 @@
+return {
+  module_id = "module.armor.plating_mk1",
+  display_name = "Armor Plating MK1",
+  category = "armor",
+  entity_labels = { "Module", "Armor" },
+  compatible_slot_kinds = { "armor" },
+  tags = { "armor", "defense" },
+  components = {
+    {
+      kind = "damage_resistance_profile",
+      properties = {
+        armor_rating = 2.0,
+        resistances = {
+          { damage_type = "Ballistic", fraction = 0.15 },
+          { damage_type = "Explosive", fraction = 0.2 },
+          { damage_type = "KineticRam", fraction = 0.3 },
+        },
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 900.0,
+    },
+  },
+}
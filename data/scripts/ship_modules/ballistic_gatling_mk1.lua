@@ -29,6 +29,29 @@ return {
         current = 500,
         capacity = 500,
       },
+    {
+      kind = "ammo_feed",
+      properties = {
+        ammo_item_ids = { "ammo.gatling_belt" },
+        rounds_per_item = 50,
+        reload_time_s = 3.0,
+        auto_reload = true,
+      },
+    },
+    },
+    {
+      kind = "power_consumer",
+      properties = {
+        energy_per_use_mj = 0.1,
+        heat_per_use = 0.3,
+      },
+    },
+    {
+      kind = "module_health",
+      properties = {
+        current = 200.0,
+        maximum = 200.0,
+      },
     },
     {
       kind = "mass_kg",
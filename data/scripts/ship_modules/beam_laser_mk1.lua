@@ -0,0 +1,52 @@
+return {
+  module_id = "module.weapon.beam_laser_mk1",
+  display_name = "Pulse Beam Laser",
+  category = "weapon",
+  entity_labels = { "Module", "Weapon", "BeamWeapon" },
+  compatible_slot_kinds = { "weapon" },
+  tags = { "weapon", "beam" },
+  components = {
+    {
+      kind = "weapon_tag",
+      properties = {},
+    },
+    {
+      kind = "beam_weapon",
+      properties = {
+        weapon_name = "Pulse Beam Laser",
+        range_m = 220.0,
+        damage_per_second = 60.0,
+        damage_type = "Energy",
+        max_heat = 100.0,
+        heat_per_second = 25.0,
+        cooling_per_second = 18.0,
+        overheat_recovery_fraction = 0.4,
+        fire_audio_profile_id = "weapon.beam_laser",
+      },
+    },
+    {
+      kind = "weapon_group",
+      properties = {
+        group = 3,
+      },
+    },
+    {
+      kind = "power_consumer",
+      properties = {
+        draw_mw = 8.0,
+        heat_per_s = 5.0,
+      },
+    },
+    {
+      kind = "module_health",
+      properties = {
+        current = 200.0,
+        maximum = 200.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 140.0,
+    },
+  },
+}
@@ -0,0 +1,24 @@
+return {
+  module_id = "module.docking.port_mk1",
+  display_name = "Docking Port MK1",
+  category = "docking",
+  entity_labels = { "Module", "DockingPort" },
+  compatible_slot_kinds = { "docking" },
+  tags = { "docking", "utility" },
+  components = {
+    {
+      kind = "docking_port",
+      properties = {
+        request_range_m = 1500.0,
+        capture_range_m = 40.0,
+        corridor_half_angle_deg = 30.0,
+        max_capture_speed_mps = 8.0,
+        undock_offset_m = 60.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 450.0,
+    },
+  },
+}
@@ -24,6 +24,13 @@ return {
         max_afterburner_velocity_mps = 250.0,
       },
     },
+    {
+      kind = "power_consumer",
+      properties = {
+        draw_mw = 6.0,
+        heat_per_s = 4.0,
+      },
+    },
     {
       kind = "thruster_plume_shader_settings",
       properties = {
@@ -47,6 +54,13 @@ return {
         afterburner_color_rgb = { 1.0, 0.96, 0.78 },
       },
     },
+    {
+      kind = "module_health",
+      properties = {
+        current = 300.0,
+        maximum = 300.0,
+      },
+    },
     {
       kind = "mass_kg",
       properties = 500.0,
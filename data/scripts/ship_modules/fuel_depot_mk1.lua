@@ -0,0 +1,26 @@
+return {
+  module_id = "module.station.fuel_depot_mk1",
+  display_name = "Fuel Depot MK1",
+  category = "station_service",
+  entity_labels = { "Module", "StationService" },
+  compatible_slot_kinds = { "station_service" },
+  tags = { "station", "service", "fuel" },
+  components = {
+    {
+      kind = "station_service",
+      properties = {
+        kind = "Refuel",
+        service_range_m = 250.0,
+      },
+    },
+    {
+      -- Credits per kilogram of fuel delivered.
+      kind = "cost",
+      properties = { credits = 1 },
+    },
+    {
+      kind = "mass_kg",
+      properties = 2600.0,
+    },
+  },
+}
@@ -10,6 +10,7 @@ return {
       kind = "fuel_tank",
       properties = {
         fuel_kg = 1000.0,
+        capacity_kg = 1000.0,
       },
     },
     {
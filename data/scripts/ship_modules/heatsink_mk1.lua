@@ -0,0 +1,21 @@
+return {
+  module_id = "module.thermal.heatsink_mk1",
+  display_name = "Radiator Heatsink",
+  category = "thermal",
+  entity_labels = { "Module", "Heatsink" },
+  compatible_slot_kinds = { "thermal" },
+  tags = { "thermal", "heatsink" },
+  components = {
+    {
+      kind = "heatsink",
+      properties = {
+        heat_capacity = 100.0,
+        dissipation_per_s = 6.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 220.0,
+    },
+  },
+}
@@ -0,0 +1,23 @@
+return {
+  module_id = "module.utility.mining_laser_mk1",
+  display_name = "Mining Laser MK1",
+  category = "utility",
+  entity_labels = { "Module", "MiningLaser" },
+  compatible_slot_kinds = { "utility" },
+  tags = { "utility", "mining", "extraction" },
+  components = {
+    {
+      kind = "mining_laser",
+      properties = {
+        range_m = 180.0,
+        cycle_time_s = 4.0,
+        yield_multiplier = 1.0,
+        active = false,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 280.0,
+    },
+  },
+}
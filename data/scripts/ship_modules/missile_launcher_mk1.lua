@@ -0,0 +1,72 @@
+return {
+  module_id = "module.weapon.missile_launcher_mk1",
+  display_name = "Seeker Missile Launcher",
+  category = "weapon",
+  entity_labels = { "Module", "Weapon", "MissileLauncher" },
+  compatible_slot_kinds = { "weapon" },
+  tags = { "weapon", "missile" },
+  components = {
+    {
+      kind = "weapon_tag",
+      properties = {},
+    },
+    {
+      kind = "missile_launcher",
+      properties = {
+        weapon_name = "Seeker Missile Launcher",
+        rpm = 30.0,
+        launch_speed_mps = 40.0,
+        thrust_mps2 = 120.0,
+        max_speed_mps = 320.0,
+        turn_rate_rad_s = 2.5,
+        fuel_lifetime_s = 8.0,
+        lock_range_m = 1200.0,
+        min_lock_signal_strength = 0.1,
+        proximity_fuse_m = 6.0,
+        blast_radius_m = 18.0,
+        damage = 80.0,
+        damage_type = "Explosive",
+      },
+    },
+    {
+      kind = "weapon_group",
+      properties = {
+        group = 2,
+      },
+    },
+    {
+      kind = "ammo_count",
+      properties = {
+        current = 12,
+        capacity = 12,
+      },
+    {
+      kind = "ammo_feed",
+      properties = {
+        ammo_item_ids = { "ammo.seeker_missile" },
+        rounds_per_item = 1,
+        reload_time_s = 6.0,
+        auto_reload = true,
+      },
+    },
+    },
+    {
+      kind = "power_consumer",
+      properties = {
+        energy_per_use_mj = 2.0,
+        heat_per_use = 3.0,
+      },
+    },
+    {
+      kind = "module_health",
+      properties = {
+        current = 200.0,
+        maximum = 200.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 180.0,
+    },
+  },
+}
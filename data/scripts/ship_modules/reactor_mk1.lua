@@ -0,0 +1,26 @@
+return {
+  module_id = "module.power.reactor_mk1",
+  display_name = "Fusion Reactor",
+  category = "power",
+  entity_labels = { "Module", "Reactor" },
+  compatible_slot_kinds = { "power" },
+  tags = { "power", "reactor" },
+  components = {
+    {
+      kind = "reactor",
+      properties = {
+        output_mw = 12.0,
+      },
+    },
+    {
+      kind = "capacitor",
+      properties = {
+        capacity_mj = 60.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 650.0,
+    },
+  },
+}
@@ -26,5 +26,65 @@ return {
       script = "ship_modules/ballistic_gatling_mk1.lua",
       tags = { "weapon", "ballistic" },
     },
+    {
+      module_id = "module.weapon.missile_launcher_mk1",
+      script = "ship_modules/missile_launcher_mk1.lua",
+      tags = { "weapon", "missile" },
+    },
+    {
+      module_id = "module.weapon.beam_laser_mk1",
+      script = "ship_modules/beam_laser_mk1.lua",
+      tags = { "weapon", "beam" },
+    },
+    {
+      module_id = "module.shield.generator_mk1",
+      script = "ship_modules/shield_generator_mk1.lua",
+      tags = { "shield", "defense" },
+    },
+    {
+      module_id = "module.armor.plating_mk1",
+      script = "ship_modules/armor_plating_mk1.lua",
+      tags = { "armor", "defense" },
+    },
+    {
+      module_id = "module.utility.tractor_beam_mk1",
+      script = "ship_modules/tractor_beam_mk1.lua",
+      tags = { "utility", "tractor", "salvage" },
+    },
+    {
+      module_id = "module.utility.mining_laser_mk1",
+      script = "ship_modules/mining_laser_mk1.lua",
+      tags = { "utility", "mining", "extraction" },
+    },
+    {
+      module_id = "module.power.reactor_mk1",
+      script = "ship_modules/reactor_mk1.lua",
+      tags = { "power", "reactor" },
+    },
+    {
+      module_id = "module.thermal.heatsink_mk1",
+      script = "ship_modules/heatsink_mk1.lua",
+      tags = { "thermal", "heatsink" },
+    },
+    {
+      module_id = "module.docking.port_mk1",
+      script = "ship_modules/docking_port_mk1.lua",
+      tags = { "docking", "utility" },
+    },
+    {
+      module_id = "module.station.repair_bay_mk1",
+      script = "ship_modules/repair_bay_mk1.lua",
+      tags = { "station", "service", "repair" },
+    },
+    {
+      module_id = "module.station.fuel_depot_mk1",
+      script = "ship_modules/fuel_depot_mk1.lua",
+      tags = { "station", "service", "fuel" },
+    },
+    {
+      module_id = "module.station.trade_terminal_mk1",
+      script = "ship_modules/trade_terminal_mk1.lua",
+      tags = { "station", "service", "market" },
+    },
   },
 }
@@ -0,0 +1,26 @@
+return {
+  module_id = "module.station.repair_bay_mk1",
+  display_name = "Repair Bay MK1",
+  category = "station_service",
+  entity_labels = { "Module", "StationService" },
+  compatible_slot_kinds = { "station_service" },
+  tags = { "station", "service", "repair" },
+  components = {
+    {
+      kind = "station_service",
+      properties = {
+        kind = "HullRepair",
+        service_range_m = 250.0,
+      },
+    },
+    {
+      -- Credits per hull point restored.
+      kind = "cost",
+      properties = { credits = 2 },
+    },
+    {
+      kind = "mass_kg",
+      properties = 3200.0,
+    },
+  },
+}
@@ -24,6 +24,13 @@ return {
         multiplier = 1.0,
       },
     },
+    {
+      kind = "module_health",
+      properties = {
+        current = 150.0,
+        maximum = 150.0,
+      },
+    },
     {
       kind = "mass_kg",
       properties = 80.0,
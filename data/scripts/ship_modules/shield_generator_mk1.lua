@@ -0,0 +1,39 @@
+return {
+  module_id = "module.shield.generator_mk1",
+  display_name = "Shield Generator MK1",
+  category = "shield",
+  entity_labels = { "Module", "ShieldGenerator" },
+  compatible_slot_kinds = { "shield" },
+  tags = { "shield", "defense" },
+  components = {
+    {
+      kind = "shield_generator",
+      properties = {
+        capacity = 400.0,
+        current = 400.0,
+        regen_rate_per_s = 25.0,
+        regen_delay_s = 3.0,
+        regen_delay_remaining_s = 0.0,
+        active = true,
+        absorption = {
+          { damage_type = "Ballistic", fraction = 0.8 },
+          { damage_type = "Energy", fraction = 1.0 },
+          { damage_type = "Explosive", fraction = 0.6 },
+          { damage_type = "Thermal", fraction = 0.9 },
+          { damage_type = "KineticRam", fraction = 0.25 },
+        },
+      },
+    },
+    {
+      kind = "power_consumer",
+      properties = {
+        draw_mw = 3.0,
+        heat_per_s = 2.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 240.0,
+    },
+  },
+}
@@ -0,0 +1,24 @@
+return {
+  module_id = "module.utility.tractor_beam_mk1",
+  display_name = "Tractor Beam MK1",
+  category = "utility",
+  entity_labels = { "Module", "TractorBeam" },
+  compatible_slot_kinds = { "utility" },
+  tags = { "utility", "tractor", "salvage" },
+  components = {
+    {
+      kind = "tractor_beam",
+      properties = {
+        range_m = 250.0,
+        hold_distance_m = 40.0,
+        max_force_n = 60000.0,
+        max_target_mass_kg = 50000.0,
+        active = false,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 320.0,
+    },
+  },
+}
@@ -0,0 +1,21 @@
+return {
+  module_id = "module.station.trade_terminal_mk1",
+  display_name = "Trade Terminal MK1",
+  category = "station_service",
+  entity_labels = { "Module", "Market" },
+  compatible_slot_kinds = { "station_service" },
+  tags = { "station", "service", "market" },
+  components = {
+    {
+      kind = "market",
+      properties = {
+        market_id = "market.frontier_exchange",
+        trade_range_m = 250.0,
+      },
+    },
+    {
+      kind = "mass_kg",
+      properties = 1800.0,
+    },
+  },
+}
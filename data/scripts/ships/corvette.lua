@@ -45,6 +45,15 @@ return {
       additive_m = 300.0,
       multiplier = 1.0,
     },
+    damage_resistance_profile = {
+      armor_rating = 1.0,
+      resistances = {
+        { damage_type = "Ballistic", fraction = 0.05 },
+      },
+    },
+    crafting_facility = {
+      facility_id = "facility.refinery_basic",
+    },
   },
 
   hardpoints = {
@@ -98,6 +107,30 @@ return {
       local_rotation_rad = 0.0,
       compatible_tags = { "weapon" },
     },
+    {
+      hardpoint_id = "shield_core",
+      display_name = "Shield Core",
+      slot_kind = "shield",
+      offset_m = { 0.0, 0.0, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "shield" },
+    },
+    {
+      hardpoint_id = "power_core",
+      display_name = "Power Core",
+      slot_kind = "power",
+      offset_m = { 0.0, -6.5, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "power" },
+    },
+    {
+      hardpoint_id = "thermal_core",
+      display_name = "Thermal Core",
+      slot_kind = "thermal",
+      offset_m = { 0.0, -3.5, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "thermal" },
+    },
   },
 
   mounted_modules = {
@@ -117,5 +150,8 @@ return {
     },
     { hardpoint_id = "scanner_dorsal", module_id = "module.scanner.array_mk1", component_overrides = {} },
     { hardpoint_id = "weapon_fore_center", module_id = "module.weapon.ballistic_gatling_mk1", component_overrides = {} },
+    { hardpoint_id = "shield_core", module_id = "module.shield.generator_mk1", component_overrides = {} },
+    { hardpoint_id = "power_core", module_id = "module.power.reactor_mk1", component_overrides = {} },
+    { hardpoint_id = "thermal_core", module_id = "module.thermal.heatsink_mk1", component_overrides = {} },
   },
 }
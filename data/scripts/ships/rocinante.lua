@@ -27,6 +27,7 @@ return {
     destructible = {
       destruction_profile_id = "explosion_burst",
       destroy_delay_s = 0.18,
+      loot_table_id = "loot.ship.rocinante",
     },
     flight_computer = {
       profile = "basic_fly_by_wire",
@@ -53,6 +54,12 @@ return {
       supports_directional_awareness = true,
       max_contacts = 64,
     },
+    damage_resistance_profile = {
+      armor_rating = 0.0,
+      resistances = {
+        { damage_type = "Thermal", fraction = 0.1 },
+      },
+    },
   },
 
   hardpoints = {
@@ -98,6 +105,54 @@ return {
       local_rotation_rad = 0.0,
       compatible_tags = { "weapon" },
     },
+    {
+      hardpoint_id = "armor_hull",
+      display_name = "Hull Armor",
+      slot_kind = "armor",
+      offset_m = { 0.0, 0.0, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "armor" },
+    },
+    {
+      hardpoint_id = "utility_aft",
+      display_name = "Aft Utility",
+      slot_kind = "utility",
+      offset_m = { 0.0, -6.0, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "utility" },
+    },
+    {
+      hardpoint_id = "utility_fore",
+      display_name = "Fore Utility",
+      slot_kind = "utility",
+      offset_m = { 0.0, 6.0, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "utility" },
+    },
+    {
+      hardpoint_id = "docking_aft",
+      display_name = "Aft Docking Port",
+      slot_kind = "docking",
+      offset_m = { 0.0, -12.0, 0.0 },
+      local_rotation_rad = 3.14159265,
+      compatible_tags = { "docking" },
+    },
+    {
+      hardpoint_id = "power_core",
+      display_name = "Power Core",
+      slot_kind = "power",
+      offset_m = { 0.0, -6.5, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "power" },
+    },
+    {
+      hardpoint_id = "thermal_core",
+      display_name = "Thermal Core",
+      slot_kind = "thermal",
+      offset_m = { 0.0, -3.5, 0.0 },
+      local_rotation_rad = 0.0,
+      compatible_tags = { "thermal" },
+    },
   },
 
   mounted_modules = {
@@ -116,5 +171,11 @@ return {
       component_overrides = {},
     },
     { hardpoint_id = "weapon_fore_center", module_id = "module.weapon.ballistic_gatling_mk1", component_overrides = {} },
+    { hardpoint_id = "armor_hull", module_id = "module.armor.plating_mk1", component_overrides = {} },
+    { hardpoint_id = "utility_aft", module_id = "module.utility.tractor_beam_mk1", component_overrides = {} },
+    { hardpoint_id = "utility_fore", module_id = "module.utility.mining_laser_mk1", component_overrides = {} },
+    { hardpoint_id = "docking_aft", module_id = "module.docking.port_mk1", component_overrides = {} },
+    { hardpoint_id = "power_core", module_id = "module.power.reactor_mk1", component_overrides = {} },
+    { hardpoint_id = "thermal_core", module_id = "module.thermal.heatsink_mk1", component_overrides = {} },
   },
 }
@@ -96,6 +96,7 @@ WorldInit.world_defaults = {
   asteroid_field_entity_id = "0012ebad-0000-0000-0000-000000000020",
   planet_bundle_id = "planet.body",
   environment_lighting_bundle_id = "environment.lighting",
+  station_bundle_id = "station.outpost",
   asteroid_field_count = 150,
   asteroid_field_center = { x = 0.0, y = 0.0 },
   asteroid_field_radius_min_m = 260.0,
@@ -205,6 +206,14 @@ WorldInit.world_defaults = {
     map_icon_asset_id = "map_icon_star_svg",
     sprite_shader_asset_id = "star_visual_wgsl",
   },
+  starter_station = {
+    entity_id = "0012ebad-0000-0000-0000-000000000030",
+    display_name = "Helion Exchange",
+    owner_id = "world:system:starter",
+    entity_labels = { "Station", "Outpost", "TradeHub" },
+    size_m = 120.0,
+    spawn_position = { x = 2000.0, y = 400.0 },
+  },
   environment_lighting = {
     entity_id = "0012ebad-0000-0000-0000-000000000011",
     display_name = "System Lighting",
@@ -325,6 +334,18 @@ local function build_environment_lighting_records(ctx)
   return ctx.spawn_bundle_graph_records(bundle_id, lighting)
 end
 
+local function build_station_records(ctx)
+  if ctx == nil or ctx.spawn_bundle_graph_records == nil then
+    return {}
+  end
+  local defaults = WorldInit.world_defaults
+  local station = defaults.starter_station
+  if station == nil then
+    return {}
+  end
+  return ctx.spawn_bundle_graph_records(defaults.station_bundle_id or "station.outpost", station)
+end
+
 local function build_tactical_presentation_defaults_records()
   local defaults = WorldInit.world_defaults.tactical_presentation_defaults
   if defaults == nil or defaults.entity_id == nil then
@@ -605,6 +626,7 @@ function WorldInit.build_graph_records(ctx)
   append_records(records, build_asteroid_field_records(ctx))
   append_records(records, build_planet_records(ctx))
   append_records(records, build_environment_lighting_records(ctx))
+  append_records(records, build_station_records(ctx))
   append_records(records, build_tactical_presentation_defaults_records())
 
   return records